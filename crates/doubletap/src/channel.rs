@@ -1,4 +1,4 @@
-use crate::types::{Event, EventData};
+use crate::types::Event;
 use futures_util::{SinkExt, StreamExt};
 use jacquard_common::IntoStatic;
 use reqwest::header::{AUTHORIZATION, HeaderValue, USER_AGENT};
@@ -24,6 +24,27 @@ pub struct ChannelConnectionHandle {
     >,
     ack_tx: mpsc::UnboundedSender<u64>,
     semaphore: Arc<Semaphore>,
+    max_concurrent: NonZero<usize>,
+}
+
+/// Observes how many of a connection's handler permits are currently in use.
+#[derive(Debug, Clone)]
+pub struct ChannelPermits {
+    semaphore: Arc<Semaphore>,
+    max_concurrent: NonZero<usize>,
+}
+
+impl ChannelPermits {
+    /// The maximum number of handler tasks that can run at once.
+    pub fn max(&self) -> usize {
+        self.max_concurrent.get()
+    }
+
+    /// The number of permits currently held, including the one reserved for the next message.
+    pub fn in_use(&self) -> usize {
+        self.max()
+            .saturating_sub(self.semaphore.available_permits())
+    }
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -79,11 +100,20 @@ impl ChannelConnectionHandle {
             read,
             ack_tx,
             semaphore,
+            max_concurrent,
         })
     }
 
+    /// Obtain a handle for observing this connection's handler permit usage.
+    pub fn permits(&self) -> ChannelPermits {
+        ChannelPermits {
+            semaphore: self.semaphore.clone(),
+            max_concurrent: self.max_concurrent,
+        }
+    }
+
     pub async fn handler<
-        Handler: Fn(EventData<'static>) -> HandlerResult + Send + Sync + 'static,
+        Handler: Fn(Event<'static>) -> HandlerResult + Send + Sync + 'static,
         // Should eventually add the Error bound once handler functions in the gifdex ingester work with it.
         HandlerErr: std::fmt::Debug, /* + Error */
         HandlerResult: std::future::Future<Output = Result<(), HandlerErr>> + Send,
@@ -126,13 +156,14 @@ impl ChannelConnectionHandle {
                                 return;
                             }
                         };
-                        let result = handler(event.data).await;
+                        let id = event.id;
+                        let result = handler(event).await;
                         if result.is_ok() {
-                            if let Err(err) = ack_tx.send(event.id) {
-                                log::warn!("failed to queue ack for event {}: {err:?}", event.id);
+                            if let Err(err) = ack_tx.send(id) {
+                                log::warn!("failed to queue ack for event {id}: {err:?}");
                             }
                         } else if let Err(err) = result {
-                            log::warn!("event {} handler failed: {err:?}", event.id);
+                            log::warn!("event {id} handler failed: {err:?}");
                         }
                         drop(permit);
                     });
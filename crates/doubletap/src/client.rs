@@ -11,11 +11,11 @@ use jacquard_common::{
     types::{did::Did, did_doc::DidDocument},
 };
 use reqwest::{
-    Response,
+    RequestBuilder, Response,
     header::{AUTHORIZATION, HeaderMap, HeaderValue, InvalidHeaderValue},
 };
 use serde::Serialize;
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 use url::Url;
 
 #[derive(Debug, Clone)]
@@ -24,6 +24,17 @@ pub struct TapClient {
     http_client: reqwest::Client,
     base_url: Url,
     password: Option<String>,
+    request_headers: Option<RequestHeaders>,
+}
+
+/// Produces extra headers to send with each request, such as trace context.
+#[derive(Clone)]
+struct RequestHeaders(Arc<dyn Fn() -> HeaderMap + Send + Sync>);
+
+impl std::fmt::Debug for RequestHeaders {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestHeaders").finish_non_exhaustive()
+    }
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -46,6 +57,7 @@ impl TapClient {
         TapClientBuilder {
             base_url,
             password: None,
+            request_headers: None,
         }
     }
 
@@ -53,10 +65,24 @@ impl TapClient {
         &self.base_url
     }
 
+    fn get(&self, url: Url) -> RequestBuilder {
+        self.with_request_headers(self.http_client.get(url))
+    }
+
+    fn post(&self, url: Url) -> RequestBuilder {
+        self.with_request_headers(self.http_client.post(url))
+    }
+
+    fn with_request_headers(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.request_headers {
+            Some(RequestHeaders(headers)) => request.headers(headers()),
+            None => request,
+        }
+    }
+
     pub async fn health(&self) -> Result<(), TapRequestError> {
         log::debug!("fetching tap health status");
         let response = self
-            .http_client
             .get(self.base_url.join("/health").expect(
                 "constructing the endpoint url from the base url should always be possible",
             ))
@@ -74,7 +100,6 @@ impl TapClient {
     ) -> Result<DidDocument<'static>, TapRequestError> {
         log::debug!("resolving {did}");
         let response = self
-            .http_client
             .get(self.base_url.join(&format!("/resolve/{did}")).expect(
                 "constructing the endpoint url from the base url should always be possible",
             ))
@@ -91,7 +116,6 @@ impl TapClient {
     pub async fn repo_info(&self, did: &Did<'_>) -> Result<RepoInfo<'static>, TapRequestError> {
         log::debug!("fetching repo information for {did}");
         let response = self
-            .http_client
             .get(self.base_url.join(&format!("/info/{did}")).expect(
                 "constructing the endpoint url from the base url should always be possible",
             ))
@@ -113,7 +137,6 @@ impl TapClient {
         }
         let payload = Payload { dids };
         let response = self
-            .http_client
             .post(self.base_url.join("/repos/add").expect(
                 "constructing the endpoint url from the base url should always be possible",
             ))
@@ -134,7 +157,6 @@ impl TapClient {
         }
         let payload = Payload { dids };
         let response = self
-            .http_client
             .post(self.base_url.join("/repos/remove").expect(
                 "constructing the endpoint url from the base url should always be possible",
             ))
@@ -150,7 +172,6 @@ impl TapClient {
     pub async fn repo_count(&self) -> Result<RepoCountResponse, TapRequestError> {
         log::debug!("fetching tap tracked repository count");
         let response = self
-            .http_client
             .get(self.base_url.join("/stats/repo-count").expect(
                 "constructing the endpoint url from the base url should always be possible",
             ))
@@ -166,7 +187,6 @@ impl TapClient {
     pub async fn record_count(&self) -> Result<RecordCountResponse, TapRequestError> {
         log::debug!("fetching tap tracked record count");
         let response = self
-            .http_client
             .get(self.base_url.join("/stats/record-count").expect(
                 "constructing the endpoint url from the base url should always be possible",
             ))
@@ -182,7 +202,6 @@ impl TapClient {
     pub async fn outbox_buffer(&self) -> Result<OutboxBufferResponse, TapRequestError> {
         log::debug!("fetching event count in tap outbox buffer");
         let response = self
-            .http_client
             .get(self.base_url.join("/stats/outbox-buffer").expect(
                 "constructing the endpoint url from the base url should always be possible",
             ))
@@ -198,7 +217,6 @@ impl TapClient {
     pub async fn resync_buffer(&self) -> Result<ResyncBufferResponse, TapRequestError> {
         log::debug!("fetching event count in tap resync buffer");
         let response = self
-            .http_client
             .get(self.base_url.join("/stats/resync-buffer").expect(
                 "constructing the endpoint url from the base url should always be possible",
             ))
@@ -217,7 +235,7 @@ impl TapClient {
             .base_url
             .join("/stats/cursors")
             .expect("constructing the endpoint url from the base url should always be possible");
-        let response = self.http_client.get(url).send().await?;
+        let response = self.get(url).send().await?;
         if !response.status().is_success() {
             return Err(TapRequestError::ErrorResponse(response));
         }
@@ -249,6 +267,7 @@ impl TapClient {
 pub struct TapClientBuilder {
     base_url: Url,
     password: Option<String>,
+    request_headers: Option<RequestHeaders>,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -267,6 +286,17 @@ impl TapClientBuilder {
         self
     }
 
+    /// Add the headers returned by a function to every request, e.g. to propagate trace context.
+    ///
+    /// The function is called for each request in the context of the caller.
+    pub fn request_headers<F: Fn() -> HeaderMap + Send + Sync + 'static>(
+        mut self,
+        request_headers: F,
+    ) -> Self {
+        self.request_headers = Some(RequestHeaders(Arc::new(request_headers)));
+        self
+    }
+
     pub fn build(self) -> Result<TapClient, TapClientBuildError> {
         if !matches!(self.base_url.scheme(), "http" | "https") {
             return Err(TapClientBuildError::InvalidUrlScheme(
@@ -299,6 +329,7 @@ impl TapClientBuilder {
             http_client,
             base_url: self.base_url,
             password: self.password,
+            request_headers: self.request_headers,
         })
     }
 }
@@ -0,0 +1,70 @@
+use crate::{AppState, routes::xrpc::generic_error};
+use axum::http::StatusCode;
+use jacquard_axum::{XrpcErrorResponse, service_auth::VerifiedServiceAuth};
+use jacquard_common::{
+    IntoStatic,
+    types::{aturi::AtUri, ident::AtIdentifier},
+    xrpc::XrpcRequest,
+};
+
+/// An account, or one of its records, that can be taken down or reported.
+pub struct Subject<'a> {
+    pub did: &'a str,
+    pub collection: Option<&'a str>,
+    pub rkey: Option<&'a str>,
+}
+
+impl<'a> Subject<'a> {
+    /// Parse a subject from an at-uri.
+    ///
+    /// The authority must be a DID, and either both the collection and rkey are present or neither is.
+    pub fn parse(uri: &'a AtUri<'_>) -> Option<Self> {
+        let AtIdentifier::Did(did) = uri.authority() else {
+            return None;
+        };
+        match (uri.collection(), uri.rkey()) {
+            (None, None) => Some(Self {
+                did: did.as_str(),
+                collection: None,
+                rkey: None,
+            }),
+            (Some(collection), Some(rkey)) => Some(Self {
+                did: did.as_str(),
+                collection: Some(collection.as_str()),
+                rkey: Some(rkey.0.as_str()),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Build the at-uri of a stored subject.
+pub fn subject_uri(did: &str, collection: Option<&str>, rkey: Option<&str>) -> AtUri<'static> {
+    match (collection, rkey) {
+        (Some(collection), Some(rkey)) => AtUri::from_parts_owned(did, collection, rkey).unwrap(),
+        _ => AtUri::new_owned(format!("at://{did}")).unwrap(),
+    }
+}
+
+/// Reject the request unless it was authenticated by one of the configured admin DIDs.
+pub fn require_admin<R, E>(
+    state: &AppState,
+    auth: &VerifiedServiceAuth<'_>,
+) -> Result<(), XrpcErrorResponse<E>>
+where
+    R: XrpcRequest,
+    E: std::error::Error + IntoStatic + serde::Serialize,
+{
+    if state
+        .admin_dids
+        .iter()
+        .any(|did| did == auth.did().as_str())
+    {
+        return Ok(());
+    }
+    Err(generic_error::<R, E>(
+        StatusCode::FORBIDDEN,
+        "Forbidden",
+        "Authenticated account is not an admin",
+    ))
+}
@@ -1,39 +1,74 @@
-use jacquard_common::types::{cid::Cid, did::Did, string::Rkey};
+use gifdex_lexicons::net_gifdex::feed::PostViewMediaVariant;
+use hmac::{Hmac, Mac};
+use jacquard_common::types::{cid::Cid, did::Did, string::Rkey, uri::Uri};
 use reqwest::Url;
+use sha2::Sha256;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 pub struct CdnClient {
     base_url: Url,
+    signing: Option<CdnSigning>,
+}
+
+/// Configuration for signing CDN urls so that they can only be used for a limited time.
+pub struct CdnSigning {
+    /// Secret shared with the CDN.
+    pub key: String,
+    /// Minimum amount of time a signed url remains valid for.
+    pub ttl: Duration,
+}
+
+/// Sizes that avatars are served at, matching the sizes the CDN can resize to.
+#[derive(Debug, Clone, Copy)]
+pub enum AvatarSize {
+    /// For avatars shown next to content, such as in a `ProfileViewBasic`.
+    Small,
+    /// For avatars shown on a profile, such as in a `ProfileView`.
+    Large,
+}
+
+impl AvatarSize {
+    fn pixels(self) -> u32 {
+        match self {
+            Self::Small => 128,
+            Self::Large => 512,
+        }
+    }
 }
 
 pub enum CdnMediaType<'a> {
     Avatar {
         did: &'a Did<'a>,
         cid: &'a Cid<'a>,
+        size: AvatarSize,
     },
     PostMedia {
         did: &'a Did<'a>,
         rkey: &'a Rkey<'a>,
         thumbnail: bool,
+        /// Request a specific encoding instead of letting the CDN negotiate one.
+        format: Option<&'a str>,
     },
 }
 
 impl CdnClient {
-    pub fn new(base_url: Url) -> Self {
-        Self { base_url }
+    pub fn new(base_url: Url, signing: Option<CdnSigning>) -> Self {
+        Self { base_url, signing }
     }
 
     pub fn make_cdn_url(&self, media_type: CdnMediaType) -> Url {
-        match media_type {
-            CdnMediaType::Avatar { did, cid } => self
+        let url = match media_type {
+            CdnMediaType::Avatar { did, cid, size } => self
                 .base_url
-                .join(&format!("/avatar/{}/{}", did, cid))
+                .join(&format!("/avatar/{}/{}?size={}", did, cid, size.pixels()))
                 .expect("avatar url construction should never fail"),
             CdnMediaType::PostMedia {
                 did,
                 rkey: tid,
                 thumbnail,
+                format,
             } => {
-                if thumbnail {
+                let mut url = if thumbnail {
                     self.base_url
                         .join(&format!("/media/{}/{}", did, tid))
                         .expect("media url construction should never fail")
@@ -41,8 +76,77 @@ impl CdnClient {
                     self.base_url
                         .join(&format!("/media/{}/{}", did, tid))
                         .expect("media url construction should never fail")
+                };
+                if let Some(format) = format {
+                    url.query_pairs_mut().append_pair("format", format);
                 }
+                url
             }
-        }
+        };
+        self.sign_url(url)
+    }
+
+    /// Append an expiry time and signature to a url if signing is enabled.
+    ///
+    /// Expiry times are rounded to the signing ttl so that the same url is handed out for
+    /// a while, allowing clients and proxies to cache the media.
+    fn sign_url(&self, mut url: Url) -> Url {
+        let Some(signing) = &self.signing else {
+            return url;
+        };
+        let ttl = signing.ttl.as_secs().max(1);
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system time should be after the unix epoch")
+            .as_secs();
+        let expires = (now / ttl + 2) * ttl;
+        let mut mac = Hmac::<Sha256>::new_from_slice(signing.key.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(format!("{}:{}", url.path(), expires).as_bytes());
+        let signature = hex::encode(mac.finalize().into_bytes());
+        url.query_pairs_mut()
+            .append_pair("expires", &expires.to_string())
+            .append_pair("sig", &signature);
+        url
+    }
+
+    /// List every encoding of a post's media that the CDN is able to serve.
+    ///
+    /// `known_sizes` maps mime-types to the sizes the CDN has recorded after serving them.
+    pub fn make_media_variants(
+        &self,
+        did: &Did,
+        rkey: &Rkey,
+        source_mime_type: &str,
+        known_sizes: &[(String, i64)],
+    ) -> Vec<PostViewMediaVariant<'static>> {
+        let formats: &[(&str, &str)] = match source_mime_type {
+            "image/gif" => &[("gif", "image/gif"), ("webp", "image/webp")],
+            "image/webp" => &[("webp", "image/webp")],
+            _ => &[],
+        };
+        formats
+            .iter()
+            .map(|(format, mime_type)| {
+                PostViewMediaVariant::new()
+                    .url(
+                        Uri::new_owned(self.make_cdn_url(CdnMediaType::PostMedia {
+                            did,
+                            rkey,
+                            thumbnail: false,
+                            format: Some(format),
+                        }))
+                        .unwrap(),
+                    )
+                    .mime_type(*mime_type)
+                    .size(
+                        known_sizes
+                            .iter()
+                            .find(|(known, _)| known == mime_type)
+                            .map(|(_, size)| *size),
+                    )
+                    .build()
+            })
+            .collect()
     }
 }
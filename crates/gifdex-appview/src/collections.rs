@@ -0,0 +1,54 @@
+use crate::{AppState, posts::author_view};
+use gifdex_lexicons::net_gifdex::feed::{self, CollectionView};
+use jacquard_common::{
+    chrono::{TimeZone, Utc},
+    types::{aturi::AtUri, collection::Collection as _, did::Did},
+};
+
+/// A collection as stored in the database, along with its author's profile.
+pub struct Collection {
+    pub did: String,
+    pub rkey: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub ordering: String,
+    pub item_count: i64,
+    pub created_at: i64,
+    pub indexed_at: i64,
+    pub display_name: Option<String>,
+    pub handle: Option<String>,
+    pub avatar_blob_cid: Option<String>,
+}
+
+impl Collection {
+    pub fn into_view(self, state: &AppState) -> CollectionView<'static> {
+        let did = Did::new_owned(&self.did).unwrap();
+        CollectionView::new()
+            .uri(
+                AtUri::from_parts_owned(&self.did, feed::collection::Collection::NSID, &self.rkey)
+                    .unwrap(),
+            )
+            .author(author_view(
+                state,
+                did,
+                self.handle,
+                self.display_name,
+                self.avatar_blob_cid,
+            ))
+            .title(self.title)
+            .description(self.description.map(|s| s.into()))
+            .ordering(self.ordering)
+            .item_count(self.item_count)
+            .created_at(
+                Utc.timestamp_millis_opt(self.created_at)
+                    .unwrap()
+                    .fixed_offset(),
+            )
+            .indexed_at(
+                Utc.timestamp_millis_opt(self.indexed_at)
+                    .unwrap()
+                    .fixed_offset(),
+            )
+            .build()
+    }
+}
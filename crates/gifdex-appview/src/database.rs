@@ -1,6 +1,7 @@
 use anyhow::Result;
-use sqlx::{PgExecutor, PgPool, migrate, postgres::PgPoolOptions};
+use sqlx::{PgExecutor, PgPool, PgTransaction, migrate, postgres::PgPoolOptions};
 
+#[derive(Clone)]
 pub struct Database {
     pool: PgPool,
 }
@@ -15,8 +16,23 @@ impl Database {
         Ok(Self { pool })
     }
 
+    /// Use an existing pool, which must already have had migrations applied.
+    pub fn from_pool(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
     /// Obtain the executor for the database pool.
     pub fn executor(&self) -> impl PgExecutor<'_> + Copy {
         &self.pool
     }
+
+    /// Number of connections currently open in the pool, and how many of those are idle.
+    pub fn connection_counts(&self) -> (u32, usize) {
+        (self.pool.size(), self.pool.num_idle())
+    }
+
+    /// Create a new transaction using the database pool.
+    pub async fn transaction(&self) -> Result<PgTransaction<'_>> {
+        Ok(self.pool.begin().await?)
+    }
 }
@@ -0,0 +1,7 @@
+use jacquard_common::types::string::Handle;
+
+/// Parse a handle from `account_profiles`, showing anything that isn't a valid handle as
+/// `handle.invalid` instead of failing the request.
+pub fn parse_handle(handle: String) -> Handle<'static> {
+    Handle::new_owned(handle).unwrap_or_else(|_| Handle::new("handle.invalid").unwrap())
+}
@@ -0,0 +1,51 @@
+use crate::database::Database;
+use anyhow::Result;
+use gifdex_lexicons::net_gifdex::{feed::SelfLabelView, labeler::rule::Rule};
+use jacquard_common::types::{aturi::AtUri, collection::Collection};
+use sqlx::query;
+use std::collections::HashMap;
+
+/// Self-labels applied by authors to their own posts, keyed by post DID and rkey.
+pub type SelfLabels = HashMap<(String, String), Vec<SelfLabelView<'static>>>;
+
+/// Fetch the self-labels of the given posts in a single query.
+///
+/// Only rules their labeler marked as `selfLabel` are returned, and only annotate rules, as
+/// takedowns can't be self-applied.
+pub async fn fetch_self_labels<'a>(
+    database: &Database,
+    posts: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> Result<SelfLabels> {
+    let (post_dids, post_rkeys): (Vec<&str>, Vec<&str>) = posts.into_iter().unzip();
+    let rows = query!(
+        r#"SELECT sl.post_did, sl.post_rkey, r.did, r.rkey, r.name, r.description,
+           r.adult_content as "adult_content!", r.default_setting::TEXT as "default_setting!"
+         FROM post_self_labels sl
+         INNER JOIN UNNEST($1::TEXT[], $2::TEXT[]) AS p(did, rkey)
+           ON p.did = sl.post_did AND p.rkey = sl.post_rkey
+         INNER JOIN labeler_rules r ON r.did = sl.rule_did AND r.rkey = sl.rule_rkey
+         WHERE r.self_label AND r.behaviour = 'annotate'
+         ORDER BY r.name"#,
+        &post_dids as &[&str],
+        &post_rkeys as &[&str]
+    )
+    .fetch_all(database.executor())
+    .await?;
+
+    let mut self_labels = SelfLabels::new();
+    for row in rows {
+        self_labels
+            .entry((row.post_did, row.post_rkey))
+            .or_default()
+            .push(
+                SelfLabelView::new()
+                    .rule(AtUri::from_parts_owned(&row.did, Rule::NSID, &row.rkey)?)
+                    .name(row.name)
+                    .description(row.description)
+                    .adult_content(row.adult_content)
+                    .default_setting(row.default_setting)
+                    .build(),
+            );
+    }
+    Ok(self_labels)
+}
@@ -0,0 +1,232 @@
+pub mod admin;
+pub mod cdn;
+pub mod collections;
+pub mod database;
+pub mod handles;
+pub mod labels;
+pub mod posts;
+pub mod reports;
+pub mod routes;
+pub mod telemetry;
+
+use crate::{
+    cdn::CdnClient,
+    routes::{
+        handle_index,
+        well_known::handle_well_known_did,
+        xrpc::{
+            com_atproto::sync::handle_get_repo_status,
+            health::handle_health,
+            net_gifdex::{
+                actor::{handle_get_ingest_status, handle_get_profile, handle_get_profiles},
+                admin::{
+                    handle_get_subject_status, handle_list_takedowns, handle_reverse_takedown,
+                    handle_takedown_subject,
+                },
+                feed::{
+                    handle_get_actor_collections, handle_get_collection,
+                    handle_get_collections_for_post, handle_get_post, handle_get_posts_by_actor,
+                    handle_get_posts_by_query, handle_get_similar_posts, handle_get_timeline,
+                },
+                graph::{handle_get_mutes, handle_mute_actor, handle_unmute_actor},
+                moderation::{
+                    handle_create_report, handle_list_reports, handle_update_report_status,
+                },
+            },
+        },
+    },
+};
+use axum::{
+    Router,
+    extract::Request,
+    http::{HeaderValue, Method, header},
+    middleware::{self as axum_middleware, Next},
+    routing::get,
+};
+use database::Database;
+use gifdex_lexicons::net_gifdex::{
+    actor::{
+        get_ingest_status::GetIngestStatusRequest, get_profile::GetProfileRequest,
+        get_profiles::GetProfilesRequest,
+    },
+    admin::{
+        get_subject_status::GetSubjectStatusRequest, list_takedowns::ListTakedownsRequest,
+        reverse_takedown::ReverseTakedownRequest, takedown_subject::TakedownSubjectRequest,
+    },
+    feed::{
+        get_actor_collections::GetActorCollectionsRequest, get_collection::GetCollectionRequest,
+        get_collections_for_post::GetCollectionsForPostRequest, get_post::GetPostRequest,
+        get_posts_by_actor::GetPostsByActorRequest, get_posts_by_query::GetPostsByQueryRequest,
+        get_similar_posts::GetSimilarPostsRequest, get_timeline::GetTimelineRequest,
+    },
+    graph::{
+        get_mutes::GetMutesRequest, mute_actor::MuteActorRequest, unmute_actor::UnmuteActorRequest,
+    },
+    moderation::{
+        create_report::CreateReportRequest, list_reports::ListReportsRequest,
+        update_report_status::UpdateReportStatusRequest,
+    },
+};
+use jacquard_api::com_atproto::sync::get_repo_status::GetRepoStatusRequest;
+use jacquard_axum::{
+    IntoRouter,
+    service_auth::{ServiceAuth, ServiceAuthConfig},
+};
+use jacquard_common::{
+    Data, IntoStatic,
+    types::{
+        did::Did,
+        did_doc::{self, DidDocument, Service},
+        string::AtprotoStr,
+    },
+    url::Url,
+};
+use jacquard_identity::JacquardResolver;
+use std::{collections::BTreeMap, sync::Arc};
+use tower_http::{
+    catch_panic::CatchPanicLayer,
+    cors::{Any, CorsLayer},
+    normalize_path::NormalizePathLayer,
+    trace::{self, DefaultOnFailure, DefaultOnRequest, DefaultOnResponse, TraceLayer},
+};
+use tracing::Level;
+
+#[derive(Clone)]
+pub struct AppState(pub Arc<AppStateInner>);
+
+pub struct AppStateInner {
+    pub database: Database,
+    pub cdn: CdnClient,
+    pub service_did_document: DidDocument<'static>,
+    pub service_auth_config: ServiceAuthConfig<JacquardResolver>,
+    /// Accounts allowed to use the `net.gifdex.admin.*` endpoints.
+    pub admin_dids: Vec<String>,
+    /// Maximum number of reports an account can make per hour.
+    pub report_rate_limit: u32,
+}
+
+impl std::ops::Deref for AppState {
+    type Target = AppStateInner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl ServiceAuth for AppState {
+    type Resolver = JacquardResolver;
+
+    fn service_did(&self) -> &Did<'_> {
+        self.service_auth_config.service_did()
+    }
+
+    fn resolver(&self) -> &Self::Resolver {
+        self.service_auth_config.resolver()
+    }
+
+    fn require_lxm(&self) -> bool {
+        ServiceAuth::require_lxm(&self.service_auth_config)
+    }
+}
+
+/// Build the AppView's router, serving every route with the given state.
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/", get(handle_index))
+        .route("/.well-known/did.json", get(handle_well_known_did))
+        // Xrpc
+        .route("/xrpc/_health", get(handle_health))
+        // AtProto Sync
+        .merge(GetRepoStatusRequest::into_router(handle_get_repo_status))
+        // Gifdex Actor
+        .merge(GetProfileRequest::into_router(handle_get_profile))
+        .merge(GetProfilesRequest::into_router(handle_get_profiles))
+        .merge(GetIngestStatusRequest::into_router(
+            handle_get_ingest_status,
+        ))
+        // Gifdex Feed
+        .merge(GetPostRequest::into_router(handle_get_post))
+        .merge(GetPostsByQueryRequest::into_router(
+            handle_get_posts_by_query,
+        ))
+        .merge(GetPostsByActorRequest::into_router(
+            handle_get_posts_by_actor,
+        ))
+        .merge(GetSimilarPostsRequest::into_router(
+            handle_get_similar_posts,
+        ))
+        .merge(GetTimelineRequest::into_router(handle_get_timeline))
+        .merge(GetCollectionRequest::into_router(handle_get_collection))
+        .merge(GetActorCollectionsRequest::into_router(
+            handle_get_actor_collections,
+        ))
+        .merge(GetCollectionsForPostRequest::into_router(
+            handle_get_collections_for_post,
+        ))
+        // Gifdex Graph
+        .merge(MuteActorRequest::into_router(handle_mute_actor))
+        .merge(UnmuteActorRequest::into_router(handle_unmute_actor))
+        .merge(GetMutesRequest::into_router(handle_get_mutes))
+        // Gifdex Admin
+        .merge(TakedownSubjectRequest::into_router(handle_takedown_subject))
+        .merge(ReverseTakedownRequest::into_router(handle_reverse_takedown))
+        .merge(GetSubjectStatusRequest::into_router(
+            handle_get_subject_status,
+        ))
+        .merge(ListTakedownsRequest::into_router(handle_list_takedowns))
+        // Gifdex Moderation
+        .merge(CreateReportRequest::into_router(handle_create_report))
+        .merge(ListReportsRequest::into_router(handle_list_reports))
+        .merge(UpdateReportStatusRequest::into_router(
+            handle_update_report_status,
+        ))
+        .layer(axum_middleware::from_fn(telemetry::metrics_middleware))
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(trace::DefaultMakeSpan::new().level(Level::INFO))
+                .on_request(DefaultOnRequest::default().level(Level::INFO))
+                .on_response(DefaultOnResponse::default().level(Level::INFO))
+                .on_failure(DefaultOnFailure::default().level(Level::ERROR)),
+        )
+        .layer(NormalizePathLayer::trim_trailing_slash())
+        .layer(CatchPanicLayer::new()) // TODO: Use custom panic handler to return Xrpc InternalServerError.
+        .layer(
+            CorsLayer::new()
+                .allow_origin("*".parse::<HeaderValue>().unwrap())
+                .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
+                .allow_headers(Any),
+        )
+        .layer(axum_middleware::from_fn(
+            async |req: Request, next: Next| {
+                let mut res = next.run(req).await;
+                let res_headers = res.headers_mut();
+                res_headers.insert(
+                    header::SERVER,
+                    HeaderValue::from_static(env!("CARGO_PKG_NAME")),
+                );
+                res_headers.insert("X-Robots-Tag", HeaderValue::from_static("none"));
+                res
+            },
+        ))
+        .with_state(state)
+}
+
+/// Build the `did:web` document advertising this AppView's service endpoint.
+pub fn build_service_did_doc(did: &Did<'_>, url: &Url) -> DidDocument<'static> {
+    DidDocument::new()
+        .context(did_doc::default_context())
+        .id(did.clone())
+        .service(vec![
+            Service::new()
+                .id("#gifdex_appview".into())
+                .r#type("GifdexAppView".into())
+                .service_endpoint(Data::String(AtprotoStr::String(
+                    url.as_str().trim_end_matches("/").into(),
+                )))
+                .extra_data(BTreeMap::default())
+                .build(),
+        ])
+        .extra_data(BTreeMap::default())
+        .build()
+        .into_static()
+}
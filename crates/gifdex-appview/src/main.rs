@@ -1,99 +1,18 @@
-mod cdn;
-mod database;
-mod routes;
-
-use crate::{
-    cdn::CdnClient,
-    routes::{
-        handle_index,
-        well_known::handle_well_known_did,
-        xrpc::{
-            com_atproto::sync::handle_get_repo_status,
-            health::handle_health,
-            net_gifdex::{
-                actor::{handle_get_profile, handle_get_profiles},
-                feed::{handle_get_post, handle_get_posts_by_actor, handle_get_posts_by_query},
-            },
-        },
-    },
-};
 use anyhow::{Context, Result};
-use axum::{
-    Router,
-    extract::Request,
-    http::{HeaderValue, Method, header},
-    middleware::{self as axum_middleware, Next},
-    routing::get,
-};
 use clap::Parser;
-use database::Database;
 use dotenvy::dotenv;
-use gifdex_lexicons::net_gifdex::{
-    actor::{get_profile::GetProfileRequest, get_profiles::GetProfilesRequest},
-    feed::{
-        get_post::GetPostRequest, get_posts_by_actor::GetPostsByActorRequest,
-        get_posts_by_query::GetPostsByQueryRequest,
-    },
-};
-use jacquard_api::com_atproto::sync::get_repo_status::GetRepoStatusRequest;
-use jacquard_axum::{
-    IntoRouter,
-    service_auth::{ServiceAuth, ServiceAuthConfig},
-};
-use jacquard_common::{
-    Data, IntoStatic,
-    types::{
-        did::Did,
-        did_doc::{self, DidDocument, Service},
-        string::AtprotoStr,
-    },
-    url::Url,
+use gifdex_appview::{
+    AppState, AppStateInner, build_service_did_doc,
+    cdn::{CdnClient, CdnSigning},
+    database::Database,
+    router, telemetry,
 };
+use jacquard_axum::service_auth::ServiceAuthConfig;
+use jacquard_common::{types::did::Did, url::Url};
 use jacquard_identity::{JacquardResolver, resolver::ResolverOptions};
-use std::{collections::BTreeMap, net::SocketAddr, sync::Arc};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 use tokio::{net::TcpListener, signal};
-use tower_http::{
-    catch_panic::CatchPanicLayer,
-    cors::{Any, CorsLayer},
-    normalize_path::NormalizePathLayer,
-    trace::{self, DefaultOnFailure, DefaultOnRequest, DefaultOnResponse, TraceLayer},
-};
-use tracing::{Level, info};
-use tracing_subscriber::EnvFilter;
-
-#[derive(Clone)]
-struct AppState(Arc<AppStateInner>);
-
-struct AppStateInner {
-    database: Database,
-    cdn: CdnClient,
-    service_did_document: DidDocument<'static>,
-    service_auth_config: ServiceAuthConfig<JacquardResolver>,
-}
-
-impl std::ops::Deref for AppState {
-    type Target = AppStateInner;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl ServiceAuth for AppState {
-    type Resolver = JacquardResolver;
-
-    fn service_did(&self) -> &Did<'_> {
-        self.service_auth_config.service_did()
-    }
-
-    fn resolver(&self) -> &Self::Resolver {
-        self.service_auth_config.resolver()
-    }
-
-    fn require_lxm(&self) -> bool {
-        ServiceAuth::require_lxm(&self.service_auth_config)
-    }
-}
+use tracing::info;
 
 #[derive(Debug, Clone, Parser)]
 #[clap(author, about, version)]
@@ -106,6 +25,20 @@ struct LaunchArguments {
     )]
     address: SocketAddr,
 
+    /// Local socket address to serve Prometheus metrics on.
+    ///
+    /// This is kept separate from the main address so metrics are never exposed publicly.
+    #[arg(
+        long = "admin-address",
+        env = "GIFDEX_APPVIEW_ADMIN_ADDRESS",
+        default_value = "127.0.0.1:9256"
+    )]
+    admin_address: SocketAddr,
+
+    /// OTLP/HTTP endpoint to export tracing spans to, e.g. `http://localhost:4318/v1/traces`.
+    #[arg(long = "otlp-endpoint", env = "GIFDEX_APPVIEW_OTLP_ENDPOINT")]
+    otlp_endpoint: Option<Url>,
+
     /// Postgres database to use for AppView data storage.
     ///
     /// This should be the same database used for all other services that read/write application data.
@@ -122,20 +55,54 @@ struct LaunchArguments {
     #[arg(long = "cdn", env = "GIFDEX_APPVIEW_CDN")]
     cdn: Url,
 
+    /// Secret key shared with the CDN for signing media urls.
+    ///
+    /// When unset, media urls are not signed.
+    #[arg(
+        long = "cdn-signing-key",
+        env = "GIFDEX_APPVIEW_CDN_SIGNING_KEY",
+        hide_env_values = true
+    )]
+    cdn_signing_key: Option<String>,
+
+    /// Minimum number of seconds that a signed media url remains valid for.
+    #[arg(
+        long = "cdn-signature-ttl",
+        env = "GIFDEX_APPVIEW_CDN_SIGNATURE_TTL",
+        default_value_t = 3600
+    )]
+    cdn_signature_ttl: u64,
+
     /// Host that this AppView will reachable by.
     ///
     /// Used for generating a `well-known/did.json` document, `did:web` identity and a AppView service endpoint.
     #[arg(long = "host", env = "GIFDEX_APPVIEW_HOST")]
     host: Url,
+
+    /// DIDs of accounts allowed to issue operator takedowns, comma separated.
+    #[arg(
+        long = "admin-dids",
+        env = "GIFDEX_APPVIEW_ADMIN_DIDS",
+        value_delimiter = ','
+    )]
+    admin_dids: Vec<String>,
+
+    /// Maximum number of reports an account can make per hour.
+    #[arg(
+        long = "report-rate-limit",
+        env = "GIFDEX_APPVIEW_REPORT_RATE_LIMIT",
+        default_value_t = 10
+    )]
+    report_rate_limit: u32,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     dotenv().ok();
-    tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("info")))
-        .init();
     let args = LaunchArguments::parse();
+    let tracer_provider =
+        gifdex_telemetry::init_tracing(env!("CARGO_PKG_NAME"), args.otlp_endpoint.as_ref())
+            .context("failed to initialise tracing")?;
 
     // Create ATProto service information.
     let service_did = Did::new_owned(format!(
@@ -155,70 +122,42 @@ async fn main() -> Result<()> {
     let database = Database::new(&args.database_url)
         .await
         .context("failed to connect to database")?;
-    let cdn = CdnClient::new(args.cdn);
+    telemetry::install(args.admin_address).context("failed to initialise metrics")?;
+    tokio::spawn(telemetry::poll_database_pool(
+        database.clone(),
+        Duration::from_secs(15),
+    ));
+    let cdn = CdnClient::new(
+        args.cdn,
+        args.cdn_signing_key.map(|key| CdnSigning {
+            key,
+            ttl: Duration::from_secs(args.cdn_signature_ttl),
+        }),
+    );
 
     // Start server.
-    let router = Router::new()
-        .route("/", get(handle_index))
-        .route("/.well-known/did.json", get(handle_well_known_did))
-        // Xrpc
-        .route("/xrpc/_health", get(handle_health))
-        // AtProto Sync
-        .merge(GetRepoStatusRequest::into_router(handle_get_repo_status))
-        // Gifdex Actor
-        .merge(GetProfileRequest::into_router(handle_get_profile))
-        .merge(GetProfilesRequest::into_router(handle_get_profiles))
-        // Gifdex Feed
-        .merge(GetPostRequest::into_router(handle_get_post))
-        .merge(GetPostsByQueryRequest::into_router(
-            handle_get_posts_by_query,
-        ))
-        .merge(GetPostsByActorRequest::into_router(
-            handle_get_posts_by_actor,
-        ))
-        // Gifdex Moderation
-        .layer(
-            TraceLayer::new_for_http()
-                .make_span_with(trace::DefaultMakeSpan::new().level(Level::INFO))
-                .on_request(DefaultOnRequest::default().level(Level::INFO))
-                .on_response(DefaultOnResponse::default().level(Level::INFO))
-                .on_failure(DefaultOnFailure::default().level(Level::ERROR)),
-        )
-        .layer(NormalizePathLayer::trim_trailing_slash())
-        .layer(CatchPanicLayer::new()) // TODO: Use custom panic handler to return Xrpc InternalServerError.
-        .layer(
-            CorsLayer::new()
-                .allow_origin("*".parse::<HeaderValue>().unwrap())
-                .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
-                .allow_headers(Any),
-        )
-        .layer(axum_middleware::from_fn(
-            async |req: Request, next: Next| {
-                let mut res = next.run(req).await;
-                let res_headers = res.headers_mut();
-                res_headers.insert(
-                    header::SERVER,
-                    HeaderValue::from_static(env!("CARGO_PKG_NAME")),
-                );
-                res_headers.insert("X-Robots-Tag", HeaderValue::from_static("none"));
-                res
-            },
-        ))
-        .with_state(AppState(Arc::new(AppStateInner {
-            database,
-            cdn,
-            service_did_document: service_did_doc,
-            service_auth_config,
-        })));
+    let router = router(AppState(Arc::new(AppStateInner {
+        database,
+        cdn,
+        service_did_document: service_did_doc,
+        service_auth_config,
+        admin_dids: args.admin_dids,
+        report_rate_limit: args.report_rate_limit,
+    })));
 
     let tcp_listener = TcpListener::bind(args.address).await?;
     info!(
         "Internal server started - listening on: http://{}",
         args.address,
     );
+    info!(
+        "Metrics server started - listening on: http://{}/metrics",
+        args.admin_address,
+    );
     axum::serve(tcp_listener, router)
         .with_graceful_shutdown(shutdown_signal())
         .await?;
+    gifdex_telemetry::shutdown_tracing(tracer_provider);
 
     Ok(())
 }
@@ -246,22 +185,3 @@ async fn shutdown_signal() {
         _ = terminate => {},
     }
 }
-
-fn build_service_did_doc(did: &Did<'_>, url: &Url) -> DidDocument<'static> {
-    DidDocument::new()
-        .context(did_doc::default_context())
-        .id(did.clone())
-        .service(vec![
-            Service::new()
-                .id("#gifdex_appview".into())
-                .r#type("GifdexAppView".into())
-                .service_endpoint(Data::String(AtprotoStr::String(
-                    url.as_str().trim_end_matches("/").into(),
-                )))
-                .extra_data(BTreeMap::default())
-                .build(),
-        ])
-        .extra_data(BTreeMap::default())
-        .build()
-        .into_static()
-}
@@ -0,0 +1,203 @@
+use crate::{
+    AppState,
+    cdn::{AvatarSize, CdnMediaType},
+    handles::parse_handle,
+    labels,
+};
+use anyhow::Result;
+use gifdex_lexicons::net_gifdex::{
+    actor::ProfileViewBasic,
+    feed::{
+        PostFeedView, PostView, PostViewMedia, PostViewMediaDimensions, ViewerState, post::Post,
+    },
+};
+use jacquard_common::{
+    chrono::{TimeZone, Utc},
+    types::{aturi::AtUri, collection::Collection, did::Did, string::Rkey, tid::Tid, uri::Uri},
+};
+use sqlx::query;
+
+/// Build the view of an account from the columns of `account_profiles`.
+pub fn author_view(
+    state: &AppState,
+    did: Did<'static>,
+    handle: Option<String>,
+    display_name: Option<String>,
+    avatar_blob_cid: Option<String>,
+) -> ProfileViewBasic<'static> {
+    let avatar = avatar_blob_cid.and_then(|blob_cid| {
+        Uri::new_owned(state.cdn.make_cdn_url(CdnMediaType::Avatar {
+            did: &did,
+            cid: &blob_cid.parse().ok()?,
+            size: AvatarSize::Small,
+        }))
+        .ok()
+    });
+    ProfileViewBasic::new()
+        .did(did)
+        .handle(handle.map(parse_handle))
+        .display_name(display_name.map(|s| s.into()))
+        .avatar(avatar)
+        .build()
+}
+
+/// Fetch the given posts and build their views in a single query, keeping the order they were
+/// given in.
+///
+/// Visibility isn't checked here, so callers should only pass posts they've already filtered
+/// for the viewer. Posts that no longer exist are skipped.
+pub async fn hydrate_posts<'a>(
+    state: &AppState,
+    posts: impl IntoIterator<Item = (&'a str, &'a str)>,
+    viewer: Option<&str>,
+) -> Result<Vec<PostView<'static>>> {
+    let (post_dids, post_rkeys): (Vec<&str>, Vec<&str>) = posts.into_iter().unzip();
+    let rows = query!(
+        r#"SELECT
+          p.did, ap.handle, ap.display_name, ap.avatar_blob_cid,
+          p.rkey, p.title, p.tags, p.media_blob_mime,
+          p.media_blob_alt, p.media_blob_width, p.media_blob_height, p.media_blob_size,
+          p.media_frame_count, p.media_duration_ms, p.created_at,
+          p.edited_at, p.indexed_at as post_indexed_at,
+          ARRAY(SELECT mv.mime_type FROM media_variants mv
+             WHERE mv.cid = p.media_blob_cid ORDER BY mv.mime_type) as "variant_mime_types!",
+          ARRAY(SELECT mv.size FROM media_variants mv
+             WHERE mv.cid = p.media_blob_cid ORDER BY mv.mime_type) as "variant_sizes!",
+          (SELECT COUNT(*) FROM post_favourites
+             WHERE post_did = p.did AND post_rkey = p.rkey) as "favourite_count!",
+          (SELECT pf.rkey FROM post_favourites pf
+           WHERE pf.post_did = p.did AND pf.post_rkey = p.rkey AND pf.did = $3
+           LIMIT 1) as "favourite_rkey"
+         FROM UNNEST($1::TEXT[], $2::TEXT[]) WITH ORDINALITY AS k(did, rkey, position)
+         INNER JOIN posts p ON p.did = k.did AND p.rkey = k.rkey
+         INNER JOIN account_profiles ap ON ap.did = p.did
+         ORDER BY k.position"#,
+        &post_dids as &[&str],
+        &post_rkeys as &[&str],
+        viewer
+    )
+    .fetch_all(state.database.executor())
+    .await?;
+
+    let mut self_labels = labels::fetch_self_labels(
+        &state.database,
+        rows.iter().map(|row| (row.did.as_str(), row.rkey.as_str())),
+    )
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|post| {
+            let did = Did::new_owned(&post.did).unwrap();
+            let rkey = Rkey::new(&post.rkey).unwrap();
+            let variant_sizes: Vec<(String, i64)> = post
+                .variant_mime_types
+                .into_iter()
+                .zip(post.variant_sizes)
+                .collect();
+            PostView::new()
+                .uri(AtUri::from_parts_owned(&post.did, Post::NSID, &post.rkey).unwrap())
+                .title(post.title)
+                .tags(
+                    post.tags
+                        .map(|tags| tags.into_iter().map(|t| t.into()).collect()),
+                )
+                .media(
+                    PostViewMedia::new()
+                        .fullsize_url(
+                            Uri::new_owned(state.cdn.make_cdn_url(CdnMediaType::PostMedia {
+                                did: &did,
+                                rkey: &rkey,
+                                thumbnail: false,
+                                format: None,
+                            }))
+                            .unwrap(),
+                        )
+                        .thumbnail_url(
+                            Uri::new_owned(state.cdn.make_cdn_url(CdnMediaType::PostMedia {
+                                did: &did,
+                                rkey: &rkey,
+                                thumbnail: true,
+                                format: None,
+                            }))
+                            .unwrap(),
+                        )
+                        .variants(state.cdn.make_media_variants(
+                            &did,
+                            &rkey,
+                            &post.media_blob_mime,
+                            &variant_sizes,
+                        ))
+                        .mime_type(post.media_blob_mime)
+                        .alt(post.media_blob_alt.map(|s| s.into()))
+                        .dimensions(
+                            PostViewMediaDimensions::new()
+                                .height(post.media_blob_height)
+                                .width(post.media_blob_width)
+                                .build(),
+                        )
+                        .size(post.media_blob_size)
+                        .frame_count(post.media_frame_count.map(i64::from))
+                        .duration(post.media_duration_ms)
+                        .build(),
+                )
+                .favourite_count(post.favourite_count)
+                .self_labels(self_labels.remove(&(post.did.clone(), post.rkey.clone())))
+                .author(author_view(
+                    state,
+                    did.clone(),
+                    post.handle,
+                    post.display_name,
+                    post.avatar_blob_cid,
+                ))
+                .viewer(ViewerState {
+                    favourite: post
+                        .favourite_rkey
+                        .as_ref()
+                        .map(|rkey| Tid::new(rkey).unwrap()),
+                    ..Default::default()
+                })
+                .created_at(
+                    Utc.timestamp_millis_opt(post.created_at)
+                        .unwrap()
+                        .fixed_offset(),
+                )
+                .edited_at(
+                    post.edited_at
+                        .map(|e| Utc.timestamp_millis_opt(e).unwrap().fixed_offset().into()),
+                )
+                .indexed_at(
+                    Utc.timestamp_millis_opt(post.post_indexed_at)
+                        .unwrap()
+                        .fixed_offset(),
+                )
+                .build()
+        })
+        .collect())
+}
+
+/// Hydrate posts for a feed, which lists them with the same fields as a single post.
+pub async fn hydrate_feed<'a>(
+    state: &AppState,
+    posts: impl IntoIterator<Item = (&'a str, &'a str)>,
+    viewer: Option<&str>,
+) -> Result<Vec<PostFeedView<'static>>> {
+    Ok(hydrate_posts(state, posts, viewer)
+        .await?
+        .into_iter()
+        .map(|post| PostFeedView {
+            author: post.author,
+            created_at: post.created_at,
+            edited_at: post.edited_at,
+            favourite_count: post.favourite_count,
+            indexed_at: post.indexed_at,
+            media: post.media,
+            self_labels: post.self_labels,
+            tags: post.tags,
+            title: post.title,
+            uri: post.uri,
+            viewer: post.viewer,
+            extra_data: post.extra_data,
+        })
+        .collect())
+}
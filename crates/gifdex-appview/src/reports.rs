@@ -0,0 +1,65 @@
+use crate::{AppState, admin::subject_uri};
+use gifdex_lexicons::net_gifdex::moderation::ReportView;
+use jacquard_common::{
+    chrono::{TimeZone, Utc},
+    types::{datetime::Datetime, did::Did},
+};
+use sqlx::query;
+
+/// Statuses a report moves through, in order, matching the `report_status` database enum.
+pub const STATUSES: [&str; 3] = ["open", "acknowledged", "resolved"];
+
+/// A report as stored in the database.
+pub struct Report {
+    pub id: i64,
+    pub reporter_did: String,
+    pub subject_did: String,
+    pub subject_collection: Option<String>,
+    pub subject_rkey: Option<String>,
+    pub reason_type: String,
+    pub reason: Option<String>,
+    pub status: String,
+    pub created_at: i64,
+    pub updated_at: Option<i64>,
+    pub updated_by: Option<String>,
+}
+
+impl Report {
+    pub fn into_view(self) -> ReportView<'static> {
+        ReportView::new()
+            .id(self.id)
+            .subject(subject_uri(
+                &self.subject_did,
+                self.subject_collection.as_deref(),
+                self.subject_rkey.as_deref(),
+            ))
+            .reporter(Did::new_owned(self.reporter_did).unwrap())
+            .reason_type(self.reason_type)
+            .reason(self.reason.map(|reason| reason.into()))
+            .status(self.status)
+            .created_at(
+                Utc.timestamp_millis_opt(self.created_at)
+                    .unwrap()
+                    .fixed_offset(),
+            )
+            .updated_at(self.updated_at.map(|updated_at| {
+                Datetime::new(Utc.timestamp_millis_opt(updated_at).unwrap().fixed_offset())
+            }))
+            .updated_by(self.updated_by.map(|did| Did::new_owned(did).unwrap()))
+            .build()
+    }
+}
+
+/// Whether the account can review reports, which admins and labelers can.
+pub async fn can_review_reports(state: &AppState, did: &str) -> sqlx::Result<bool> {
+    if state.admin_dids.iter().any(|admin| admin == did) {
+        return Ok(true);
+    }
+    let result = query!(
+        r#"SELECT EXISTS(SELECT 1 FROM labeler_rules WHERE did = $1) as "exists!""#,
+        did
+    )
+    .fetch_one(state.database.executor())
+    .await?;
+    Ok(result.exists)
+}
@@ -1,7 +1,7 @@
-use crate::AppState;
+use crate::{AppState, routes::xrpc::internal_error};
 use axum::{Json, extract::State};
 use jacquard_api::com_atproto::sync::get_repo_status::{
-    GetRepoStatusError, GetRepoStatusOutput, GetRepoStatusRequest,
+    GetRepoStatus, GetRepoStatusError, GetRepoStatusOutput, GetRepoStatusRequest,
 };
 use jacquard_axum::{ExtractXrpc, XrpcErrorResponse};
 use jacquard_common::xrpc::XrpcError;
@@ -17,7 +17,7 @@ pub async fn handle_get_repo_status(
     )
     .fetch_optional(state.database.executor())
     .await
-    .unwrap(); // TODO: Use Xrpc error.
+    .map_err(internal_error::<GetRepoStatus, _>)?;
 
     let Some(account) = record else {
         return Err(XrpcError::Xrpc(GetRepoStatusError::RepoNotFound(None)).into());
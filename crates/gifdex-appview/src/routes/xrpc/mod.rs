@@ -1,3 +1,45 @@
 pub mod com_atproto;
 pub mod health;
 pub mod net_gifdex;
+
+use axum::http::StatusCode;
+use jacquard_axum::XrpcErrorResponse;
+use jacquard_common::{
+    IntoStatic,
+    xrpc::{GenericXrpcError, XrpcError, XrpcRequest},
+};
+
+/// Build an error response for an error that isn't declared by the endpoint's lexicon.
+pub fn generic_error<R, E>(status: StatusCode, error: &str, message: &str) -> XrpcErrorResponse<E>
+where
+    R: XrpcRequest,
+    E: std::error::Error + IntoStatic + serde::Serialize,
+{
+    XrpcErrorResponse::new(
+        status,
+        XrpcError::Generic(GenericXrpcError {
+            error: error.into(),
+            message: Some(message.into()),
+            nsid: R::NSID,
+            method: R::METHOD.as_str(),
+            http_status: status,
+        }),
+    )
+}
+
+/// Build an error response for an unexpected failure while handling a request, such as a
+/// database error.
+///
+/// The error is logged rather than returned to the client, as it may contain internal details.
+pub fn internal_error<R, E>(err: impl std::fmt::Debug) -> XrpcErrorResponse<E>
+where
+    R: XrpcRequest,
+    E: std::error::Error + IntoStatic + serde::Serialize,
+{
+    tracing::error!("Failed to handle {} request: {err:?}", R::NSID);
+    generic_error::<R, E>(
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "InternalServerError",
+        "An internal error occurred",
+    )
+}
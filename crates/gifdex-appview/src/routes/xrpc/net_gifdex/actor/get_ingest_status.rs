@@ -0,0 +1,64 @@
+use crate::{AppState, routes::xrpc::internal_error};
+use axum::{Json, extract::State};
+use gifdex_lexicons::net_gifdex::actor::{
+    RejectedRecord,
+    get_ingest_status::{GetIngestStatus, GetIngestStatusOutput, GetIngestStatusRequest},
+};
+use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractServiceAuth};
+use jacquard_common::{
+    IntoStatic,
+    chrono::{TimeZone, Utc},
+    types::{aturi::AtUri, string::Cid},
+    xrpc::GenericXrpcError,
+};
+use sqlx::query;
+
+pub async fn handle_get_ingest_status(
+    State(state): State<AppState>,
+    ExtractServiceAuth(auth): ExtractServiceAuth,
+    ExtractXrpc(request): ExtractXrpc<GetIngestStatusRequest>,
+) -> Result<Json<GetIngestStatusOutput<'static>>, XrpcErrorResponse<GenericXrpcError>> {
+    let limit = request.limit.unwrap_or(50).min(100);
+
+    let records = query!(
+        "SELECT did, collection, rkey, cid, reason, rejected_at FROM rejected_records
+         WHERE did = $1 AND ($2::BIGINT IS NULL OR rejected_at < $2)
+         ORDER BY rejected_at DESC LIMIT $3",
+        auth.did().as_str(),
+        request.cursor,
+        limit
+    )
+    .fetch_all(state.database.executor())
+    .await
+    .map_err(internal_error::<GetIngestStatus, _>)?;
+
+    // Generate cursor if we have more records.
+    let cursor = if records.len() == limit as usize {
+        records.last().map(|record| record.rejected_at)
+    } else {
+        None
+    };
+
+    Ok(Json(GetIngestStatusOutput {
+        rejected_records: records
+            .into_iter()
+            .map(|record| {
+                RejectedRecord::new()
+                    .uri(
+                        AtUri::from_parts_owned(&record.did, &record.collection, &record.rkey)
+                            .unwrap(),
+                    )
+                    .cid(record.cid.as_deref().map(|cid| Cid::str(cid).into_static()))
+                    .reason(record.reason)
+                    .rejected_at(
+                        Utc.timestamp_millis_opt(record.rejected_at)
+                            .unwrap()
+                            .fixed_offset(),
+                    )
+                    .build()
+            })
+            .collect(),
+        cursor,
+        extra_data: None,
+    }))
+}
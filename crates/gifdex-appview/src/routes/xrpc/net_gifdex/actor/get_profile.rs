@@ -1,11 +1,19 @@
-use crate::{AppState, cdn::CdnMediaType};
+use crate::{
+    AppState,
+    cdn::{AvatarSize, CdnMediaType},
+    handles::parse_handle,
+    routes::xrpc::internal_error,
+};
 use axum::{Json, extract::State};
 use gifdex_lexicons::net_gifdex::actor::{
-    ProfileView,
-    get_profile::{GetProfileError, GetProfileOutput, GetProfileRequest},
+    ProfileView, ProfileViewerState,
+    get_profile::{GetProfile, GetProfileError, GetProfileOutput, GetProfileRequest},
 };
 use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractOptionalServiceAuth};
-use jacquard_common::{types::uri::Uri, xrpc::XrpcError};
+use jacquard_common::{
+    types::{tid::Tid, uri::Uri},
+    xrpc::XrpcError,
+};
 use sqlx::query;
 
 pub async fn handle_get_profile(
@@ -17,33 +25,66 @@ pub async fn handle_get_profile(
     tracing::debug!("Authenticated DID for request: {auth_did:?}");
 
     let account = query!(
-        r#"SELECT did, handle, display_name, avatar_blob_cid, pronouns, indexed_at,
-        (SELECT COUNT(*) FROM posts WHERE did = accounts.did) as "post_count!"
-        FROM accounts WHERE did = $1"#,
-        request.actor.as_str()
+        r#"SELECT accounts.did, ap.handle, pronouns, indexed_at, ap.display_name,
+        ap.avatar_blob_cid,
+        is_active, status, is_admin_taken_down(accounts.did, NULL, NULL) as "admin_takedown!",
+        is_hidden_from_viewer($2, accounts.did) as "hidden!",
+        (SELECT COUNT(*) FROM posts WHERE did = accounts.did
+           AND NOT is_admin_taken_down(did, 'net.gifdex.feed.post', rkey)) as "post_count!",
+        (SELECT COUNT(*) FROM counted_follows WHERE subject_did = accounts.did) as "follower_count!",
+        (SELECT COUNT(*) FROM counted_follows WHERE did = accounts.did) as "following_count!",
+        (SELECT f.rkey FROM follows f WHERE f.did = $2 AND f.subject_did = accounts.did
+           LIMIT 1) as "following_rkey"
+        FROM accounts
+        INNER JOIN account_profiles ap ON ap.did = accounts.did
+        WHERE accounts.did = $1"#,
+        request.actor.as_str(),
+        auth_did
     )
     .fetch_optional(state.database.executor())
     .await
-    .unwrap(); // TODO: Use Xrpc error.
+    .map_err(internal_error::<GetProfile, _>)?;
 
-    let Some(account) = account else {
+    let Some(account) = account.filter(|account| !account.hidden) else {
         return Err(XrpcError::Xrpc(GetProfileError::ProfileNotFound(None)).into());
     };
+    if !account.is_active {
+        return Err(XrpcError::Xrpc(match account.status.as_str() {
+            "takendown" => GetProfileError::AccountTakedown(None),
+            _ => GetProfileError::AccountDeactivated(None),
+        })
+        .into());
+    }
+    if account.admin_takedown {
+        return Err(XrpcError::Xrpc(GetProfileError::AccountTakedown(None)).into());
+    }
 
     Ok(Json(GetProfileOutput {
         value: ProfileView::new()
             .did(request.actor.clone())
-            .handle(account.handle.map(|handle| handle.parse().unwrap()))
+            .handle(account.handle.map(parse_handle))
             .display_name(account.display_name.map(|display_name| display_name.into()))
             .pronouns(account.pronouns.map(|pronouns| pronouns.into()))
             .avatar(account.avatar_blob_cid.and_then(|bc| {
                 Uri::new_owned(state.cdn.make_cdn_url(CdnMediaType::Avatar {
                     did: &request.actor,
                     cid: &bc.parse().ok()?,
+                    size: AvatarSize::Large,
                 }))
                 .ok()
             }))
             .post_count(account.post_count)
+            .follower_count(account.follower_count)
+            .following_count(account.following_count)
+            .viewer(auth_did.map(|_| {
+                ProfileViewerState {
+                    following: account
+                        .following_rkey
+                        .as_ref()
+                        .map(|rkey| Tid::new(rkey).unwrap()),
+                    ..Default::default()
+                }
+            }))
             .build(),
         extra_data: None,
     }))
@@ -1,12 +1,17 @@
-use crate::{AppState, cdn::CdnMediaType};
+use crate::{
+    AppState,
+    cdn::{AvatarSize, CdnMediaType},
+    handles::parse_handle,
+    routes::xrpc::internal_error,
+};
 use axum::{Json, extract::State};
 use gifdex_lexicons::net_gifdex::actor::{
-    ProfileView,
-    get_profiles::{GetProfilesOutput, GetProfilesRequest},
+    ProfileView, ProfileViewerState,
+    get_profiles::{GetProfiles, GetProfilesOutput, GetProfilesRequest},
 };
 use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractOptionalServiceAuth};
 use jacquard_common::{
-    types::{did::Did, uri::Uri},
+    types::{did::Did, tid::Tid, uri::Uri},
     xrpc::GenericXrpcError,
 };
 use sqlx::query;
@@ -21,14 +26,25 @@ pub async fn handle_get_profiles(
 
     let actors: Vec<String> = request.actors.iter().map(|d| d.to_string()).collect();
     let account = query!(
-        r#"SELECT did, handle, display_name, avatar_blob_cid, pronouns, indexed_at,
-         (SELECT COUNT(*) FROM posts WHERE did = accounts.did) as "post_count!"
-         FROM accounts WHERE did = ANY($1)"#,
-        &actors
+        r#"SELECT accounts.did, ap.handle, pronouns, indexed_at, ap.display_name,
+        ap.avatar_blob_cid,
+         (SELECT COUNT(*) FROM posts WHERE did = accounts.did
+            AND NOT is_admin_taken_down(did, 'net.gifdex.feed.post', rkey)) as "post_count!",
+         (SELECT COUNT(*) FROM counted_follows WHERE subject_did = accounts.did) as "follower_count!",
+         (SELECT COUNT(*) FROM counted_follows WHERE did = accounts.did) as "following_count!",
+         (SELECT f.rkey FROM follows f WHERE f.did = $2 AND f.subject_did = accounts.did
+            LIMIT 1) as "following_rkey"
+         FROM accounts
+         INNER JOIN account_profiles ap ON ap.did = accounts.did
+         WHERE accounts.did = ANY($1) AND is_active
+           AND NOT is_admin_taken_down(accounts.did, NULL, NULL)
+           AND NOT is_hidden_from_viewer($2, accounts.did)"#,
+        &actors,
+        auth_did
     )
     .fetch_all(state.database.executor())
     .await
-    .unwrap(); // TODO: Use Xrpc error.
+    .map_err(internal_error::<GetProfiles, _>)?;
 
     Ok(Json(GetProfilesOutput {
         profiles: account
@@ -37,17 +53,29 @@ pub async fn handle_get_profiles(
                 let did: Did = account.did.parse().unwrap();
                 ProfileView::new()
                     .did(did.clone())
-                    .handle(account.handle.map(|handle| handle.parse().unwrap()))
+                    .handle(account.handle.map(parse_handle))
                     .display_name(account.display_name.map(|s| s.into()))
                     .pronouns(account.pronouns.map(|pronouns| pronouns.into()))
                     .avatar(account.avatar_blob_cid.and_then(|bc| {
                         Uri::new_owned(state.cdn.make_cdn_url(CdnMediaType::Avatar {
                             did: &did,
                             cid: &bc.parse().ok()?,
+                            size: AvatarSize::Large,
                         }))
                         .ok()
                     }))
                     .post_count(account.post_count)
+                    .follower_count(account.follower_count)
+                    .following_count(account.following_count)
+                    .viewer(auth_did.map(|_| {
+                        ProfileViewerState {
+                            following: account
+                                .following_rkey
+                                .as_ref()
+                                .map(|rkey| Tid::new(rkey).unwrap()),
+                            ..Default::default()
+                        }
+                    }))
                     .build()
             })
             .collect(),
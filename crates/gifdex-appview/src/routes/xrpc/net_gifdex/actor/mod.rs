@@ -1,5 +1,7 @@
+mod get_ingest_status;
 mod get_profile;
 mod get_profiles;
 
+pub use get_ingest_status::*;
 pub use get_profile::*;
 pub use get_profiles::*;
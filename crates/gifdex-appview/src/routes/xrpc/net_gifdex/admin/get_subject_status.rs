@@ -0,0 +1,92 @@
+use crate::{
+    AppState,
+    admin::{Subject, require_admin, subject_uri},
+    routes::xrpc::internal_error,
+};
+use axum::{Json, extract::State};
+use gifdex_lexicons::net_gifdex::admin::{
+    TakedownEvent, TakedownView,
+    get_subject_status::{
+        GetSubjectStatus, GetSubjectStatusError, GetSubjectStatusOutput, GetSubjectStatusRequest,
+    },
+};
+use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractServiceAuth};
+use jacquard_common::{
+    chrono::{TimeZone, Utc},
+    types::did::Did,
+    xrpc::XrpcError,
+};
+use sqlx::query;
+
+pub async fn handle_get_subject_status(
+    State(state): State<AppState>,
+    ExtractServiceAuth(auth): ExtractServiceAuth,
+    ExtractXrpc(request): ExtractXrpc<GetSubjectStatusRequest>,
+) -> Result<Json<GetSubjectStatusOutput<'static>>, XrpcErrorResponse<GetSubjectStatusError<'static>>>
+{
+    require_admin::<GetSubjectStatus, _>(&state, &auth)?;
+    let Some(subject) = Subject::parse(&request.subject) else {
+        return Err(XrpcError::Xrpc(GetSubjectStatusError::InvalidSubject(None)).into());
+    };
+    let subject_uri = subject_uri(subject.did, subject.collection, subject.rkey);
+
+    let takedown = query!(
+        "SELECT admin_did, reason, created_at FROM admin_takedowns
+         WHERE subject_did = $1
+           AND subject_collection IS NOT DISTINCT FROM $2
+           AND subject_rkey IS NOT DISTINCT FROM $3",
+        subject.did,
+        subject.collection,
+        subject.rkey
+    )
+    .fetch_optional(state.database.executor())
+    .await
+    .map_err(internal_error::<GetSubjectStatus, _>)?;
+
+    let history = query!(
+        r#"SELECT action::TEXT as "action!", admin_did, reason, created_at FROM admin_takedown_events
+         WHERE subject_did = $1
+           AND subject_collection IS NOT DISTINCT FROM $2
+           AND subject_rkey IS NOT DISTINCT FROM $3
+         ORDER BY id DESC"#,
+        subject.did,
+        subject.collection,
+        subject.rkey
+    )
+    .fetch_all(state.database.executor())
+    .await
+    .map_err(internal_error::<GetSubjectStatus, _>)?;
+
+    Ok(Json(GetSubjectStatusOutput {
+        takedown: takedown.map(|takedown| {
+            TakedownView::new()
+                .subject(subject_uri.clone())
+                .admin(Did::new_owned(takedown.admin_did).unwrap())
+                .reason(takedown.reason.map(|reason| reason.into()))
+                .created_at(
+                    Utc.timestamp_millis_opt(takedown.created_at)
+                        .unwrap()
+                        .fixed_offset(),
+                )
+                .build()
+        }),
+        history: history
+            .into_iter()
+            .map(|event| {
+                TakedownEvent::new()
+                    .action(event.action)
+                    .subject(subject_uri.clone())
+                    .admin(Did::new_owned(event.admin_did).unwrap())
+                    .reason(event.reason.map(|reason| reason.into()))
+                    .created_at(
+                        Utc.timestamp_millis_opt(event.created_at)
+                            .unwrap()
+                            .fixed_offset(),
+                    )
+                    .build()
+            })
+            .collect(),
+        subject: subject_uri,
+        extra_data: None,
+    }))
+}
@@ -0,0 +1,69 @@
+use crate::{
+    AppState,
+    admin::{require_admin, subject_uri},
+    routes::xrpc::internal_error,
+};
+use axum::{Json, extract::State};
+use gifdex_lexicons::net_gifdex::admin::{
+    TakedownView,
+    list_takedowns::{ListTakedowns, ListTakedownsOutput, ListTakedownsRequest},
+};
+use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractServiceAuth};
+use jacquard_common::{
+    chrono::{TimeZone, Utc},
+    types::did::Did,
+    xrpc::GenericXrpcError,
+};
+use sqlx::query;
+
+pub async fn handle_list_takedowns(
+    State(state): State<AppState>,
+    ExtractServiceAuth(auth): ExtractServiceAuth,
+    ExtractXrpc(request): ExtractXrpc<ListTakedownsRequest>,
+) -> Result<Json<ListTakedownsOutput<'static>>, XrpcErrorResponse<GenericXrpcError>> {
+    require_admin::<ListTakedowns, _>(&state, &auth)?;
+    let limit = request.limit.unwrap_or(50).min(100);
+
+    let takedowns = query!(
+        "SELECT subject_did, subject_collection, subject_rkey, admin_did, reason, created_at
+         FROM admin_takedowns
+         WHERE ($1::BIGINT IS NULL OR created_at < $1)
+         ORDER BY created_at DESC LIMIT $2",
+        request.cursor,
+        limit
+    )
+    .fetch_all(state.database.executor())
+    .await
+    .map_err(internal_error::<ListTakedowns, _>)?;
+
+    // Generate cursor if we have more takedowns.
+    let cursor = if takedowns.len() == limit as usize {
+        takedowns.last().map(|takedown| takedown.created_at)
+    } else {
+        None
+    };
+
+    Ok(Json(ListTakedownsOutput {
+        takedowns: takedowns
+            .into_iter()
+            .map(|takedown| {
+                TakedownView::new()
+                    .subject(subject_uri(
+                        &takedown.subject_did,
+                        takedown.subject_collection.as_deref(),
+                        takedown.subject_rkey.as_deref(),
+                    ))
+                    .admin(Did::new_owned(takedown.admin_did).unwrap())
+                    .reason(takedown.reason.map(|reason| reason.into()))
+                    .created_at(
+                        Utc.timestamp_millis_opt(takedown.created_at)
+                            .unwrap()
+                            .fixed_offset(),
+                    )
+                    .build()
+            })
+            .collect(),
+        cursor,
+        extra_data: None,
+    }))
+}
@@ -0,0 +1,9 @@
+mod get_subject_status;
+mod list_takedowns;
+mod reverse_takedown;
+mod takedown_subject;
+
+pub use get_subject_status::*;
+pub use list_takedowns::*;
+pub use reverse_takedown::*;
+pub use takedown_subject::*;
@@ -0,0 +1,89 @@
+use crate::{
+    AppState,
+    admin::{Subject, require_admin, subject_uri},
+    routes::xrpc::internal_error,
+};
+use axum::{Json, extract::State};
+use gifdex_lexicons::net_gifdex::admin::{
+    TakedownEvent,
+    reverse_takedown::{
+        ReverseTakedown, ReverseTakedownError, ReverseTakedownOutput, ReverseTakedownRequest,
+    },
+};
+use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractServiceAuth};
+use jacquard_common::{
+    chrono::{TimeZone, Utc},
+    types::did::Did,
+    xrpc::XrpcError,
+};
+use sqlx::query;
+
+pub async fn handle_reverse_takedown(
+    State(state): State<AppState>,
+    ExtractServiceAuth(auth): ExtractServiceAuth,
+    ExtractXrpc(request): ExtractXrpc<ReverseTakedownRequest>,
+) -> Result<Json<ReverseTakedownOutput<'static>>, XrpcErrorResponse<ReverseTakedownError<'static>>>
+{
+    require_admin::<ReverseTakedown, _>(&state, &auth)?;
+    let Some(subject) = Subject::parse(&request.subject) else {
+        return Err(XrpcError::Xrpc(ReverseTakedownError::InvalidSubject(None)).into());
+    };
+    let reason = request.reason.as_deref();
+
+    let mut tx = state
+        .database
+        .transaction()
+        .await
+        .map_err(internal_error::<ReverseTakedown, _>)?;
+    let removed = query!(
+        "DELETE FROM admin_takedowns
+         WHERE subject_did = $1
+           AND subject_collection IS NOT DISTINCT FROM $2
+           AND subject_rkey IS NOT DISTINCT FROM $3",
+        subject.did,
+        subject.collection,
+        subject.rkey
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(internal_error::<ReverseTakedown, _>)?;
+    if removed.rows_affected() == 0 {
+        return Err(XrpcError::Xrpc(ReverseTakedownError::SubjectNotTakenDown(None)).into());
+    }
+    let event = query!(
+        "INSERT INTO admin_takedown_events (action, subject_did, subject_collection, subject_rkey, admin_did, reason)
+         VALUES ('reverseTakedown', $1, $2, $3, $4, $5)
+         RETURNING admin_did, reason, created_at",
+        subject.did,
+        subject.collection,
+        subject.rkey,
+        auth.did().as_str(),
+        reason
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(internal_error::<ReverseTakedown, _>)?;
+    tx.commit()
+        .await
+        .map_err(internal_error::<ReverseTakedown, _>)?;
+
+    tracing::info!(
+        "Admin {} reversed takedown of {}",
+        auth.did().as_str(),
+        request.subject.as_str()
+    );
+    Ok(Json(ReverseTakedownOutput {
+        event: TakedownEvent::new()
+            .action("reverseTakedown")
+            .subject(subject_uri(subject.did, subject.collection, subject.rkey))
+            .admin(Did::new_owned(event.admin_did).unwrap())
+            .reason(event.reason.map(|reason| reason.into()))
+            .created_at(
+                Utc.timestamp_millis_opt(event.created_at)
+                    .unwrap()
+                    .fixed_offset(),
+            )
+            .build(),
+        extra_data: None,
+    }))
+}
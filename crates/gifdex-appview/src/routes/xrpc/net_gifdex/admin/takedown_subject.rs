@@ -0,0 +1,90 @@
+use crate::{
+    AppState,
+    admin::{Subject, require_admin, subject_uri},
+    routes::xrpc::internal_error,
+};
+use axum::{Json, extract::State};
+use gifdex_lexicons::net_gifdex::admin::{
+    TakedownView,
+    takedown_subject::{
+        TakedownSubject, TakedownSubjectError, TakedownSubjectOutput, TakedownSubjectRequest,
+    },
+};
+use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractServiceAuth};
+use jacquard_common::{
+    chrono::{TimeZone, Utc},
+    types::did::Did,
+    xrpc::XrpcError,
+};
+use sqlx::query;
+
+pub async fn handle_takedown_subject(
+    State(state): State<AppState>,
+    ExtractServiceAuth(auth): ExtractServiceAuth,
+    ExtractXrpc(request): ExtractXrpc<TakedownSubjectRequest>,
+) -> Result<Json<TakedownSubjectOutput<'static>>, XrpcErrorResponse<TakedownSubjectError<'static>>>
+{
+    require_admin::<TakedownSubject, _>(&state, &auth)?;
+    let Some(subject) = Subject::parse(&request.subject) else {
+        return Err(XrpcError::Xrpc(TakedownSubjectError::InvalidSubject(None)).into());
+    };
+    let reason = request.reason.as_deref();
+
+    let mut tx = state
+        .database
+        .transaction()
+        .await
+        .map_err(internal_error::<TakedownSubject, _>)?;
+    let takedown = query!(
+        "INSERT INTO admin_takedowns (subject_did, subject_collection, subject_rkey, admin_did, reason)
+         VALUES ($1, $2, $3, $4, $5)
+         ON CONFLICT (subject_did, subject_collection, subject_rkey) DO UPDATE SET
+           admin_did = EXCLUDED.admin_did,
+           reason = EXCLUDED.reason,
+           created_at = EXCLUDED.created_at
+         RETURNING admin_did, reason, created_at",
+        subject.did,
+        subject.collection,
+        subject.rkey,
+        auth.did().as_str(),
+        reason
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(internal_error::<TakedownSubject, _>)?;
+    query!(
+        "INSERT INTO admin_takedown_events (action, subject_did, subject_collection, subject_rkey, admin_did, reason, created_at)
+         VALUES ('takedown', $1, $2, $3, $4, $5, $6)",
+        subject.did,
+        subject.collection,
+        subject.rkey,
+        takedown.admin_did,
+        takedown.reason,
+        takedown.created_at
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(internal_error::<TakedownSubject, _>)?;
+    tx.commit()
+        .await
+        .map_err(internal_error::<TakedownSubject, _>)?;
+
+    tracing::info!(
+        "Admin {} took down {}",
+        auth.did().as_str(),
+        request.subject.as_str()
+    );
+    Ok(Json(TakedownSubjectOutput {
+        takedown: TakedownView::new()
+            .subject(subject_uri(subject.did, subject.collection, subject.rkey))
+            .admin(Did::new_owned(takedown.admin_did).unwrap())
+            .reason(takedown.reason.map(|reason| reason.into()))
+            .created_at(
+                Utc.timestamp_millis_opt(takedown.created_at)
+                    .unwrap()
+                    .fixed_offset(),
+            )
+            .build(),
+        extra_data: None,
+    }))
+}
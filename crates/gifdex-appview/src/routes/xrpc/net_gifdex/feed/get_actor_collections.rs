@@ -0,0 +1,72 @@
+use crate::{AppState, collections::Collection, routes::xrpc::internal_error};
+use axum::{Json, extract::State};
+use gifdex_lexicons::net_gifdex::feed::get_actor_collections::{
+    GetActorCollections, GetActorCollectionsError, GetActorCollectionsOutput,
+    GetActorCollectionsRequest,
+};
+use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractOptionalServiceAuth};
+use jacquard_common::xrpc::XrpcError;
+use sqlx::{query, query_as};
+
+pub async fn handle_get_actor_collections(
+    State(state): State<AppState>,
+    ExtractOptionalServiceAuth(auth): ExtractOptionalServiceAuth,
+    ExtractXrpc(request): ExtractXrpc<GetActorCollectionsRequest>,
+) -> Result<
+    Json<GetActorCollectionsOutput<'static>>,
+    XrpcErrorResponse<GetActorCollectionsError<'static>>,
+> {
+    let auth_did = auth.as_ref().map(|a| a.did().as_str());
+    tracing::debug!("Authenticated DID for request: {auth_did:?}");
+
+    let limit = request.limit.unwrap_or(50).clamp(1, 100);
+
+    let account_exists = query!(
+        "SELECT 1 as exists FROM accounts
+         WHERE did = $1 AND is_active AND NOT is_admin_taken_down(did, NULL, NULL)
+           AND NOT is_hidden_from_viewer($2, did)",
+        request.actor.as_str(),
+        auth_did
+    )
+    .fetch_optional(state.database.executor())
+    .await
+    .map_err(internal_error::<GetActorCollections, _>)?;
+    if account_exists.is_none() {
+        return Err(XrpcError::Xrpc(GetActorCollectionsError::ActorNotFound(None)).into());
+    }
+
+    let collections = query_as!(
+        Collection,
+        r#"SELECT c.did, c.rkey, c.title, c.description, c.ordering::TEXT as "ordering!",
+           collection_item_count(c.did, c.rkey) as "item_count!",
+           c.created_at, c.indexed_at, ap.handle, ap.display_name, ap.avatar_blob_cid
+         FROM collections c
+         INNER JOIN account_profiles ap ON ap.did = c.did
+         WHERE c.did = $1
+           AND NOT is_admin_taken_down(c.did, 'net.gifdex.feed.collection', c.rkey)
+           AND ($2::BIGINT IS NULL OR c.created_at < $2)
+         ORDER BY c.created_at DESC LIMIT $3"#,
+        request.actor.as_str(),
+        request.cursor,
+        limit
+    )
+    .fetch_all(state.database.executor())
+    .await
+    .map_err(internal_error::<GetActorCollections, _>)?;
+
+    // Generate cursor if we have more collections.
+    let cursor = if collections.len() == limit as usize {
+        collections.last().map(|collection| collection.created_at)
+    } else {
+        None
+    };
+
+    Ok(Json(GetActorCollectionsOutput {
+        collections: collections
+            .into_iter()
+            .map(|collection| collection.into_view(&state))
+            .collect(),
+        cursor,
+        extra_data: None,
+    }))
+}
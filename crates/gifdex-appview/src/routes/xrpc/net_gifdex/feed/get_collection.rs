@@ -0,0 +1,113 @@
+use crate::{
+    AppState, collections::Collection as CollectionRecord, posts::hydrate_feed,
+    routes::xrpc::internal_error,
+};
+use axum::{Json, extract::State};
+use gifdex_lexicons::net_gifdex::feed::{
+    collection::Collection as FeedCollection,
+    get_collection::{
+        GetCollection, GetCollectionError, GetCollectionOutput, GetCollectionRequest,
+    },
+};
+use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractOptionalServiceAuth};
+use jacquard_common::{
+    types::{collection::Collection, ident::AtIdentifier},
+    xrpc::XrpcError,
+};
+use sqlx::{query, query_as};
+
+pub async fn handle_get_collection(
+    State(state): State<AppState>,
+    ExtractOptionalServiceAuth(auth): ExtractOptionalServiceAuth,
+    ExtractXrpc(request): ExtractXrpc<GetCollectionRequest>,
+) -> Result<Json<GetCollectionOutput<'static>>, XrpcErrorResponse<GetCollectionError<'static>>> {
+    let auth_did = auth.as_ref().map(|a| a.did().as_str());
+    tracing::debug!("Authenticated DID for request: {auth_did:?}");
+
+    let limit = request.limit.unwrap_or(50).clamp(1, 100);
+    // Manually ordered items aren't ordered by a single column, so the cursor is an offset.
+    let offset = request.cursor.unwrap_or(0).max(0);
+
+    let (AtIdentifier::Did(collection_did), Some(collection_nsid), Some(collection_rkey)) = (
+        request.uri.authority(),
+        request.uri.collection(),
+        request.uri.rkey(),
+    ) else {
+        return Err(XrpcError::Xrpc(GetCollectionError::CollectionNotFound(None)).into());
+    };
+    if collection_nsid.as_str() != FeedCollection::NSID {
+        return Err(XrpcError::Xrpc(GetCollectionError::CollectionNotFound(None)).into());
+    }
+
+    let collection = query_as!(
+        CollectionRecord,
+        r#"SELECT c.did, c.rkey, c.title, c.description, c.ordering::TEXT as "ordering!",
+           collection_item_count(c.did, c.rkey) as "item_count!",
+           c.created_at, c.indexed_at, ap.handle, ap.display_name, ap.avatar_blob_cid
+         FROM collections c
+         INNER JOIN accounts a ON a.did = c.did
+         INNER JOIN account_profiles ap ON ap.did = c.did
+         WHERE c.did = $1 AND c.rkey = $2 AND a.is_active
+           AND NOT is_admin_taken_down(a.did, NULL, NULL)
+           AND NOT is_admin_taken_down(c.did, 'net.gifdex.feed.collection', c.rkey)
+           AND NOT is_hidden_from_viewer($3, a.did)"#,
+        collection_did.as_str(),
+        collection_rkey.0.as_str(),
+        auth_did
+    )
+    .fetch_optional(state.database.executor())
+    .await
+    .map_err(internal_error::<GetCollection, _>)?;
+    let Some(collection) = collection else {
+        return Err(XrpcError::Xrpc(GetCollectionError::CollectionNotFound(None)).into());
+    };
+
+    let posts = query!(
+        "SELECT p.did, p.rkey
+         FROM collection_items ci
+         INNER JOIN posts p ON p.did = ci.post_did AND p.rkey = ci.post_rkey
+         INNER JOIN accounts a ON a.did = p.did
+         WHERE ci.did = $1 AND ci.collection_rkey = $2 AND a.is_active
+           AND NOT is_admin_taken_down(a.did, NULL, NULL)
+           AND NOT is_admin_taken_down(p.did, 'net.gifdex.feed.post', p.rkey)
+           AND NOT is_hidden_from_viewer($3, a.did)
+         ORDER BY
+           CASE WHEN $4 = 'manual' THEN ci.position END ASC NULLS LAST,
+           CASE WHEN $4 = 'newest' THEN ci.created_at END DESC,
+           ci.created_at ASC, ci.rkey
+         LIMIT $5 OFFSET $6",
+        collection.did,
+        collection.rkey,
+        auth_did,
+        collection.ordering,
+        limit,
+        offset
+    )
+    .fetch_all(state.database.executor())
+    .await
+    .map_err(internal_error::<GetCollection, _>)?;
+
+    // Generate cursor if we have more posts.
+    let cursor = if posts.len() == limit as usize {
+        Some(offset + limit)
+    } else {
+        None
+    };
+
+    let items = hydrate_feed(
+        &state,
+        posts
+            .iter()
+            .map(|post| (post.did.as_str(), post.rkey.as_str())),
+        auth_did,
+    )
+    .await
+    .map_err(internal_error::<GetCollection, _>)?;
+
+    Ok(Json(GetCollectionOutput {
+        collection: collection.into_view(&state),
+        items,
+        cursor,
+        extra_data: None,
+    }))
+}
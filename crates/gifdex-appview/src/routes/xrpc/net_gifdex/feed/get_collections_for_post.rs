@@ -0,0 +1,89 @@
+use crate::{AppState, collections::Collection, routes::xrpc::internal_error};
+use axum::{Json, extract::State};
+use gifdex_lexicons::net_gifdex::feed::{
+    get_collections_for_post::{
+        GetCollectionsForPost, GetCollectionsForPostOutput, GetCollectionsForPostRequest,
+    },
+    post::Post,
+};
+use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractOptionalServiceAuth};
+use jacquard_common::{
+    types::{collection::Collection as _, ident::AtIdentifier},
+    xrpc::GenericXrpcError,
+};
+use sqlx::query_as;
+
+pub async fn handle_get_collections_for_post(
+    State(state): State<AppState>,
+    ExtractOptionalServiceAuth(auth): ExtractOptionalServiceAuth,
+    ExtractXrpc(request): ExtractXrpc<GetCollectionsForPostRequest>,
+) -> Result<Json<GetCollectionsForPostOutput<'static>>, XrpcErrorResponse<GenericXrpcError>> {
+    let auth_did = auth.as_ref().map(|a| a.did().as_str());
+    tracing::debug!("Authenticated DID for request: {auth_did:?}");
+
+    let limit = request.limit.unwrap_or(50).clamp(1, 100);
+    // Results are ordered by when the post was added, which isn't part of the view, so the
+    // cursor is an offset.
+    let offset = request.cursor.unwrap_or(0).max(0);
+
+    // Only posts can be in collections.
+    let (post_did, post_rkey) = match (
+        request.uri.authority(),
+        request.uri.collection(),
+        request.uri.rkey(),
+    ) {
+        (AtIdentifier::Did(did), Some(collection), Some(rkey))
+            if collection.as_str() == Post::NSID =>
+        {
+            (did, rkey)
+        }
+        _ => {
+            return Ok(Json(GetCollectionsForPostOutput {
+                collections: Vec::new(),
+                cursor: None,
+                extra_data: None,
+            }));
+        }
+    };
+
+    let collections = query_as!(
+        Collection,
+        r#"SELECT c.did, c.rkey, c.title, c.description, c.ordering::TEXT as "ordering!",
+           collection_item_count(c.did, c.rkey) as "item_count!",
+           c.created_at, c.indexed_at, ap.handle, ap.display_name, ap.avatar_blob_cid
+         FROM collection_items ci
+         INNER JOIN collections c ON c.did = ci.did AND c.rkey = ci.collection_rkey
+         INNER JOIN accounts a ON a.did = c.did
+         INNER JOIN account_profiles ap ON ap.did = c.did
+         WHERE ci.post_did = $1 AND ci.post_rkey = $2 AND a.is_active
+           AND NOT is_admin_taken_down(a.did, NULL, NULL)
+           AND NOT is_admin_taken_down(c.did, 'net.gifdex.feed.collection', c.rkey)
+           AND NOT is_hidden_from_viewer($3, a.did)
+         ORDER BY ci.created_at DESC, ci.did, ci.rkey
+         LIMIT $4 OFFSET $5"#,
+        post_did.as_str(),
+        post_rkey.0.as_str(),
+        auth_did,
+        limit,
+        offset
+    )
+    .fetch_all(state.database.executor())
+    .await
+    .map_err(internal_error::<GetCollectionsForPost, _>)?;
+
+    // Generate cursor if we have more collections.
+    let cursor = if collections.len() == limit as usize {
+        Some(offset + limit)
+    } else {
+        None
+    };
+
+    Ok(Json(GetCollectionsForPostOutput {
+        collections: collections
+            .into_iter()
+            .map(|collection| collection.into_view(&state))
+            .collect(),
+        cursor,
+        extra_data: None,
+    }))
+}
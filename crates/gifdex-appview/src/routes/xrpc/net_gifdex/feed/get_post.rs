@@ -1,20 +1,10 @@
-use crate::{AppState, cdn::CdnMediaType};
+use crate::{AppState, posts::hydrate_posts, routes::xrpc::internal_error};
 use axum::{Json, extract::State};
-use gifdex_lexicons::net_gifdex::{
-    actor::ProfileViewBasic,
-    feed::{
-        self, PostView, PostViewMedia, PostViewMediaDimensions,
-        get_post::{GetPostError, GetPostOutput, GetPostRequest},
-        post::Post,
-    },
+use gifdex_lexicons::net_gifdex::feed::get_post::{
+    GetPost, GetPostError, GetPostOutput, GetPostRequest,
 };
 use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractOptionalServiceAuth};
-use jacquard_common::{
-    IntoStatic,
-    chrono::{TimeZone, Utc},
-    types::{aturi::AtUri, collection::Collection, string::Rkey, uri::Uri},
-    xrpc::XrpcError,
-};
+use jacquard_common::xrpc::XrpcError;
 use sqlx::query;
 
 pub async fn handle_get_post(
@@ -26,16 +16,10 @@ pub async fn handle_get_post(
     tracing::debug!("Authenticated DID for request: {auth_did:?}");
 
     let record = query!(
-        r#"SELECT 
-          a.did, a.display_name, a.handle, a.avatar_blob_cid, a.indexed_at as account_indexed_at, 
-          p.rkey, p.title, p.tags, p.media_blob_cid, p.media_blob_mime, 
-          p.media_blob_alt, p.created_at, p.edited_at, p.indexed_at as post_indexed_at, 
-          p.media_blob_width, p.media_blob_height,
-          (SELECT COUNT(*) FROM post_favourites
-             WHERE post_did = p.did AND post_rkey = p.rkey) as "favourite_count!",
-           (SELECT pf.rkey FROM post_favourites pf
-             WHERE pf.post_did = p.did AND pf.post_rkey = p.rkey AND pf.did = $3
-           LIMIT 1) as "favourite_rkey"
+        r#"SELECT a.is_active, a.status,
+          is_admin_taken_down(a.did, NULL, NULL) as "account_admin_takedown!",
+          is_admin_taken_down(a.did, 'net.gifdex.feed.post', p.rkey) as "post_admin_takedown!",
+          is_hidden_from_viewer($3, a.did) as "hidden!"
          FROM accounts a
          INNER JOIN posts p ON a.did = p.did
          WHERE a.did = $1 AND p.rkey = $2"#,
@@ -45,88 +29,40 @@ pub async fn handle_get_post(
     )
     .fetch_optional(state.database.executor())
     .await
-    .unwrap(); // TODO: Use Xrpc error.
+    .map_err(internal_error::<GetPost, _>)?;
 
-    let Some(record) = record else {
+    let Some(record) = record.filter(|record| !record.hidden) else {
         return Err(XrpcError::Xrpc(GetPostError::PostNotFound(None)).into());
     };
-
-    // Build profile view.
-    let profile_view = ProfileViewBasic::new()
-        .did(request.actor.clone())
-        .handle(record.handle.map(|h| h.parse().unwrap()))
-        .display_name(record.display_name.map(|s| s.into()))
-        .avatar(record.avatar_blob_cid.and_then(|bc| {
-            Uri::new_owned(state.cdn.make_cdn_url(CdnMediaType::Avatar {
-                did: &request.actor,
-                cid: &bc.parse().ok()?,
-            }))
-            .ok()
-        }))
-        .build();
-
-    // Build post view.
-    let post_at_uri = AtUri::from_parts_owned(&record.did, Post::NSID, &record.rkey).unwrap();
-    let rkey = Rkey::new(&record.rkey).unwrap();
-    let post_view = PostView::new()
-        .uri(post_at_uri)
-        .title(record.title.into_static())
-        .tags(
-            record
-                .tags
-                .map(|tags| tags.into_iter().map(|t| t.into()).collect()),
-        )
-        .media(
-            PostViewMedia::new()
-                .fullsize_url(
-                    Uri::new_owned(state.cdn.make_cdn_url(CdnMediaType::PostMedia {
-                        did: &request.actor,
-                        rkey: &rkey,
-                        thumbnail: false,
-                    }))
-                    .unwrap(),
-                )
-                .thumbnail_url(
-                    Uri::new_owned(state.cdn.make_cdn_url(CdnMediaType::PostMedia {
-                        did: &request.actor,
-                        rkey: &rkey,
-                        thumbnail: true,
-                    }))
-                    .unwrap(),
-                )
-                .mime_type(record.media_blob_mime.into_static())
-                .alt(record.media_blob_alt.map(|s| s.into()))
-                .dimensions(
-                    PostViewMediaDimensions::new()
-                        .height(record.media_blob_height)
-                        .width(record.media_blob_width)
-                        .build(),
-                )
-                .build(),
-        )
-        .favourite_count(record.favourite_count)
-        .author(profile_view)
-        .viewer(feed::ViewerState {
-            favourite: record
-                .favourite_rkey
-                .as_ref()
-                .map(|uri| uri.parse().unwrap()),
-            ..Default::default()
+    if !record.is_active {
+        return Err(XrpcError::Xrpc(match record.status.as_str() {
+            "takendown" => GetPostError::AccountTakedown(None),
+            _ => GetPostError::AccountDeactivated(None),
         })
-        .created_at(
-            Utc.timestamp_millis_opt(record.created_at)
-                .unwrap()
-                .fixed_offset(),
-        )
-        .indexed_at(
-            Utc.timestamp_millis_opt(record.post_indexed_at)
-                .unwrap()
-                .fixed_offset(),
-        )
-        .build();
+        .into());
+    }
+    if record.account_admin_takedown {
+        return Err(XrpcError::Xrpc(GetPostError::AccountTakedown(None)).into());
+    }
+    if record.post_admin_takedown {
+        return Err(XrpcError::Xrpc(GetPostError::PostNotFound(None)).into());
+    }
+
+    let post = hydrate_posts(
+        &state,
+        [(request.actor.as_str(), request.rkey.as_str())],
+        auth_did,
+    )
+    .await
+    .map_err(internal_error::<GetPost, _>)?
+    .pop();
+    // The post may have been deleted since it was checked.
+    let Some(post) = post else {
+        return Err(XrpcError::Xrpc(GetPostError::PostNotFound(None)).into());
+    };
 
     Ok(Json(GetPostOutput {
-        post: post_view,
+        post,
         extra_data: None,
     }))
 }
@@ -1,19 +1,117 @@
-use crate::AppState;
+use crate::{AppState, posts::hydrate_feed, routes::xrpc::internal_error};
 use axum::{Json, extract::State};
 use gifdex_lexicons::net_gifdex::feed::get_posts_by_query::{
-    GetPostsByQueryError, GetPostsByQueryOutput, GetPostsByQueryRequest,
+    GetPostsByQuery, GetPostsByQueryError, GetPostsByQueryOutput, GetPostsByQueryRequest,
+    GetPostsByQuerySortBy,
 };
+use gifdex_media::SIMILAR_HASH_DISTANCE;
 use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractOptionalServiceAuth};
+use jacquard_common::xrpc::XrpcError;
+use sqlx::query;
 
 pub async fn handle_get_posts_by_query(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     ExtractOptionalServiceAuth(auth): ExtractOptionalServiceAuth,
-    ExtractXrpc(_request): ExtractXrpc<GetPostsByQueryRequest>,
+    ExtractXrpc(request): ExtractXrpc<GetPostsByQueryRequest>,
 ) -> Result<Json<GetPostsByQueryOutput<'static>>, XrpcErrorResponse<GetPostsByQueryError<'static>>>
 {
     let auth_did = auth.as_ref().map(|a| a.did().as_str());
     tracing::debug!("Authenticated DID for request: {auth_did:?}");
 
-    // TODO: stub
-    unimplemented!()
+    let limit = request.limit.unwrap_or(50).clamp(1, 100);
+    let sort_by = request.sort_by.unwrap_or(GetPostsByQuerySortBy::Relevance);
+    let search = request
+        .query
+        .as_deref()
+        .map(str::trim)
+        .filter(|query| !query.is_empty());
+    // Results aren't ordered by a single column, so the cursor is an offset.
+    let offset = request.cursor.unwrap_or(0).max(0);
+
+    // Reuploads of the same media are collapsed into the highest ranked copy before the page is
+    // taken, so offsets stay consistent between pages. Comparing every match against the ones
+    // ranked above it is quadratic, but only covers a single account's posts.
+    let results = query!(
+        r#"WITH matches AS (
+           SELECT p.did, p.rkey, p.media_phash, row_number() OVER (ORDER BY
+             CASE WHEN $3 = 'relevance' THEN
+               (lower($2) = ANY(SELECT lower(tag) FROM unnest(p.tags) tag)
+                 OR lower(p.title) = lower($2))
+             END DESC,
+             CASE WHEN $3 = 'top' THEN
+               (SELECT COUNT(*) FROM post_favourites WHERE post_did = p.did AND post_rkey = p.rkey)
+             END DESC,
+             CASE WHEN $3 = 'oldest' THEN p.created_at END ASC,
+             p.created_at DESC, p.rkey DESC
+           ) as rank
+           FROM accounts a
+           INNER JOIN posts p ON a.did = p.did
+           WHERE a.did = $1 AND a.is_active
+             AND NOT is_admin_taken_down(p.did, 'net.gifdex.feed.post', p.rkey)
+             AND NOT is_hidden_from_viewer($6, a.did) AND (
+             $2::TEXT IS NULL
+             OR strpos(lower(p.title), lower($2)) > 0
+             OR lower($2) = ANY(SELECT lower(tag) FROM unnest(p.tags) tag)
+           )
+         )
+         SELECT m.did as "did!", m.rkey as "rkey!"
+         FROM matches m
+         WHERE NOT EXISTS(
+           SELECT 1 FROM matches higher
+           WHERE higher.rank < m.rank
+             AND media_hash_distance(higher.media_phash, m.media_phash) <= $7
+         )
+         ORDER BY m.rank
+         LIMIT $4 OFFSET $5"#,
+        request.actor.as_str(),
+        search,
+        sort_by.as_str(),
+        limit,
+        offset,
+        auth_did,
+        SIMILAR_HASH_DISTANCE as i32
+    )
+    .fetch_all(state.database.executor())
+    .await
+    .map_err(internal_error::<GetPostsByQuery, _>)?;
+
+    // If no posts found, check if the account exists.
+    if results.is_empty() {
+        let account_exists = query!(
+            "SELECT 1 as exists FROM accounts
+             WHERE did = $1 AND is_active AND NOT is_admin_taken_down(did, NULL, NULL)
+               AND NOT is_hidden_from_viewer($2, did)",
+            request.actor.as_str(),
+            auth_did
+        )
+        .fetch_optional(state.database.executor())
+        .await
+        .map_err(internal_error::<GetPostsByQuery, _>)?;
+        if account_exists.is_none() {
+            return Err(XrpcError::Xrpc(GetPostsByQueryError::ActorNotFound(None)).into());
+        }
+    }
+
+    // Generate cursor if we have more posts.
+    let cursor = if results.len() == limit as usize {
+        Some(offset + limit)
+    } else {
+        None
+    };
+
+    let feed = hydrate_feed(
+        &state,
+        results
+            .iter()
+            .map(|post| (post.did.as_str(), post.rkey.as_str())),
+        auth_did,
+    )
+    .await
+    .map_err(internal_error::<GetPostsByQuery, _>)?;
+
+    Ok(Json(GetPostsByQueryOutput {
+        feed,
+        cursor,
+        extra_data: None,
+    }))
 }
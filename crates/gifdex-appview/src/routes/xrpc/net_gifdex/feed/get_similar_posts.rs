@@ -0,0 +1,81 @@
+use crate::{AppState, posts::hydrate_feed, routes::xrpc::internal_error};
+use axum::{Json, extract::State};
+use gifdex_lexicons::net_gifdex::feed::get_similar_posts::{
+    GetSimilarPosts, GetSimilarPostsError, GetSimilarPostsOutput, GetSimilarPostsRequest,
+};
+use gifdex_media::SIMILAR_HASH_DISTANCE;
+use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractOptionalServiceAuth};
+use jacquard_common::xrpc::XrpcError;
+use sqlx::query;
+
+pub async fn handle_get_similar_posts(
+    State(state): State<AppState>,
+    ExtractOptionalServiceAuth(auth): ExtractOptionalServiceAuth,
+    ExtractXrpc(request): ExtractXrpc<GetSimilarPostsRequest>,
+) -> Result<Json<GetSimilarPostsOutput<'static>>, XrpcErrorResponse<GetSimilarPostsError<'static>>>
+{
+    let auth_did = auth.as_ref().map(|a| a.did().as_str());
+    tracing::debug!("Authenticated DID for request: {auth_did:?}");
+
+    let limit = request.limit.unwrap_or(25).clamp(1, 100);
+
+    let source = query!(
+        "SELECT p.media_phash FROM posts p
+         INNER JOIN accounts a ON a.did = p.did
+         WHERE p.did = $1 AND p.rkey = $2 AND a.is_active
+           AND NOT is_admin_taken_down(p.did, 'net.gifdex.feed.post', p.rkey)
+           AND NOT is_hidden_from_viewer($3, p.did)",
+        request.actor.as_str(),
+        request.rkey.as_ref(),
+        auth_did
+    )
+    .fetch_optional(state.database.executor())
+    .await
+    .map_err(internal_error::<GetSimilarPosts, _>)?;
+    let Some(source) = source else {
+        return Err(XrpcError::Xrpc(GetSimilarPostsError::PostNotFound(None)).into());
+    };
+    // Media that couldn't be hashed has nothing to compare against.
+    let Some(phash) = source.media_phash else {
+        return Ok(Json(GetSimilarPostsOutput {
+            feed: Vec::new(),
+            extra_data: None,
+        }));
+    };
+
+    let posts = query!(
+        "SELECT p.did, p.rkey FROM posts p
+         INNER JOIN accounts a ON a.did = p.did
+         WHERE a.is_active
+           AND NOT is_admin_taken_down(p.did, 'net.gifdex.feed.post', p.rkey)
+           AND NOT is_hidden_from_viewer($5, p.did)
+           AND NOT (p.did = $1 AND p.rkey = $2)
+           AND media_hash_distance(p.media_phash, $3) <= $6
+         ORDER BY media_hash_distance(p.media_phash, $3) ASC, p.created_at ASC
+         LIMIT $4",
+        request.actor.as_str(),
+        request.rkey.as_ref(),
+        phash,
+        limit,
+        auth_did,
+        SIMILAR_HASH_DISTANCE as i32
+    )
+    .fetch_all(state.database.executor())
+    .await
+    .map_err(internal_error::<GetSimilarPosts, _>)?;
+
+    let feed = hydrate_feed(
+        &state,
+        posts
+            .iter()
+            .map(|post| (post.did.as_str(), post.rkey.as_str())),
+        auth_did,
+    )
+    .await
+    .map_err(internal_error::<GetSimilarPosts, _>)?;
+
+    Ok(Json(GetSimilarPostsOutput {
+        feed,
+        extra_data: None,
+    }))
+}
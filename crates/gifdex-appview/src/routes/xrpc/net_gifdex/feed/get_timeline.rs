@@ -0,0 +1,83 @@
+use crate::{
+    AppState,
+    posts::hydrate_feed,
+    routes::xrpc::{generic_error, internal_error},
+};
+use axum::{Json, extract::State, http::StatusCode};
+use gifdex_lexicons::net_gifdex::feed::get_timeline::{
+    GetTimeline, GetTimelineOutput, GetTimelineRequest,
+};
+use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractServiceAuth};
+use jacquard_common::xrpc::GenericXrpcError;
+use sqlx::query;
+
+pub async fn handle_get_timeline(
+    State(state): State<AppState>,
+    ExtractServiceAuth(auth): ExtractServiceAuth,
+    ExtractXrpc(request): ExtractXrpc<GetTimelineRequest>,
+) -> Result<Json<GetTimelineOutput<'static>>, XrpcErrorResponse<GenericXrpcError>> {
+    let limit = request.limit.unwrap_or(50).clamp(1, 100);
+    let cursor = match request.cursor.as_deref() {
+        Some(cursor) => Some(parse_cursor(cursor).ok_or_else(|| {
+            generic_error::<GetTimeline, _>(
+                StatusCode::BAD_REQUEST,
+                "InvalidRequest",
+                "Invalid cursor",
+            )
+        })?),
+        None => None,
+    };
+
+    // Posts can share a creation time, so the cursor also holds the last post's DID and rkey to
+    // resume exactly after it.
+    let posts = query!(
+        "SELECT p.did, p.rkey, p.created_at FROM posts p
+         INNER JOIN accounts a ON a.did = p.did
+         WHERE p.did IN (SELECT subject_did FROM follows WHERE did = $1) AND a.is_active
+           AND NOT is_admin_taken_down(p.did, 'net.gifdex.feed.post', p.rkey)
+           AND NOT is_hidden_from_viewer($1, p.did)
+           AND ($2::BIGINT IS NULL OR (p.created_at, p.did, p.rkey) < ($2, $3, $4))
+         ORDER BY p.created_at DESC, p.did DESC, p.rkey DESC LIMIT $5",
+        auth.did().as_str(),
+        cursor.map(|(created_at, _, _)| created_at),
+        cursor.map(|(_, did, _)| did),
+        cursor.map(|(_, _, rkey)| rkey),
+        limit
+    )
+    .fetch_all(state.database.executor())
+    .await
+    .map_err(internal_error::<GetTimeline, _>)?;
+
+    // Generate cursor if we have more posts.
+    let cursor = if posts.len() == limit as usize {
+        posts
+            .last()
+            .map(|post| format!("{}/{}/{}", post.created_at, post.did, post.rkey).into())
+    } else {
+        None
+    };
+
+    let feed = hydrate_feed(
+        &state,
+        posts
+            .iter()
+            .map(|post| (post.did.as_str(), post.rkey.as_str())),
+        Some(auth.did().as_str()),
+    )
+    .await
+    .map_err(internal_error::<GetTimeline, _>)?;
+
+    Ok(Json(GetTimelineOutput {
+        feed,
+        cursor,
+        extra_data: None,
+    }))
+}
+
+/// Parse a cursor of the form `{created_at}/{did}/{rkey}`, which can't be ambiguous as neither
+/// DIDs nor record keys contain slashes.
+fn parse_cursor(cursor: &str) -> Option<(i64, &str, &str)> {
+    let mut parts = cursor.splitn(3, '/');
+    let created_at = parts.next()?.parse().ok()?;
+    Some((created_at, parts.next()?, parts.next()?))
+}
@@ -1,7 +1,17 @@
+mod get_actor_collections;
+mod get_collection;
+mod get_collections_for_post;
 mod get_post;
 mod get_posts_by_actor;
 mod get_posts_by_query;
+mod get_similar_posts;
+mod get_timeline;
 
+pub use get_actor_collections::*;
+pub use get_collection::*;
+pub use get_collections_for_post::*;
 pub use get_post::*;
 pub use get_posts_by_actor::*;
 pub use get_posts_by_query::*;
+pub use get_similar_posts::*;
+pub use get_timeline::*;
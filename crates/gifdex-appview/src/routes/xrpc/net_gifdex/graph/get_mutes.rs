@@ -0,0 +1,88 @@
+use crate::{
+    AppState,
+    cdn::{AvatarSize, CdnMediaType},
+    handles::parse_handle,
+    routes::xrpc::internal_error,
+};
+use axum::{Json, extract::State};
+use gifdex_lexicons::net_gifdex::{
+    actor::{ProfileView, ProfileViewerState},
+    graph::get_mutes::{GetMutes, GetMutesOutput, GetMutesRequest},
+};
+use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractServiceAuth};
+use jacquard_common::{
+    types::{did::Did, tid::Tid, uri::Uri},
+    xrpc::GenericXrpcError,
+};
+use sqlx::query;
+
+pub async fn handle_get_mutes(
+    State(state): State<AppState>,
+    ExtractServiceAuth(auth): ExtractServiceAuth,
+    ExtractXrpc(request): ExtractXrpc<GetMutesRequest>,
+) -> Result<Json<GetMutesOutput<'static>>, XrpcErrorResponse<GenericXrpcError>> {
+    let limit = request.limit.unwrap_or(50).clamp(1, 100);
+
+    let mutes = query!(
+        r#"SELECT a.did, ap.handle, a.pronouns, m.created_at, ap.display_name, ap.avatar_blob_cid,
+         (SELECT COUNT(*) FROM posts WHERE did = a.did
+            AND NOT is_admin_taken_down(did, 'net.gifdex.feed.post', rkey)) as "post_count!",
+         (SELECT COUNT(*) FROM counted_follows WHERE subject_did = a.did) as "follower_count!",
+         (SELECT COUNT(*) FROM counted_follows WHERE did = a.did) as "following_count!",
+         (SELECT f.rkey FROM follows f WHERE f.did = $1 AND f.subject_did = a.did
+            LIMIT 1) as "following_rkey"
+         FROM mutes m
+         INNER JOIN accounts a ON a.did = m.subject_did
+         INNER JOIN account_profiles ap ON ap.did = a.did
+         WHERE m.did = $1 AND ($2::BIGINT IS NULL OR m.created_at < $2)
+         ORDER BY m.created_at DESC LIMIT $3"#,
+        auth.did().as_str(),
+        request.cursor,
+        limit
+    )
+    .fetch_all(state.database.executor())
+    .await
+    .map_err(internal_error::<GetMutes, _>)?;
+
+    // Generate cursor if we have more mutes.
+    let cursor = if mutes.len() == limit as usize {
+        mutes.last().map(|mute| mute.created_at)
+    } else {
+        None
+    };
+
+    Ok(Json(GetMutesOutput {
+        mutes: mutes
+            .into_iter()
+            .map(|account| {
+                let did: Did = account.did.parse().unwrap();
+                ProfileView::new()
+                    .did(did.clone())
+                    .handle(account.handle.map(parse_handle))
+                    .display_name(account.display_name.map(|s| s.into()))
+                    .pronouns(account.pronouns.map(|pronouns| pronouns.into()))
+                    .avatar(account.avatar_blob_cid.and_then(|bc| {
+                        Uri::new_owned(state.cdn.make_cdn_url(CdnMediaType::Avatar {
+                            did: &did,
+                            cid: &bc.parse().ok()?,
+                            size: AvatarSize::Large,
+                        }))
+                        .ok()
+                    }))
+                    .post_count(account.post_count)
+                    .follower_count(account.follower_count)
+                    .following_count(account.following_count)
+                    .viewer(ProfileViewerState {
+                        following: account
+                            .following_rkey
+                            .as_ref()
+                            .map(|rkey| Tid::new(rkey).unwrap()),
+                        ..Default::default()
+                    })
+                    .build()
+            })
+            .collect(),
+        cursor,
+        extra_data: None,
+    }))
+}
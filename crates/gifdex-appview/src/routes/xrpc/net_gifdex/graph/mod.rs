@@ -0,0 +1,7 @@
+mod get_mutes;
+mod mute_actor;
+mod unmute_actor;
+
+pub use get_mutes::*;
+pub use mute_actor::*;
+pub use unmute_actor::*;
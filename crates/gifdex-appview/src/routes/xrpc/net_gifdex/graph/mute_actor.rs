@@ -0,0 +1,34 @@
+use crate::{
+    AppState,
+    routes::xrpc::{generic_error, internal_error},
+};
+use axum::{extract::State, http::StatusCode};
+use gifdex_lexicons::net_gifdex::graph::mute_actor::{MuteActor, MuteActorRequest};
+use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractServiceAuth};
+use jacquard_common::xrpc::GenericXrpcError;
+use sqlx::query;
+
+pub async fn handle_mute_actor(
+    State(state): State<AppState>,
+    ExtractServiceAuth(auth): ExtractServiceAuth,
+    ExtractXrpc(request): ExtractXrpc<MuteActorRequest>,
+) -> Result<(), XrpcErrorResponse<GenericXrpcError>> {
+    if request.actor == *auth.did() {
+        return Err(generic_error::<MuteActor, _>(
+            StatusCode::BAD_REQUEST,
+            "InvalidRequest",
+            "An account can't mute itself",
+        ));
+    }
+
+    query!(
+        "INSERT INTO mutes (did, subject_did) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+        auth.did().as_str(),
+        request.actor.as_str()
+    )
+    .execute(state.database.executor())
+    .await
+    .map_err(internal_error::<MuteActor, _>)?;
+
+    Ok(())
+}
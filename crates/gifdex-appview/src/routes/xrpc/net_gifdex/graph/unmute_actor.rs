@@ -0,0 +1,23 @@
+use crate::{AppState, routes::xrpc::internal_error};
+use axum::extract::State;
+use gifdex_lexicons::net_gifdex::graph::unmute_actor::{UnmuteActor, UnmuteActorRequest};
+use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractServiceAuth};
+use jacquard_common::xrpc::GenericXrpcError;
+use sqlx::query;
+
+pub async fn handle_unmute_actor(
+    State(state): State<AppState>,
+    ExtractServiceAuth(auth): ExtractServiceAuth,
+    ExtractXrpc(request): ExtractXrpc<UnmuteActorRequest>,
+) -> Result<(), XrpcErrorResponse<GenericXrpcError>> {
+    query!(
+        "DELETE FROM mutes WHERE did = $1 AND subject_did = $2",
+        auth.did().as_str(),
+        request.actor.as_str()
+    )
+    .execute(state.database.executor())
+    .await
+    .map_err(internal_error::<UnmuteActor, _>)?;
+
+    Ok(())
+}
@@ -1,2 +1,5 @@
 pub mod actor;
+pub mod admin;
 pub mod feed;
+pub mod graph;
+pub mod moderation;
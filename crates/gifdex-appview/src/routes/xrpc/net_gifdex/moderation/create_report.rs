@@ -0,0 +1,90 @@
+use crate::{AppState, admin::Subject, reports::Report, routes::xrpc::internal_error};
+use axum::{Json, extract::State, http::StatusCode};
+use gifdex_lexicons::net_gifdex::moderation::create_report::{
+    CreateReport, CreateReportError, CreateReportOutput, CreateReportRequest,
+};
+use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractServiceAuth};
+use jacquard_common::{types::uri::Uri, xrpc::XrpcError};
+use sqlx::{query, query_as};
+
+pub async fn handle_create_report(
+    State(state): State<AppState>,
+    ExtractServiceAuth(auth): ExtractServiceAuth,
+    ExtractXrpc(request): ExtractXrpc<CreateReportRequest>,
+) -> Result<Json<CreateReportOutput<'static>>, XrpcErrorResponse<CreateReportError<'static>>> {
+    let subject = match &request.subject {
+        Uri::Did(did) => Some(Subject {
+            did: did.as_str(),
+            collection: None,
+            rkey: None,
+        }),
+        Uri::At(uri) => Subject::parse(uri),
+        _ => None,
+    };
+    let Some(subject) = subject else {
+        return Err(XrpcError::Xrpc(CreateReportError::InvalidSubject(None)).into());
+    };
+
+    // Limit how many reports an account can make within the last hour. Reports from the same
+    // account are serialised by an advisory lock held until the transaction ends, so concurrent
+    // requests can't all pass the count before any of them is inserted.
+    let mut tx = state
+        .database
+        .transaction()
+        .await
+        .map_err(internal_error::<CreateReport, _>)?;
+    query!(
+        "SELECT pg_advisory_xact_lock(hashtextextended('create_report:' || $1, 0))",
+        auth.did().as_str()
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(internal_error::<CreateReport, _>)?;
+    let recent = query!(
+        r#"SELECT COUNT(*) as "count!" FROM reports
+         WHERE reporter_did = $1
+           AND created_at > (extract(epoch from now()) * 1000)::BIGINT - 3600000"#,
+        auth.did().as_str()
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(internal_error::<CreateReport, _>)?;
+    if recent.count >= state.report_rate_limit as i64 {
+        return Err(XrpcErrorResponse::new(
+            StatusCode::TOO_MANY_REQUESTS,
+            XrpcError::Xrpc(CreateReportError::RateLimitExceeded(None)),
+        ));
+    }
+
+    let report = query_as!(
+        Report,
+        r#"INSERT INTO reports (reporter_did, subject_did, subject_collection, subject_rkey, reason_type, reason)
+         VALUES ($1, $2, $3, $4, $5::TEXT::report_reason, $6)
+         RETURNING id, reporter_did, subject_did, subject_collection, subject_rkey,
+           reason_type::TEXT as "reason_type!", reason, status::TEXT as "status!",
+           created_at, updated_at, updated_by"#,
+        auth.did().as_str(),
+        subject.did,
+        subject.collection,
+        subject.rkey,
+        request.reason_type.as_str(),
+        request.reason.as_deref()
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(internal_error::<CreateReport, _>)?;
+    tx.commit()
+        .await
+        .map_err(internal_error::<CreateReport, _>)?;
+
+    tracing::info!(
+        "Account {} reported {} for {}",
+        auth.did().as_str(),
+        request.subject.as_str(),
+        request.reason_type
+    );
+    Ok(Json(CreateReportOutput {
+        report: report.into_view(),
+        extra_data: None,
+    }))
+}
@@ -0,0 +1,90 @@
+use crate::{
+    AppState,
+    admin::Subject,
+    reports::{Report, STATUSES, can_review_reports},
+    routes::xrpc::{generic_error, internal_error},
+};
+use axum::{Json, extract::State, http::StatusCode};
+use gifdex_lexicons::net_gifdex::moderation::list_reports::{
+    ListReports, ListReportsOutput, ListReportsRequest,
+};
+use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractServiceAuth};
+use jacquard_common::xrpc::GenericXrpcError;
+use sqlx::query_as;
+
+pub async fn handle_list_reports(
+    State(state): State<AppState>,
+    ExtractServiceAuth(auth): ExtractServiceAuth,
+    ExtractXrpc(request): ExtractXrpc<ListReportsRequest>,
+) -> Result<Json<ListReportsOutput<'static>>, XrpcErrorResponse<GenericXrpcError>> {
+    let can_review = can_review_reports(&state, auth.did().as_str())
+        .await
+        .map_err(internal_error::<ListReports, _>)?;
+    if !can_review {
+        return Err(generic_error::<ListReports, _>(
+            StatusCode::FORBIDDEN,
+            "Forbidden",
+            "Authenticated account is not an admin or labeler",
+        ));
+    }
+    let status = request.status.as_deref();
+    if status.is_some_and(|status| !STATUSES.contains(&status)) {
+        return Err(generic_error::<ListReports, _>(
+            StatusCode::BAD_REQUEST,
+            "InvalidRequest",
+            "Unknown status",
+        ));
+    }
+    let subject = match &request.subject {
+        Some(uri) => match Subject::parse(uri) {
+            Some(subject) => Some(subject),
+            None => {
+                return Err(generic_error::<ListReports, _>(
+                    StatusCode::BAD_REQUEST,
+                    "InvalidRequest",
+                    "The subject must be a DID, or a record referenced by DID, collection and rkey",
+                ));
+            }
+        },
+        None => None,
+    };
+    let limit = request.limit.unwrap_or(50).clamp(1, 100);
+
+    let reports = query_as!(
+        Report,
+        r#"SELECT id, reporter_did, subject_did, subject_collection, subject_rkey,
+           reason_type::TEXT as "reason_type!", reason, status::TEXT as "status!",
+           created_at, updated_at, updated_by
+         FROM reports
+         WHERE ($1::TEXT IS NULL OR status = $1::TEXT::report_status)
+           AND ($2::TEXT IS NULL OR (
+             subject_did = $2
+             AND subject_collection IS NOT DISTINCT FROM $3
+             AND subject_rkey IS NOT DISTINCT FROM $4
+           ))
+           AND ($5::BIGINT IS NULL OR id < $5)
+         ORDER BY id DESC LIMIT $6"#,
+        status,
+        subject.as_ref().map(|subject| subject.did),
+        subject.as_ref().and_then(|subject| subject.collection),
+        subject.as_ref().and_then(|subject| subject.rkey),
+        request.cursor,
+        limit
+    )
+    .fetch_all(state.database.executor())
+    .await
+    .map_err(internal_error::<ListReports, _>)?;
+
+    // Generate cursor if we have more reports.
+    let cursor = if reports.len() == limit as usize {
+        reports.last().map(|report| report.id)
+    } else {
+        None
+    };
+
+    Ok(Json(ListReportsOutput {
+        reports: reports.into_iter().map(Report::into_view).collect(),
+        cursor,
+        extra_data: None,
+    }))
+}
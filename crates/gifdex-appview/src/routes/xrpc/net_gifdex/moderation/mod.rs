@@ -0,0 +1,7 @@
+mod create_report;
+mod list_reports;
+mod update_report_status;
+
+pub use create_report::*;
+pub use list_reports::*;
+pub use update_report_status::*;
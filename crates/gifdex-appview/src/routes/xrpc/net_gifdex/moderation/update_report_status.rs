@@ -0,0 +1,82 @@
+use crate::{
+    AppState,
+    reports::{Report, can_review_reports},
+    routes::xrpc::{generic_error, internal_error},
+};
+use axum::{Json, extract::State, http::StatusCode};
+use gifdex_lexicons::net_gifdex::moderation::update_report_status::{
+    UpdateReportStatus, UpdateReportStatusError, UpdateReportStatusOutput,
+    UpdateReportStatusRequest,
+};
+use jacquard_axum::{ExtractXrpc, XrpcErrorResponse, service_auth::ExtractServiceAuth};
+use jacquard_common::xrpc::XrpcError;
+use sqlx::{query, query_as};
+
+pub async fn handle_update_report_status(
+    State(state): State<AppState>,
+    ExtractServiceAuth(auth): ExtractServiceAuth,
+    ExtractXrpc(request): ExtractXrpc<UpdateReportStatusRequest>,
+) -> Result<
+    Json<UpdateReportStatusOutput<'static>>,
+    XrpcErrorResponse<UpdateReportStatusError<'static>>,
+> {
+    let can_review = can_review_reports(&state, auth.did().as_str())
+        .await
+        .map_err(internal_error::<UpdateReportStatus, _>)?;
+    if !can_review {
+        return Err(generic_error::<UpdateReportStatus, _>(
+            StatusCode::FORBIDDEN,
+            "Forbidden",
+            "Authenticated account is not an admin or labeler",
+        ));
+    }
+    if !matches!(request.status.as_ref(), "acknowledged" | "resolved") {
+        return Err(generic_error::<UpdateReportStatus, _>(
+            StatusCode::BAD_REQUEST,
+            "InvalidRequest",
+            "Unknown status",
+        ));
+    }
+
+    // Statuses only move forward, following the order of the `report_status` enum.
+    let report = query_as!(
+        Report,
+        r#"UPDATE reports SET
+           status = $2::TEXT::report_status,
+           updated_at = (extract(epoch from now()) * 1000)::BIGINT,
+           updated_by = $3
+         WHERE id = $1 AND status < $2::TEXT::report_status
+         RETURNING id, reporter_did, subject_did, subject_collection, subject_rkey,
+           reason_type::TEXT as "reason_type!", reason, status::TEXT as "status!",
+           created_at, updated_at, updated_by"#,
+        request.id,
+        request.status.as_ref(),
+        auth.did().as_str()
+    )
+    .fetch_optional(state.database.executor())
+    .await
+    .map_err(internal_error::<UpdateReportStatus, _>)?;
+
+    let Some(report) = report else {
+        let exists = query!("SELECT 1 as exists FROM reports WHERE id = $1", request.id)
+            .fetch_optional(state.database.executor())
+            .await
+            .map_err(internal_error::<UpdateReportStatus, _>)?;
+        return Err(XrpcError::Xrpc(match exists {
+            Some(_) => UpdateReportStatusError::InvalidTransition(None),
+            None => UpdateReportStatusError::ReportNotFound(None),
+        })
+        .into());
+    };
+
+    tracing::info!(
+        "Account {} moved report {} to {}",
+        auth.did().as_str(),
+        report.id,
+        report.status
+    );
+    Ok(Json(UpdateReportStatusOutput {
+        report: report.into_view(),
+        extra_data: None,
+    }))
+}
@@ -0,0 +1,82 @@
+use crate::database::Database;
+use anyhow::{Context, Result};
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use metrics::{
+    Unit, counter, describe_counter, describe_gauge, describe_histogram, gauge, histogram,
+};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder};
+use std::{
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+
+/// Buckets for request latency, from cached lookups up to slow search queries.
+const REQUEST_DURATION_BUCKETS: [f64; 12] = [
+    0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 10.0,
+];
+
+/// Install the global metrics recorder and serve it in the Prometheus text format on the
+/// given admin address.
+pub fn install(address: SocketAddr) -> Result<()> {
+    PrometheusBuilder::new()
+        .with_http_listener(address)
+        .set_buckets_for_metric(
+            Matcher::Full("appview_request_duration_seconds".into()),
+            &REQUEST_DURATION_BUCKETS,
+        )?
+        .install()
+        .context("failed to install prometheus exporter")?;
+
+    describe_counter!(
+        "appview_requests_total",
+        "Requests handled, by XRPC method and response status."
+    );
+    describe_histogram!(
+        "appview_request_duration_seconds",
+        Unit::Seconds,
+        "Time taken to handle a request, by XRPC method and response status."
+    );
+    describe_gauge!(
+        "appview_database_connections",
+        "Connections currently open in the database pool."
+    );
+    describe_gauge!(
+        "appview_database_idle_connections",
+        "Open database pool connections that are not in use."
+    );
+    Ok(())
+}
+
+/// Count and time every request, labelled by the XRPC method it was routed to.
+pub async fn metrics_middleware(req: Request, next: Next) -> Response {
+    let nsid = match req.extensions().get::<MatchedPath>() {
+        Some(path) => {
+            let path = path.as_str();
+            path.strip_prefix("/xrpc/").unwrap_or(path).to_owned()
+        }
+        None => "unmatched".to_owned(),
+    };
+    let started = Instant::now();
+    let res = next.run(req).await;
+    let status = res.status().as_u16().to_string();
+    counter!("appview_requests_total", "nsid" => nsid.clone(), "status" => status.clone())
+        .increment(1);
+    histogram!("appview_request_duration_seconds", "nsid" => nsid, "status" => status)
+        .record(started.elapsed().as_secs_f64());
+    res
+}
+
+/// Periodically record how many database pool connections are open and idle.
+pub async fn poll_database_pool(database: Database, interval: Duration) {
+    let mut interval = tokio::time::interval(interval);
+    loop {
+        interval.tick().await;
+        let (open, idle) = database.connection_counts();
+        gauge!("appview_database_connections").set(open as f64);
+        gauge!("appview_database_idle_connections").set(idle as f64);
+    }
+}
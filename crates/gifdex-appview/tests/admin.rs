@@ -0,0 +1,124 @@
+//! Checks that only admins can manage operator takedowns, and that takedowns can be reversed.
+
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::{ADMIN, TestApp, insert_accounts, insert_post};
+use serde_json::json;
+use sqlx::PgPool;
+
+const AUTHOR: &str = "did:plc:ewvi7nxzyoun6zhxrhs64oiz";
+const NON_ADMIN: &str = "did:plc:44ybard66vv44zksje25o7dz";
+const RKEY: &str = "3jzfcijpj2z2a:bafkreidwslb22nkaxoadyaqlhlxgntmiq4jdenhkbrxhcq6avxlt75br5u";
+
+fn post_uri() -> String {
+    format!("at://{AUTHOR}/net.gifdex.feed.post/{RKEY}")
+}
+
+/// The error returned when fetching the post, if any.
+async fn get_post_error(app: &TestApp) -> Result<Option<String>> {
+    let (_, body) = app
+        .query(
+            None,
+            "net.gifdex.feed.getPost",
+            &format!("actor={AUTHOR}&rkey={RKEY}"),
+        )
+        .await?;
+    Ok(body["error"].as_str().map(str::to_owned))
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn non_admins_are_forbidden(pool: PgPool) -> Result<()> {
+    let app = TestApp::new(pool).await?;
+
+    let (status, body) = app
+        .procedure(
+            NON_ADMIN,
+            "net.gifdex.admin.takedownSubject",
+            json!({ "subject": format!("at://{AUTHOR}") }),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["error"], "Forbidden");
+
+    let (status, _) = app
+        .query(Some(NON_ADMIN), "net.gifdex.admin.listTakedowns", "")
+        .await?;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn unauthenticated_requests_are_rejected(pool: PgPool) -> Result<()> {
+    let app = TestApp::new(pool).await?;
+
+    let (status, _) = app
+        .query(None, "net.gifdex.admin.listTakedowns", "")
+        .await?;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn takedowns_hide_posts_until_reversed(pool: PgPool) -> Result<()> {
+    insert_accounts(&pool, &[AUTHOR]).await?;
+    insert_post(&pool, AUTHOR, RKEY, 0).await?;
+    let app = TestApp::new(pool).await?;
+    assert_eq!(get_post_error(&app).await?, None);
+
+    let (status, body) = app
+        .procedure(
+            ADMIN,
+            "net.gifdex.admin.takedownSubject",
+            json!({ "subject": post_uri(), "reason": "Spam" }),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["takedown"]["admin"], ADMIN);
+    assert_eq!(get_post_error(&app).await?.as_deref(), Some("PostNotFound"));
+
+    let (status, _) = app
+        .procedure(
+            ADMIN,
+            "net.gifdex.admin.reverseTakedown",
+            json!({ "subject": post_uri() }),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(get_post_error(&app).await?, None);
+
+    let (status, body) = app
+        .query(
+            Some(ADMIN),
+            "net.gifdex.admin.getSubjectStatus",
+            &format!("subject={}", post_uri()),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.get("takedown").is_none());
+    let actions: Vec<_> = body["history"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|event| event["action"].as_str().unwrap())
+        .collect();
+    assert_eq!(actions, ["reverseTakedown", "takedown"]);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn reversing_a_subject_without_a_takedown_fails(pool: PgPool) -> Result<()> {
+    let app = TestApp::new(pool).await?;
+
+    let (status, body) = app
+        .procedure(
+            ADMIN,
+            "net.gifdex.admin.reverseTakedown",
+            json!({ "subject": post_uri() }),
+        )
+        .await?;
+    assert_ne!(status, StatusCode::OK);
+    assert_eq!(body["error"], "SubjectNotTakenDown");
+    Ok(())
+}
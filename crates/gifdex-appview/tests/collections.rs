@@ -0,0 +1,201 @@
+//! Checks that collections list their visible items in the collection's order.
+
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::{ADMIN, TestApp, insert_accounts, insert_post, post_rkeys};
+use serde_json::{Value, json};
+use sqlx::{PgPool, query};
+
+const CURATOR: &str = "did:plc:ewvi7nxzyoun6zhxrhs64oiz";
+const AUTHOR: &str = "did:plc:44ybard66vv44zksje25o7dz";
+const COLLECTION_RKEY: &str = "3jzfcijpj2z2a";
+const OTHER_COLLECTION_RKEY: &str = "3jzfcijpj2z2b";
+
+fn collection_uri(rkey: &str) -> String {
+    format!("at://{CURATOR}/net.gifdex.feed.collection/{rkey}")
+}
+
+fn post_rkey(i: usize) -> String {
+    format!("3jzfcijpj2z2{i}")
+}
+
+async fn insert_collection(
+    pool: &PgPool,
+    rkey: &str,
+    ordering: &str,
+    created_at: i64,
+) -> Result<()> {
+    query!(
+        "INSERT INTO collections (did, rkey, title, ordering, created_at) \
+         VALUES ($1, $2, 'Collection', $3::TEXT::collection_ordering, $4)",
+        CURATOR,
+        rkey,
+        ordering,
+        created_at
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Add the author's post to a collection, at the given manual position and creation time.
+async fn insert_item(
+    pool: &PgPool,
+    collection_rkey: &str,
+    post: usize,
+    position: Option<i64>,
+    created_at: i64,
+) -> Result<()> {
+    query!(
+        "INSERT INTO collection_items (did, rkey, collection_rkey, post_did, post_rkey, \
+         position, created_at) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        CURATOR,
+        format!("{collection_rkey}{post}"),
+        collection_rkey,
+        AUTHOR,
+        post_rkey(post),
+        position,
+        created_at
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+async fn get_collection(app: &TestApp, rkey: &str) -> Result<Value> {
+    let (status, body) = app
+        .query(
+            None,
+            "net.gifdex.feed.getCollection",
+            &format!("uri={}", collection_uri(rkey)),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    Ok(body)
+}
+
+/// The URIs of the collections in a response, in order.
+fn collection_uris(body: &Value) -> Vec<&str> {
+    body["collections"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|collection| collection["uri"].as_str().unwrap())
+        .collect()
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn collections_list_items_in_their_ordering(pool: PgPool) -> Result<()> {
+    insert_accounts(&pool, &[CURATOR, AUTHOR]).await?;
+    for post in 0..3 {
+        insert_post(&pool, AUTHOR, &post_rkey(post), 0).await?;
+    }
+    insert_collection(&pool, COLLECTION_RKEY, "manual", 0).await?;
+    insert_item(&pool, COLLECTION_RKEY, 0, None, 1).await?;
+    insert_item(&pool, COLLECTION_RKEY, 1, Some(2), 2).await?;
+    insert_item(&pool, COLLECTION_RKEY, 2, Some(1), 3).await?;
+    insert_collection(&pool, OTHER_COLLECTION_RKEY, "newest", 0).await?;
+    insert_item(&pool, OTHER_COLLECTION_RKEY, 0, None, 1).await?;
+    insert_item(&pool, OTHER_COLLECTION_RKEY, 1, None, 3).await?;
+    insert_item(&pool, OTHER_COLLECTION_RKEY, 2, None, 2).await?;
+    let app = TestApp::new(pool).await?;
+
+    // Items without a position go after positioned ones.
+    let manual = get_collection(&app, COLLECTION_RKEY).await?;
+    assert_eq!(post_rkeys(&manual["items"]), [2, 1, 0].map(post_rkey));
+    assert_eq!(manual["collection"]["itemCount"], 3);
+    let newest = get_collection(&app, OTHER_COLLECTION_RKEY).await?;
+    assert_eq!(post_rkeys(&newest["items"]), [1, 2, 0].map(post_rkey));
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn collections_skip_items_that_cannot_be_shown(pool: PgPool) -> Result<()> {
+    insert_accounts(&pool, &[CURATOR, AUTHOR]).await?;
+    insert_post(&pool, AUTHOR, &post_rkey(0), 0).await?;
+    insert_post(&pool, AUTHOR, &post_rkey(1), 0).await?;
+    insert_collection(&pool, COLLECTION_RKEY, "manual", 0).await?;
+    for post in 0..3 {
+        // The last post was never indexed.
+        insert_item(&pool, COLLECTION_RKEY, post, Some(post as i64), 0).await?;
+    }
+    let app = TestApp::new(pool).await?;
+    let (status, _) = app
+        .procedure(
+            ADMIN,
+            "net.gifdex.admin.takedownSubject",
+            json!({ "subject": format!("at://{AUTHOR}/net.gifdex.feed.post/{}", post_rkey(1)) }),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+
+    let collection = get_collection(&app, COLLECTION_RKEY).await?;
+    assert_eq!(post_rkeys(&collection["items"]), [post_rkey(0)]);
+    assert_eq!(collection["collection"]["itemCount"], 1);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn missing_collections_are_not_found(pool: PgPool) -> Result<()> {
+    insert_accounts(&pool, &[CURATOR]).await?;
+    let app = TestApp::new(pool).await?;
+
+    let (_, body) = app
+        .query(
+            None,
+            "net.gifdex.feed.getCollection",
+            &format!("uri={}", collection_uri(COLLECTION_RKEY)),
+        )
+        .await?;
+    assert_eq!(body["error"], "CollectionNotFound");
+    let (_, body) = app
+        .query(
+            None,
+            "net.gifdex.feed.getCollection",
+            &format!("uri=at://{CURATOR}/net.gifdex.feed.post/{COLLECTION_RKEY}"),
+        )
+        .await?;
+    assert_eq!(body["error"], "CollectionNotFound");
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn collections_are_listed_by_author_and_post(pool: PgPool) -> Result<()> {
+    insert_accounts(&pool, &[CURATOR, AUTHOR]).await?;
+    insert_post(&pool, AUTHOR, &post_rkey(0), 0).await?;
+    insert_post(&pool, AUTHOR, &post_rkey(1), 0).await?;
+    insert_collection(&pool, COLLECTION_RKEY, "manual", 1).await?;
+    insert_collection(&pool, OTHER_COLLECTION_RKEY, "manual", 2).await?;
+    insert_item(&pool, COLLECTION_RKEY, 0, None, 0).await?;
+    insert_item(&pool, OTHER_COLLECTION_RKEY, 1, None, 0).await?;
+    let app = TestApp::new(pool).await?;
+
+    let (status, body) = app
+        .query(
+            None,
+            "net.gifdex.feed.getActorCollections",
+            &format!("actor={CURATOR}"),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        collection_uris(&body),
+        [
+            collection_uri(OTHER_COLLECTION_RKEY),
+            collection_uri(COLLECTION_RKEY)
+        ]
+    );
+
+    let (status, body) = app
+        .query(
+            None,
+            "net.gifdex.feed.getCollectionsForPost",
+            &format!("uri=at://{AUTHOR}/net.gifdex.feed.post/{}", post_rkey(0)),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(collection_uris(&body), [collection_uri(COLLECTION_RKEY)]);
+    Ok(())
+}
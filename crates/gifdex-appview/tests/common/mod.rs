@@ -0,0 +1,216 @@
+//! Fixtures shared by the integration tests.
+//!
+//! These run against a real Postgres database, with `DATABASE_URL` pointing at a server the tests
+//! can create throwaway databases on. Requests are authenticated with service auth tokens signed
+//! by keys published from a local PLC directory, so they're verified the same way as in
+//! production.
+
+#![allow(dead_code)]
+
+use anyhow::Result;
+use axum::{
+    Json, Router,
+    body::Body,
+    extract::Path,
+    http::{Request, StatusCode, header},
+    routing::get,
+};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use gifdex_appview::{
+    AppState, AppStateInner, build_service_did_doc, cdn::CdnClient, database::Database, router,
+};
+use http_body_util::BodyExt;
+use jacquard_axum::service_auth::ServiceAuthConfig;
+use jacquard_common::{types::did::Did, url::Url};
+use jacquard_identity::{
+    JacquardResolver,
+    resolver::{DidStep, PlcSource, ResolverOptions},
+};
+use k256::ecdsa::{Signature, SigningKey, signature::Signer};
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use sqlx::{PgPool, query};
+use std::{
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tokio::net::TcpListener;
+use tower::ServiceExt;
+
+/// DID the AppView under test serves as.
+pub const SERVICE_DID: &str = "did:web:appview.gifdex.test";
+
+/// Account configured as an admin.
+pub const ADMIN: &str = "did:plc:kkf4naxqmweop7dv4l2iqqf5";
+
+/// Number of reports an account can make per hour.
+pub const REPORT_RATE_LIMIT: u32 = 3;
+
+/// The AppView's router, along with a PLC directory publishing every account's signing key.
+pub struct TestApp {
+    router: Router,
+}
+
+impl TestApp {
+    /// Serve the AppView against the test's database.
+    pub async fn new(pool: PgPool) -> Result<Self> {
+        let plc_directory = TcpListener::bind("127.0.0.1:0").await?;
+        let plc_url = Url::parse(&format!("http://{}/", plc_directory.local_addr()?))?;
+        let plc_router = Router::new().route("/{did}", get(handle_did_document));
+        tokio::spawn(async move { axum::serve(plc_directory, plc_router).await });
+
+        let service_did = Did::new_static(SERVICE_DID)?;
+        let resolver_options = ResolverOptions::new()
+            .plc_source(PlcSource::PlcDirectory { base: plc_url })
+            .handle_order(Vec::new())
+            .did_order(vec![DidStep::PlcHttp])
+            .validate_doc_id(true)
+            .public_fallback_for_handle(false)
+            .build();
+        let service_url = Url::parse("http://appview.gifdex.test")?;
+        let cdn_url = Url::parse("http://cdn.gifdex.test")?;
+        let state = AppState(Arc::new(AppStateInner {
+            database: Database::from_pool(pool),
+            cdn: CdnClient::new(cdn_url, None),
+            service_did_document: build_service_did_doc(&service_did, &service_url),
+            service_auth_config: ServiceAuthConfig::new(
+                service_did,
+                JacquardResolver::new(reqwest::Client::new(), resolver_options),
+            ),
+            admin_dids: vec![ADMIN.to_owned()],
+            report_rate_limit: REPORT_RATE_LIMIT,
+        }));
+        Ok(Self {
+            router: router(state),
+        })
+    }
+
+    /// Make a query, authenticated as `viewer` when given.
+    pub async fn query(
+        &self,
+        viewer: Option<&str>,
+        nsid: &str,
+        params: &str,
+    ) -> Result<(StatusCode, Value)> {
+        let mut request = Request::get(format!("/xrpc/{nsid}?{params}"));
+        if let Some(viewer) = viewer {
+            request = request.header(header::AUTHORIZATION, bearer(viewer, nsid));
+        }
+        self.send(request.body(Body::empty())?).await
+    }
+
+    /// Make a procedure call, authenticated as `viewer`.
+    pub async fn procedure(
+        &self,
+        viewer: &str,
+        nsid: &str,
+        input: Value,
+    ) -> Result<(StatusCode, Value)> {
+        let request = Request::post(format!("/xrpc/{nsid}"))
+            .header(header::AUTHORIZATION, bearer(viewer, nsid))
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(input.to_string()))?;
+        self.send(request).await
+    }
+
+    async fn send(&self, request: Request<Body>) -> Result<(StatusCode, Value)> {
+        let response = self.router.clone().oneshot(request).await?;
+        let status = response.status();
+        let body = response.into_body().collect().await?.to_bytes();
+        let body = match body.is_empty() {
+            true => Value::Null,
+            false => serde_json::from_slice(&body)?,
+        };
+        Ok((status, body))
+    }
+}
+
+/// Signing key for an account, derived from its DID so the PLC directory can publish it without
+/// any setup.
+fn signing_key(did: &str) -> SigningKey {
+    SigningKey::from_slice(&Sha256::digest(did)).unwrap()
+}
+
+async fn handle_did_document(Path(did): Path<String>) -> Json<Value> {
+    let mut public_key = vec![0xe7, 0x01];
+    public_key.extend_from_slice(
+        signing_key(&did)
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes(),
+    );
+    Json(json!({
+        "@context": ["https://www.w3.org/ns/did/v1"],
+        "id": did,
+        "verificationMethod": [{
+            "id": format!("{did}#atproto"),
+            "type": "Multikey",
+            "controller": did,
+            "publicKeyMultibase": multibase::encode(multibase::Base::Base58Btc, public_key),
+        }],
+    }))
+}
+
+/// An `Authorization` header with a service auth token for calling `nsid` as `did`.
+fn bearer(did: &str, nsid: &str) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let header = json!({ "alg": "ES256K", "typ": "JWT" });
+    let claims = json!({
+        "iss": did,
+        "aud": SERVICE_DID,
+        "iat": now,
+        "exp": now + 60,
+        "lxm": nsid,
+    });
+    let signing_input = format!(
+        "{}.{}",
+        URL_SAFE_NO_PAD.encode(header.to_string()),
+        URL_SAFE_NO_PAD.encode(claims.to_string())
+    );
+    let signature: Signature = signing_key(did).sign(signing_input.as_bytes());
+    format!(
+        "Bearer {signing_input}.{}",
+        URL_SAFE_NO_PAD.encode(signature.to_bytes())
+    )
+}
+
+pub async fn insert_accounts(pool: &PgPool, dids: &[&str]) -> Result<()> {
+    for did in dids {
+        query!("INSERT INTO accounts (did, created_at) VALUES ($1, 0)", did)
+            .execute(pool)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Index a post directly, created at the given time.
+pub async fn insert_post(pool: &PgPool, did: &str, rkey: &str, created_at: i64) -> Result<()> {
+    query!(
+        "INSERT INTO posts (did, rkey, title, media_blob_cid, media_blob_mime, \
+         media_blob_width, media_blob_height, created_at) \
+         VALUES ($1, $2, 'Post', 'bafkreib7ytgp45cyodrmbwm7ohzq74dfnsg63va4yhl5hu3wwdn6nbpc6m', \
+         'image/webp', 1, 1, $3)",
+        did,
+        rkey,
+        created_at
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Record keys of a list of posts, in order.
+pub fn post_rkeys(posts: &Value) -> Vec<String> {
+    posts
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|item| {
+            let uri = item["uri"].as_str().unwrap_or_default();
+            uri.rsplit('/').next().unwrap_or_default().to_owned()
+        })
+        .collect()
+}
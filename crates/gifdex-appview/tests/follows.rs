@@ -0,0 +1,139 @@
+//! Checks follower counts, the viewer's follow state and paging through the timeline.
+
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::{TestApp, insert_accounts, insert_post, post_rkeys};
+use serde_json::Value;
+use sqlx::{PgPool, query};
+
+const VIEWER: &str = "did:plc:ewvi7nxzyoun6zhxrhs64oiz";
+const AUTHOR: &str = "did:plc:44ybard66vv44zksje25o7dz";
+const OTHER_AUTHOR: &str = "did:plc:ar7c4by46qjdydhdevvrndac";
+const DEACTIVATED: &str = "did:plc:vwzwgnygau7ed7b7wt5ux7y2";
+const BLOCKED: &str = "did:plc:upo6iq6ekh66d5mwhzrvsjhq";
+const FOLLOW_RKEY: &str = "3jzfcijpj2z2b";
+const OTHER_FOLLOW_RKEY: &str = "3jzfcijpj2z2c";
+
+async fn insert_follow(pool: &PgPool, did: &str, rkey: &str, subject_did: &str) -> Result<()> {
+    query!(
+        "INSERT INTO follows (did, rkey, subject_did, created_at) VALUES ($1, $2, $3, 0)",
+        did,
+        rkey,
+        subject_did
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+async fn get_profile(app: &TestApp, viewer: Option<&str>, actor: &str) -> Result<Value> {
+    let (status, body) = app
+        .query(
+            viewer,
+            "net.gifdex.actor.getProfile",
+            &format!("actor={actor}"),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    Ok(body)
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn counts_only_include_visible_accounts(pool: PgPool) -> Result<()> {
+    insert_accounts(&pool, &[VIEWER, AUTHOR, DEACTIVATED, BLOCKED]).await?;
+    for follower in [VIEWER, DEACTIVATED, BLOCKED] {
+        insert_follow(&pool, follower, FOLLOW_RKEY, AUTHOR).await?;
+    }
+    query!(
+        "UPDATE accounts SET is_active = false, status = 'deactivated' WHERE did = $1",
+        DEACTIVATED
+    )
+    .execute(&pool)
+    .await?;
+    query!(
+        "INSERT INTO blocks (did, rkey, subject_did, created_at) VALUES ($1, '3jzfcijpj2z2a', $2, 0)",
+        AUTHOR,
+        BLOCKED
+    )
+    .execute(&pool)
+    .await?;
+    let app = TestApp::new(pool).await?;
+
+    let author = get_profile(&app, None, AUTHOR).await?;
+    assert_eq!(author["followerCount"], 1);
+    assert_eq!(author["followingCount"], 0);
+    let viewer = get_profile(&app, None, VIEWER).await?;
+    assert_eq!(viewer["followerCount"], 0);
+    assert_eq!(viewer["followingCount"], 1);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn profiles_show_whether_the_viewer_follows_them(pool: PgPool) -> Result<()> {
+    insert_accounts(&pool, &[VIEWER, AUTHOR, OTHER_AUTHOR]).await?;
+    insert_follow(&pool, VIEWER, FOLLOW_RKEY, AUTHOR).await?;
+    let app = TestApp::new(pool).await?;
+
+    let followed = get_profile(&app, Some(VIEWER), AUTHOR).await?;
+    assert_eq!(followed["viewer"]["following"], FOLLOW_RKEY);
+    let unfollowed = get_profile(&app, Some(VIEWER), OTHER_AUTHOR).await?;
+    assert!(unfollowed["viewer"].get("following").is_none());
+    let anonymous = get_profile(&app, None, AUTHOR).await?;
+    assert!(anonymous.get("viewer").is_none());
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn timelines_page_through_posts_sharing_a_creation_time(pool: PgPool) -> Result<()> {
+    insert_accounts(&pool, &[VIEWER, AUTHOR, OTHER_AUTHOR]).await?;
+    insert_follow(&pool, VIEWER, FOLLOW_RKEY, AUTHOR).await?;
+    insert_follow(&pool, VIEWER, OTHER_FOLLOW_RKEY, OTHER_AUTHOR).await?;
+    let rkey = |i| format!("3jzfcijpj2z2{i}");
+    for (i, created_at) in [3, 2, 2, 2, 1].into_iter().enumerate() {
+        insert_post(&pool, AUTHOR, &rkey(i), created_at).await?;
+        insert_post(&pool, OTHER_AUTHOR, &rkey(i), created_at).await?;
+    }
+    let app = TestApp::new(pool).await?;
+
+    let mut seen = Vec::new();
+    let mut cursor = None;
+    loop {
+        let params = match &cursor {
+            Some(cursor) => format!("limit=3&cursor={cursor}"),
+            None => "limit=3".to_owned(),
+        };
+        let (status, body) = app
+            .query(Some(VIEWER), "net.gifdex.feed.getTimeline", &params)
+            .await?;
+        assert_eq!(status, StatusCode::OK);
+        seen.extend(post_rkeys(&body["feed"]));
+        match body["cursor"].as_str() {
+            Some(next) => cursor = Some(next.to_owned()),
+            None => break,
+        }
+    }
+
+    // Newest first, then by DID and rkey, with the other author's DID sorting first.
+    let expected = [0, 0, 3, 2, 1, 3, 2, 1, 4, 4].map(rkey);
+    assert_eq!(seen, expected);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn timelines_reject_malformed_cursors(pool: PgPool) -> Result<()> {
+    insert_accounts(&pool, &[VIEWER]).await?;
+    let app = TestApp::new(pool).await?;
+
+    let (status, body) = app
+        .query(
+            Some(VIEWER),
+            "net.gifdex.feed.getTimeline",
+            "cursor=yesterday",
+        )
+        .await?;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"], "InvalidRequest");
+    Ok(())
+}
@@ -0,0 +1,124 @@
+//! Checks that muted and blocked accounts are hidden from the viewer, and only from them.
+
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::{TestApp, insert_accounts, insert_post, post_rkeys};
+use serde_json::{Value, json};
+use sqlx::{PgPool, query};
+
+const VIEWER: &str = "did:plc:ewvi7nxzyoun6zhxrhs64oiz";
+const AUTHOR: &str = "did:plc:44ybard66vv44zksje25o7dz";
+const BYSTANDER: &str = "did:plc:ar7c4by46qjdydhdevvrndac";
+const RKEY: &str = "3jzfcijpj2z2a:bafkreidwslb22nkaxoadyaqlhlxgntmiq4jdenhkbrxhcq6avxlt75br5u";
+
+async fn setup(pool: &PgPool) -> Result<()> {
+    insert_accounts(pool, &[VIEWER, AUTHOR, BYSTANDER]).await?;
+    insert_post(pool, AUTHOR, RKEY, 0).await
+}
+
+async fn insert_block(pool: &PgPool, did: &str, subject_did: &str) -> Result<()> {
+    query!(
+        "INSERT INTO blocks (did, rkey, subject_did, created_at) VALUES ($1, '3jzfcijpj2z2a', $2, 0)",
+        did,
+        subject_did
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+async fn posts_by_author(app: &TestApp, viewer: Option<&str>) -> Result<Value> {
+    let (_, body) = app
+        .query(
+            viewer,
+            "net.gifdex.feed.getPostsByActor",
+            &format!("actor={AUTHOR}"),
+        )
+        .await?;
+    Ok(body)
+}
+
+/// Whether the author, and their post, can be seen by the viewer.
+async fn is_visible(app: &TestApp, viewer: Option<&str>) -> Result<bool> {
+    let (profile_status, _) = app
+        .query(
+            viewer,
+            "net.gifdex.actor.getProfile",
+            &format!("actor={AUTHOR}"),
+        )
+        .await?;
+    let (post_status, _) = app
+        .query(
+            viewer,
+            "net.gifdex.feed.getPost",
+            &format!("actor={AUTHOR}&rkey={RKEY}"),
+        )
+        .await?;
+    let posts = posts_by_author(app, viewer).await?;
+    let visible = profile_status == StatusCode::OK;
+    assert_eq!(post_status == StatusCode::OK, visible);
+    assert_eq!(post_rkeys(&posts["feed"]) == [RKEY], visible);
+    Ok(visible)
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn muted_accounts_are_hidden_from_the_viewer(pool: PgPool) -> Result<()> {
+    setup(&pool).await?;
+    let app = TestApp::new(pool).await?;
+    assert!(is_visible(&app, Some(VIEWER)).await?);
+
+    let (status, _) = app
+        .procedure(
+            VIEWER,
+            "net.gifdex.graph.muteActor",
+            json!({ "actor": AUTHOR }),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(!is_visible(&app, Some(VIEWER)).await?);
+    assert_eq!(
+        posts_by_author(&app, Some(VIEWER)).await?["error"],
+        "ActorNotFound"
+    );
+    assert!(is_visible(&app, Some(BYSTANDER)).await?);
+    assert!(is_visible(&app, None).await?);
+
+    let (_, body) = app
+        .query(Some(VIEWER), "net.gifdex.graph.getMutes", "")
+        .await?;
+    assert_eq!(body["mutes"][0]["did"], AUTHOR);
+
+    app.procedure(
+        VIEWER,
+        "net.gifdex.graph.unmuteActor",
+        json!({ "actor": AUTHOR }),
+    )
+    .await?;
+    assert!(is_visible(&app, Some(VIEWER)).await?);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn blocked_accounts_are_hidden_from_the_viewer(pool: PgPool) -> Result<()> {
+    setup(&pool).await?;
+    insert_block(&pool, VIEWER, AUTHOR).await?;
+    let app = TestApp::new(pool).await?;
+
+    assert!(!is_visible(&app, Some(VIEWER)).await?);
+    assert!(is_visible(&app, Some(BYSTANDER)).await?);
+    assert!(is_visible(&app, None).await?);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn accounts_blocking_the_viewer_are_hidden_from_them(pool: PgPool) -> Result<()> {
+    setup(&pool).await?;
+    insert_block(&pool, AUTHOR, VIEWER).await?;
+    let app = TestApp::new(pool).await?;
+
+    assert!(!is_visible(&app, Some(VIEWER)).await?);
+    assert!(is_visible(&app, Some(BYSTANDER)).await?);
+    Ok(())
+}
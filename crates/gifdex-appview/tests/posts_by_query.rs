@@ -0,0 +1,66 @@
+//! Checks that reuploads of the same media are collapsed across every page of results.
+
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::{TestApp, insert_accounts, insert_post, post_rkeys};
+use sqlx::{PgPool, query};
+
+const AUTHOR: &str = "did:plc:ewvi7nxzyoun6zhxrhs64oiz";
+
+const ORIGINAL_HASH: i64 = 0x0f0f_3c3c_5a5a_6969;
+/// The original's hash with a few bits flipped, as re-encoding the media does.
+const COPY_HASH: i64 = ORIGINAL_HASH ^ 0b1001_0110;
+const UNRELATED_HASH: i64 = !ORIGINAL_HASH;
+
+fn rkey(i: usize) -> String {
+    format!("3jzfcijpj2z2{i}")
+}
+
+async fn insert_post_with_hash(pool: &PgPool, i: usize, phash: i64) -> Result<()> {
+    // Later posts are older, so they're ranked lower when sorted by newest.
+    insert_post(pool, AUTHOR, &rkey(i), -(i as i64)).await?;
+    query!(
+        "UPDATE posts SET media_phash = $3 WHERE did = $1 AND rkey = $2",
+        AUTHOR,
+        rkey(i),
+        phash
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn reuploads_are_collapsed_across_pages(pool: PgPool) -> Result<()> {
+    insert_accounts(&pool, &[AUTHOR]).await?;
+    insert_post_with_hash(&pool, 0, ORIGINAL_HASH).await?;
+    insert_post_with_hash(&pool, 1, COPY_HASH).await?;
+    insert_post_with_hash(&pool, 2, UNRELATED_HASH).await?;
+    insert_post_with_hash(&pool, 3, COPY_HASH).await?;
+    insert_post(&pool, AUTHOR, &rkey(4), -4).await?;
+    let app = TestApp::new(pool).await?;
+
+    let mut seen = Vec::new();
+    let mut cursor = None;
+    loop {
+        let mut params = format!("actor={AUTHOR}&sortBy=newest&limit=1");
+        if let Some(cursor) = cursor {
+            params.push_str(&format!("&cursor={cursor}"));
+        }
+        let (status, body) = app
+            .query(None, "net.gifdex.feed.getPostsByQuery", &params)
+            .await?;
+        assert_eq!(status, StatusCode::OK);
+        seen.extend(post_rkeys(&body["feed"]));
+        match body["cursor"].as_i64() {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+
+    // Posts without a media hash are never collapsed.
+    assert_eq!(seen, [0, 2, 4].map(rkey));
+    Ok(())
+}
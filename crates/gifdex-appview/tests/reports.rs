@@ -0,0 +1,83 @@
+//! Checks that reports are rate limited per reporter and can only be reviewed by moderators.
+
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::{ADMIN, REPORT_RATE_LIMIT, TestApp};
+use serde_json::json;
+use sqlx::PgPool;
+
+const REPORTER: &str = "did:plc:ewvi7nxzyoun6zhxrhs64oiz";
+const OTHER_REPORTER: &str = "did:plc:44ybard66vv44zksje25o7dz";
+const SUBJECT: &str = "did:plc:ar7c4by46qjdydhdevvrndac";
+
+async fn report(app: &TestApp, reporter: &str) -> Result<(StatusCode, serde_json::Value)> {
+    app.procedure(
+        reporter,
+        "net.gifdex.moderation.createReport",
+        json!({ "subject": SUBJECT, "reasonType": "spam" }),
+    )
+    .await
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn reports_are_rate_limited_per_reporter(pool: PgPool) -> Result<()> {
+    let app = TestApp::new(pool).await?;
+
+    for _ in 0..REPORT_RATE_LIMIT {
+        let (status, body) = report(&app, REPORTER).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["report"]["reporter"], REPORTER);
+        assert_eq!(body["report"]["status"], "open");
+    }
+    let (status, body) = report(&app, REPORTER).await?;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(body["error"], "RateLimitExceeded");
+
+    let (status, _) = report(&app, OTHER_REPORTER).await?;
+    assert_eq!(status, StatusCode::OK);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn only_moderators_can_review_reports(pool: PgPool) -> Result<()> {
+    let app = TestApp::new(pool).await?;
+    let (_, body) = report(&app, REPORTER).await?;
+    let id = body["report"]["id"].as_i64().unwrap();
+
+    let (status, _) = app
+        .query(Some(REPORTER), "net.gifdex.moderation.listReports", "")
+        .await?;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = app
+        .procedure(
+            REPORTER,
+            "net.gifdex.moderation.updateReportStatus",
+            json!({ "id": id, "status": "resolved" }),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, body) = app
+        .procedure(
+            ADMIN,
+            "net.gifdex.moderation.updateReportStatus",
+            json!({ "id": id, "status": "resolved" }),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["report"]["status"], "resolved");
+    assert_eq!(body["report"]["updatedBy"], ADMIN);
+
+    let (status, body) = app
+        .query(
+            Some(ADMIN),
+            "net.gifdex.moderation.listReports",
+            "status=open",
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["reports"], json!([]));
+    Ok(())
+}
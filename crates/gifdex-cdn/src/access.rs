@@ -0,0 +1,268 @@
+use crate::AppState;
+use axum::{
+    extract::{ConnectInfo, Query, Request, State},
+    http::{HeaderMap, HeaderName, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use governor::{DefaultKeyedRateLimiter, Quota, RateLimiter, clock::Clock};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+use std::{
+    net::{IpAddr, SocketAddr},
+    num::NonZeroU32,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tracing::warn;
+
+/// Controls who may fetch media: either through a URL signed by the AppView, or
+/// anonymously at a limited rate.
+pub struct AccessControl {
+    /// Keys accepted for URL signatures. More than one can be configured during key rotation.
+    signing_keys: Vec<Vec<u8>>,
+    require_signature: bool,
+    rate_limiter: Option<DefaultKeyedRateLimiter<IpAddr>>,
+    /// Header the client address is read from instead of the peer address. The header is
+    /// trusted as-is, so this must only be set behind a proxy that overwrites it.
+    client_ip_header: Option<HeaderName>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SignatureQuery {
+    expires: Option<u64>,
+    sig: Option<String>,
+}
+
+impl AccessControl {
+    pub fn new(
+        signing_keys: Vec<String>,
+        require_signature: bool,
+        rate_limit: u32,
+        rate_limit_burst: u32,
+        client_ip_header: Option<HeaderName>,
+    ) -> Self {
+        let rate_limiter = NonZeroU32::new(rate_limit).map(|rate_limit| {
+            let burst = NonZeroU32::new(rate_limit_burst).unwrap_or(rate_limit);
+            RateLimiter::keyed(Quota::per_second(rate_limit).allow_burst(burst))
+        });
+        Self {
+            signing_keys: signing_keys.into_iter().map(String::into_bytes).collect(),
+            require_signature,
+            rate_limiter,
+            client_ip_header,
+        }
+    }
+
+    /// Drop rate limiter state for clients that have fully replenished their quota.
+    pub fn prune_rate_limiter(&self) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.retain_recent();
+            rate_limiter.shrink_to_fit();
+        }
+    }
+
+    /// Check a signature produced by the AppView over the request path and expiry time.
+    fn verify_signature(&self, path: &str, expires: u64, signature: &str) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(u64::MAX);
+        if expires < now {
+            return false;
+        }
+        let Ok(signature) = hex::decode(signature) else {
+            return false;
+        };
+        self.signing_keys.iter().any(|key| {
+            let mut mac =
+                Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+            mac.update(format!("{path}:{expires}").as_bytes());
+            mac.verify_slice(&signature).is_ok()
+        })
+    }
+
+    fn client_ip(&self, headers: &HeaderMap, peer: SocketAddr) -> IpAddr {
+        self.client_ip_header
+            .as_ref()
+            .and_then(|name| headers.get(name))
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(',').next())
+            .and_then(|value| value.trim().parse().ok())
+            .unwrap_or(peer.ip())
+    }
+}
+
+pub async fn access_middleware(
+    State(state): State<Arc<AppState>>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let access = &state.access;
+    let query = Query::<SignatureQuery>::try_from_uri(req.uri())
+        .map(|Query(query)| query)
+        .unwrap_or(SignatureQuery {
+            expires: None,
+            sig: None,
+        });
+
+    match (query.expires, query.sig.as_deref()) {
+        (Some(expires), Some(signature)) if !access.signing_keys.is_empty() => {
+            if !access.verify_signature(req.uri().path(), expires, signature) {
+                return (StatusCode::FORBIDDEN, "Invalid or expired URL signature").into_response();
+            }
+        }
+        _ if access.require_signature => {
+            return (StatusCode::FORBIDDEN, "A signed URL is required").into_response();
+        }
+        _ => {
+            if let Some(rate_limiter) = &access.rate_limiter {
+                let ip = access.client_ip(req.headers(), peer);
+                if let Err(not_until) = rate_limiter.check_key(&ip) {
+                    warn!("rate limited unsigned request from {ip}");
+                    let retry_after =
+                        not_until.wait_time_from(governor::clock::DefaultClock::default().now());
+                    return (
+                        StatusCode::TOO_MANY_REQUESTS,
+                        [(
+                            header::RETRY_AFTER,
+                            retry_after.as_secs().max(1).to_string(),
+                        )],
+                        "Too many requests",
+                    )
+                        .into_response();
+                }
+            }
+        }
+    }
+
+    next.run(req).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    const KEY: &str = "current-key";
+    const PATH: &str = "/media/did:plc:ewvi7nxzyoun6zhxrhs64oiz/3jzfcijpj2z2a/fullsize";
+
+    fn access(signing_keys: &[&str], client_ip_header: Option<&str>) -> AccessControl {
+        AccessControl::new(
+            signing_keys.iter().map(|key| key.to_string()).collect(),
+            false,
+            0,
+            0,
+            client_ip_header.map(|name| HeaderName::from_bytes(name.as_bytes()).unwrap()),
+        )
+    }
+
+    /// Sign a path the way the AppView does.
+    fn sign(key: &str, path: &str, expires: u64) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key.as_bytes()).unwrap();
+        mac.update(format!("{path}:{expires}").as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    fn in_an_hour() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 3600
+    }
+
+    #[test]
+    fn accepts_valid_signature() {
+        let expires = in_an_hour();
+        let signature = sign(KEY, PATH, expires);
+        assert!(access(&[KEY], None).verify_signature(PATH, expires, &signature));
+    }
+
+    #[test]
+    fn accepts_signature_from_any_configured_key() {
+        let expires = in_an_hour();
+        let signature = sign("previous-key", PATH, expires);
+        assert!(access(&[KEY, "previous-key"], None).verify_signature(PATH, expires, &signature));
+    }
+
+    #[test]
+    fn rejects_expired_signature() {
+        let expires = in_an_hour() - 7200;
+        let signature = sign(KEY, PATH, expires);
+        assert!(!access(&[KEY], None).verify_signature(PATH, expires, &signature));
+    }
+
+    #[test]
+    fn rejects_tampered_path() {
+        let expires = in_an_hour();
+        let signature = sign(KEY, PATH, expires);
+        let other_path = PATH.replace("fullsize", "thumbnail");
+        assert!(!access(&[KEY], None).verify_signature(&other_path, expires, &signature));
+    }
+
+    #[test]
+    fn rejects_tampered_expiry() {
+        let expires = in_an_hour();
+        let signature = sign(KEY, PATH, expires);
+        assert!(!access(&[KEY], None).verify_signature(PATH, expires + 3600, &signature));
+    }
+
+    #[test]
+    fn rejects_tampered_signature() {
+        let expires = in_an_hour();
+        let mut signature = sign(KEY, PATH, expires).into_bytes();
+        signature[0] = if signature[0] == b'0' { b'1' } else { b'0' };
+        let signature = String::from_utf8(signature).unwrap();
+        let access = access(&[KEY], None);
+        assert!(!access.verify_signature(PATH, expires, &signature));
+        assert!(!access.verify_signature(PATH, expires, "not hex"));
+        assert!(!access.verify_signature(PATH, expires, ""));
+    }
+
+    #[test]
+    fn rejects_signature_from_unknown_key() {
+        let expires = in_an_hour();
+        let signature = sign("other-key", PATH, expires);
+        assert!(!access(&[KEY], None).verify_signature(PATH, expires, &signature));
+    }
+
+    #[test]
+    fn client_ip_uses_first_address_from_trusted_header() {
+        let peer: SocketAddr = "10.0.0.1:443".parse().unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-forwarded-for",
+            HeaderValue::from_static("203.0.113.7, 10.0.0.2"),
+        );
+        let access = access(&[], Some("x-forwarded-for"));
+        assert_eq!(
+            access.client_ip(&headers, peer),
+            "203.0.113.7".parse::<IpAddr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn client_ip_ignores_header_unless_configured() {
+        let peer: SocketAddr = "10.0.0.1:443".parse().unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", HeaderValue::from_static("203.0.113.7"));
+        assert_eq!(access(&[], None).client_ip(&headers, peer), peer.ip());
+    }
+
+    #[test]
+    fn client_ip_falls_back_to_peer_address() {
+        let peer: SocketAddr = "10.0.0.1:443".parse().unwrap();
+        let access = access(&[], Some("x-forwarded-for"));
+        assert_eq!(access.client_ip(&HeaderMap::new(), peer), peer.ip());
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-forwarded-for",
+            HeaderValue::from_static("not an address"),
+        );
+        assert_eq!(access.client_ip(&headers, peer), peer.ip());
+    }
+}
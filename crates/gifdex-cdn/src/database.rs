@@ -1,6 +1,7 @@
 use anyhow::Result;
 use sqlx::{PgExecutor, PgPool, PgTransaction, migrate, postgres::PgPoolOptions};
 
+#[derive(Clone)]
 pub struct Database {
     pool: PgPool,
 }
@@ -20,6 +21,11 @@ impl Database {
         &self.pool
     }
 
+    /// Number of connections currently open in the pool, and how many of those are idle.
+    pub fn connection_counts(&self) -> (u32, usize) {
+        (self.pool.size(), self.pool.num_idle())
+    }
+
     /// Create a new transaction using the database pool.
     pub async fn transaction(&self) -> Result<PgTransaction<'_>> {
         Ok(self.pool.begin().await?)
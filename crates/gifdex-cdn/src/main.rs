@@ -1,19 +1,29 @@
+mod access;
 mod database;
+mod moderation;
 mod routes;
+mod telemetry;
+mod transcode;
 
-use crate::routes::{avatar::get_avatar_handler, gif::get_gif_handler};
+use crate::{
+    access::{AccessControl, access_middleware},
+    routes::{avatar::get_avatar_handler, gif::get_gif_handler},
+};
 use anyhow::Result;
 use axum::{
     Router,
+    body::Bytes,
     extract::Request,
-    http::{HeaderValue, StatusCode, header},
+    http::{HeaderName, HeaderValue, StatusCode, header},
     middleware::{self as axum_middleware, Next},
     routing::get,
 };
 use clap::Parser;
 use database::Database;
 use dotenvy::dotenv;
-use std::{net::SocketAddr, sync::Arc};
+use gifdex_media::MediaFormat;
+use moka::future::Cache;
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 use tokio::{net::TcpListener, signal};
 use tower_http::{
     catch_panic::CatchPanicLayer,
@@ -21,7 +31,6 @@ use tower_http::{
     trace::{self, DefaultOnFailure, DefaultOnRequest, DefaultOnResponse, TraceLayer},
 };
 use tracing::{Level, info};
-use tracing_subscriber::EnvFilter;
 
 const MAX_AVATAR_SIZE: usize = 3 * 1024 * 1024; // 3MB
 const MAX_BLOB_SIZE: usize = 10 * 1024 * 1024; // 10MB
@@ -37,22 +46,112 @@ struct Arguments {
     )]
     address: SocketAddr,
 
+    /// Local socket address to serve Prometheus metrics on.
+    ///
+    /// This is kept separate from the main address so metrics are never exposed publicly.
+    #[arg(
+        long = "admin-address",
+        env = "GIFDEX_CDN_ADMIN_ADDRESS",
+        default_value = "127.0.0.1:9291"
+    )]
+    admin_address: SocketAddr,
+
+    /// OTLP/HTTP endpoint to export tracing spans to, e.g. `http://localhost:4318/v1/traces`.
+    ///
+    /// Trace context is also propagated to PDSes using the W3C `traceparent` header.
+    #[arg(long = "otlp-endpoint", env = "GIFDEX_CDN_OTLP_ENDPOINT")]
+    otlp_endpoint: Option<reqwest::Url>,
+
     #[arg(long = "database-url", env = "DATABASE_URL")]
     database_url: String,
+
+    /// Maximum total size in bytes of transcoded media variants kept in memory.
+    #[arg(
+        long = "variant-cache-capacity",
+        env = "GIFDEX_CDN_VARIANT_CACHE_CAPACITY",
+        default_value_t = 256 * 1024 * 1024
+    )]
+    variant_cache_capacity: u64,
+
+    /// Maximum total size in bytes of resized avatars kept in memory.
+    #[arg(
+        long = "avatar-cache-capacity",
+        env = "GIFDEX_CDN_AVATAR_CACHE_CAPACITY",
+        default_value_t = 64 * 1024 * 1024
+    )]
+    avatar_cache_capacity: u64,
+
+    /// Secret keys accepted for signed media URLs, comma separated.
+    ///
+    /// The AppView signs with a single key; list both the old and new key here while rotating.
+    #[arg(
+        long = "signing-keys",
+        env = "GIFDEX_CDN_SIGNING_KEYS",
+        value_delimiter = ',',
+        hide_env_values = true
+    )]
+    signing_keys: Vec<String>,
+
+    /// Reject any media request that does not carry a valid signature.
+    #[arg(
+        long = "require-signed-urls",
+        env = "GIFDEX_CDN_REQUIRE_SIGNED_URLS",
+        default_value_t = false,
+        requires = "signing_keys"
+    )]
+    require_signed_urls: bool,
+
+    /// Requests per second allowed from a single IP address for unsigned URLs, 0 to disable.
+    #[arg(
+        long = "unsigned-rate-limit",
+        env = "GIFDEX_CDN_UNSIGNED_RATE_LIMIT",
+        default_value_t = 10
+    )]
+    unsigned_rate_limit: u32,
+
+    /// Number of unsigned requests a single IP address can make in a burst.
+    #[arg(
+        long = "unsigned-rate-limit-burst",
+        env = "GIFDEX_CDN_UNSIGNED_RATE_LIMIT_BURST",
+        default_value_t = 50
+    )]
+    unsigned_rate_limit_burst: u32,
+
+    /// Header containing the client IP address when running behind a reverse proxy.
+    ///
+    /// The first address in the header is used, e.g. `X-Forwarded-For`. Only set this when the
+    /// CDN can't be reached except through a trusted proxy that overwrites the header, as
+    /// clients can otherwise pick any address and evade the unsigned rate limit.
+    #[arg(long = "client-ip-header", env = "GIFDEX_CDN_CLIENT_IP_HEADER")]
+    client_ip_header: Option<HeaderName>,
+
+    /// DIDs of labelers whose takedown labels the CDN enforces, comma separated.
+    #[arg(
+        long = "trusted-labelers",
+        env = "GIFDEX_CDN_TRUSTED_LABELERS",
+        value_delimiter = ','
+    )]
+    trusted_labelers: Vec<String>,
 }
 
 struct AppState {
     database: Database,
     http_client: reqwest::Client,
+    /// Transcoded media keyed by the source blob CID and output format.
+    variant_cache: Cache<(String, MediaFormat), Bytes>,
+    /// Resized avatars keyed by the source blob CID and size.
+    avatar_cache: Cache<(String, u32), Bytes>,
+    access: AccessControl,
+    /// Labelers whose takedowns are enforced.
+    trusted_labelers: Vec<String>,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     dotenv().ok();
-    tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("info")))
-        .init();
     let args = Arguments::parse();
+    let tracer_provider =
+        gifdex_telemetry::init_tracing(env!("CARGO_PKG_NAME"), args.otlp_endpoint.as_ref())?;
     let app_state = Arc::new(AppState {
         database: Database::new(&args.database_url).await?,
         http_client: reqwest::Client::builder()
@@ -63,16 +162,56 @@ async fn main() -> Result<()> {
                 env!("CARGO_PKG_VERSION")
             ))
             .build()?,
+        variant_cache: Cache::builder()
+            .max_capacity(args.variant_cache_capacity)
+            .weigher(|_, bytes: &Bytes| bytes.len().try_into().unwrap_or(u32::MAX))
+            .build(),
+        avatar_cache: Cache::builder()
+            .max_capacity(args.avatar_cache_capacity)
+            .weigher(|_, bytes: &Bytes| bytes.len().try_into().unwrap_or(u32::MAX))
+            .build(),
+        access: AccessControl::new(
+            args.signing_keys,
+            args.require_signed_urls,
+            args.unsigned_rate_limit,
+            args.unsigned_rate_limit_burst,
+            args.client_ip_header,
+        ),
+        trusted_labelers: args.trusted_labelers,
     });
 
-    let router = Router::new()
-        .route("/", get(async || "Gifdex CDN"))
+    telemetry::install(args.admin_address)?;
+    tokio::spawn(telemetry::poll_database_pool(
+        app_state.database.clone(),
+        Duration::from_secs(15),
+    ));
+    tokio::spawn({
+        let app_state = Arc::clone(&app_state);
+        async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(60));
+            loop {
+                interval.tick().await;
+                app_state.access.prune_rate_limiter();
+            }
+        }
+    });
+
+    let media_router = Router::new()
         .route("/media/{did}/{rkey}", get(get_gif_handler))
         .route("/avatar/{did}/{cid}", get(get_avatar_handler))
+        .route_layer(axum_middleware::from_fn_with_state(
+            Arc::clone(&app_state),
+            access_middleware,
+        ));
+
+    let router = Router::new()
+        .route("/", get(async || "Gifdex CDN"))
+        .merge(media_router)
         .nest(
             "/xrpc",
             Router::new().route("/", get(async || StatusCode::OK)),
         )
+        .layer(axum_middleware::from_fn(telemetry::metrics_middleware))
         .layer(
             TraceLayer::new_for_http()
                 .make_span_with(trace::DefaultMakeSpan::new().level(Level::INFO))
@@ -105,9 +244,17 @@ async fn main() -> Result<()> {
         "Internal server started - listening on: http://{}",
         args.address,
     );
-    axum::serve(tcp_listener, router)
-        .with_graceful_shutdown(shutdown_signal())
-        .await?;
+    info!(
+        "Metrics server started - listening on: http://{}/metrics",
+        args.admin_address,
+    );
+    axum::serve(
+        tcp_listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal())
+    .await?;
+    gifdex_telemetry::shutdown_tracing(tracer_provider);
 
     Ok(())
 }
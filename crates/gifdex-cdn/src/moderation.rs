@@ -0,0 +1,48 @@
+use crate::AppState;
+use sqlx::query;
+
+/// Whether an operator or a trusted labeler has taken down the account.
+pub async fn is_account_taken_down(state: &AppState, did: &str) -> sqlx::Result<bool> {
+    let result = query!(
+        "SELECT is_admin_taken_down($1, NULL, NULL) OR EXISTS(
+           SELECT 1 FROM labels l
+           INNER JOIN labeler_rules r ON r.did = l.rule_did AND r.rkey = l.rule_rkey
+           WHERE l.subject_did = $1 AND l.subject_collection IS NULL
+             AND l.did = ANY($2) AND r.takedown
+             AND (l.expires_at IS NULL OR l.expires_at > (extract(epoch from now()) * 1000)::BIGINT)
+         ) as \"exists!\"",
+        did,
+        &state.trusted_labelers
+    )
+    .fetch_one(state.database.executor())
+    .await?;
+    Ok(result.exists)
+}
+
+/// Whether an operator or a trusted labeler has taken down the post or its author.
+///
+/// Labels that opted in to media hash matching also take down reuploads of similar media,
+/// through the matches ingest stores in `label_media_matches`.
+pub async fn is_post_taken_down(state: &AppState, did: &str, rkey: &str) -> sqlx::Result<bool> {
+    let result = query!(
+        "SELECT is_admin_taken_down($1, 'net.gifdex.feed.post', $2) OR EXISTS(
+           SELECT 1 FROM labels l
+           INNER JOIN labeler_rules r ON r.did = l.rule_did AND r.rkey = l.rule_rkey
+           WHERE l.subject_did = $1 AND l.subject_collection IS NULL
+             AND l.did = ANY($3) AND r.takedown
+             AND (l.expires_at IS NULL OR l.expires_at > (extract(epoch from now()) * 1000)::BIGINT)
+           UNION ALL
+           SELECT 1 FROM post_labels pl
+           INNER JOIN labeler_rules r ON r.did = pl.rule_did AND r.rkey = pl.rule_rkey
+           WHERE pl.post_did = $1 AND pl.post_rkey = $2
+             AND pl.did = ANY($3) AND r.takedown
+             AND (pl.expires_at IS NULL OR pl.expires_at > (extract(epoch from now()) * 1000)::BIGINT)
+         ) as \"exists!\"",
+        did,
+        rkey,
+        &state.trusted_labelers
+    )
+    .fetch_one(state.database.executor())
+    .await?;
+    Ok(result.exists)
+}
@@ -1,20 +1,34 @@
-use crate::{AppState, MAX_AVATAR_SIZE, routes::stream_with_limit};
+use crate::{
+    AppState, MAX_AVATAR_SIZE,
+    moderation::is_account_taken_down,
+    routes::{MEDIA_CACHE_CONTROL, fetch_blob, not_modified, taken_down_response},
+    telemetry,
+    transcode::{AVATAR_SIZES, resize_avatar},
+};
 use axum::{
-    body::Body,
-    extract::{Path, State},
-    http::{Response, StatusCode, header},
+    body::{Body, Bytes},
+    extract::{Path, Query, State},
+    http::{HeaderMap, Response, StatusCode, header},
     response::IntoResponse,
 };
 use cid::Cid;
 use jacquard_common::types::did::Did;
 use multihash_codetable::{Code, MultihashDigest};
 use reqwest::Url;
+use serde::Deserialize;
 use sqlx::query;
 use std::sync::Arc;
 use tracing::warn;
 
+#[derive(Debug, Deserialize)]
+pub struct AvatarQuery {
+    size: Option<u32>,
+}
+
 pub async fn get_avatar_handler(
     Path((did, cid)): Path<(String, String)>,
+    Query(params): Query<AvatarQuery>,
+    headers: HeaderMap,
     State(state): State<Arc<AppState>>,
 ) -> impl IntoResponse {
     // Strictly verify the received path types.
@@ -40,17 +54,23 @@ pub async fn get_avatar_handler(
                 .into_response();
         }
     };
+    if let Some(size) = params.size
+        && !AVATAR_SIZES.contains(&size)
+    {
+        return (StatusCode::BAD_REQUEST, "Unsupported avatar size").into_response();
+    }
 
-    // Ensur the CID that's being requested matches our records.
+    // Ensure the CID that's being requested is the account's avatar (possibly from its Bluesky
+    // profile) and the account is active.
     match query!(
-        "SELECT EXISTS(SELECT 1 FROM accounts WHERE did = $1 AND avatar_blob_cid = $2)",
+        "SELECT EXISTS(SELECT 1 FROM accounts a INNER JOIN account_profiles ap ON ap.did = a.did WHERE a.did = $1 AND ap.avatar_blob_cid = $2 AND a.is_active)",
         did.as_str(),
         cid.to_string()
     )
     .fetch_optional(state.database.executor())
     .await
     {
-        Ok(result) if result.is_none() => {
+        Ok(Some(result)) if result.exists != Some(true) => {
             return (
                 StatusCode::NOT_FOUND,
                 "Blob CID does not match account record blog CID",
@@ -64,6 +84,38 @@ pub async fn get_avatar_handler(
         }
     };
 
+    // Refuse avatars of accounts that have been taken down, dropping any copies we still hold.
+    match is_account_taken_down(&state, did.as_str()).await {
+        Ok(false) => {}
+        Ok(true) => {
+            for size in AVATAR_SIZES {
+                state
+                    .avatar_cache
+                    .invalidate(&(cid.to_string(), size))
+                    .await;
+            }
+            return taken_down_response("Account has been taken down");
+        }
+        Err(err) => {
+            warn!("database error: {err:?}");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    }
+    let etag = match params.size {
+        Some(size) => format!("\"{cid}.{size}\""),
+        None => format!("\"{cid}\""),
+    };
+    if let Some(response) = not_modified(&headers, &etag) {
+        return response;
+    }
+    if let Some(size) = params.size {
+        let cached = state.avatar_cache.get(&(cid.to_string(), size)).await;
+        telemetry::record_cache_lookup("avatar", cached.is_some());
+        if let Some(bytes) = cached {
+            return resized_avatar_response(bytes, &etag, None);
+        }
+    }
+
     // Get the user's PDS URL from our accounts data.
     let pds_url = match query!("SELECT pds FROM accounts WHERE did = $1", did.as_str())
         .fetch_optional(state.database.executor())
@@ -99,28 +151,9 @@ pub async fn get_avatar_handler(
     };
 
     // Fetch the blob from the user's PDS
-    let response = match state.http_client.get(blob_url).send().await {
-        Ok(resp) => resp,
-        Err(err) => {
-            warn!("failed to fetch blob from PDS: {err:?}");
-            return (
-                StatusCode::BAD_GATEWAY,
-                "Failed to fetch blob from upstream PDS",
-            )
-                .into_response();
-        }
-    };
-    if !response.status().is_success() {
-        warn!("PDS returned error status: {}", response.status());
-        return (
-            StatusCode::BAD_GATEWAY,
-            "Failed to fetch blob from upstream PDS",
-        )
-            .into_response();
-    }
-    let bytes = match stream_with_limit(response, MAX_AVATAR_SIZE).await {
+    let bytes = match fetch_blob(&state, blob_url, MAX_AVATAR_SIZE, "avatar").await {
         Ok(bytes) => bytes,
-        Err(status) => return status.into_response(),
+        Err(response) => return response,
     };
 
     // Strictly validate the blob, computing and comparing it's CID hash and best-guessing it's mime-type.
@@ -137,6 +170,7 @@ pub async fn get_avatar_handler(
     };
     if computed_cid != cid {
         warn!("CID mismatch: expected {cid}, computed {computed_cid}");
+        telemetry::record_cid_mismatch("avatar");
         return StatusCode::BAD_GATEWAY.into_response();
     }
     let mime_type = match infer::get(&bytes).map(|t| t) {
@@ -147,6 +181,25 @@ pub async fn get_avatar_handler(
         }
     };
 
+    if let Some(size) = params.size {
+        let bytes = match tokio::task::spawn_blocking(move || resize_avatar(&bytes, size)).await {
+            Ok(Ok(bytes)) => Bytes::from(bytes),
+            Ok(Err(err)) => {
+                warn!("failed to resize avatar {cid} to {size}px: {err:?}");
+                return StatusCode::UNPROCESSABLE_ENTITY.into_response();
+            }
+            Err(err) => {
+                warn!("resize task failed: {err:?}");
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        };
+        state
+            .avatar_cache
+            .insert((cid.to_string(), size), bytes.clone())
+            .await;
+        return resized_avatar_response(bytes, &etag, pds_url.host_str());
+    }
+
     Response::builder()
         .status(StatusCode::OK)
         .header(header::CONTENT_TYPE, mime_type.mime_type())
@@ -155,7 +208,8 @@ pub async fn get_avatar_handler(
             "default-src 'none'; sandbox",
         )
         .header(header::X_CONTENT_TYPE_OPTIONS, "nosniff")
-        .header(header::CACHE_CONTROL, "public, max-age=604800")
+        .header(header::CACHE_CONTROL, MEDIA_CACHE_CONTROL)
+        .header(header::ETAG, &etag)
         .header(
             header::CONTENT_DISPOSITION,
             format!(
@@ -172,3 +226,28 @@ pub async fn get_avatar_handler(
         .unwrap()
         .into_response()
 }
+
+fn resized_avatar_response(
+    bytes: Bytes,
+    etag: &str,
+    upstream_pds: Option<&str>,
+) -> axum::response::Response {
+    let mut builder = Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "image/webp")
+        .header(
+            header::CONTENT_SECURITY_POLICY,
+            "default-src 'none'; sandbox",
+        )
+        .header(header::X_CONTENT_TYPE_OPTIONS, "nosniff")
+        .header(header::CACHE_CONTROL, MEDIA_CACHE_CONTROL)
+        .header(header::ETAG, etag)
+        .header(
+            header::CONTENT_DISPOSITION,
+            "attachment; filename=\"avatar.webp\"",
+        );
+    if let Some(host) = upstream_pds {
+        builder = builder.header("Upstream-PDS", format!(" {host}"));
+    }
+    builder.body(Body::from(bytes)).unwrap().into_response()
+}
@@ -1,20 +1,35 @@
-use crate::{AppState, MAX_BLOB_SIZE, routes::stream_with_limit};
+use crate::{
+    AppState, MAX_BLOB_SIZE,
+    moderation::is_post_taken_down,
+    routes::{MEDIA_CACHE_CONTROL, fetch_blob, not_modified, taken_down_response},
+    telemetry,
+    transcode::gif_to_webp,
+};
 use axum::{
-    body::Body,
-    extract::{Path, State},
-    http::{Response, StatusCode, header},
+    body::{Body, Bytes},
+    extract::{Path, Query, State},
+    http::{HeaderMap, Response, StatusCode, header},
     response::IntoResponse,
 };
 use cid::Cid;
+use gifdex_media::{InspectLimits, MediaFormat};
 use jacquard_common::types::{did::Did, tid::Tid};
 use multihash_codetable::{Code, MultihashDigest};
 use reqwest::Url;
+use serde::Deserialize;
 use sqlx::query;
 use std::sync::Arc;
 use tracing::warn;
 
+#[derive(Debug, Deserialize)]
+pub struct GifQuery {
+    format: Option<String>,
+}
+
 pub async fn get_gif_handler(
     Path((did, rkey)): Path<(String, String)>,
+    Query(params): Query<GifQuery>,
+    headers: HeaderMap,
     State(state): State<Arc<AppState>>,
 ) -> impl IntoResponse {
     // Strictly verify the received path types.
@@ -63,9 +78,11 @@ pub async fn get_gif_handler(
         }
     };
 
-    // Ensure the post exists in our records.
+    // Ensure the post exists in our records and its author is active.
     let post = match query!(
-        "SELECT title FROM posts WHERE did = $1 AND rkey = $2",
+        "SELECT p.title, p.media_blob_mime, a.pds FROM posts p
+         INNER JOIN accounts a ON a.did = p.did
+         WHERE p.did = $1 AND p.rkey = $2 AND a.is_active",
         did.as_str(),
         rkey
     )
@@ -82,26 +99,79 @@ pub async fn get_gif_handler(
         }
     };
 
-    // Get the user's PDS URL from our accounts data.
-    let pds_url = match query!("SELECT pds FROM accounts WHERE did = $1", did.as_str())
-        .fetch_optional(state.database.executor())
-        .await
-    {
-        Ok(Some(record)) if record.pds.is_some() => {
-            Url::parse(&format!("https://{}", record.pds.unwrap())).unwrap()
+    // Refuse media that has been taken down, dropping any copies we still hold.
+    match is_post_taken_down(&state, did.as_str(), &rkey).await {
+        Ok(false) => {}
+        Ok(true) => {
+            for format in [MediaFormat::Gif, MediaFormat::Webp] {
+                state
+                    .variant_cache
+                    .invalidate(&(rkey_cid.to_string(), format))
+                    .await;
+            }
+            return taken_down_response("Media has been taken down");
+        }
+        Err(err) => {
+            warn!("database error: {err:?}");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
         }
-        Ok(Some(_)) | Ok(None) => {
-            warn!("No PDS endpoint found for {did}");
+    }
+
+    // Pick which variant to serve, preferring an explicit `?format=` over the Accept header.
+    let Some(source_format) = MediaFormat::from_mime(&post.media_blob_mime) else {
+        warn!("unsupported stored mime-type: {}", post.media_blob_mime);
+        return StatusCode::UNPROCESSABLE_ENTITY.into_response();
+    };
+    let target_format = match params.format.as_deref() {
+        Some(format) => match MediaFormat::from_extension(format) {
+            Some(format) => format,
+            None => return (StatusCode::BAD_REQUEST, "Unsupported format").into_response(),
+        },
+        None if source_format == MediaFormat::Gif && accepts_webp(&headers) => MediaFormat::Webp,
+        None => source_format,
+    };
+    let transcode = match (source_format, target_format) {
+        (source, target) if source == target => false,
+        (MediaFormat::Gif, MediaFormat::Webp) => true,
+        _ => {
             return (
-                StatusCode::NOT_FOUND,
-                "No AtprotoPersonalDataServer service endpoint found in resolved DID document",
+                StatusCode::NOT_ACCEPTABLE,
+                "Requested format is not available for this media",
             )
                 .into_response();
         }
-        Err(err) => {
-            warn!("failed to resolve DID {did}: {err:?}");
-            return (StatusCode::BAD_GATEWAY, "Failed to resolve DID").into_response();
+    };
+    let etag = format!("\"{rkey_cid}.{}\"", target_format.extension());
+    if let Some(response) = not_modified(&headers, &etag) {
+        return response;
+    }
+    let cache_key = (rkey_cid.to_string(), target_format);
+    if transcode {
+        let cached = state.variant_cache.get(&cache_key).await;
+        telemetry::record_cache_lookup("variant", cached.is_some());
+        if let Some(bytes) = cached {
+            return media_response(
+                bytes,
+                target_format,
+                &etag,
+                &post.title,
+                post.pds.as_deref(),
+            );
         }
+    }
+
+    // Get the user's PDS URL from our accounts data.
+    let Some(pds_url) = post
+        .pds
+        .as_ref()
+        .and_then(|pds| Url::parse(&format!("https://{pds}")).ok())
+    else {
+        warn!("No PDS endpoint found for {did}");
+        return (
+            StatusCode::NOT_FOUND,
+            "No AtprotoPersonalDataServer service endpoint found in resolved DID document",
+        )
+            .into_response();
     };
 
     let blob_url = {
@@ -117,28 +187,9 @@ pub async fn get_gif_handler(
     };
 
     // Fetch the blob from the user's PDS
-    let response = match state.http_client.get(blob_url).send().await {
-        Ok(resp) => resp,
-        Err(err) => {
-            warn!("failed to fetch blob from PDS: {err:?}");
-            return (
-                StatusCode::BAD_GATEWAY,
-                "Failed to fetch blob from upstream PDS",
-            )
-                .into_response();
-        }
-    };
-    if !response.status().is_success() {
-        warn!("PDS returned error status: {}", response.status());
-        return (
-            StatusCode::BAD_GATEWAY,
-            "Failed to fetch blob from upstream PDS",
-        )
-            .into_response();
-    }
-    let bytes = match stream_with_limit(response, MAX_BLOB_SIZE).await {
+    let bytes = match fetch_blob(&state, blob_url, MAX_BLOB_SIZE, "media").await {
         Ok(bytes) => bytes,
-        Err(status) => return status.into_response(),
+        Err(response) => return response,
     };
 
     // Strictly validate the blob, computing and comparing its CID hash and validating its mime-type.
@@ -155,34 +206,104 @@ pub async fn get_gif_handler(
     };
     if computed_cid != rkey_cid {
         warn!("CID mismatch: expected {rkey_cid}, computed {computed_cid}");
+        telemetry::record_cid_mismatch("media");
         return StatusCode::BAD_GATEWAY.into_response();
     }
-    let mime_type = match infer::get(&bytes).map(|t| t.mime_type()) {
-        Some(m) if matches!(m, "image/gif" | "image/webp") => m,
-        _ => {
-            warn!("invalid or unsupported image format");
+    let bytes = match tokio::task::spawn_blocking(move || {
+        gifdex_media::inspect(&bytes, &InspectLimits::default()).map(|info| (bytes, info))
+    })
+    .await
+    {
+        Ok(Ok((bytes, info))) if info.format == source_format => bytes,
+        Ok(Ok(_)) => {
+            warn!("blob format doesn't match the indexed mime-type");
+            return StatusCode::UNPROCESSABLE_ENTITY.into_response();
+        }
+        Ok(Err(err)) => {
+            warn!("blob failed media inspection: {err}");
+            return StatusCode::UNPROCESSABLE_ENTITY.into_response();
+        }
+        Err(err) => {
+            warn!("inspection task failed: {err:?}");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    if !transcode {
+        return media_response(bytes, target_format, &etag, &post.title, pds_url.host_str());
+    }
+
+    let bytes = match tokio::task::spawn_blocking(move || gif_to_webp(&bytes)).await {
+        Ok(Ok(bytes)) => Bytes::from(bytes),
+        Ok(Err(err)) => {
+            warn!("failed to transcode {rkey_cid} to {target_format:?}: {err:?}");
             return StatusCode::UNPROCESSABLE_ENTITY.into_response();
         }
+        Err(err) => {
+            warn!("transcode task failed: {err:?}");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
     };
+    record_variant_size(&state, &cache_key.0, target_format.mime_type(), bytes.len()).await;
+    state.variant_cache.insert(cache_key, bytes.clone()).await;
+    media_response(bytes, target_format, &etag, &post.title, pds_url.host_str())
+}
+
+/// Whether the client explicitly lists WebP as an acceptable image type.
+fn accepts_webp(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|item| {
+            let mut parts = item.split(';').map(str::trim);
+            parts.next() == Some("image/webp") && !parts.any(|param| param == "q=0")
+        })
+}
+
+/// Store the size of a newly produced variant so the AppView can advertise it.
+///
+/// Only called after transcoding, as the original's size is recorded during ingest.
+async fn record_variant_size(state: &AppState, cid: &str, mime_type: &str, size: usize) {
+    if let Err(err) = query!(
+        "INSERT INTO media_variants (cid, mime_type, size) VALUES ($1, $2, $3)
+         ON CONFLICT (cid, mime_type) DO NOTHING",
+        cid,
+        mime_type,
+        size as i64
+    )
+    .execute(state.database.executor())
+    .await
+    {
+        warn!("failed to record size of {mime_type} variant for {cid}: {err:?}");
+    }
+}
 
-    Response::builder()
+fn media_response(
+    bytes: Bytes,
+    format: MediaFormat,
+    etag: &str,
+    title: &str,
+    upstream_pds: Option<&str>,
+) -> axum::response::Response {
+    let mut builder = Response::builder()
         .status(StatusCode::OK)
-        .header(header::CONTENT_TYPE, mime_type)
+        .header(header::CONTENT_TYPE, format.mime_type())
         .header(
             header::CONTENT_SECURITY_POLICY,
             "default-src 'none'; sandbox",
         )
         .header(header::X_CONTENT_TYPE_OPTIONS, "nosniff")
-        .header(header::CACHE_CONTROL, "public, max-age=604800")
+        .header(header::CACHE_CONTROL, MEDIA_CACHE_CONTROL)
+        .header(header::ETAG, etag)
+        .header(header::VARY, "Accept")
         .header(
             header::CONTENT_DISPOSITION,
-            format!("attachment; filename=\"{}\"", post.title),
-        )
-        .header(
-            "Upstream-PDS",
-            format!(" {}", pds_url.host_str().unwrap_or("unknown")),
-        )
-        .body(Body::from(bytes))
-        .unwrap()
-        .into_response()
+            format!("attachment; filename=\"{title}.{}\"", format.extension()),
+        );
+    if let Some(host) = upstream_pds {
+        builder = builder.header("Upstream-PDS", format!(" {host}"));
+    }
+    builder.body(Body::from(bytes)).unwrap().into_response()
 }
@@ -1,10 +1,107 @@
-use axum::body::Bytes;
+use crate::{AppState, telemetry};
+use axum::{
+    body::Bytes,
+    http::{HeaderMap, header},
+    response::{IntoResponse, Response},
+};
 use futures::StreamExt;
-use reqwest::StatusCode;
+use reqwest::{StatusCode, Url};
+use std::time::Instant;
 
 pub mod avatar;
 pub mod gif;
 
+/// How long clients and edge caches may reuse media before revalidating it with us.
+///
+/// Blobs never change, but a takedown has to reach copies cached outside the CDN too, so they're
+/// only kept briefly and revalidated through [`not_modified`], which runs after the moderation
+/// checks and is cheap as it doesn't touch the blob.
+const MEDIA_CACHE_CONTROL: &str = "public, max-age=300, must-revalidate";
+
+/// Refuse media that has been taken down, without letting the refusal be cached so it lifts as
+/// soon as the takedown does.
+fn taken_down_response(message: &'static str) -> Response {
+    (
+        StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS,
+        [(header::CACHE_CONTROL, "no-store")],
+        message,
+    )
+        .into_response()
+}
+
+/// Answer a revalidation with 304 Not Modified if the client already holds this version.
+fn not_modified(headers: &HeaderMap, etag: &str) -> Option<Response> {
+    let matches = headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim())
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag);
+    matches.then(|| {
+        (
+            StatusCode::NOT_MODIFIED,
+            [
+                (header::ETAG, etag),
+                (header::CACHE_CONTROL, MEDIA_CACHE_CONTROL),
+            ],
+        )
+            .into_response()
+    })
+}
+
+/// Fetch a blob from a PDS, recording how long the upstream request took.
+#[tracing::instrument(skip_all, fields(url = blob_url.as_str(), kind))]
+async fn fetch_blob(
+    state: &AppState,
+    blob_url: Url,
+    max_size: usize,
+    kind: &'static str,
+) -> Result<Bytes, Response> {
+    let started = Instant::now();
+    let response = match state
+        .http_client
+        .get(blob_url)
+        .headers(gifdex_telemetry::trace_headers())
+        .send()
+        .await
+    {
+        Ok(resp) => resp,
+        Err(err) => {
+            tracing::warn!("failed to fetch blob from PDS: {err:?}");
+            telemetry::record_upstream_fetch(kind, started.elapsed(), "error");
+            return Err((
+                StatusCode::BAD_GATEWAY,
+                "Failed to fetch blob from upstream PDS",
+            )
+                .into_response());
+        }
+    };
+    if !response.status().is_success() {
+        tracing::warn!("PDS returned error status: {}", response.status());
+        telemetry::record_upstream_fetch(kind, started.elapsed(), "error");
+        return Err((
+            StatusCode::BAD_GATEWAY,
+            "Failed to fetch blob from upstream PDS",
+        )
+            .into_response());
+    }
+    match stream_with_limit(response, max_size).await {
+        Ok(bytes) => {
+            telemetry::record_upstream_fetch(kind, started.elapsed(), "ok");
+            Ok(bytes)
+        }
+        Err(status) => {
+            let outcome = match status {
+                StatusCode::PAYLOAD_TOO_LARGE => "too_large",
+                _ => "error",
+            };
+            telemetry::record_upstream_fetch(kind, started.elapsed(), outcome);
+            Err(status.into_response())
+        }
+    }
+}
+
 async fn stream_with_limit(
     response: reqwest::Response,
     max_size: usize,
@@ -35,3 +132,50 @@ async fn stream_with_limit(
 
     Ok(Bytes::from(buffer))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    const ETAG: &str = "\"bafkreidwslb22nkaxoadyaqlhlxgntmiq4jdenhkbrxhcq6avxlt75br5u.webp\"";
+
+    fn if_none_match(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, HeaderValue::from_static(value));
+        headers
+    }
+
+    #[test]
+    fn matching_etag_is_not_modified() {
+        let response = not_modified(&if_none_match(ETAG), ETAG).unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[header::ETAG], ETAG);
+        assert_eq!(
+            response.headers()[header::CACHE_CONTROL],
+            MEDIA_CACHE_CONTROL
+        );
+    }
+
+    #[test]
+    fn etag_lists_and_weak_tags_match() {
+        let headers = if_none_match(
+            "\"other\", W/\"bafkreidwslb22nkaxoadyaqlhlxgntmiq4jdenhkbrxhcq6avxlt75br5u.webp\"",
+        );
+        assert!(not_modified(&headers, ETAG).is_some());
+        assert!(not_modified(&if_none_match("*"), ETAG).is_some());
+    }
+
+    #[test]
+    fn other_etags_are_served_again() {
+        assert!(not_modified(&HeaderMap::new(), ETAG).is_none());
+        assert!(not_modified(&if_none_match("\"other\""), ETAG).is_none());
+    }
+
+    #[test]
+    fn takedowns_are_not_cached() {
+        let response = taken_down_response("Media has been taken down");
+        assert_eq!(response.status(), StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS);
+        assert_eq!(response.headers()[header::CACHE_CONTROL], "no-store");
+    }
+}
@@ -0,0 +1,129 @@
+use crate::database::Database;
+use anyhow::{Context, Result};
+use axum::{
+    body::HttpBody,
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use metrics::{
+    Unit, counter, describe_counter, describe_gauge, describe_histogram, gauge, histogram,
+};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder};
+use std::{
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+
+/// Buckets for request and upstream fetch latency, from cache hits up to slow transcodes.
+const DURATION_BUCKETS: [f64; 12] = [
+    0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 10.0,
+];
+
+/// Install the global metrics recorder and serve it in the Prometheus text format on the
+/// given admin address.
+pub fn install(address: SocketAddr) -> Result<()> {
+    PrometheusBuilder::new()
+        .with_http_listener(address)
+        .set_buckets_for_metric(
+            Matcher::Suffix("_duration_seconds".into()),
+            &DURATION_BUCKETS,
+        )?
+        .install()
+        .context("failed to install prometheus exporter")?;
+
+    describe_counter!(
+        "cdn_requests_total",
+        "Requests handled, by route and response status."
+    );
+    describe_histogram!(
+        "cdn_request_duration_seconds",
+        Unit::Seconds,
+        "Time taken to handle a request, by route and response status."
+    );
+    describe_counter!(
+        "cdn_bytes_served_total",
+        Unit::Bytes,
+        "Response body bytes served, by route."
+    );
+    describe_histogram!(
+        "cdn_upstream_fetch_duration_seconds",
+        Unit::Seconds,
+        "Time taken to fetch a blob from a PDS, by media kind and outcome."
+    );
+    describe_counter!(
+        "cdn_cid_mismatches_total",
+        "Blobs fetched from a PDS whose contents did not match the requested CID."
+    );
+    describe_counter!(
+        "cdn_payload_too_large_total",
+        "Blobs fetched from a PDS that exceeded the maximum size."
+    );
+    describe_counter!(
+        "cdn_cache_requests_total",
+        "Lookups in the in-memory caches, by cache and whether they hit."
+    );
+    describe_gauge!(
+        "cdn_database_connections",
+        "Connections currently open in the database pool."
+    );
+    describe_gauge!(
+        "cdn_database_idle_connections",
+        "Open database pool connections that are not in use."
+    );
+    Ok(())
+}
+
+/// Count, time and measure every response, labelled by the route it was handled by.
+pub async fn metrics_middleware(req: Request, next: Next) -> Response {
+    let route = match req.extensions().get::<MatchedPath>() {
+        Some(path) => path.as_str().to_owned(),
+        None => "unmatched".to_owned(),
+    };
+    let started = Instant::now();
+    let res = next.run(req).await;
+    let status = res.status().as_u16().to_string();
+    counter!("cdn_requests_total", "route" => route.clone(), "status" => status.clone())
+        .increment(1);
+    histogram!("cdn_request_duration_seconds", "route" => route.clone(), "status" => status)
+        .record(started.elapsed().as_secs_f64());
+    if let Some(size) = res.body().size_hint().exact() {
+        counter!("cdn_bytes_served_total", "route" => route).increment(size);
+    }
+    res
+}
+
+/// Record how long fetching a blob from a PDS took and how it ended.
+pub fn record_upstream_fetch(kind: &'static str, elapsed: Duration, outcome: &'static str) {
+    histogram!("cdn_upstream_fetch_duration_seconds", "kind" => kind, "outcome" => outcome)
+        .record(elapsed.as_secs_f64());
+    if outcome == "too_large" {
+        counter!("cdn_payload_too_large_total", "kind" => kind).increment(1);
+    }
+}
+
+/// Count a blob whose contents did not hash to the CID it was requested by.
+pub fn record_cid_mismatch(kind: &'static str) {
+    counter!("cdn_cid_mismatches_total", "kind" => kind).increment(1);
+}
+
+/// Count a lookup in one of the in-memory caches.
+pub fn record_cache_lookup(cache: &'static str, hit: bool) {
+    counter!(
+        "cdn_cache_requests_total",
+        "cache" => cache,
+        "result" => if hit { "hit" } else { "miss" },
+    )
+    .increment(1);
+}
+
+/// Periodically record how many database pool connections are open and idle.
+pub async fn poll_database_pool(database: Database, interval: Duration) {
+    let mut interval = tokio::time::interval(interval);
+    loop {
+        interval.tick().await;
+        let (open, idle) = database.connection_counts();
+        gauge!("cdn_database_connections").set(open as f64);
+        gauge!("cdn_database_idle_connections").set(idle as f64);
+    }
+}
@@ -0,0 +1,269 @@
+use anyhow::{Result, bail};
+use image::{
+    AnimationDecoder, DynamicImage, ImageDecoder, ImageReader, Limits, RgbaImage,
+    codecs::gif::GifDecoder, imageops::FilterType, metadata::LoopCount,
+};
+use image_webp::{ColorType, WebPEncoder};
+use std::io::Cursor;
+
+/// Square sizes, in pixels, that avatars can be requested at.
+pub const AVATAR_SIZES: [u32; 3] = [64, 128, 512];
+
+/// Resize an avatar into a square lossless WebP of the given size.
+///
+/// The EXIF orientation is applied before resizing, and because the image is re-encoded
+/// from raw pixels no metadata from the original upload is carried over.
+pub fn resize_avatar(bytes: &[u8], size: u32) -> Result<Vec<u8>> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(8192);
+    limits.max_image_height = Some(8192);
+    let mut reader = ImageReader::new(Cursor::new(bytes)).with_guessed_format()?;
+    reader.limits(limits);
+    let mut decoder = reader.into_decoder()?;
+    let orientation = decoder.orientation()?;
+    let mut image = DynamicImage::from_decoder(decoder)?;
+    image.apply_orientation(orientation);
+
+    let resized = image
+        .resize_to_fill(size, size, FilterType::Lanczos3)
+        .into_rgba8();
+    let mut out = Vec::new();
+    WebPEncoder::new(&mut out).encode(
+        resized.as_raw(),
+        resized.width(),
+        resized.height(),
+        ColorType::Rgba8,
+    )?;
+    Ok(out)
+}
+
+/// Transcode an animated (or static) GIF into a lossless animated WebP.
+///
+/// Frames are fully composited by the GIF decoder, so only the region that changed
+/// since the previous frame is encoded to keep the output small.
+pub fn gif_to_webp(bytes: &[u8]) -> Result<Vec<u8>> {
+    let decoder = GifDecoder::new(Cursor::new(bytes))?;
+    let loop_count = match decoder.loop_count() {
+        LoopCount::Infinite => 0,
+        LoopCount::Finite(count) => count.get().min(u16::MAX as u32) as u16,
+    };
+    let frames = decoder.into_frames().collect_frames()?;
+    let Some(first) = frames.first() else {
+        bail!("GIF contains no frames");
+    };
+    let (width, height) = first.buffer().dimensions();
+
+    let mut anmf_chunks = Vec::new();
+    let mut previous: Option<&RgbaImage> = None;
+    for frame in &frames {
+        let buffer = frame.buffer();
+        let (numer, denom) = frame.delay().numer_denom_ms();
+        let duration = (numer / denom.max(1)).min(0xFF_FFFF);
+
+        // WebP frame offsets must be even, so round the changed region outwards.
+        let (x, y, w, h) = match previous {
+            Some(prev) => match changed_region(prev, buffer) {
+                Some((x, y, w, h)) => (x & !1, y & !1, w + (x & 1), h + (y & 1)),
+                None => (0, 0, 1, 1),
+            },
+            None => (0, 0, width, height),
+        };
+        let region = image::imageops::crop_imm(buffer, x, y, w, h).to_image();
+
+        let mut payload = Vec::new();
+        WebPEncoder::new(&mut payload).encode(region.as_raw(), w, h, ColorType::Rgba8)?;
+        let vp8l = extract_vp8l(&payload)?;
+
+        let mut anmf = Vec::with_capacity(16 + 8 + vp8l.len());
+        anmf.extend_from_slice(&u24(x / 2));
+        anmf.extend_from_slice(&u24(y / 2));
+        anmf.extend_from_slice(&u24(w - 1));
+        anmf.extend_from_slice(&u24(h - 1));
+        anmf.extend_from_slice(&u24(duration));
+        // Don't alpha-blend or dispose: each region replaces the pixels beneath it on the canvas.
+        anmf.push(0b10);
+        write_chunk(&mut anmf, b"VP8L", vp8l);
+        write_chunk(&mut anmf_chunks, b"ANMF", &anmf);
+        previous = Some(buffer);
+    }
+
+    let mut vp8x = Vec::with_capacity(10);
+    vp8x.push((1 << 4) | (1 << 1)); // Alpha + animation flags.
+    vp8x.extend_from_slice(&[0, 0, 0]);
+    vp8x.extend_from_slice(&u24(width - 1));
+    vp8x.extend_from_slice(&u24(height - 1));
+
+    let mut anim = Vec::with_capacity(6);
+    anim.extend_from_slice(&[0, 0, 0, 0]);
+    anim.extend_from_slice(&loop_count.to_le_bytes());
+
+    let mut body = Vec::with_capacity(anmf_chunks.len() + 64);
+    body.extend_from_slice(b"WEBP");
+    write_chunk(&mut body, b"VP8X", &vp8x);
+    write_chunk(&mut body, b"ANIM", &anim);
+    body.extend_from_slice(&anmf_chunks);
+
+    let mut out = Vec::with_capacity(body.len() + 8);
+    write_chunk(&mut out, b"RIFF", &body);
+    Ok(out)
+}
+
+/// Find the bounding box of pixels that differ between two equally sized frames.
+fn changed_region(prev: &RgbaImage, next: &RgbaImage) -> Option<(u32, u32, u32, u32)> {
+    let (mut min_x, mut min_y, mut max_x, mut max_y) = (u32::MAX, u32::MAX, 0, 0);
+    for (x, y, pixel) in next.enumerate_pixels() {
+        if prev.get_pixel(x, y) != pixel {
+            min_x = min_x.min(x);
+            min_y = min_y.min(y);
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
+        }
+    }
+    (min_x != u32::MAX).then(|| (min_x, min_y, max_x - min_x + 1, max_y - min_y + 1))
+}
+
+/// Pull the VP8L bitstream out of a simple (single chunk) WebP file.
+fn extract_vp8l(webp: &[u8]) -> Result<&[u8]> {
+    if webp.len() < 20 || &webp[12..16] != b"VP8L" {
+        bail!("encoder produced an unexpected WebP layout");
+    }
+    let len = u32::from_le_bytes([webp[16], webp[17], webp[18], webp[19]]) as usize;
+    match webp.get(20..20 + len) {
+        Some(payload) => Ok(payload),
+        None => bail!("encoder produced a truncated WebP chunk"),
+    }
+}
+
+fn write_chunk(out: &mut Vec<u8>, fourcc: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(fourcc);
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out.extend_from_slice(data);
+    if data.len() % 2 == 1 {
+        out.push(0);
+    }
+}
+
+fn u24(value: u32) -> [u8; 3] {
+    let [a, b, c, _] = value.to_le_bytes();
+    [a, b, c]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{
+        Delay, Frame, Rgba,
+        codecs::{
+            gif::{GifEncoder, Repeat},
+            webp::WebPDecoder,
+        },
+    };
+    use std::time::Duration;
+
+    const WIDTH: u32 = 17;
+    const HEIGHT: u32 = 11;
+
+    /// A frame's background colour, an optional colour for an odd-positioned square drawn on
+    /// top, and its delay in milliseconds.
+    type TestFrame = (Rgba<u8>, Option<Rgba<u8>>, u64);
+
+    fn make_gif(frames: &[TestFrame], repeat: Repeat) -> Vec<u8> {
+        let mut out = Vec::new();
+        {
+            let mut encoder = GifEncoder::new(&mut out);
+            encoder.set_repeat(repeat).unwrap();
+            for (background, square, delay_ms) in frames {
+                let mut buffer = RgbaImage::from_pixel(WIDTH, HEIGHT, *background);
+                if let Some(square) = square {
+                    for y in 3..6 {
+                        for x in 5..8 {
+                            buffer.put_pixel(x, y, *square);
+                        }
+                    }
+                }
+                let delay = Delay::from_saturating_duration(Duration::from_millis(*delay_ms));
+                encoder
+                    .encode_frame(Frame::from_parts(buffer, 0, 0, delay))
+                    .unwrap();
+            }
+        }
+        out
+    }
+
+    fn decode_webp(bytes: &[u8]) -> (u32, u32, Vec<Frame>) {
+        let decoder = WebPDecoder::new(Cursor::new(bytes)).unwrap();
+        let (width, height) = decoder.dimensions();
+        let frames = decoder.into_frames().collect_frames().unwrap();
+        (width, height, frames)
+    }
+
+    fn delay_ms(frame: &Frame) -> u32 {
+        let (numer, denom) = frame.delay().numer_denom_ms();
+        numer / denom
+    }
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+    const BLUE: Rgba<u8> = Rgba([0, 0, 255, 255]);
+    const CLEAR: Rgba<u8> = Rgba([0, 0, 0, 0]);
+
+    #[test]
+    fn animated_gif_round_trips_frames_and_timing() {
+        let gif = make_gif(
+            &[(RED, None, 100), (RED, Some(BLUE), 50), (BLUE, None, 200)],
+            Repeat::Infinite,
+        );
+        let expected = GifDecoder::new(Cursor::new(&gif))
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+
+        let webp = gif_to_webp(&gif).unwrap();
+        assert_eq!(&webp[0..4], b"RIFF");
+        assert_eq!(&webp[8..12], b"WEBP");
+        let (width, height, frames) = decode_webp(&webp);
+        assert_eq!((width, height), (WIDTH, HEIGHT));
+        assert_eq!(frames.len(), 3);
+        assert_eq!(
+            frames.iter().map(delay_ms).collect::<Vec<_>>(),
+            [100, 50, 200]
+        );
+        for (frame, expected) in frames.iter().zip(&expected) {
+            assert_eq!(frame.buffer(), expected.buffer());
+        }
+    }
+
+    #[test]
+    fn unchanged_frames_keep_their_timing() {
+        let gif = make_gif(&[(RED, None, 80), (RED, None, 120)], Repeat::Infinite);
+        let (_, _, frames) = decode_webp(&gif_to_webp(&gif).unwrap());
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames.iter().map(delay_ms).collect::<Vec<_>>(), [80, 120]);
+        assert_eq!(frames[1].buffer(), frames[0].buffer());
+    }
+
+    #[test]
+    fn transparency_is_preserved() {
+        let gif = make_gif(&[(CLEAR, Some(RED), 100)], Repeat::Infinite);
+        let (_, _, frames) = decode_webp(&gif_to_webp(&gif).unwrap());
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].buffer().get_pixel(0, 0)[3], 0);
+        assert_eq!(*frames[0].buffer().get_pixel(5, 3), RED);
+    }
+
+    #[test]
+    fn loop_count_is_carried_over() {
+        for (repeat, expected) in [(Repeat::Infinite, 0u16), (Repeat::Finite(3), 3)] {
+            let webp = gif_to_webp(&make_gif(&[(RED, None, 100)], repeat)).unwrap();
+            // RIFF header (12) + VP8X chunk (8 + 10) + ANIM header (8) + background colour (4).
+            assert_eq!(&webp[30..34], b"ANIM");
+            assert_eq!(u16::from_le_bytes([webp[42], webp[43]]), expected);
+        }
+    }
+
+    #[test]
+    fn truncated_gif_is_rejected() {
+        let gif = make_gif(&[(RED, None, 100), (BLUE, None, 100)], Repeat::Infinite);
+        assert!(gif_to_webp(&gif[..gif.len() / 2]).is_err());
+    }
+}
@@ -0,0 +1,53 @@
+use crate::database::Database;
+use anyhow::Result;
+use gifdex_lexicons::net_gifdex::feed::post::Post;
+use jacquard_common::types::collection::Collection;
+use sqlx::query;
+use tracing::{info, warn};
+
+/// Count rows that reference data which no longer exists and report them.
+///
+/// Ingest removes dependent rows as records and accounts are deleted, so any orphans found
+/// here point to missed events or a bug in a handler. Favourites can briefly reference a post
+/// that hasn't been indexed yet, so a small number of those is expected during backfill.
+pub async fn report_orphans(database: &Database) -> Result<()> {
+    let orphans = query!(
+        r#"SELECT
+          (SELECT COUNT(*) FROM labels l
+             WHERE NOT EXISTS (SELECT 1 FROM accounts a WHERE a.did = l.subject_did))
+             as "labels_missing_subject_account!",
+          (SELECT COUNT(*) FROM labels l
+             WHERE l.subject_collection = $1
+             AND NOT EXISTS (SELECT 1 FROM posts p
+               WHERE p.did = l.subject_did AND p.rkey = l.subject_rkey))
+             as "labels_missing_subject_post!",
+          (SELECT COUNT(*) FROM labels l
+             WHERE NOT EXISTS (SELECT 1 FROM labeler_rules r
+               WHERE r.did = l.rule_did AND r.rkey = l.rule_rkey))
+             as "labels_missing_rule!",
+          (SELECT COUNT(*) FROM post_favourites pf
+             WHERE NOT EXISTS (SELECT 1 FROM posts p
+               WHERE p.did = pf.post_did AND p.rkey = pf.post_rkey))
+             as "favourites_missing_post!""#,
+        Post::NSID
+    )
+    .fetch_one(database.executor())
+    .await?;
+
+    if orphans.labels_missing_subject_account == 0
+        && orphans.labels_missing_subject_post == 0
+        && orphans.labels_missing_rule == 0
+        && orphans.favourites_missing_post == 0
+    {
+        info!("Consistency check found no orphaned rows");
+    } else {
+        warn!(
+            labels_missing_subject_account = orphans.labels_missing_subject_account,
+            labels_missing_subject_post = orphans.labels_missing_subject_post,
+            labels_missing_rule = orphans.labels_missing_rule,
+            favourites_missing_post = orphans.favourites_missing_post,
+            "Consistency check found orphaned rows"
+        );
+    }
+    Ok(())
+}
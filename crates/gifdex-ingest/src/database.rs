@@ -16,6 +16,11 @@ impl Database {
         Ok(Self { pool })
     }
 
+    /// Use an existing pool, which must already have had migrations applied.
+    pub fn from_pool(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
     /// Obtain the executor for the database pool.
     pub fn executor(&self) -> impl PgExecutor<'_> + Copy {
         &self.pool
@@ -0,0 +1,185 @@
+use crate::AppState;
+use anyhow::{Context, Result};
+use gifdex_lexicons::net_gifdex::{
+    actor::profile::Profile,
+    feed::{
+        collection::Collection as FeedCollection, collection_item::CollectionItem,
+        favourite::Favourite, post::Post,
+    },
+    graph::{block::Block, follow::Follow},
+    labeler::{label::Label, rule::Rule},
+};
+use jacquard_common::types::{collection::Collection, did::Did};
+use serde::Deserialize;
+use sqlx::query;
+use std::{collections::HashSet, sync::Arc, time::Duration};
+use tracing::{error, info, warn};
+use url::Url;
+
+/// Every collection that makes a repository relevant to Gifdex.
+const COLLECTIONS: [&str; 9] = [
+    Post::NSID,
+    Favourite::NSID,
+    FeedCollection::NSID,
+    CollectionItem::NSID,
+    Block::NSID,
+    Follow::NSID,
+    Profile::NSID,
+    Label::NSID,
+    Rule::NSID,
+];
+
+/// Maximum number of DIDs sent to tap in a single request.
+const TAP_BATCH_SIZE: usize = 1000;
+
+/// Maximum number of repositories untracked in a single run, so a relay that drops part of its
+/// listing can't untrack everyone at once.
+const MAX_REMOVALS: usize = 100;
+
+#[derive(Deserialize)]
+struct ListReposByCollectionOutput {
+    repos: Vec<ListReposByCollectionRepo>,
+    cursor: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ListReposByCollectionRepo {
+    did: String,
+}
+
+/// Periodically ask a relay for repositories holding Gifdex records, tracking any new ones
+/// with tap and untracking ones it discovered earlier that no longer hold any.
+pub async fn run(state: Arc<AppState>, relay_url: Url, interval: Duration) {
+    let mut interval = tokio::time::interval(interval);
+    loop {
+        interval.tick().await;
+        if let Err(err) = discover(&state, &relay_url).await {
+            error!("Failed to discover repositories from relay: {err:?}");
+        }
+    }
+}
+
+async fn discover(state: &AppState, relay_url: &Url) -> Result<()> {
+    let mut discovered = HashSet::new();
+    for collection in COLLECTIONS {
+        discovered.extend(list_repos_by_collection(state, relay_url, collection).await?);
+    }
+    // An empty listing is far more likely to be a relay problem than every user leaving.
+    if discovered.is_empty() {
+        warn!("Relay listed no repositories with Gifdex records, skipping discovery");
+        return Ok(());
+    }
+
+    let tracked: HashSet<String> = query!("SELECT did FROM discovered_repos")
+        .fetch_all(state.database.executor())
+        .await?
+        .into_iter()
+        .map(|repo| repo.did)
+        .collect();
+    let known: HashSet<String> = query!("SELECT did FROM accounts")
+        .fetch_all(state.database.executor())
+        .await?
+        .into_iter()
+        .map(|account| account.did)
+        .chain(tracked.iter().cloned())
+        .collect();
+
+    let added: Vec<Did> = discovered
+        .difference(&known)
+        .filter_map(|did| Did::new_owned(did).ok())
+        .collect();
+    for batch in added.chunks(TAP_BATCH_SIZE) {
+        state
+            .tap_client
+            .add_repos(batch)
+            .await
+            .context("failed to add discovered repositories to tap")?;
+        let dids: Vec<&str> = batch.iter().map(|did| did.as_str()).collect();
+        query!(
+            "INSERT INTO discovered_repos (did) SELECT * FROM UNNEST($1::TEXT[])
+             ON CONFLICT (did) DO NOTHING",
+            &dids as &[&str]
+        )
+        .execute(state.database.executor())
+        .await?;
+    }
+
+    // Only repositories discovery added itself are untracked, leaving their indexed records in
+    // place, as operators may track repositories the relay doesn't list.
+    let stale: Vec<&String> = tracked.difference(&discovered).collect();
+    if stale.len() > MAX_REMOVALS {
+        warn!(
+            stale = stale.len(),
+            "Relay no longer lists many discovered repositories, only untracking {MAX_REMOVALS}"
+        );
+    }
+    let removed: Vec<Did> = stale
+        .into_iter()
+        .take(MAX_REMOVALS)
+        .filter_map(|did| Did::new_owned(did).ok())
+        .collect();
+    if !removed.is_empty() {
+        state
+            .tap_client
+            .remove_repos(&removed)
+            .await
+            .context("failed to remove repositories from tap")?;
+        let dids: Vec<&str> = removed.iter().map(|did| did.as_str()).collect();
+        query!(
+            "DELETE FROM discovered_repos WHERE did = ANY($1)",
+            &dids as &[&str]
+        )
+        .execute(state.database.executor())
+        .await?;
+    }
+
+    info!(
+        discovered = discovered.len(),
+        added = added.len(),
+        removed = removed.len(),
+        "Finished discovering repositories from relay"
+    );
+    Ok(())
+}
+
+/// Fetch every DID the relay lists as holding records in a collection.
+async fn list_repos_by_collection(
+    state: &AppState,
+    relay_url: &Url,
+    collection: &str,
+) -> Result<Vec<String>> {
+    let mut endpoint = relay_url
+        .join("/xrpc/com.atproto.sync.listReposByCollection")
+        .context("failed to build relay url")?;
+    let mut dids = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        endpoint
+            .query_pairs_mut()
+            .clear()
+            .append_pair("collection", collection)
+            .append_pair("limit", "2000");
+        if let Some(cursor) = &cursor {
+            endpoint.query_pairs_mut().append_pair("cursor", cursor);
+        }
+        let output = state
+            .http_client
+            .get(endpoint.as_str())
+            .headers(gifdex_telemetry::trace_headers())
+            .timeout(Duration::from_secs(30))
+            .send()
+            .await
+            .with_context(|| format!("failed to list repositories holding {collection}"))?
+            .error_for_status()
+            .with_context(|| format!("relay returned an error listing {collection}"))?
+            .json::<ListReposByCollectionOutput>()
+            .await
+            .with_context(|| format!("invalid response listing {collection}"))?;
+        dids.extend(output.repos.into_iter().map(|repo| repo.did));
+        match output.cursor {
+            Some(next) if !next.is_empty() => cursor = Some(next),
+            _ => break,
+        }
+    }
+    Ok(dids)
+}
@@ -0,0 +1,91 @@
+use anyhow::Result;
+use doubletap::types::RecordEventData;
+use gifdex_lexicons::{app_bsky, validate::LexiconValidate};
+use sqlx::{PgTransaction, query};
+use tracing::{debug, error, info, warn};
+
+use crate::{AppState, handlers::reject_record};
+
+pub async fn handle_bsky_profile_create_event(
+    record_data: &RecordEventData<'_>,
+    data: &app_bsky::actor::profile::Profile<'_>,
+    tx: &mut PgTransaction<'_>,
+    state: &AppState,
+) -> Result<()> {
+    if !state.index_bsky_profiles {
+        debug!("Ignored Bluesky profile record as indexing them is disabled");
+        return Ok(());
+    }
+
+    // Ensure the record rkey is a valid exactly 'self'.
+    if record_data.rkey.as_str() != "self" {
+        warn!(
+            "Rejected record: Bluesky profile record is invalid as it does not use the rkey 'self'"
+        );
+        reject_record(record_data, "invalid_profile_rkey", tx).await?;
+        return Ok(());
+    }
+
+    // Enforce the constraints declared by the lexicon, including the avatar's mimetype + size.
+    if let Err(err) = data.validate() {
+        warn!("Rejected record: {err}");
+        reject_record(record_data, "lexicon_constraint_violated", tx).await?;
+        return Ok(());
+    }
+
+    // Validate that the avatar blob CID is valid.
+    if let Some(avatar) = &data.avatar
+        && !avatar.blob().cid().is_valid()
+    {
+        warn!("Rejected record: invalid blob CID in for avatar");
+        reject_record(record_data, "invalid_avatar_cid", tx).await?;
+        return Ok(());
+    }
+
+    match query!(
+        "INSERT INTO bsky_profiles (did, display_name, avatar_blob_cid) \
+         VALUES ($1, $2, $3) \
+         ON CONFLICT(did) DO UPDATE SET \
+         display_name = excluded.display_name, \
+         avatar_blob_cid = excluded.avatar_blob_cid, \
+         indexed_at = excluded.indexed_at",
+        record_data.did.as_str(),
+        data.display_name.as_deref(),
+        data.avatar.as_ref().map(|s| s.blob().cid().as_str()),
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        Ok(_) => {
+            info!("Upserted Bluesky profile into database");
+            Ok(())
+        }
+        Err(err) => {
+            error!("Failed to upsert Bluesky profile into database: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+pub async fn handle_bsky_profile_delete_event(
+    record_data: &RecordEventData<'_>,
+    tx: &mut PgTransaction<'_>,
+    _state: &AppState,
+) -> Result<()> {
+    match query!(
+        "DELETE FROM bsky_profiles WHERE did = $1",
+        record_data.did.as_str()
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        Ok(_) => {
+            info!("Deleted Bluesky profile from database");
+            Ok(())
+        }
+        Err(err) => {
+            error!("Failed to delete Bluesky profile from database: {err:?}");
+            Err(err.into())
+        }
+    }
+}
@@ -1,28 +1,61 @@
-use crate::AppState;
+use crate::{
+    AppState,
+    handles::{HandleVerification, verify_handle},
+};
 use anyhow::Result;
 use doubletap::types::{IdentityEventData, IdentityEventStatus};
-use sqlx::{PgTransaction, query};
-use tracing::{error, info};
+use sqlx::query;
+use tracing::{error, info, warn};
 
-pub async fn handle_identity(
-    identity: &IdentityEventData<'_>,
-    tx: &mut PgTransaction<'_>,
-    state: &AppState,
-) -> Result<()> {
-    // Completely purge data related to accounts that are deleted or takendown.
-    // Note: this does not delete any labels applied to the account or their content.
-    if matches!(
-        identity.status,
-        IdentityEventStatus::Deleted | IdentityEventStatus::Takendown
-    ) {
+pub async fn handle_identity(identity: &IdentityEventData<'_>, state: &AppState) -> Result<()> {
+    // Completely purge data related to accounts that are deleted, along with any labels that
+    // were applied to the account or its content. Labels the account issued as a labeler are
+    // moved to `label_tombstones` first so there remains a record of them.
+    //
+    // Takendown, suspended and deactivated accounts are kept and hidden through `is_active`
+    // instead, so their content can be restored as-is if the account becomes active again.
+    if identity.status == IdentityEventStatus::Deleted {
+        let mut tx = state.database.transaction().await?;
+        if let Err(err) = query!(
+            "INSERT INTO label_tombstones ( \
+                 rkey, did, rule_did, rule_rkey, rule_name, rule_behaviour, \
+                 subject_did, subject_collection, subject_rkey, reason, match_media_hash, \
+                 created_at, expires_at, edited_at, indexed_at \
+             ) \
+             SELECT l.rkey, l.did, l.rule_did, l.rule_rkey, r.name, r.behaviour, \
+                 l.subject_did, l.subject_collection, l.subject_rkey, l.reason, l.match_media_hash, \
+                 l.created_at, l.expires_at, l.edited_at, l.indexed_at \
+             FROM labels l \
+             LEFT JOIN labeler_rules r ON r.did = l.rule_did AND r.rkey = l.rule_rkey \
+             WHERE l.did = $1 \
+             ON CONFLICT(did, rkey) DO NOTHING",
+            identity.did.as_str()
+        )
+        .execute(&mut *tx)
+        .await
+        {
+            error!("Failed to tombstone labels issued by account: {err:?}");
+            return Err(err.into());
+        };
+        if let Err(err) = query!(
+            "DELETE FROM labels WHERE subject_did = $1",
+            identity.did.as_str()
+        )
+        .execute(&mut *tx)
+        .await
+        {
+            error!("Failed to delete labels applied to account: {err:?}");
+            return Err(err.into());
+        };
         if let Err(err) = query!("DELETE FROM accounts WHERE did = $1", identity.did.as_str())
-            .execute(&mut **tx)
+            .execute(&mut *tx)
             .await
         {
             error!("Failed to delete account: {err:?}");
             return Err(err.into());
         };
-        info!("Removed all userdata for account as it was deleted or takendown");
+        tx.commit().await?;
+        info!("Removed all userdata for account as it was deleted");
         return Ok(());
     }
 
@@ -33,25 +66,56 @@ pub async fn handle_identity(
         .pds_endpoint()
         .map(|pds| pds.host_str().unwrap().to_string());
 
-    // Update state of account incase of handle/status/is_active updates.
+    // Tap sends whatever handle the DID document claims, so only mark it as verified if it
+    // also resolves back to this DID. Unverified handles are shown as `handle.invalid`.
+    // Handles are resolved before the transaction is opened so slow lookups don't hold it.
+    let verification = verify_handle(
+        state.handle_resolver.as_ref(),
+        &identity.did,
+        &identity.handle,
+    )
+    .await;
+    match verification {
+        HandleVerification::Verified => {}
+        HandleVerification::Invalid => warn!(
+            "Handle {:?} does not resolve back to account, marking it as invalid",
+            identity.handle
+        ),
+        HandleVerification::Unresolved => warn!(
+            "Failed to resolve handle {:?}, keeping its previous verification",
+            identity.handle
+        ),
+    }
+
+    // Update state of account incase of handle/status/is_active updates. A handle that couldn't
+    // be resolved keeps its previous verification if it hasn't changed, and is otherwise left
+    // unverified until it's re-verified in the background.
+    let mut tx = state.database.transaction().await?;
     match query!(
-        "INSERT INTO accounts (did, handle, pds, is_active, status, created_at) \
-         VALUES ($1, $2, $3, $4, $5, (extract(epoch from now()) * 1000)::BIGINT) \
+        "INSERT INTO accounts (did, handle, handle_verified_at, pds, is_active, status, created_at) \
+         VALUES ($1, $2, CASE WHEN $3 THEN (extract(epoch from now()) * 1000)::BIGINT END, \
+                 $4, $5, $6, (extract(epoch from now()) * 1000)::BIGINT) \
          ON CONFLICT(did) DO UPDATE SET \
          handle = excluded.handle, \
+         handle_verified_at = CASE \
+             WHEN $3 IS NULL AND accounts.handle = excluded.handle THEN accounts.handle_verified_at \
+             ELSE excluded.handle_verified_at \
+         END, \
          is_active = excluded.is_active, \
          pds = excluded.pds, \
          status = excluded.status",
         identity.did.as_str(),
         identity.handle.as_str(),
+        verification.as_verified(),
         pds,
         identity.is_active,
         identity.status.as_str()
     )
-    .execute(&mut **tx)
+    .execute(&mut *tx)
     .await
     {
         Ok(_) => {
+            tx.commit().await?;
             info!("Upserted stored account data into database");
             Ok(())
         }
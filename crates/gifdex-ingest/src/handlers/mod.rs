@@ -1,27 +1,37 @@
+mod app_bsky;
 mod identity;
 mod net_gifdex;
 
 use crate::AppState;
 use crate::handlers::{
     self,
+    app_bsky::actor::{handle_bsky_profile_create_event, handle_bsky_profile_delete_event},
     net_gifdex::{
         actor::{handle_profile_create_event, handle_profile_delete_event},
         feed::{
+            handle_collection_create_event, handle_collection_delete_event,
+            handle_collection_item_create_event, handle_collection_item_delete_event,
             handle_favourite_create_event, handle_favourite_delete_event, handle_post_create,
             handle_post_delete,
         },
+        graph::{
+            handle_block_create_event, handle_block_delete_event, handle_follow_create_event,
+            handle_follow_delete_event,
+        },
         labeler::{
             handle_label_create_event, handle_label_delete_event, handle_rule_create_event,
             handle_rule_delete_event,
         },
     },
 };
+use crate::telemetry;
+use ::gifdex_lexicons::app_bsky as bsky_lexicons;
 use anyhow::bail;
-use doubletap::types::{EventData, RecordAction};
+use doubletap::types::{EventData, RecordAction, RecordEventData};
 use gifdex_lexicons::net_gifdex as gifdex_lexicons;
 use jacquard_common::types::collection::Collection;
-use sqlx::query;
-use std::sync::Arc;
+use sqlx::{PgTransaction, query};
+use std::{sync::Arc, time::Instant};
 
 #[tracing::instrument(
     skip(state, data),
@@ -76,15 +86,80 @@ use std::sync::Arc;
     )
 )]
 pub async fn handle_event(state: Arc<AppState>, data: EventData<'static>) -> anyhow::Result<()> {
+    let (collection, action) = event_labels(&data);
+    telemetry::record_event(collection, action);
+    let started = Instant::now();
+    let result = dispatch_event(state, data).await;
+    telemetry::record_event_result(collection, action, started.elapsed(), &result);
+    result
+}
+
+/// Metric labels for an event, limited to known collections to keep label cardinality bounded.
+fn event_labels(data: &EventData<'_>) -> (&'static str, &'static str) {
+    match data {
+        EventData::Identity { .. } => ("identity", "update"),
+        EventData::Record { record } => {
+            let collection = match record.collection.as_str() {
+                gifdex_lexicons::feed::post::Post::NSID => gifdex_lexicons::feed::post::Post::NSID,
+                gifdex_lexicons::feed::favourite::Favourite::NSID => {
+                    gifdex_lexicons::feed::favourite::Favourite::NSID
+                }
+                gifdex_lexicons::feed::collection::Collection::NSID => {
+                    gifdex_lexicons::feed::collection::Collection::NSID
+                }
+                gifdex_lexicons::feed::collection_item::CollectionItem::NSID => {
+                    gifdex_lexicons::feed::collection_item::CollectionItem::NSID
+                }
+                gifdex_lexicons::graph::block::Block::NSID => {
+                    gifdex_lexicons::graph::block::Block::NSID
+                }
+                gifdex_lexicons::graph::follow::Follow::NSID => {
+                    gifdex_lexicons::graph::follow::Follow::NSID
+                }
+                gifdex_lexicons::actor::profile::Profile::NSID => {
+                    gifdex_lexicons::actor::profile::Profile::NSID
+                }
+                gifdex_lexicons::labeler::label::Label::NSID => {
+                    gifdex_lexicons::labeler::label::Label::NSID
+                }
+                gifdex_lexicons::labeler::rule::Rule::NSID => {
+                    gifdex_lexicons::labeler::rule::Rule::NSID
+                }
+                bsky_lexicons::actor::profile::Profile::NSID => {
+                    bsky_lexicons::actor::profile::Profile::NSID
+                }
+                _ => "unknown",
+            };
+            let action = match &record.action {
+                RecordAction::Create { .. } => "create",
+                RecordAction::Update { .. } => "update",
+                RecordAction::Delete => "delete",
+                _ => "unknown",
+            };
+            (collection, action)
+        }
+        _ => ("unknown", "unknown"),
+    }
+}
+
+async fn dispatch_event(state: Arc<AppState>, data: EventData<'static>) -> anyhow::Result<()> {
     match data {
         EventData::Identity { identity } => {
-            let mut tx = state.database.transaction().await?;
-            handlers::identity::handle_identity(&identity, &mut tx, &state).await?;
-            tx.commit().await?;
-            Ok(())
+            handlers::identity::handle_identity(&identity, &state).await
         }
         EventData::Record { record } => {
             let mut tx = state.database.transaction().await?;
+
+            // Any earlier rejection is superseded, and recorded again if this version is refused.
+            query!(
+                "DELETE FROM rejected_records WHERE did = $1 AND collection = $2 AND rkey = $3",
+                record.did.as_str(),
+                record.collection.as_str(),
+                record.rkey.as_str()
+            )
+            .execute(&mut *tx)
+            .await?;
+
             match &record.action {
                 RecordAction::Create {
                     record: payload, ..
@@ -105,6 +180,37 @@ pub async fn handle_event(state: Arc<AppState>, data: EventData<'static>) -> any
                         )
                         .await?
                     }
+                    gifdex_lexicons::feed::collection::Collection::NSID => {
+                        handle_collection_create_event(
+                            &record,
+                            &payload.deserialize()?,
+                            &mut tx,
+                            &state,
+                        )
+                        .await?
+                    }
+                    gifdex_lexicons::feed::collection_item::CollectionItem::NSID => {
+                        handle_collection_item_create_event(
+                            &record,
+                            &payload.deserialize()?,
+                            &mut tx,
+                            &state,
+                        )
+                        .await?
+                    }
+                    gifdex_lexicons::graph::block::Block::NSID => {
+                        handle_block_create_event(&record, &payload.deserialize()?, &mut tx, &state)
+                            .await?
+                    }
+                    gifdex_lexicons::graph::follow::Follow::NSID => {
+                        handle_follow_create_event(
+                            &record,
+                            &payload.deserialize()?,
+                            &mut tx,
+                            &state,
+                        )
+                        .await?
+                    }
                     gifdex_lexicons::actor::profile::Profile::NSID => {
                         handle_profile_create_event(
                             &record,
@@ -122,6 +228,15 @@ pub async fn handle_event(state: Arc<AppState>, data: EventData<'static>) -> any
                         handle_rule_create_event(&record, &payload.deserialize()?, &mut tx, &state)
                             .await?
                     }
+                    bsky_lexicons::actor::profile::Profile::NSID => {
+                        handle_bsky_profile_create_event(
+                            &record,
+                            &payload.deserialize()?,
+                            &mut tx,
+                            &state,
+                        )
+                        .await?
+                    }
                     collection @ _ => {
                         tracing::error!(
                             "No record create/update handler for collection '{collection}': please ensure tap is sending the correct collections."
@@ -137,6 +252,18 @@ pub async fn handle_event(state: Arc<AppState>, data: EventData<'static>) -> any
                     gifdex_lexicons::feed::favourite::Favourite::NSID => {
                         handle_favourite_delete_event(&record, &mut tx, &state).await?
                     }
+                    gifdex_lexicons::feed::collection::Collection::NSID => {
+                        handle_collection_delete_event(&record, &mut tx, &state).await?
+                    }
+                    gifdex_lexicons::feed::collection_item::CollectionItem::NSID => {
+                        handle_collection_item_delete_event(&record, &mut tx, &state).await?
+                    }
+                    gifdex_lexicons::graph::block::Block::NSID => {
+                        handle_block_delete_event(&record, &mut tx, &state).await?
+                    }
+                    gifdex_lexicons::graph::follow::Follow::NSID => {
+                        handle_follow_delete_event(&record, &mut tx, &state).await?
+                    }
                     gifdex_lexicons::actor::profile::Profile::NSID => {
                         handle_profile_delete_event(&record, &mut tx, &state).await?
                     }
@@ -146,6 +273,9 @@ pub async fn handle_event(state: Arc<AppState>, data: EventData<'static>) -> any
                     gifdex_lexicons::labeler::rule::Rule::NSID => {
                         handle_rule_delete_event(&record, &mut tx, &state).await?
                     }
+                    bsky_lexicons::actor::profile::Profile::NSID => {
+                        handle_bsky_profile_delete_event(&record, &mut tx, &state).await?
+                    }
                     collection => {
                         tracing::error!(
                             "No record delete handler for collection '{collection}': please ensure tap is sending the correct collections."
@@ -179,3 +309,35 @@ pub async fn handle_event(state: Arc<AppState>, data: EventData<'static>) -> any
         }
     }
 }
+
+/// Persist why a record was refused so its author can look it up, and count the rejection.
+pub(crate) async fn reject_record(
+    record_data: &RecordEventData<'_>,
+    reason: &'static str,
+    tx: &mut PgTransaction<'_>,
+) -> anyhow::Result<()> {
+    telemetry::record_rejected(reason);
+    let cid = match &record_data.action {
+        RecordAction::Create { cid, .. } | RecordAction::Update { cid, .. } => Some(cid.as_str()),
+        _ => None,
+    };
+    if let Err(err) = query!(
+        "INSERT INTO rejected_records (did, collection, rkey, cid, reason) VALUES ($1, $2, $3, $4, $5)
+         ON CONFLICT (did, collection, rkey) DO UPDATE SET
+            cid = EXCLUDED.cid,
+            reason = EXCLUDED.reason,
+            rejected_at = EXCLUDED.rejected_at",
+        record_data.did.as_str(),
+        record_data.collection.as_str(),
+        record_data.rkey.as_str(),
+        cid,
+        reason
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        tracing::error!("Failed to record rejection in database: {err:?}");
+        return Err(err.into());
+    }
+    Ok(())
+}
@@ -1,10 +1,10 @@
 use anyhow::Result;
 use doubletap::types::RecordEventData;
-use gifdex_lexicons::net_gifdex;
+use gifdex_lexicons::{net_gifdex, validate::LexiconValidate};
 use sqlx::{PgTransaction, query};
 use tracing::{error, info, warn};
 
-use crate::AppState;
+use crate::{AppState, handlers::reject_record};
 
 pub async fn handle_profile_create_event(
     record_data: &RecordEventData<'_>,
@@ -17,31 +17,32 @@ pub async fn handle_profile_create_event(
         warn!(
             "Rejected record: actor profile record is invalid as it does not use the rkey 'self'"
         );
+        reject_record(record_data, "invalid_profile_rkey", tx).await?;
         return Ok(());
     }
 
-    // Validate that the avatar blob CID is valid,
-    // and that the reported mimetype + size are in bounds.
-    if let Some(avatar) = &data.avatar {
-        if !avatar.blob().cid().is_valid() {
-            warn!("Rejected record: invalid blob CID in for avatar");
-            return Ok(());
-        };
-        if !matches!(avatar.blob().mime_type.as_str(), "image/png" | "image/jpeg") {
-            warn!("Rejected record: blob isn't a valid mimetype");
-            return Ok(());
-        }
-        if avatar.blob().size == 3 * 1024 * 1024 {
-            warn!("Rejected record: blob is above maximum size");
-            return Ok(());
-        }
+    // Enforce the constraints declared by the lexicon, including the avatar's mimetype + size.
+    if let Err(err) = data.validate() {
+        warn!("Rejected record: {err}");
+        reject_record(record_data, "lexicon_constraint_violated", tx).await?;
+        return Ok(());
+    }
+
+    // Validate that the avatar blob CID is valid.
+    if let Some(avatar) = &data.avatar
+        && !avatar.blob().cid().is_valid()
+    {
+        warn!("Rejected record: invalid blob CID in for avatar");
+        reject_record(record_data, "invalid_avatar_cid", tx).await?;
+        return Ok(());
     }
 
     match query!(
-        "INSERT INTO accounts (did, display_name, pronouns, \
+        "INSERT INTO accounts (did, has_profile, display_name, pronouns, \
          avatar_blob_cid, created_at) \
-         VALUES ($1, $2, $3, $4, $5) \
+         VALUES ($1, true, $2, $3, $4, $5) \
          ON CONFLICT(did) DO UPDATE SET \
+         has_profile = excluded.has_profile, \
          display_name = excluded.display_name, \
          pronouns = excluded.pronouns, \
          avatar_blob_cid = excluded.avatar_blob_cid, \
@@ -75,10 +76,12 @@ pub async fn handle_profile_delete_event(
         warn!(
             "Rejected record: actor profile record is invalid as it does not use the rkey 'self'"
         );
+        reject_record(record_data, "invalid_profile_rkey", tx).await?;
         return Ok(());
     }
     match query!(
         "UPDATE accounts SET \
+         has_profile = false, \
          display_name = NULL, \
          pronouns = NULL, \
          avatar_blob_cid = NULL \
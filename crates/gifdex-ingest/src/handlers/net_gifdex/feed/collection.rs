@@ -0,0 +1,85 @@
+use crate::{AppState, handlers::reject_record};
+use anyhow::Result;
+use doubletap::types::RecordEventData;
+use gifdex_lexicons::{net_gifdex, validate::LexiconValidate};
+use jacquard_common::types::tid::Tid;
+use sqlx::{PgTransaction, query};
+use tracing::{error, info};
+
+pub async fn handle_collection_create_event(
+    record_data: &RecordEventData<'_>,
+    data: &net_gifdex::feed::collection::Collection<'_>,
+    tx: &mut PgTransaction<'_>,
+    _state: &AppState,
+) -> Result<()> {
+    // Enforce the constraints declared by the lexicon.
+    if let Err(err) = data.validate() {
+        tracing::warn!("Rejected record: {err}");
+        reject_record(record_data, "lexicon_constraint_violated", tx).await?;
+        return Ok(());
+    }
+    // Ensure the record rkey is a valid TID.
+    if Tid::new(&record_data.rkey).is_err() {
+        tracing::warn!("Rejected record: invalid rkey");
+        reject_record(record_data, "invalid_rkey", tx).await?;
+        return Ok(());
+    }
+    // Orderings this appview doesn't know about fall back to the default.
+    let ordering = match data.ordering.as_deref() {
+        Some("newest") => "newest",
+        Some("oldest") => "oldest",
+        _ => "manual",
+    };
+
+    match query!(
+        "INSERT INTO collections (did, rkey, title, description, ordering, created_at)
+         VALUES ($1, $2, $3, $4, $5::TEXT::collection_ordering, $6)
+         ON CONFLICT (did, rkey) DO UPDATE SET
+            title = EXCLUDED.title,
+            description = EXCLUDED.description,
+            ordering = EXCLUDED.ordering,
+            created_at = EXCLUDED.created_at",
+        record_data.did.as_str(),
+        record_data.rkey.as_str(),
+        data.title.as_ref(),
+        data.description.as_deref(),
+        ordering,
+        data.created_at.as_ref().timestamp_millis()
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        Ok(_) => {
+            info!("Upserted feed collection into database");
+            Ok(())
+        }
+        Err(err) => {
+            error!("Failed to upsert feed collection into database: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+pub async fn handle_collection_delete_event(
+    record_data: &RecordEventData<'_>,
+    tx: &mut PgTransaction<'_>,
+    _state: &AppState,
+) -> Result<()> {
+    match query!(
+        "DELETE FROM collections WHERE did = $1 AND rkey = $2",
+        record_data.did.as_str(),
+        record_data.rkey.as_str()
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        Ok(_) => {
+            info!("Deleted feed collection from database");
+            Ok(())
+        }
+        Err(err) => {
+            error!("Failed to delete feed collection from database: {err:?}");
+            Err(err.into())
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{AppState, handlers::reject_record};
+use anyhow::Result;
+use doubletap::types::RecordEventData;
+use gifdex_lexicons::{net_gifdex, validate::LexiconValidate};
+use jacquard_common::types::{cid::Cid, collection::Collection, ident::AtIdentifier, tid::Tid};
+use sqlx::{PgTransaction, query};
+use tracing::{error, info};
+
+pub async fn handle_collection_item_create_event(
+    record_data: &RecordEventData<'_>,
+    data: &net_gifdex::feed::collection_item::CollectionItem<'_>,
+    tx: &mut PgTransaction<'_>,
+    _state: &AppState,
+) -> Result<()> {
+    // Enforce the constraints declared by the lexicon.
+    if let Err(err) = data.validate() {
+        tracing::warn!("Rejected record: {err}");
+        reject_record(record_data, "lexicon_constraint_violated", tx).await?;
+        return Ok(());
+    }
+    // Ensure the record rkey is a valid TID.
+    if Tid::new(&record_data.rkey).is_err() {
+        tracing::warn!("Rejected record: invalid rkey");
+        reject_record(record_data, "invalid_rkey", tx).await?;
+        return Ok(());
+    }
+    // Ensure the referenced collection is one of the record author's own collections.
+    let collection_rkey = match (
+        data.collection.authority(),
+        data.collection.collection(),
+        data.collection.rkey(),
+    ) {
+        (AtIdentifier::Did(did), Some(collection), Some(rkey))
+            if did.as_str() == record_data.did.as_str()
+                && collection.as_str() == net_gifdex::feed::collection::Collection::NSID
+                && Tid::new(rkey.as_ref()).is_ok() =>
+        {
+            rkey
+        }
+        at_uri => {
+            tracing::warn!("Rejected record: invalid collection at-uri ({at_uri:?})");
+            reject_record(record_data, "invalid_collection_uri", tx).await?;
+            return Ok(());
+        }
+    };
+    // Ensure the record's referenced subject is a post.
+    let (post_did, post_collection, post_rkey) = match (
+        data.subject.authority(),
+        data.subject.collection(),
+        data.subject.rkey(),
+    ) {
+        (did, Some(collection), Some(rkey)) => {
+            // Ensure post rkey is valid.
+            match rkey.as_ref().split_once(":") {
+                Some((tid, cid)) => {
+                    if Tid::new(tid).is_err() {
+                        tracing::warn!("Rejected record: invalid TID in rkey");
+                        reject_record(record_data, "invalid_subject_rkey_tid", tx).await?;
+                        return Ok(());
+                    }
+                    if !Cid::str(cid).is_valid() {
+                        tracing::warn!("Rejected record: invalid CID in rkey");
+                        reject_record(record_data, "invalid_subject_rkey_cid", tx).await?;
+                        return Ok(());
+                    };
+                }
+                None => {
+                    tracing::warn!("Rejected record: rkey doesn't match tid:cid format");
+                    reject_record(record_data, "invalid_subject_rkey_format", tx).await?;
+                    return Ok(());
+                }
+            };
+            (did, collection, rkey)
+        }
+        at_uri => {
+            tracing::warn!("Rejected record: invalid subject at-uri ({at_uri:?})");
+            reject_record(record_data, "invalid_subject_uri", tx).await?;
+            return Ok(());
+        }
+    };
+    if post_collection.as_str() != net_gifdex::feed::post::Post::NSID {
+        tracing::warn!(
+            "Rejected record: subject at-uri referenced a collection that was not {}",
+            net_gifdex::feed::post::Post::NSID
+        );
+        reject_record(record_data, "invalid_subject_collection", tx).await?;
+        return Ok(());
+    }
+    // Blocked accounts can't collect the blocker's posts.
+    let blocked = match query!(
+        r#"SELECT EXISTS(SELECT 1 FROM blocks WHERE did = $1 AND subject_did = $2) as "exists!""#,
+        post_did.as_str(),
+        record_data.did.as_str()
+    )
+    .fetch_one(&mut **tx)
+    .await
+    {
+        Ok(row) => row.exists,
+        Err(err) => {
+            error!("Failed to check for blocks in database: {err:?}");
+            return Err(err.into());
+        }
+    };
+    if blocked {
+        tracing::warn!("Rejected record: collected post author has blocked the record author");
+        reject_record(record_data, "blocked_by_subject_author", tx).await?;
+        return Ok(());
+    }
+
+    // An update replaces the item's previous version, while a post that's already in the
+    // collection through another item is left where it is.
+    if let Err(err) = query!(
+        "DELETE FROM collection_items WHERE did = $1 AND rkey = $2",
+        record_data.did.as_str(),
+        record_data.rkey.as_str()
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        error!("Failed to clear previous feed collection item from database: {err:?}");
+        return Err(err.into());
+    }
+    match query!(
+        "INSERT INTO collection_items (did, rkey, collection_rkey, post_did, post_rkey, \
+         position, created_at) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7) \
+         ON CONFLICT (did, collection_rkey, post_did, post_rkey) DO NOTHING",
+        record_data.did.as_str(),
+        record_data.rkey.as_str(),
+        collection_rkey.as_ref(),
+        post_did.as_str(),
+        post_rkey.as_ref(),
+        data.position,
+        data.created_at.as_ref().timestamp_millis()
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        Ok(_) => {
+            info!("Upserted feed collection item into database");
+            Ok(())
+        }
+        Err(err) => {
+            error!("Failed to upsert feed collection item into database: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+pub async fn handle_collection_item_delete_event(
+    record_data: &RecordEventData<'_>,
+    tx: &mut PgTransaction<'_>,
+    _state: &AppState,
+) -> Result<()> {
+    match query!(
+        "DELETE FROM collection_items WHERE did = $1 AND rkey = $2",
+        record_data.did.as_str(),
+        record_data.rkey.as_str()
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        Ok(_) => {
+            info!("Deleted feed collection item from database");
+            Ok(())
+        }
+        Err(err) => {
+            error!("Failed to delete feed collection item from database: {err:?}");
+            Err(err.into())
+        }
+    }
+}
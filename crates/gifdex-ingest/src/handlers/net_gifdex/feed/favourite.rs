@@ -1,7 +1,7 @@
-use crate::AppState;
+use crate::{AppState, handlers::reject_record};
 use anyhow::Result;
 use doubletap::types::RecordEventData;
-use gifdex_lexicons::net_gifdex;
+use gifdex_lexicons::{net_gifdex, validate::LexiconValidate};
 use jacquard_common::types::{cid::Cid, collection::Collection, tid::Tid};
 use sqlx::{PgTransaction, query};
 use tracing::{error, info};
@@ -12,9 +12,16 @@ pub async fn handle_favourite_create_event(
     tx: &mut PgTransaction<'_>,
     _state: &AppState,
 ) -> Result<()> {
+    // Enforce the constraints declared by the lexicon.
+    if let Err(err) = data.validate() {
+        tracing::warn!("Rejected record: {err}");
+        reject_record(record_data, "lexicon_constraint_violated", tx).await?;
+        return Ok(());
+    }
     // Ensure the record rkey is a valid TID .
     if Tid::new(&record_data.rkey).is_err() {
         tracing::warn!("Rejected record: invalid rkey");
+        reject_record(record_data, "invalid_rkey", tx).await?;
         return Ok(());
     }
     // Ensure the record's referenced subject is a post.
@@ -29,15 +36,18 @@ pub async fn handle_favourite_create_event(
                 Some((tid, cid)) => {
                     if Tid::new(tid).is_err() {
                         tracing::warn!("Rejected record: invalid TID in rkey");
+                        reject_record(record_data, "invalid_subject_rkey_tid", tx).await?;
                         return Ok(());
                     }
                     if !Cid::str(cid).is_valid() {
                         tracing::warn!("Rejected record: invalid CID in rkey");
+                        reject_record(record_data, "invalid_subject_rkey_cid", tx).await?;
                         return Ok(());
                     };
                 }
                 None => {
                     tracing::warn!("Rejected record: rkey doesn't match tid:cid format");
+                    reject_record(record_data, "invalid_subject_rkey_format", tx).await?;
                     return Ok(());
                 }
             };
@@ -45,6 +55,7 @@ pub async fn handle_favourite_create_event(
         }
         at_uri => {
             tracing::warn!("Rejected record: invalid subject at-uri ({at_uri:?})");
+            reject_record(record_data, "invalid_subject_uri", tx).await?;
             return Ok(());
         }
     };
@@ -53,6 +64,27 @@ pub async fn handle_favourite_create_event(
             "Rejected record: subject at-uri referenced a collection that was not {}",
             net_gifdex::feed::post::Post::NSID
         );
+        reject_record(record_data, "invalid_subject_collection", tx).await?;
+        return Ok(());
+    }
+    // Blocked accounts can't favourite the blocker's posts.
+    let blocked = match query!(
+        r#"SELECT EXISTS(SELECT 1 FROM blocks WHERE did = $1 AND subject_did = $2) as "exists!""#,
+        post_did.as_str(),
+        record_data.did.as_str()
+    )
+    .fetch_one(&mut **tx)
+    .await
+    {
+        Ok(row) => row.exists,
+        Err(err) => {
+            error!("Failed to check for blocks in database: {err:?}");
+            return Err(err.into());
+        }
+    };
+    if blocked {
+        tracing::warn!("Rejected record: favourited post author has blocked the record author");
+        reject_record(record_data, "blocked_by_subject_author", tx).await?;
         return Ok(());
     }
 
@@ -1,4 +1,8 @@
+mod collection;
+mod collection_item;
 mod favourite;
 mod post;
+pub use collection::*;
+pub use collection_item::*;
 pub use favourite::*;
 pub use post::*;
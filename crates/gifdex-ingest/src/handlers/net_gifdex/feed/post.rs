@@ -1,54 +1,79 @@
-use crate::AppState;
-use anyhow::{Context, Result, bail};
+use crate::{AppState, handlers::reject_record, media_matches};
+use anyhow::{Context, Result};
 use doubletap::types::RecordEventData;
-use gifdex_lexicons::net_gifdex;
-use jacquard_common::types::{cid::Cid, tid::Tid};
+use gifdex_lexicons::{
+    net_gifdex::{self, labeler::rule::Rule},
+    validate::LexiconValidate,
+};
+use gifdex_media::InspectLimits;
+use jacquard_common::types::{cid::Cid, collection::Collection, tid::Tid};
 use sqlx::{PgTransaction, query};
 use std::time::Duration;
 use tracing::{error, info, warn};
 use url::Url;
 
+const MAX_BLOB_SIZE: usize = 5_000_000; // 5MB, as declared by the lexicon.
+
 pub async fn handle_post_create(
     record_data: &RecordEventData<'_>,
     data: &net_gifdex::feed::post::Post<'_>,
     tx: &mut PgTransaction<'_>,
     state: &AppState,
 ) -> Result<()> {
+    // Enforce the constraints declared by the lexicon, including the blob's mimetype + size.
+    if let Err(err) = data.validate() {
+        warn!("Rejected record: {err}");
+        reject_record(record_data, "lexicon_constraint_violated", tx).await?;
+        return Ok(());
+    }
+
     // Validate rkey format as tid:cid and matches blob
     match record_data.rkey.split_once(":") {
         Some((tid_str, cid_str)) => {
             if Tid::new(tid_str).is_err() {
                 warn!("Rejected record: invalid TID in rkey");
+                reject_record(record_data, "invalid_rkey_tid", tx).await?;
                 return Ok(());
             }
             let cid = Cid::str(cid_str);
             if !cid.is_valid() {
                 warn!("Rejected record: invalid CID in rkey");
+                reject_record(record_data, "invalid_rkey_cid", tx).await?;
                 return Ok(());
             }
             // Validate rkey CID matches blob CID
             if cid != *data.media.blob.blob().cid() {
                 warn!("Rejected record: rkey CID doesn't match blob CID");
+                reject_record(record_data, "rkey_cid_mismatch", tx).await?;
                 return Ok(());
             }
         }
         None => {
             warn!("Rejected record: rkey doesn't match tid:cid format");
+            reject_record(record_data, "invalid_rkey_format", tx).await?;
             return Ok(());
         }
     };
 
-    // Loosely-validate the provided blob's mimetype + size.
-    if !matches!(
-        data.media.blob.blob().mime_type.as_str(),
-        "image/gif" | "image/webp"
-    ) {
-        warn!("Rejected record: blob isn't a valid mimetype");
-        return Ok(());
-    }
-    if data.media.blob.blob().size == 10 * 1024 * 1024 {
-        warn!("Rejected record: blob is above maximum size");
-        return Ok(());
+    // Self-labels must reference labeler rules, though those may not have been indexed yet.
+    let mut self_label_dids = Vec::new();
+    let mut self_label_rkeys = Vec::new();
+    for label in data.labels.iter().flatten() {
+        match (label.collection().map(|v| v.as_str()), label.rkey()) {
+            (Some(Rule::NSID), Some(rkey)) => {
+                self_label_dids.push(label.authority().as_str());
+                self_label_rkeys.push(rkey.0.as_str());
+            }
+            _ => {
+                warn!(
+                    label_uri = label.as_str(),
+                    "Rejected record: self-label must reference a {} record",
+                    Rule::NSID
+                );
+                reject_record(record_data, "invalid_self_label", tx).await?;
+                return Ok(());
+            }
+        }
     }
 
     // Extract tag data.
@@ -71,7 +96,7 @@ pub async fn handle_post_create(
         .await?
         .pds_endpoint()
         .unwrap();
-    let response = validate_gif_or_webp(
+    let Some(bytes) = fetch_blob(
         &pds.join(&format!(
             "/xrpc/com.atproto.sync.getBlob?did={}&cid={}",
             record_data.did,
@@ -79,38 +104,124 @@ pub async fn handle_post_create(
         ))?,
         &state.http_client,
     )
-    .await?;
+    .await?
+    else {
+        warn!("Rejected record: blob is above maximum size");
+        reject_record(record_data, "media_too_large", tx).await?;
+        return Ok(());
+    };
 
-    match query!(
+    // Fully decode the media to make sure it's structurally valid and within budget,
+    // then compute its perceptual hash for duplicate detection.
+    let (media_info, media_phash) = match tokio::task::spawn_blocking(move || {
+        gifdex_media::inspect(&bytes, &InspectLimits::default())
+            .map(|info| (info, gifdex_media::perceptual_hash(&bytes)))
+    })
+    .await?
+    {
+        Ok((media_info, Ok(phash))) => (media_info, Some(phash)),
+        Ok((media_info, Err(err))) => {
+            warn!("Failed to compute perceptual hash for media: {err}");
+            (media_info, None)
+        }
+        Err(err) => {
+            warn!("Rejected record: media failed inspection: {err}");
+            reject_record(record_data, "media_inspection_failed", tx).await?;
+            return Ok(());
+        }
+    };
+
+    if let Err(err) = query!(
         "INSERT INTO posts (did, rkey, title, media_blob_cid, media_blob_mime, \
-         media_blob_alt, media_blob_width, media_blob_height, tags, created_at) \
-         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) \
+         media_blob_alt, media_blob_width, media_blob_height, media_blob_size, \
+         media_frame_count, media_duration_ms, media_phash, tags, created_at) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14) \
          ON CONFLICT(did, rkey) DO UPDATE SET \
          title = excluded.title, \
          media_blob_alt = excluded.media_blob_alt, \
          tags = excluded.tags, \
+         media_blob_size = excluded.media_blob_size, \
+         media_frame_count = excluded.media_frame_count, \
+         media_duration_ms = excluded.media_duration_ms, \
+         media_phash = excluded.media_phash, \
          created_at = excluded.created_at, \
          edited_at = extract(epoch from now())::BIGINT",
         record_data.did.as_str(),
         record_data.rkey.as_str(),
         data.title.as_str(),
         data.media.blob.blob().cid().as_str(),
-        response.mime_type,
+        media_info.format.mime_type(),
         data.media.alt.as_ref().map(|v| v.as_str()),
-        response.width as i64,
-        response.height as i64,
+        media_info.width as i64,
+        media_info.height as i64,
+        media_info.size as i64,
+        media_info.frame_count as i64,
+        media_info.duration_ms as i64,
+        media_phash.map(|phash| phash as i64),
         tags_array.as_deref(),
         data.created_at.as_ref().timestamp_millis()
     )
     .execute(&mut **tx)
     .await
+    {
+        error!("Failed to upsert post into database: {err:?}");
+        return Err(err.into());
+    }
+    info!("Upserted post into database");
+
+    if let Err(err) =
+        media_matches::match_post(tx, record_data.did.as_str(), record_data.rkey.as_str()).await
+    {
+        error!("Failed to match post to labels of similar media: {err:?}");
+        return Err(err.into());
+    }
+
+    // The original is always served as-is, so its size can be advertised up front. The CDN
+    // records the sizes of other variants as it produces them.
+    if let Err(err) = query!(
+        "INSERT INTO media_variants (cid, mime_type, size) VALUES ($1, $2, $3) \
+         ON CONFLICT (cid, mime_type) DO NOTHING",
+        data.media.blob.blob().cid().as_str(),
+        media_info.format.mime_type(),
+        media_info.size as i64
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        error!("Failed to record size of original media variant: {err:?}");
+        return Err(err.into());
+    }
+
+    if let Err(err) = query!(
+        "DELETE FROM post_self_labels WHERE post_did = $1 AND post_rkey = $2",
+        record_data.did.as_str(),
+        record_data.rkey.as_str()
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        error!("Failed to clear self-labels of post: {err:?}");
+        return Err(err.into());
+    }
+    match query!(
+        "INSERT INTO post_self_labels (post_did, post_rkey, rule_did, rule_rkey) \
+         SELECT $1, $2, rule_did, rule_rkey FROM UNNEST($3::TEXT[], $4::TEXT[]) \
+         AS l(rule_did, rule_rkey) \
+         ON CONFLICT DO NOTHING",
+        record_data.did.as_str(),
+        record_data.rkey.as_str(),
+        &self_label_dids as &[&str],
+        &self_label_rkeys as &[&str]
+    )
+    .execute(&mut **tx)
+    .await
     {
         Ok(_) => {
-            info!("Upserted post into database");
+            info!("Replaced self-labels of post");
             Ok(())
         }
         Err(err) => {
-            error!("Failed to upsert post into database: {err:?}");
+            error!("Failed to insert self-labels of post: {err:?}");
             Err(err.into())
         }
     }
@@ -121,6 +232,32 @@ pub async fn handle_post_delete(
     tx: &mut PgTransaction<'_>,
     _state: &AppState,
 ) -> Result<()> {
+    // Favourites and labels of the post would otherwise be orphaned, and counted again if
+    // the post was ever re-created with the same rkey.
+    if let Err(err) = query!(
+        "DELETE FROM post_favourites WHERE post_did = $1 AND post_rkey = $2",
+        record_data.did.as_str(),
+        record_data.rkey.as_str()
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        error!("Failed to delete favourites of post: {err:?}");
+        return Err(err.into());
+    };
+    if let Err(err) = query!(
+        "DELETE FROM labels WHERE subject_did = $1 AND subject_collection = $2 AND subject_rkey = $3",
+        record_data.did.as_str(),
+        net_gifdex::feed::post::Post::NSID,
+        record_data.rkey.as_str()
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        error!("Failed to delete labels applied to post: {err:?}");
+        return Err(err.into());
+    };
+
     match query!(
         "DELETE FROM posts WHERE did = $1 AND rkey = $2",
         record_data.did.as_str(),
@@ -140,58 +277,26 @@ pub async fn handle_post_delete(
     }
 }
 
-async fn validate_gif_or_webp(url: &Url, http_client: &reqwest::Client) -> Result<ImageInfo> {
+/// Download a blob in full, returning [`None`] if it's larger than [`MAX_BLOB_SIZE`].
+#[tracing::instrument(skip_all, fields(url = url.as_str()))]
+async fn fetch_blob(url: &Url, http_client: &reqwest::Client) -> Result<Option<Vec<u8>>> {
     let mut buffer = Vec::new();
     let mut response = http_client
         .get(url.as_str())
-        .timeout(Duration::from_secs(10))
+        .headers(gifdex_telemetry::trace_headers())
+        .timeout(Duration::from_secs(30))
         .send()
         .await
-        .context("Failed to fetch image")?;
+        .context("Failed to fetch blob")?
+        .error_for_status()
+        .context("PDS returned an error for blob")?;
 
     while let Some(chunk) = response.chunk().await.context("Failed to read chunk")? {
-        buffer.extend_from_slice(&chunk);
-
-        // Try detection once we have enough bytes
-        if buffer.len() >= 32 {
-            // Validate MIME type first (fast check)
-            if let Some(kind) = infer::get(&buffer) {
-                let mime = kind.mime_type();
-                if mime != "image/gif" && mime != "image/webp" {
-                    bail!("Unsupported format: {}", mime);
-                }
-
-                // Then get dimensions
-                if let Ok(size) = imagesize::blob_size(&buffer) {
-                    // Validate dimensions
-                    if size.width > 10_000 || size.height > 10_000 {
-                        bail!("Dimensions too large: {}x{}", size.width, size.height);
-                    }
-
-                    if size.width == 0 || size.height == 0 {
-                        bail!("Invalid dimensions: {}x{}", size.width, size.height);
-                    }
-
-                    return Ok(ImageInfo {
-                        width: size.width,
-                        height: size.height,
-                        mime_type: mime.to_string(),
-                    });
-                }
-            }
-        }
-
-        if buffer.len() > 32_768 {
-            break;
+        if buffer.len() + chunk.len() > MAX_BLOB_SIZE {
+            return Ok(None);
         }
+        buffer.extend_from_slice(&chunk);
     }
 
-    bail!("Failed to detect image format and dimensions")
-}
-
-#[derive(Debug)]
-struct ImageInfo {
-    width: usize,
-    height: usize,
-    mime_type: String,
+    Ok(Some(buffer))
 }
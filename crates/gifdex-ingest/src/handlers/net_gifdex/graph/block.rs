@@ -0,0 +1,80 @@
+use crate::{AppState, handlers::reject_record};
+use anyhow::Result;
+use doubletap::types::RecordEventData;
+use gifdex_lexicons::{net_gifdex, validate::LexiconValidate};
+use jacquard_common::types::tid::Tid;
+use sqlx::{PgTransaction, query};
+use tracing::{error, info};
+
+pub async fn handle_block_create_event(
+    record_data: &RecordEventData<'_>,
+    data: &net_gifdex::graph::block::Block<'_>,
+    tx: &mut PgTransaction<'_>,
+    _state: &AppState,
+) -> Result<()> {
+    // Enforce the constraints declared by the lexicon.
+    if let Err(err) = data.validate() {
+        tracing::warn!("Rejected record: {err}");
+        reject_record(record_data, "lexicon_constraint_violated", tx).await?;
+        return Ok(());
+    }
+    // Ensure the record rkey is a valid TID.
+    if Tid::new(&record_data.rkey).is_err() {
+        tracing::warn!("Rejected record: invalid rkey");
+        reject_record(record_data, "invalid_rkey", tx).await?;
+        return Ok(());
+    }
+    // An account can't block itself.
+    if data.subject.as_str() == record_data.did.as_str() {
+        tracing::warn!("Rejected record: block subject is the record author");
+        reject_record(record_data, "invalid_block_subject", tx).await?;
+        return Ok(());
+    }
+
+    match query!(
+        "INSERT INTO blocks (did, rkey, subject_did, created_at) VALUES ($1, $2, $3, $4)
+         ON CONFLICT (did, rkey) DO UPDATE SET
+            subject_did = EXCLUDED.subject_did,
+            created_at = EXCLUDED.created_at",
+        record_data.did.as_str(),
+        record_data.rkey.as_str(),
+        data.subject.as_str(),
+        data.created_at.as_ref().timestamp_millis()
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        Ok(_) => {
+            info!("Upserted graph block into database");
+            Ok(())
+        }
+        Err(err) => {
+            error!("Failed to upsert graph block into database: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+pub async fn handle_block_delete_event(
+    record_data: &RecordEventData<'_>,
+    tx: &mut PgTransaction<'_>,
+    _state: &AppState,
+) -> Result<()> {
+    match query!(
+        "DELETE FROM blocks WHERE did = $1 AND rkey = $2",
+        record_data.did.as_str(),
+        record_data.rkey.as_str()
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        Ok(_) => {
+            info!("Deleted graph block from database");
+            Ok(())
+        }
+        Err(err) => {
+            error!("Failed to delete graph block from database: {err:?}");
+            Err(err.into())
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{AppState, handlers::reject_record};
+use anyhow::Result;
+use doubletap::types::RecordEventData;
+use gifdex_lexicons::{net_gifdex, validate::LexiconValidate};
+use jacquard_common::types::tid::Tid;
+use sqlx::{PgTransaction, query};
+use tracing::{error, info};
+
+pub async fn handle_follow_create_event(
+    record_data: &RecordEventData<'_>,
+    data: &net_gifdex::graph::follow::Follow<'_>,
+    tx: &mut PgTransaction<'_>,
+    _state: &AppState,
+) -> Result<()> {
+    // Enforce the constraints declared by the lexicon.
+    if let Err(err) = data.validate() {
+        tracing::warn!("Rejected record: {err}");
+        reject_record(record_data, "lexicon_constraint_violated", tx).await?;
+        return Ok(());
+    }
+    // Ensure the record rkey is a valid TID.
+    if Tid::new(&record_data.rkey).is_err() {
+        tracing::warn!("Rejected record: invalid rkey");
+        reject_record(record_data, "invalid_rkey", tx).await?;
+        return Ok(());
+    }
+    // An account can't follow itself.
+    if data.subject.as_str() == record_data.did.as_str() {
+        tracing::warn!("Rejected record: follow subject is the record author");
+        reject_record(record_data, "invalid_follow_subject", tx).await?;
+        return Ok(());
+    }
+    // An account can only follow another once, so later records for the same subject are
+    // rejected rather than counted again.
+    let duplicate = match query!(
+        r#"SELECT EXISTS(SELECT 1 FROM follows WHERE did = $1 AND subject_did = $2 AND rkey != $3)
+           as "exists!""#,
+        record_data.did.as_str(),
+        data.subject.as_str(),
+        record_data.rkey.as_str()
+    )
+    .fetch_one(&mut **tx)
+    .await
+    {
+        Ok(row) => row.exists,
+        Err(err) => {
+            error!("Failed to check for existing follows in database: {err:?}");
+            return Err(err.into());
+        }
+    };
+    if duplicate {
+        tracing::warn!("Rejected record: subject is already followed by the record author");
+        reject_record(record_data, "duplicate_follow", tx).await?;
+        return Ok(());
+    }
+
+    match query!(
+        "INSERT INTO follows (did, rkey, subject_did, created_at) VALUES ($1, $2, $3, $4)
+         ON CONFLICT (did, rkey) DO UPDATE SET
+            subject_did = EXCLUDED.subject_did,
+            created_at = EXCLUDED.created_at",
+        record_data.did.as_str(),
+        record_data.rkey.as_str(),
+        data.subject.as_str(),
+        data.created_at.as_ref().timestamp_millis()
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        Ok(_) => {
+            info!("Upserted graph follow into database");
+            Ok(())
+        }
+        Err(err) => {
+            error!("Failed to upsert graph follow into database: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+pub async fn handle_follow_delete_event(
+    record_data: &RecordEventData<'_>,
+    tx: &mut PgTransaction<'_>,
+    _state: &AppState,
+) -> Result<()> {
+    match query!(
+        "DELETE FROM follows WHERE did = $1 AND rkey = $2",
+        record_data.did.as_str(),
+        record_data.rkey.as_str()
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        Ok(_) => {
+            info!("Deleted graph follow from database");
+            Ok(())
+        }
+        Err(err) => {
+            error!("Failed to delete graph follow from database: {err:?}");
+            Err(err.into())
+        }
+    }
+}
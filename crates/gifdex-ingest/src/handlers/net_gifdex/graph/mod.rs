@@ -0,0 +1,5 @@
+mod block;
+mod follow;
+
+pub use block::*;
+pub use follow::*;
@@ -1,7 +1,10 @@
-use crate::AppState;
+use crate::{AppState, handlers::reject_record, media_matches};
 use anyhow::Result;
 use doubletap::types::RecordEventData;
-use gifdex_lexicons::net_gifdex::{self, labeler::rule::Rule};
+use gifdex_lexicons::{
+    net_gifdex::{self, labeler::rule::Rule},
+    validate::LexiconValidate,
+};
 use jacquard_common::types::collection::Collection;
 use sqlx::{PgTransaction, query};
 use tracing::{error, info};
@@ -12,6 +15,13 @@ pub async fn handle_label_create_event(
     tx: &mut PgTransaction<'_>,
     _state: &AppState,
 ) -> Result<()> {
+    // Enforce the constraints declared by the lexicon.
+    if let Err(err) = data.validate() {
+        tracing::warn!("Rejected record: {err}");
+        reject_record(record_data, "lexicon_constraint_violated", tx).await?;
+        return Ok(());
+    }
+
     let (subject_did, subject_collection, subject_rkey) = (
         data.subject.authority().as_str(),
         data.subject.collection().map(|v| v.as_str()),
@@ -28,6 +38,7 @@ pub async fn handle_label_create_event(
                 rule_uri = data.rule.as_str(),
                 "Rejected record: rule must be a complete AT-URI with collection and rkey"
             );
+            reject_record(record_data, "incomplete_rule_uri", tx).await?;
             return Ok(());
         }
         (_, Some(collection), _) if collection != Rule::NSID => {
@@ -36,6 +47,7 @@ pub async fn handle_label_create_event(
                 "Rejected record: rule must reference the {} collection",
                 Rule::NSID
             );
+            reject_record(record_data, "invalid_rule_collection", tx).await?;
             return Ok(());
         }
         (rule_did, _, _) => {
@@ -44,17 +56,18 @@ pub async fn handle_label_create_event(
                 labeler_did = record_data.did.as_str(),
                 "Rejected record: labeler can only apply their own rules"
             );
+            reject_record(record_data, "foreign_rule", tx).await?;
             return Ok(());
         }
     };
 
-    match query!(
+    if let Err(err) = query!(
         "INSERT INTO labels (\
              rkey, did, rule_did, rule_rkey, \
              subject_did, subject_collection, subject_rkey, \
-             reason, created_at, expires_at \
+             reason, match_media_hash, created_at, expires_at \
          ) VALUES ( \
-             $1, $2, $3, $4, $5, $6, $7, $8, $9, $10 \
+             $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11 \
          ) \
          ON CONFLICT(did, rkey) DO UPDATE SET \
          rule_did = excluded.rule_did, \
@@ -63,6 +76,7 @@ pub async fn handle_label_create_event(
          subject_collection = excluded.subject_collection, \
          subject_rkey = excluded.subject_rkey, \
          reason = excluded.reason, \
+         match_media_hash = excluded.match_media_hash, \
          created_at = excluded.created_at, \
          edited_at = extract(epoch from now())::BIGINT, \
          expires_at = excluded.expires_at",
@@ -74,20 +88,28 @@ pub async fn handle_label_create_event(
         subject_collection,
         subject_rkey,
         data.reason.as_deref(),
+        data.match_media_hash.unwrap_or(false),
         data.created_at.as_ref().timestamp_millis(),
         data.expires_at
             .as_ref()
-            .map(|expiry| expiry.as_ref().timestamp_micros())
+            .map(|expiry| expiry.as_ref().timestamp_millis())
     )
     .execute(&mut **tx)
     .await
     {
-        Ok(_) => {
-            info!("Upserted label application");
+        error!("Failed to upsert label application: {err:?}");
+        return Err(err.into());
+    }
+    info!("Upserted label application");
+
+    match media_matches::match_label(tx, record_data.did.as_str(), record_data.rkey.as_str()).await
+    {
+        Ok(()) => {
+            info!("Matched label to posts with similar media");
             Ok(())
         }
         Err(err) => {
-            error!("Failed to upsert label application: {err:?}");
+            error!("Failed to match label to posts with similar media: {err:?}");
             Err(err.into())
         }
     }
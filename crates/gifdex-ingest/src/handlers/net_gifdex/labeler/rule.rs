@@ -1,7 +1,7 @@
-use crate::AppState;
+use crate::{AppState, handlers::reject_record};
 use anyhow::Result;
 use doubletap::types::RecordEventData;
-use gifdex_lexicons::net_gifdex;
+use gifdex_lexicons::{net_gifdex, validate::LexiconValidate};
 use sqlx::{PgTransaction, query};
 use tracing::{error, info};
 
@@ -11,6 +11,13 @@ pub async fn handle_rule_create_event(
     tx: &mut PgTransaction<'_>,
     _state: &AppState,
 ) -> Result<()> {
+    // Enforce the constraints declared by the lexicon.
+    if let Err(err) = data.validate() {
+        tracing::warn!("Rejected record: {err}");
+        reject_record(record_data, "lexicon_constraint_violated", tx).await?;
+        return Ok(());
+    }
+
     // Determine behaviour type and extract fields based on behaviour variant.
     let (behaviour, default_setting, adult_content, takedown) = match &data.behaviour {
         net_gifdex::labeler::rule::RuleBehaviour::Annotate(annotate) => (
@@ -24,6 +31,7 @@ pub async fn handle_rule_create_event(
         }
         behaviour => {
             tracing::warn!("Rejected record: unknown rule_behaviour: {behaviour:?}");
+            reject_record(record_data, "unknown_rule_behaviour", tx).await?;
             return Ok(());
         }
     };
@@ -31,11 +39,11 @@ pub async fn handle_rule_create_event(
     match query!(
         r#"INSERT INTO labeler_rules (
             rkey, did, name, description, behaviour,
-            default_setting, adult_content, takedown,
+            default_setting, adult_content, takedown, self_label,
             created_at, indexed_at
         ) VALUES (
             $1, $2, $3, $4, $5::TEXT::labeler_behaviour, $6::TEXT::labeler_behaviour_setting, $7, $8,
-            $9, extract(epoch from now())::BIGINT
+            $9, $10, extract(epoch from now())::BIGINT
         )
         ON CONFLICT(did, rkey) DO UPDATE SET
             name = excluded.name,
@@ -44,6 +52,7 @@ pub async fn handle_rule_create_event(
             default_setting = excluded.default_setting,
             adult_content = excluded.adult_content,
             takedown = excluded.takedown,
+            self_label = excluded.self_label,
             created_at = excluded.created_at,
             edited_at = extract(epoch from now())::BIGINT"#,
         record_data.rkey.as_str(),
@@ -54,6 +63,7 @@ pub async fn handle_rule_create_event(
         default_setting,
         adult_content,
         takedown,
+        data.self_label.unwrap_or(false),
         data.created_at.as_ref().timestamp_millis()
     )
     .execute(&mut **tx)
@@ -75,6 +85,19 @@ pub async fn handle_rule_delete_event(
     tx: &mut PgTransaction<'_>,
     _state: &AppState,
 ) -> Result<()> {
+    // Labels can't be interpreted without their rule.
+    if let Err(err) = query!(
+        "DELETE FROM labels WHERE rule_did = $1 AND rule_rkey = $2",
+        record_data.did.as_str(),
+        record_data.rkey.as_str()
+    )
+    .execute(&mut **tx)
+    .await
+    {
+        error!("Failed to delete labels applying labeler rule: {err:?}");
+        return Err(err.into());
+    };
+
     match query!(
         "DELETE FROM labeler_rules WHERE did = $1 AND rkey = $2",
         record_data.did.as_str(),
@@ -1,3 +1,4 @@
 pub mod actor;
 pub mod feed;
+pub mod graph;
 pub mod labeler;
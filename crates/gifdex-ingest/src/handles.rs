@@ -0,0 +1,148 @@
+use crate::AppState;
+use anyhow::Result;
+use jacquard_common::types::{did::Did, handle::Handle};
+use jacquard_identity::{PublicResolver, resolver::IdentityResolver};
+use sqlx::query;
+use std::{future::Future, pin::Pin, sync::Arc, time::Duration};
+use tracing::{debug, error, info};
+
+/// How long a verified handle is trusted before it's checked again, so handles whose DNS record
+/// or well-known endpoint stops pointing at the account are eventually marked invalid.
+const REVERIFY_AFTER: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Maximum number of accounts loaded at once while re-verifying handles.
+const REVERIFY_BATCH_SIZE: i64 = 500;
+
+/// Resolves handles to the DID they claim, so handles sent by tap can be checked against the
+/// account before they're shown.
+pub trait HandleResolver: Send + Sync {
+    fn resolve_handle<'a>(
+        &'a self,
+        handle: &'a Handle<'_>,
+    ) -> Pin<Box<dyn Future<Output = Result<Did<'static>>> + Send + 'a>>;
+}
+
+impl HandleResolver for PublicResolver {
+    fn resolve_handle<'a>(
+        &'a self,
+        handle: &'a Handle<'_>,
+    ) -> Pin<Box<dyn Future<Output = Result<Did<'static>>> + Send + 'a>> {
+        Box::pin(async move { Ok(IdentityResolver::resolve_handle(self, handle).await?) })
+    }
+}
+
+/// The outcome of checking a handle against an account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandleVerification {
+    /// The handle resolves back to the account.
+    Verified,
+    /// The handle is malformed or resolves to a different account.
+    Invalid,
+    /// The handle couldn't be resolved, which may only be temporary.
+    Unresolved,
+}
+
+impl HandleVerification {
+    /// Whether the handle was verified, or `None` if it's unknown.
+    pub fn as_verified(&self) -> Option<bool> {
+        match self {
+            Self::Verified => Some(true),
+            Self::Invalid => Some(false),
+            Self::Unresolved => None,
+        }
+    }
+}
+
+/// Check whether a handle is syntactically valid and resolves back to the given DID.
+pub async fn verify_handle(
+    resolver: &dyn HandleResolver,
+    did: &Did<'_>,
+    handle: &str,
+) -> HandleVerification {
+    let Some(handle) = Handle::new(handle).ok().filter(Handle::is_valid) else {
+        return HandleVerification::Invalid;
+    };
+    match resolver.resolve_handle(&handle).await {
+        Ok(resolved) if resolved.as_str() == did.as_str() => HandleVerification::Verified,
+        Ok(resolved) => {
+            debug!("Handle {handle} resolves to {resolved} instead");
+            HandleVerification::Invalid
+        }
+        Err(err) => {
+            debug!("Failed to resolve handle {handle}: {err:?}");
+            HandleVerification::Unresolved
+        }
+    }
+}
+
+/// Periodically re-verify handles that haven't been verified yet, or not for a while, so
+/// handles that failed to resolve temporarily recover and ones that stop resolving are caught.
+pub async fn run(state: Arc<AppState>, interval: Duration) {
+    let mut interval = tokio::time::interval(interval);
+    loop {
+        interval.tick().await;
+        if let Err(err) = reverify_handles(&state).await {
+            error!("Failed to re-verify handles: {err:?}");
+        }
+    }
+}
+
+/// Re-verify every handle that's unverified or was last verified before [`REVERIFY_AFTER`].
+///
+/// Handles that can't be resolved are left as they are until the next run.
+pub async fn reverify_handles(state: &AppState) -> Result<()> {
+    let (mut verified, mut invalid, mut unresolved) = (0, 0, 0);
+    let mut after = String::new();
+    loop {
+        let accounts = query!(
+            r#"SELECT did, handle as "handle!" FROM accounts
+               WHERE handle IS NOT NULL
+               AND (handle_verified_at IS NULL
+                    OR handle_verified_at < (extract(epoch from now()) * 1000)::BIGINT - $1)
+               AND did > $2
+               ORDER BY did
+               LIMIT $3"#,
+            REVERIFY_AFTER.as_millis() as i64,
+            after,
+            REVERIFY_BATCH_SIZE
+        )
+        .fetch_all(state.database.executor())
+        .await?;
+        let Some(last) = accounts.last() else {
+            break;
+        };
+        after = last.did.clone();
+
+        for account in accounts {
+            let Ok(did) = Did::new(&account.did) else {
+                continue;
+            };
+            let result = verify_handle(state.handle_resolver.as_ref(), &did, &account.handle).await;
+            let Some(is_verified) = result.as_verified() else {
+                unresolved += 1;
+                continue;
+            };
+            if is_verified {
+                verified += 1;
+            } else {
+                invalid += 1;
+            }
+            // Skipped if the handle changed while it was being resolved.
+            query!(
+                "UPDATE accounts SET handle_verified_at = \
+                 CASE WHEN $3 THEN (extract(epoch from now()) * 1000)::BIGINT END \
+                 WHERE did = $1 AND handle = $2",
+                account.did,
+                account.handle,
+                is_verified
+            )
+            .execute(state.database.executor())
+            .await?;
+        }
+    }
+
+    info!(
+        "Re-verified account handles: {verified} verified, {invalid} invalid, {unresolved} unresolved"
+    );
+    Ok(())
+}
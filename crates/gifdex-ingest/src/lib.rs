@@ -0,0 +1,19 @@
+pub mod consistency;
+pub mod database;
+pub mod discovery;
+pub mod handlers;
+pub mod handles;
+pub mod media_matches;
+pub mod telemetry;
+
+use crate::{database::Database, handles::HandleResolver};
+use doubletap::client::TapClient;
+use std::sync::Arc;
+
+pub struct AppState {
+    pub database: Database,
+    pub tap_client: TapClient,
+    pub http_client: reqwest::Client,
+    pub index_bsky_profiles: bool,
+    pub handle_resolver: Arc<dyn HandleResolver>,
+}
@@ -1,21 +1,29 @@
-mod database;
-mod handlers;
+mod repos;
 
-use crate::{database::Database, handlers::handle_event};
+use crate::repos::ReposCommand;
 use anyhow::{Context, Result};
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use dotenvy::dotenv;
 use doubletap::client::TapClient;
-use std::{num::NonZero, sync::Arc, time::Duration};
-use tracing_subscriber::EnvFilter;
+use gifdex_ingest::{
+    AppState, consistency, database::Database, discovery, handlers::handle_event, handles,
+    telemetry,
+};
+use jacquard_identity::PublicResolver;
+use std::{net::SocketAddr, num::NonZero, sync::Arc, time::Duration};
+use tokio::signal;
+use tracing::Instrument;
 use url::Url;
 
 #[derive(Parser)]
-#[command(author, version, about, long_about)]
+#[command(author, version, about, long_about, subcommand_negates_reqs = true)]
 struct Arguments {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// The local database URL to use for persistent storage.
-    #[clap(long = "database-url", env = "DATABASE_URL")]
-    database_url: String,
+    #[clap(long = "database-url", env = "DATABASE_URL", required = true)]
+    database_url: Option<String>,
 
     #[clap(long = "tap-url", env = "GIFDEX_INGEST_TAP_URL")]
     tap_url: Url,
@@ -23,14 +31,88 @@ struct Arguments {
     #[clap(long = "tap-password", env = "GIFDEX_INGEST_TAP_PASSWORD")]
     tap_password: Option<String>,
 
-    #[clap(long = "concurrent-messages", env = "GIFDEX_CONCURRENT_MESSAGES")]
-    concurrent_messages: NonZero<usize>,
+    #[clap(
+        long = "concurrent-messages",
+        env = "GIFDEX_CONCURRENT_MESSAGES",
+        required = true
+    )]
+    concurrent_messages: Option<NonZero<usize>>,
+
+    /// Seconds between checks for rows referencing data that no longer exists.
+    #[clap(
+        long = "consistency-check-interval",
+        env = "GIFDEX_INGEST_CONSISTENCY_CHECK_INTERVAL",
+        default_value_t = 3600
+    )]
+    consistency_check_interval: u64,
+
+    /// Relay to discover repositories holding Gifdex records from, using
+    /// `com.atproto.sync.listReposByCollection`.
+    ///
+    /// Discovered repositories are tracked with tap automatically, and repositories that no
+    /// longer hold any Gifdex records are untracked.
+    #[clap(
+        long = "discovery-relay-url",
+        env = "GIFDEX_INGEST_DISCOVERY_RELAY_URL"
+    )]
+    discovery_relay_url: Option<Url>,
+
+    /// Seconds between discovering repositories from the relay.
+    #[clap(
+        long = "discovery-interval",
+        env = "GIFDEX_INGEST_DISCOVERY_INTERVAL",
+        default_value_t = 3600
+    )]
+    discovery_interval: u64,
+
+    /// Seconds between re-verifying handles that are unverified or haven't been verified for a
+    /// day, including handles that failed to resolve when their identity event was handled.
+    #[clap(
+        long = "handle-verification-interval",
+        env = "GIFDEX_INGEST_HANDLE_VERIFICATION_INTERVAL",
+        default_value_t = 3600
+    )]
+    handle_verification_interval: u64,
+
+    /// Index `app.bsky.actor.profile` records, whose display name and avatar are shown for
+    /// accounts without a Gifdex profile.
+    ///
+    /// Tap must also be configured to send the collection for this to have any effect.
+    #[clap(
+        long = "index-bsky-profiles",
+        env = "GIFDEX_INGEST_INDEX_BSKY_PROFILES",
+        default_value_t = false
+    )]
+    index_bsky_profiles: bool,
+
+    /// Local socket address to serve Prometheus metrics on at `/metrics`.
+    #[clap(
+        long = "metrics-address",
+        env = "GIFDEX_INGEST_METRICS_ADDRESS",
+        default_value = "127.0.0.1:9255"
+    )]
+    metrics_address: SocketAddr,
+
+    /// Seconds between polling tap for its buffer sizes and cursors.
+    #[clap(
+        long = "tap-stats-interval",
+        env = "GIFDEX_INGEST_TAP_STATS_INTERVAL",
+        default_value_t = 15
+    )]
+    tap_stats_interval: u64,
+
+    /// OTLP/HTTP endpoint to export tracing spans to, e.g. `http://localhost:4318/v1/traces`.
+    ///
+    /// Trace context is also propagated to tap and PDSes using the W3C `traceparent` header.
+    #[clap(long = "otlp-endpoint", env = "GIFDEX_INGEST_OTLP_ENDPOINT")]
+    otlp_endpoint: Option<Url>,
 }
 
-struct AppState {
-    database: Database,
-    tap_client: TapClient,
-    http_client: reqwest::Client,
+#[derive(Subcommand)]
+enum Command {
+    /// Manage the repositories tracked by tap.
+    #[command(subcommand)]
+    Repos(ReposCommand),
 }
 
 #[tokio::main]
@@ -39,19 +121,33 @@ async fn main() -> Result<()> {
         .install_default()
         .expect("Failed to install default rustls crypto provider");
     dotenv().ok();
-    tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("info")))
-        .init();
     let args = Arguments::parse();
+    let tracer_provider =
+        gifdex_telemetry::init_tracing(env!("CARGO_PKG_NAME"), args.otlp_endpoint.as_ref())
+            .context("failed to initialise tracing")?;
 
     // Initialise application state.
     let tap_client = TapClient::builder(args.tap_url.clone())
         .password(args.tap_password)
+        .request_headers(gifdex_telemetry::trace_headers)
         .build()
         .context("failed to initialise tap client")?;
+    if let Some(Command::Repos(command)) = args.command {
+        let result = repos::run(command, &tap_client, args.database_url.as_deref()).await;
+        gifdex_telemetry::shutdown_tracing(tracer_provider);
+        return result;
+    }
+    telemetry::install(args.metrics_address).context("failed to initialise metrics")?;
+    tracing::info!(
+        "Metrics server started - listening on: http://{}/metrics",
+        args.metrics_address
+    );
     let tap_channel = tap_client
         .channel()
-        .max_concurrent(args.concurrent_messages)
+        .max_concurrent(
+            args.concurrent_messages
+                .expect("concurrent messages should be required without a subcommand"),
+        )
         .build()
         .context("failed to construct tap channel")?;
     let http_client = reqwest::Client::builder()
@@ -62,38 +158,113 @@ async fn main() -> Result<()> {
         ))
         .build()
         .context("failed to initialise http client")?;
-    let database = Database::new(&args.database_url)
-        .await
-        .context("failed to initialise database")?;
+    let database = Database::new(
+        args.database_url
+            .as_deref()
+            .expect("database url should be required without a subcommand"),
+    )
+    .await
+    .context("failed to initialise database")?;
 
     // Connect to tap and begin processing events.
     let state = Arc::new(AppState {
         database,
         tap_client,
+        handle_resolver: Arc::new(PublicResolver::default()),
         http_client,
+        index_bsky_profiles: args.index_bsky_profiles,
     });
-    loop {
-        const TAP_RECONNECT_INTERVAL: Duration = Duration::from_secs(30);
-        let state = state.clone();
-        let connection = match tap_channel.connect().await {
-            Ok(r) => r,
-            Err(err) => {
-                tracing::error!(
-                    "Unable to connect to tap channel - retrying in {TAP_RECONNECT_INTERVAL:?}: {err:?}"
-                );
-                tokio::time::sleep(TAP_RECONNECT_INTERVAL).await;
-                continue;
+    tokio::spawn({
+        let database = state.database.clone();
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(args.consistency_check_interval.max(1)));
+        async move {
+            loop {
+                interval.tick().await;
+                if let Err(err) = consistency::report_orphans(&database).await {
+                    tracing::error!("Failed to run consistency check: {err:?}");
+                }
             }
-        };
-        connection
-            .handler(move |data| {
-                let state = state.clone();
-                handle_event(state, data)
-            })
+        }
+    });
+    tokio::spawn(telemetry::poll_tap(
+        state.clone(),
+        Duration::from_secs(args.tap_stats_interval.max(1)),
+    ));
+    tokio::spawn(handles::run(
+        state.clone(),
+        Duration::from_secs(args.handle_verification_interval.max(1)),
+    ));
+    if let Some(relay_url) = args.discovery_relay_url {
+        tokio::spawn(discovery::run(
+            state.clone(),
+            relay_url,
+            Duration::from_secs(args.discovery_interval.max(1)),
+        ));
+    }
+    // Handle events until the process is asked to stop, then flush any buffered spans.
+    let process_events = async move {
+        loop {
+            const TAP_RECONNECT_INTERVAL: Duration = Duration::from_secs(30);
+            let state = state.clone();
+            let connection = match tap_channel.connect().await {
+                Ok(r) => r,
+                Err(err) => {
+                    tracing::error!(
+                        "Unable to connect to tap channel - retrying in {TAP_RECONNECT_INTERVAL:?}: {err:?}"
+                    );
+                    tokio::time::sleep(TAP_RECONNECT_INTERVAL).await;
+                    continue;
+                }
+            };
+            let permits_task = tokio::spawn(telemetry::poll_channel_permits(
+                connection.permits(),
+                Duration::from_secs(1),
+            ));
+            connection
+                .handler(move |event| {
+                    let state = state.clone();
+                    handle_event(state, event.data)
+                        .instrument(tracing::info_span!("tap_event", event_id = event.id))
+                })
+                .await;
+            permits_task.abort();
+            tracing::info!(
+                "Tap channel was closed while handling events -  reconnecting automatically in {TAP_RECONNECT_INTERVAL:?}: "
+            );
+            tokio::time::sleep(TAP_RECONNECT_INTERVAL).await;
+        }
+    };
+    tokio::select! {
+        _ = process_events => {},
+        _ = shutdown_signal() => {},
+    }
+    gifdex_telemetry::shutdown_tracing(tracer_provider);
+
+    Ok(())
+}
+
+// https://github.com/tokio-rs/axum/blob/15917c6dbcb4a48707a20e9cfd021992a279a662/examples/graceful-shutdown/src/main.rs#L55
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        signal::ctrl_c()
+            .await
+            .expect("failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("failed to install signal handler")
+            .recv()
             .await;
-        tracing::info!(
-            "Tap channel was closed while handling events -  reconnecting automatically in {TAP_RECONNECT_INTERVAL:?}: "
-        );
-        tokio::time::sleep(TAP_RECONNECT_INTERVAL).await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
     }
 }
@@ -0,0 +1,81 @@
+//! Tracks which posts have media similar to a post whose label opted in to hash matching.
+//!
+//! Comparing perceptual hashes can't be served by an index, so the matches are found once as
+//! labels and posts are indexed and stored in `label_media_matches`, where takedown checks can
+//! look them up directly.
+
+use gifdex_lexicons::net_gifdex::feed::post::Post;
+use gifdex_media::SIMILAR_HASH_DISTANCE;
+use jacquard_common::types::collection::Collection;
+use sqlx::{PgTransaction, query};
+
+/// Replace the posts matched by a label with every post whose media is similar to the labelled
+/// post's, or none if the label doesn't opt in to hash matching.
+pub async fn match_label(tx: &mut PgTransaction<'_>, did: &str, rkey: &str) -> sqlx::Result<()> {
+    query!(
+        "DELETE FROM label_media_matches WHERE label_did = $1 AND label_rkey = $2",
+        did,
+        rkey
+    )
+    .execute(&mut **tx)
+    .await?;
+    query!(
+        "INSERT INTO label_media_matches (label_did, label_rkey, post_did, post_rkey) \
+         SELECT l.did, l.rkey, p.did, p.rkey FROM labels l \
+         INNER JOIN posts source ON source.did = l.subject_did AND source.rkey = l.subject_rkey \
+         INNER JOIN posts p ON media_hash_distance(p.media_phash, source.media_phash) <= $4 \
+         WHERE l.did = $1 AND l.rkey = $2 AND l.subject_collection = $3 AND l.match_media_hash \
+         ON CONFLICT DO NOTHING",
+        did,
+        rkey,
+        Post::NSID,
+        SIMILAR_HASH_DISTANCE as i32
+    )
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// Replace the labels matching a post after its media hash is stored.
+///
+/// This covers the post being a copy of media that's already labelled, as well as labels that
+/// were applied to the post before it was indexed.
+pub async fn match_post(tx: &mut PgTransaction<'_>, did: &str, rkey: &str) -> sqlx::Result<()> {
+    query!(
+        "DELETE FROM label_media_matches WHERE post_did = $1 AND post_rkey = $2",
+        did,
+        rkey
+    )
+    .execute(&mut **tx)
+    .await?;
+    query!(
+        "INSERT INTO label_media_matches (label_did, label_rkey, post_did, post_rkey) \
+         SELECT l.did, l.rkey, p.did, p.rkey FROM posts p \
+         INNER JOIN labels l ON l.match_media_hash AND l.subject_collection = $3 \
+         INNER JOIN posts source ON source.did = l.subject_did AND source.rkey = l.subject_rkey \
+         WHERE p.did = $1 AND p.rkey = $2 \
+           AND media_hash_distance(p.media_phash, source.media_phash) <= $4 \
+         ON CONFLICT DO NOTHING",
+        did,
+        rkey,
+        Post::NSID,
+        SIMILAR_HASH_DISTANCE as i32
+    )
+    .execute(&mut **tx)
+    .await?;
+
+    let labels = query!(
+        "SELECT did, rkey FROM labels \
+         WHERE subject_did = $1 AND subject_rkey = $2 AND subject_collection = $3 \
+           AND match_media_hash",
+        did,
+        rkey,
+        Post::NSID
+    )
+    .fetch_all(&mut **tx)
+    .await?;
+    for label in labels {
+        match_label(tx, &label.did, &label.rkey).await?;
+    }
+    Ok(())
+}
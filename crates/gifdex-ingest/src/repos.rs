@@ -0,0 +1,222 @@
+use anyhow::{Context, Result, bail};
+use clap::Subcommand;
+use doubletap::{client::TapClient, types::RepoState};
+use gifdex_ingest::database::Database;
+use jacquard_common::types::{did::Did, string::Handle};
+use jacquard_identity::{PublicResolver, resolver::IdentityResolver};
+use sqlx::query;
+use std::{collections::BTreeSet, path::PathBuf, time::Duration};
+
+#[derive(Subcommand)]
+pub enum ReposCommand {
+    /// Start tracking repositories and wait for them to finish backfilling.
+    Add {
+        /// DIDs or handles of the repositories to add.
+        targets: Vec<String>,
+
+        /// File containing a DID or handle on each line.
+        #[clap(long = "file", short = 'f')]
+        file: Option<PathBuf>,
+
+        /// Return as soon as tap has accepted the repositories.
+        #[clap(long = "no-wait")]
+        no_wait: bool,
+
+        /// Seconds between checks of each repository's backfill progress.
+        #[clap(long = "poll-interval", default_value_t = 5)]
+        poll_interval: u64,
+    },
+    /// Stop tracking repositories.
+    Remove {
+        /// DIDs or handles of the repositories to remove.
+        targets: Vec<String>,
+
+        /// File containing a DID or handle on each line.
+        #[clap(long = "file", short = 'f')]
+        file: Option<PathBuf>,
+    },
+    /// List the repositories that ingest has indexed accounts for.
+    List,
+    /// Show tap's overall progress, or the state of specific repositories.
+    Status {
+        /// DIDs or handles of the repositories to show.
+        targets: Vec<String>,
+    },
+}
+
+pub async fn run(
+    command: ReposCommand,
+    tap_client: &TapClient,
+    database_url: Option<&str>,
+) -> Result<()> {
+    match command {
+        ReposCommand::Add {
+            targets,
+            file,
+            no_wait,
+            poll_interval,
+        } => {
+            let dids = resolve_targets(targets, file).await?;
+            tap_client
+                .add_repos(&dids)
+                .await
+                .context("failed to add repositories to tap")?;
+            println!("Added {} repositories to tap", dids.len());
+            if !no_wait {
+                wait_until_active(tap_client, dids, Duration::from_secs(poll_interval.max(1)))
+                    .await?;
+            }
+        }
+        ReposCommand::Remove { targets, file } => {
+            let dids = resolve_targets(targets, file).await?;
+            tap_client
+                .remove_repos(&dids)
+                .await
+                .context("failed to remove repositories from tap")?;
+            println!("Removed {} repositories from tap", dids.len());
+        }
+        ReposCommand::List => {
+            let database_url =
+                database_url.context("a database url is required to list repositories")?;
+            let database = Database::new(database_url)
+                .await
+                .context("failed to initialise database")?;
+            let accounts = query!(
+                r#"SELECT a.did, a.handle, a.status,
+                  (SELECT COUNT(*) FROM posts WHERE did = a.did) as "post_count!"
+                FROM accounts a ORDER BY a.did"#
+            )
+            .fetch_all(database.executor())
+            .await?;
+            for account in &accounts {
+                println!(
+                    "{}\t{}\t{}\t{} posts",
+                    account.did,
+                    account.handle.as_deref().unwrap_or("-"),
+                    account.status,
+                    account.post_count
+                );
+            }
+            println!("{} repositories", accounts.len());
+        }
+        ReposCommand::Status { targets } => {
+            if targets.is_empty() {
+                print_tap_stats(tap_client).await?;
+            } else {
+                for did in resolve_targets(targets, None).await? {
+                    let info = tap_client
+                        .repo_info(&did)
+                        .await
+                        .with_context(|| format!("failed to fetch repository info for {did}"))?;
+                    println!(
+                        "{}\t{}\t{}\t{} records\t{}",
+                        info.did, info.handle, info.state, info.records, info.error
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Turn DIDs and handles from the command line and an optional file into a list of DIDs.
+async fn resolve_targets(
+    mut targets: Vec<String>,
+    file: Option<PathBuf>,
+) -> Result<Vec<Did<'static>>> {
+    if let Some(file) = file {
+        let contents = tokio::fs::read_to_string(&file)
+            .await
+            .with_context(|| format!("failed to read {}", file.display()))?;
+        targets.extend(
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(String::from),
+        );
+    }
+    if targets.is_empty() {
+        bail!("no repositories were given");
+    }
+
+    let resolver = PublicResolver::default();
+    let mut dids = BTreeSet::new();
+    for target in targets {
+        if let Ok(did) = Did::new_owned(&target) {
+            dids.insert(did);
+            continue;
+        }
+        let handle = Handle::new(target.trim_start_matches('@'))
+            .with_context(|| format!("'{target}' is not a valid DID or handle"))?;
+        let did = resolver
+            .resolve_handle(&handle)
+            .await
+            .with_context(|| format!("failed to resolve handle '{handle}'"))?;
+        dids.insert(did);
+    }
+    Ok(dids.into_iter().collect())
+}
+
+/// Poll tap until every repository has finished backfilling or failed.
+async fn wait_until_active(
+    tap_client: &TapClient,
+    mut pending: Vec<Did<'static>>,
+    poll_interval: Duration,
+) -> Result<()> {
+    let mut failed = 0;
+    while !pending.is_empty() {
+        tokio::time::sleep(poll_interval).await;
+        let mut still_pending = Vec::with_capacity(pending.len());
+        for did in pending {
+            let info = match tap_client.repo_info(&did).await {
+                Ok(info) => info,
+                Err(err) => {
+                    tracing::warn!("Failed to fetch repository info for {did}: {err:?}");
+                    still_pending.push(did);
+                    continue;
+                }
+            };
+            match info.state {
+                RepoState::Active => {
+                    println!("{did}: active with {} records", info.records);
+                }
+                RepoState::Error
+                | RepoState::Takendown
+                | RepoState::Suspended
+                | RepoState::Deactivated => {
+                    println!("{did}: {} {}", info.state, info.error);
+                    failed += 1;
+                }
+                _ => still_pending.push(did),
+            }
+        }
+        pending = still_pending;
+        if !pending.is_empty() {
+            println!("{} repositories still backfilling", pending.len());
+            print_tap_stats(tap_client).await?;
+        }
+    }
+    if failed > 0 {
+        bail!("{failed} repositories could not be backfilled");
+    }
+    Ok(())
+}
+
+async fn print_tap_stats(tap_client: &TapClient) -> Result<()> {
+    let (repo_count, record_count, outbox_buffer, resync_buffer) = tokio::try_join!(
+        tap_client.repo_count(),
+        tap_client.record_count(),
+        tap_client.outbox_buffer(),
+        tap_client.resync_buffer(),
+    )
+    .context("failed to fetch tap statistics")?;
+    println!(
+        "tap: {} repos, {} records, {} events in outbox buffer, {} events in resync buffer",
+        repo_count.repo_count,
+        record_count.record_count,
+        outbox_buffer.outbox_buffer,
+        resync_buffer.resync_buffer
+    );
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use crate::AppState;
+use anyhow::{Context, Result};
+use doubletap::channel::ChannelPermits;
+use metrics::{
+    Unit, counter, describe_counter, describe_gauge, describe_histogram, gauge, histogram,
+};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+use tracing::warn;
+
+/// Buckets for event handler latency, from simple row updates up to slow blob fetches.
+const HANDLER_DURATION_BUCKETS: [f64; 12] = [
+    0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 10.0,
+];
+
+/// Install the global metrics recorder and serve it in the Prometheus text format at
+/// `/metrics` on the given address.
+pub fn install(address: SocketAddr) -> Result<()> {
+    PrometheusBuilder::new()
+        .with_http_listener(address)
+        .set_buckets_for_metric(
+            Matcher::Full("ingest_event_duration_seconds".into()),
+            &HANDLER_DURATION_BUCKETS,
+        )?
+        .install()
+        .context("failed to install prometheus exporter")?;
+
+    describe_counter!(
+        "ingest_events_total",
+        "Events received from tap, by collection and action."
+    );
+    describe_counter!(
+        "ingest_records_rejected_total",
+        "Records that were acknowledged without being indexed, by rejection reason."
+    );
+    describe_counter!(
+        "ingest_event_errors_total",
+        "Events whose handler failed and will be redelivered by tap, by error type."
+    );
+    describe_histogram!(
+        "ingest_event_duration_seconds",
+        Unit::Seconds,
+        "Time taken to handle an event, by collection and action."
+    );
+    describe_gauge!(
+        "ingest_channel_permits_in_use",
+        "Tap channel handler permits currently held."
+    );
+    describe_gauge!(
+        "ingest_channel_permits_max",
+        "Maximum number of tap channel handler permits."
+    );
+    describe_gauge!(
+        "tap_outbox_buffer",
+        "Events buffered in tap's outbox waiting to be delivered."
+    );
+    describe_gauge!(
+        "tap_resync_buffer",
+        "Events buffered in tap waiting for a repository resync to finish."
+    );
+    describe_gauge!(
+        "tap_firehose_cursor",
+        "Sequence number of the last firehose event tap processed."
+    );
+    Ok(())
+}
+
+/// Count an event that was received from tap.
+pub fn record_event(collection: &'static str, action: &'static str) {
+    counter!("ingest_events_total", "collection" => collection, "action" => action).increment(1);
+}
+
+/// Record how long handling an event took and, if it failed, why.
+pub fn record_event_result(
+    collection: &'static str,
+    action: &'static str,
+    elapsed: Duration,
+    result: &Result<()>,
+) {
+    histogram!(
+        "ingest_event_duration_seconds",
+        "collection" => collection,
+        "action" => action,
+        "outcome" => if result.is_ok() { "ok" } else { "error" },
+    )
+    .record(elapsed.as_secs_f64());
+    if let Err(err) = result {
+        counter!("ingest_event_errors_total", "type" => error_type(err)).increment(1);
+    }
+}
+
+/// Count a record that was rejected rather than indexed.
+pub fn record_rejected(reason: &'static str) {
+    counter!("ingest_records_rejected_total", "reason" => reason).increment(1);
+}
+
+/// Classify a handler error by the first error in its chain that we can recognise.
+fn error_type(err: &anyhow::Error) -> &'static str {
+    for cause in err.chain() {
+        if cause.is::<sqlx::Error>() {
+            return "database";
+        }
+        if cause.is::<serde_json::Error>() {
+            return "deserialize";
+        }
+        if cause.is::<reqwest::Error>() {
+            return "http";
+        }
+    }
+    "other"
+}
+
+/// Periodically sample how many of a tap channel connection's handler permits are in use.
+pub async fn poll_channel_permits(permits: ChannelPermits, interval: Duration) {
+    let mut interval = tokio::time::interval(interval);
+    gauge!("ingest_channel_permits_max").set(permits.max() as f64);
+    loop {
+        interval.tick().await;
+        gauge!("ingest_channel_permits_in_use").set(permits.in_use() as f64);
+    }
+}
+
+/// Periodically poll tap for its buffer sizes and cursors.
+pub async fn poll_tap(state: Arc<AppState>, interval: Duration) {
+    let mut interval = tokio::time::interval(interval);
+    loop {
+        interval.tick().await;
+        match state.tap_client.outbox_buffer().await {
+            Ok(response) => gauge!("tap_outbox_buffer").set(response.outbox_buffer as f64),
+            Err(err) => warn!("Failed to fetch tap outbox buffer: {err:?}"),
+        }
+        match state.tap_client.resync_buffer().await {
+            Ok(response) => gauge!("tap_resync_buffer").set(response.resync_buffer as f64),
+            Err(err) => warn!("Failed to fetch tap resync buffer: {err:?}"),
+        }
+        match state.tap_client.cursors().await {
+            Ok(response) => {
+                if let Some(firehose) = response.firehose {
+                    gauge!("tap_firehose_cursor").set(firehose as f64);
+                }
+            }
+            Err(err) => warn!("Failed to fetch tap cursors: {err:?}"),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Checks that blocks are indexed between distinct accounts and stop the blocked account from
+//! interacting with the blocker's posts.
+
+mod common;
+
+use anyhow::Result;
+use common::{RECORD_CID, app_state, insert_accounts, rejections, send_record};
+use gifdex_ingest::AppState;
+use serde_json::json;
+use sqlx::{PgPool, query};
+use std::sync::Arc;
+
+const AUTHOR: &str = "did:plc:ewvi7nxzyoun6zhxrhs64oiz";
+const FAN: &str = "did:plc:44ybard66vv44zksje25o7dz";
+const POST_RKEY: &str = "3jzfcijpj2z2a:bafkreidwslb22nkaxoadyaqlhlxgntmiq4jdenhkbrxhcq6avxlt75br5u";
+const FAVOURITE_RKEY: &str = "3jzfcijpj2z2b";
+const BLOCK_RKEY: &str = "3jzfcijpj2z2c";
+const ITEM_RKEY: &str = "3jzfcijpj2z2d";
+
+async fn send_block(state: &Arc<AppState>, action: &str, subject: &str) -> Result<()> {
+    let record = (action != "delete").then(|| {
+        json!({
+            "$type": "net.gifdex.graph.block",
+            "subject": subject,
+            "createdAt": "2026-01-01T00:00:00.000Z",
+        })
+    });
+    send_record(
+        state,
+        AUTHOR,
+        "net.gifdex.graph.block",
+        BLOCK_RKEY,
+        action,
+        record,
+    )
+    .await
+}
+
+async fn send_favourite(state: &Arc<AppState>, action: &str) -> Result<()> {
+    let record = json!({
+        "$type": "net.gifdex.feed.favourite",
+        "subject": format!("at://{AUTHOR}/net.gifdex.feed.post/{POST_RKEY}"),
+        "createdAt": "2026-01-01T00:00:00.000Z",
+    });
+    send_record(
+        state,
+        FAN,
+        "net.gifdex.feed.favourite",
+        FAVOURITE_RKEY,
+        action,
+        Some(record),
+    )
+    .await
+}
+
+/// Accounts the author has blocked.
+async fn blocked_dids(pool: &PgPool) -> Result<Vec<String>> {
+    let blocks = query!("SELECT subject_did FROM blocks WHERE did = $1", AUTHOR)
+        .fetch_all(pool)
+        .await?;
+    Ok(blocks.into_iter().map(|block| block.subject_did).collect())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn blocks_are_indexed_until_deleted(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, FAN]).await?;
+
+    send_block(&state, "create", FAN).await?;
+    assert_eq!(blocked_dids(&pool).await?, [FAN]);
+
+    send_block(&state, "delete", FAN).await?;
+    assert!(blocked_dids(&pool).await?.is_empty());
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn accounts_cannot_block_themselves(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, FAN]).await?;
+
+    send_block(&state, "create", AUTHOR).await?;
+    assert!(blocked_dids(&pool).await?.is_empty());
+    assert_eq!(
+        rejections(&pool, AUTHOR, BLOCK_RKEY).await?,
+        [(
+            "invalid_block_subject".to_owned(),
+            Some(RECORD_CID.to_owned())
+        )]
+    );
+
+    send_block(&state, "update", FAN).await?;
+    assert!(rejections(&pool, AUTHOR, BLOCK_RKEY).await?.is_empty());
+    assert_eq!(blocked_dids(&pool).await?, [FAN]);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn blocked_accounts_cannot_favourite_the_blockers_posts(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, FAN]).await?;
+
+    send_block(&state, "create", FAN).await?;
+    send_favourite(&state, "create").await?;
+    assert_eq!(
+        rejections(&pool, FAN, FAVOURITE_RKEY).await?,
+        [(
+            "blocked_by_subject_author".to_owned(),
+            Some(RECORD_CID.to_owned())
+        )]
+    );
+
+    send_block(&state, "delete", FAN).await?;
+    send_favourite(&state, "update").await?;
+    assert!(rejections(&pool, FAN, FAVOURITE_RKEY).await?.is_empty());
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn blocked_accounts_cannot_collect_the_blockers_posts(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, FAN]).await?;
+
+    send_block(&state, "create", FAN).await?;
+    send_record(
+        &state,
+        FAN,
+        "net.gifdex.feed.collectionItem",
+        ITEM_RKEY,
+        "create",
+        Some(json!({
+            "$type": "net.gifdex.feed.collectionItem",
+            "collection": format!("at://{FAN}/net.gifdex.feed.collection/3jzfcijpj2z2e"),
+            "subject": format!("at://{AUTHOR}/net.gifdex.feed.post/{POST_RKEY}"),
+            "createdAt": "2026-01-01T00:00:00.000Z",
+        })),
+    )
+    .await?;
+    assert_eq!(
+        rejections(&pool, FAN, ITEM_RKEY).await?,
+        [(
+            "blocked_by_subject_author".to_owned(),
+            Some(RECORD_CID.to_owned())
+        )]
+    );
+    let items = query!(
+        r#"SELECT COUNT(*) as "count!" FROM collection_items WHERE did = $1"#,
+        FAN
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(items.count, 0);
+    Ok(())
+}
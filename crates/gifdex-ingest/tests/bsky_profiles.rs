@@ -0,0 +1,130 @@
+//! Checks that Bluesky profiles are shown for accounts until they create a Gifdex profile.
+
+mod common;
+
+use anyhow::Result;
+use common::{app_state, test_state};
+use doubletap::types::EventData;
+use gifdex_ingest::{AppState, handlers::handle_event};
+use jacquard_common::IntoStatic;
+use serde_json::{Value, json};
+use sqlx::{PgPool, query};
+use std::sync::Arc;
+
+const AUTHOR: &str = "did:plc:ewvi7nxzyoun6zhxrhs64oiz";
+const REV: &str = "3jzfcijpj2z2a";
+const RECORD_CID: &str = "bafkreib7ytgp45cyodrmbwm7ohzq74dfnsg63va4yhl5hu3wwdn6nbpc6m";
+const AVATAR_CID: &str = "bafkreidwslb22nkaxoadyaqlhlxgntmiq4jdenhkbrxhcq6avxlt75br5u";
+
+async fn insert_account(pool: &PgPool) -> Result<()> {
+    query!(
+        "INSERT INTO accounts (did, created_at) VALUES ($1, 0)",
+        AUTHOR
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+async fn send(
+    state: &Arc<AppState>,
+    collection: &str,
+    action: &str,
+    payload: Option<Value>,
+) -> Result<()> {
+    let mut record = json!({
+        "live": true,
+        "did": AUTHOR,
+        "rev": REV,
+        "collection": collection,
+        "rkey": "self",
+        "action": action,
+    });
+    if let Some(mut payload) = payload {
+        payload["$type"] = json!(collection);
+        record["record"] = payload;
+        record["cid"] = json!(RECORD_CID);
+    }
+    let event = json!({ "type": "record", "record": record }).to_string();
+    let event = serde_json::from_str::<EventData>(&event)?.into_static();
+    handle_event(state.clone(), event).await
+}
+
+async fn send_bsky_profile(state: &Arc<AppState>) -> Result<()> {
+    send(
+        state,
+        "app.bsky.actor.profile",
+        "create",
+        Some(json!({
+            "displayName": "Bluesky name",
+            "avatar": {
+                "$type": "blob",
+                "ref": { "$link": AVATAR_CID },
+                "mimeType": "image/png",
+                "size": 1000,
+            },
+        })),
+    )
+    .await
+}
+
+async fn shown_profile(pool: &PgPool) -> Result<(Option<String>, Option<String>)> {
+    let row = query!(
+        "SELECT display_name, avatar_blob_cid FROM account_profiles WHERE did = $1",
+        AUTHOR
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok((row.display_name, row.avatar_blob_cid))
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn bluesky_profiles_are_shown_until_a_gifdex_profile_exists(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_account(&pool).await?;
+
+    send_bsky_profile(&state).await?;
+    assert_eq!(
+        shown_profile(&pool).await?,
+        (Some("Bluesky name".to_owned()), Some(AVATAR_CID.to_owned()))
+    );
+
+    // A Gifdex profile takes priority, even for the fields it leaves empty.
+    send(
+        &state,
+        "net.gifdex.actor.profile",
+        "create",
+        Some(json!({
+            "displayName": "Gifdex name",
+            "createdAt": "2026-01-01T00:00:00.000Z",
+        })),
+    )
+    .await?;
+    assert_eq!(
+        shown_profile(&pool).await?,
+        (Some("Gifdex name".to_owned()), None)
+    );
+
+    send(&state, "net.gifdex.actor.profile", "delete", None).await?;
+    assert_eq!(
+        shown_profile(&pool).await?,
+        (Some("Bluesky name".to_owned()), Some(AVATAR_CID.to_owned()))
+    );
+
+    send(&state, "app.bsky.actor.profile", "delete", None).await?;
+    assert_eq!(shown_profile(&pool).await?, (None, None));
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn bluesky_profiles_are_ignored_unless_enabled(pool: PgPool) -> Result<()> {
+    let state = Arc::new(AppState {
+        index_bsky_profiles: false,
+        ..test_state(pool.clone())
+    });
+    insert_account(&pool).await?;
+
+    send_bsky_profile(&state).await?;
+    assert_eq!(shown_profile(&pool).await?, (None, None));
+    Ok(())
+}
@@ -0,0 +1,227 @@
+//! Checks that collections and their items are indexed, and that items only go into their
+//! author's own collections.
+
+mod common;
+
+use anyhow::Result;
+use common::{RECORD_CID, app_state, insert_accounts, rejections, send_record};
+use gifdex_ingest::AppState;
+use serde_json::{Value, json};
+use sqlx::{PgPool, query};
+use std::sync::Arc;
+
+const AUTHOR: &str = "did:plc:ewvi7nxzyoun6zhxrhs64oiz";
+const CURATOR: &str = "did:plc:44ybard66vv44zksje25o7dz";
+const POST_RKEY: &str = "3jzfcijpj2z2a:bafkreidwslb22nkaxoadyaqlhlxgntmiq4jdenhkbrxhcq6avxlt75br5u";
+const ITEM_RKEY: &str = "3jzfcijpj2z2b";
+const OTHER_ITEM_RKEY: &str = "3jzfcijpj2z2d";
+const COLLECTION_RKEY: &str = "3jzfcijpj2z2c";
+const OTHER_COLLECTION_RKEY: &str = "3jzfcijpj2z2e";
+
+fn collection_uri(did: &str, rkey: &str) -> String {
+    format!("at://{did}/net.gifdex.feed.collection/{rkey}")
+}
+
+async fn send_collection(state: &Arc<AppState>, action: &str, record: Option<Value>) -> Result<()> {
+    send_record(
+        state,
+        CURATOR,
+        "net.gifdex.feed.collection",
+        COLLECTION_RKEY,
+        action,
+        record,
+    )
+    .await
+}
+
+async fn send_collection_item(
+    state: &Arc<AppState>,
+    rkey: &str,
+    collection: &str,
+    position: i64,
+) -> Result<()> {
+    let record = json!({
+        "$type": "net.gifdex.feed.collectionItem",
+        "collection": collection,
+        "subject": format!("at://{AUTHOR}/net.gifdex.feed.post/{POST_RKEY}"),
+        "position": position,
+        "createdAt": "2026-01-01T00:00:00.000Z",
+    });
+    send_record(
+        state,
+        CURATOR,
+        "net.gifdex.feed.collectionItem",
+        rkey,
+        "create",
+        Some(record),
+    )
+    .await
+}
+
+/// The curator's items as `(rkey, collection rkey, position)`.
+async fn items(pool: &PgPool) -> Result<Vec<(String, String, Option<i64>)>> {
+    let items = query!(
+        "SELECT rkey, collection_rkey, position FROM collection_items WHERE did = $1 \
+         ORDER BY rkey",
+        CURATOR
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(items
+        .into_iter()
+        .map(|item| (item.rkey, item.collection_rkey, item.position))
+        .collect())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn collections_are_indexed_until_deleted(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[CURATOR]).await?;
+
+    send_collection(
+        &state,
+        "create",
+        Some(json!({
+            "$type": "net.gifdex.feed.collection",
+            "title": "Reactions",
+            "ordering": "newest",
+            "createdAt": "2026-01-01T00:00:00.000Z",
+        })),
+    )
+    .await?;
+    // Orderings the appview doesn't know about fall back to the default.
+    send_collection(
+        &state,
+        "update",
+        Some(json!({
+            "$type": "net.gifdex.feed.collection",
+            "title": "Best reactions",
+            "ordering": "random",
+            "createdAt": "2026-01-01T00:00:00.000Z",
+        })),
+    )
+    .await?;
+    let collection = query!(
+        r#"SELECT title, ordering::TEXT as "ordering!" FROM collections
+           WHERE did = $1 AND rkey = $2"#,
+        CURATOR,
+        COLLECTION_RKEY
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(collection.title, "Best reactions");
+    assert_eq!(collection.ordering, "manual");
+
+    send_collection(&state, "delete", None).await?;
+    let collections = query!(
+        r#"SELECT COUNT(*) as "count!" FROM collections WHERE did = $1"#,
+        CURATOR
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(collections.count, 0);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn collection_items_must_reference_the_authors_own_collections(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, CURATOR]).await?;
+
+    send_collection_item(
+        &state,
+        ITEM_RKEY,
+        &collection_uri(AUTHOR, COLLECTION_RKEY),
+        0,
+    )
+    .await?;
+    assert_eq!(
+        rejections(&pool, CURATOR, ITEM_RKEY).await?,
+        [(
+            "invalid_collection_uri".to_owned(),
+            Some(RECORD_CID.to_owned())
+        )]
+    );
+
+    send_collection_item(
+        &state,
+        ITEM_RKEY,
+        &collection_uri(CURATOR, COLLECTION_RKEY),
+        0,
+    )
+    .await?;
+    assert!(rejections(&pool, CURATOR, ITEM_RKEY).await?.is_empty());
+    assert_eq!(
+        items(&pool).await?,
+        [(ITEM_RKEY.to_owned(), COLLECTION_RKEY.to_owned(), Some(0))]
+    );
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn collection_items_are_replaced_on_update(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, CURATOR]).await?;
+
+    send_collection_item(
+        &state,
+        ITEM_RKEY,
+        &collection_uri(CURATOR, COLLECTION_RKEY),
+        0,
+    )
+    .await?;
+    send_collection_item(
+        &state,
+        ITEM_RKEY,
+        &collection_uri(CURATOR, OTHER_COLLECTION_RKEY),
+        2,
+    )
+    .await?;
+    assert_eq!(
+        items(&pool).await?,
+        [(
+            ITEM_RKEY.to_owned(),
+            OTHER_COLLECTION_RKEY.to_owned(),
+            Some(2)
+        )]
+    );
+
+    send_record(
+        &state,
+        CURATOR,
+        "net.gifdex.feed.collectionItem",
+        ITEM_RKEY,
+        "delete",
+        None,
+    )
+    .await?;
+    assert!(items(&pool).await?.is_empty());
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn posts_are_only_added_to_a_collection_once(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, CURATOR]).await?;
+
+    send_collection_item(
+        &state,
+        ITEM_RKEY,
+        &collection_uri(CURATOR, COLLECTION_RKEY),
+        0,
+    )
+    .await?;
+    send_collection_item(
+        &state,
+        OTHER_ITEM_RKEY,
+        &collection_uri(CURATOR, COLLECTION_RKEY),
+        1,
+    )
+    .await?;
+    // The post stays where the first item put it.
+    assert_eq!(
+        items(&pool).await?,
+        [(ITEM_RKEY.to_owned(), COLLECTION_RKEY.to_owned(), Some(0))]
+    );
+    Ok(())
+}
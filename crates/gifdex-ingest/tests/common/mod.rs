@@ -0,0 +1,122 @@
+//! Fixtures shared by the integration tests.
+//!
+//! The database tests run against a real Postgres database, with `DATABASE_URL` pointing at a
+//! server the tests can create throwaway databases on.
+
+#![allow(dead_code)]
+
+use anyhow::{Result, anyhow};
+use doubletap::{client::TapClient, types::EventData};
+use gifdex_ingest::{
+    AppState, database::Database, handlers::handle_event, handles::HandleResolver,
+};
+use jacquard_common::{
+    IntoStatic,
+    types::{did::Did, handle::Handle},
+};
+use serde_json::{Value, json};
+use sqlx::{PgPool, query};
+use std::{collections::HashMap, future::Future, pin::Pin, sync::Arc};
+
+/// State for handling events against the test's database, with every optional feature enabled.
+pub fn app_state(pool: PgPool) -> Arc<AppState> {
+    Arc::new(test_state(pool))
+}
+
+/// The state behind [`app_state`], for tests that need to change part of it.
+pub fn test_state(pool: PgPool) -> AppState {
+    let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+    AppState {
+        database: Database::from_pool(pool),
+        tap_client: TapClient::new("http://localhost".parse().unwrap()).unwrap(),
+        http_client: reqwest::Client::new(),
+        index_bsky_profiles: true,
+        handle_resolver: Arc::new(StubHandleResolver::default()),
+    }
+}
+
+/// Resolves handles from a fixed table instead of the network, failing for any handle not in it.
+#[derive(Default)]
+pub struct StubHandleResolver(HashMap<String, String>);
+
+impl StubHandleResolver {
+    /// Resolve each handle to the paired DID.
+    pub fn new<const N: usize>(handles: [(&str, &str); N]) -> Self {
+        Self(
+            handles
+                .into_iter()
+                .map(|(handle, did)| (handle.to_owned(), did.to_owned()))
+                .collect(),
+        )
+    }
+}
+
+impl HandleResolver for StubHandleResolver {
+    fn resolve_handle<'a>(
+        &'a self,
+        handle: &'a Handle<'_>,
+    ) -> Pin<Box<dyn Future<Output = Result<Did<'static>>> + Send + 'a>> {
+        Box::pin(async move {
+            let did = self
+                .0
+                .get(handle.as_str())
+                .ok_or_else(|| anyhow!("handle not found"))?;
+            Ok(Did::new_owned(did)?)
+        })
+    }
+}
+
+/// Revision and record CID used for every event sent by the tests.
+pub const REV: &str = "3jzfcijpj2z2a";
+pub const RECORD_CID: &str = "bafkreib7ytgp45cyodrmbwm7ohzq74dfnsg63va4yhl5hu3wwdn6nbpc6m";
+
+pub async fn insert_accounts(pool: &PgPool, dids: &[&str]) -> Result<()> {
+    for did in dids {
+        query!("INSERT INTO accounts (did, created_at) VALUES ($1, 0)", did)
+            .execute(pool)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Handle a record event, including the record's contents unless it's being deleted.
+pub async fn send_record(
+    state: &Arc<AppState>,
+    did: &str,
+    collection: &str,
+    rkey: &str,
+    action: &str,
+    record: Option<Value>,
+) -> Result<()> {
+    let mut event = json!({
+        "live": true,
+        "did": did,
+        "rev": REV,
+        "collection": collection,
+        "rkey": rkey,
+        "action": action,
+    });
+    if let Some(record) = record {
+        event["record"] = record;
+        event["cid"] = json!(RECORD_CID);
+    }
+    let event = json!({ "type": "record", "record": event }).to_string();
+    let event = serde_json::from_str::<EventData>(&event)?.into_static();
+    handle_event(state.clone(), event).await
+}
+
+/// Reasons the given record was rejected, along with the CID of the rejected version.
+pub async fn rejections(
+    pool: &PgPool,
+    did: &str,
+    rkey: &str,
+) -> Result<Vec<(String, Option<String>)>> {
+    let rows = query!(
+        "SELECT reason, cid FROM rejected_records WHERE did = $1 AND rkey = $2",
+        did,
+        rkey
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().map(|row| (row.reason, row.cid)).collect())
+}
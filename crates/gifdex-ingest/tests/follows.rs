@@ -0,0 +1,107 @@
+//! Checks that follows are indexed once per pair of distinct accounts.
+
+mod common;
+
+use anyhow::Result;
+use common::{RECORD_CID, app_state, insert_accounts, rejections, send_record};
+use gifdex_ingest::AppState;
+use serde_json::json;
+use sqlx::{PgPool, query};
+use std::sync::Arc;
+
+const AUTHOR: &str = "did:plc:ewvi7nxzyoun6zhxrhs64oiz";
+const FAN: &str = "did:plc:44ybard66vv44zksje25o7dz";
+const FOLLOW_RKEY: &str = "3jzfcijpj2z2b";
+const DUPLICATE_RKEY: &str = "3jzfcijpj2z2c";
+
+async fn send_follow(state: &Arc<AppState>, rkey: &str, subject: &str) -> Result<()> {
+    let record = json!({
+        "$type": "net.gifdex.graph.follow",
+        "subject": subject,
+        "createdAt": "2026-01-01T00:00:00.000Z",
+    });
+    send_record(
+        state,
+        FAN,
+        "net.gifdex.graph.follow",
+        rkey,
+        "create",
+        Some(record),
+    )
+    .await
+}
+
+/// Record keys of the fan's follows of the author.
+async fn follow_rkeys(pool: &PgPool) -> Result<Vec<String>> {
+    let follows = query!(
+        "SELECT rkey FROM follows WHERE did = $1 AND subject_did = $2",
+        FAN,
+        AUTHOR
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(follows.into_iter().map(|follow| follow.rkey).collect())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn accounts_cannot_follow_themselves(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, FAN]).await?;
+
+    send_follow(&state, FOLLOW_RKEY, FAN).await?;
+    assert_eq!(
+        rejections(&pool, FAN, FOLLOW_RKEY).await?,
+        [(
+            "invalid_follow_subject".to_owned(),
+            Some(RECORD_CID.to_owned())
+        )]
+    );
+
+    send_follow(&state, FOLLOW_RKEY, AUTHOR).await?;
+    assert!(rejections(&pool, FAN, FOLLOW_RKEY).await?.is_empty());
+    assert_eq!(follow_rkeys(&pool).await?, [FOLLOW_RKEY]);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn accounts_can_only_follow_each_other_once(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, FAN]).await?;
+
+    send_follow(&state, FOLLOW_RKEY, AUTHOR).await?;
+    send_follow(&state, DUPLICATE_RKEY, AUTHOR).await?;
+    assert_eq!(follow_rkeys(&pool).await?, [FOLLOW_RKEY]);
+    assert_eq!(
+        rejections(&pool, FAN, DUPLICATE_RKEY).await?,
+        [("duplicate_follow".to_owned(), Some(RECORD_CID.to_owned()))]
+    );
+
+    // Sending the original again is an update, not a duplicate.
+    send_follow(&state, FOLLOW_RKEY, AUTHOR).await?;
+    assert!(rejections(&pool, FAN, FOLLOW_RKEY).await?.is_empty());
+    assert_eq!(follow_rkeys(&pool).await?, [FOLLOW_RKEY]);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn deleted_follows_are_removed(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, FAN]).await?;
+
+    send_follow(&state, FOLLOW_RKEY, AUTHOR).await?;
+    send_record(
+        &state,
+        FAN,
+        "net.gifdex.graph.follow",
+        FOLLOW_RKEY,
+        "delete",
+        None,
+    )
+    .await?;
+    assert!(follow_rkeys(&pool).await?.is_empty());
+
+    // The account can follow again once the earlier follow is gone.
+    send_follow(&state, DUPLICATE_RKEY, AUTHOR).await?;
+    assert_eq!(follow_rkeys(&pool).await?, [DUPLICATE_RKEY]);
+    Ok(())
+}
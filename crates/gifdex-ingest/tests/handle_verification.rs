@@ -0,0 +1,116 @@
+//! Checks that handles are only verified when they resolve back to the account's DID.
+
+mod common;
+
+use anyhow::Result;
+use common::{StubHandleResolver, test_state};
+use gifdex_ingest::handles::{HandleVerification, reverify_handles, verify_handle};
+use jacquard_common::types::did::Did;
+use sqlx::{PgPool, query};
+use std::sync::Arc;
+
+const ACCOUNT: &str = "did:plc:ewvi7nxzyoun6zhxrhs64oiz";
+const OTHER_ACCOUNT: &str = "did:plc:44ybard66vv44zksje25o7dz";
+const THIRD_ACCOUNT: &str = "did:plc:ar7c4by46qjdydhdevvrndac";
+
+fn resolver() -> StubHandleResolver {
+    StubHandleResolver::new([
+        ("alice.example.com", ACCOUNT),
+        ("bob.example.com", OTHER_ACCOUNT),
+    ])
+}
+
+async fn insert_account(
+    pool: &PgPool,
+    did: &str,
+    handle: &str,
+    handle_verified_at: Option<i64>,
+) -> Result<()> {
+    query!(
+        "INSERT INTO accounts (did, handle, handle_verified_at, created_at) VALUES ($1, $2, $3, 0)",
+        did,
+        handle,
+        handle_verified_at
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+async fn handle_verified_at(pool: &PgPool, did: &str) -> Result<Option<i64>> {
+    Ok(query!(
+        "SELECT handle_verified_at FROM accounts WHERE did = $1",
+        did
+    )
+    .fetch_one(pool)
+    .await?
+    .handle_verified_at)
+}
+
+#[tokio::test]
+async fn handles_resolving_back_to_the_account_are_verified() {
+    let did = Did::new(ACCOUNT).unwrap();
+    assert_eq!(
+        verify_handle(&resolver(), &did, "alice.example.com").await,
+        HandleVerification::Verified
+    );
+}
+
+#[tokio::test]
+async fn handles_resolving_to_another_account_are_invalid() {
+    let did = Did::new(ACCOUNT).unwrap();
+    assert_eq!(
+        verify_handle(&resolver(), &did, "bob.example.com").await,
+        HandleVerification::Invalid
+    );
+}
+
+#[tokio::test]
+async fn malformed_handles_are_invalid() {
+    let did = Did::new(ACCOUNT).unwrap();
+    assert_eq!(
+        verify_handle(&resolver(), &did, "handle.invalid").await,
+        HandleVerification::Invalid
+    );
+    assert_eq!(
+        verify_handle(&resolver(), &did, "not a handle").await,
+        HandleVerification::Invalid
+    );
+}
+
+#[tokio::test]
+async fn unresolvable_handles_are_unresolved() {
+    let did = Did::new(ACCOUNT).unwrap();
+    assert_eq!(
+        verify_handle(&resolver(), &did, "carol.example.com").await,
+        HandleVerification::Unresolved
+    );
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn reverification_updates_resolved_handles(pool: PgPool) -> Result<()> {
+    // Stored before verification was introduced, so never verified.
+    insert_account(&pool, ACCOUNT, "alice.example.com", None).await?;
+    // Verified long ago, but the handle now belongs to someone else.
+    insert_account(&pool, THIRD_ACCOUNT, "bob.example.com", Some(1)).await?;
+
+    let mut state = test_state(pool.clone());
+    state.handle_resolver = Arc::new(resolver());
+    reverify_handles(&state).await?;
+
+    assert!(handle_verified_at(&pool, ACCOUNT).await?.is_some());
+    assert_eq!(handle_verified_at(&pool, THIRD_ACCOUNT).await?, None);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn reverification_keeps_handles_that_fail_to_resolve(pool: PgPool) -> Result<()> {
+    insert_account(&pool, ACCOUNT, "carol.example.com", Some(1)).await?;
+
+    let mut state = test_state(pool.clone());
+    state.handle_resolver = Arc::new(resolver());
+    reverify_handles(&state).await?;
+
+    assert_eq!(handle_verified_at(&pool, ACCOUNT).await?, Some(1));
+    Ok(())
+}
@@ -0,0 +1,141 @@
+//! Checks that records breaking the constraints declared by their lexicon are rejected.
+
+mod common;
+
+use anyhow::Result;
+use common::{app_state, insert_accounts, rejections, send_record};
+use gifdex_ingest::AppState;
+use serde_json::json;
+use sqlx::{PgPool, query};
+use std::sync::Arc;
+
+const AUTHOR: &str = "did:plc:ewvi7nxzyoun6zhxrhs64oiz";
+const LABELER: &str = "did:plc:ar7c4by46qjdydhdevvrndac";
+const POST_RKEY: &str = "3jzfcijpj2z2a:bafkreidwslb22nkaxoadyaqlhlxgntmiq4jdenhkbrxhcq6avxlt75br5u";
+
+async fn send_rule(state: &Arc<AppState>, name: &str) -> Result<()> {
+    send_record(
+        state,
+        LABELER,
+        "net.gifdex.labeler.rule",
+        "spam",
+        "create",
+        Some(json!({
+            "$type": "net.gifdex.labeler.rule",
+            "name": name,
+            "description": "Unwanted content",
+            "behaviour": {
+                "$type": "net.gifdex.labeler.rule#moderate",
+                "takedown": true,
+            },
+            "createdAt": "2026-01-01T00:00:00.000Z",
+        })),
+    )
+    .await
+}
+
+async fn send_post(
+    state: &Arc<AppState>,
+    mime_type: &str,
+    size: usize,
+    labels: &[String],
+) -> Result<()> {
+    send_record(
+        state,
+        AUTHOR,
+        "net.gifdex.feed.post",
+        POST_RKEY,
+        "create",
+        Some(json!({
+            "$type": "net.gifdex.feed.post",
+            "title": "Post",
+            "media": {
+                "blob": {
+                    "$type": "blob",
+                    "ref": { "$link": POST_RKEY.split_once(':').unwrap().1 },
+                    "mimeType": mime_type,
+                    "size": size,
+                },
+            },
+            "labels": labels,
+            "createdAt": "2026-01-01T00:00:00.000Z",
+        })),
+    )
+    .await
+}
+
+async fn rule_count(pool: &PgPool) -> Result<i64> {
+    let result = query!(
+        r#"SELECT COUNT(*) as "count!" FROM labeler_rules WHERE did = $1"#,
+        LABELER
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(result.count)
+}
+
+/// The reason the post was rejected for, if it was.
+async fn post_rejection(pool: &PgPool) -> Result<Option<String>> {
+    Ok(rejections(pool, AUTHOR, POST_RKEY)
+        .await?
+        .pop()
+        .map(|(reason, _)| reason))
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn lexicon_constraints_are_counted_in_graphemes(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[LABELER]).await?;
+
+    // Each of these is a single grapheme made up of several code points.
+    send_rule(&state, &"👍🏽".repeat(20)).await?;
+    assert_eq!(rule_count(&pool).await?, 1);
+
+    send_rule(&state, &"e".repeat(21)).await?;
+    assert_eq!(
+        rejections(&pool, LABELER, "spam").await?[0].0,
+        "lexicon_constraint_violated"
+    );
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn posts_with_undeclared_media_are_rejected(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR]).await?;
+
+    send_post(&state, "video/mp4", 1_000, &[]).await?;
+    assert_eq!(
+        post_rejection(&pool).await?.as_deref(),
+        Some("lexicon_constraint_violated")
+    );
+
+    // The lexicon only accepts WebP media.
+    send_post(&state, "image/gif", 1_000, &[]).await?;
+    assert_eq!(
+        post_rejection(&pool).await?.as_deref(),
+        Some("lexicon_constraint_violated")
+    );
+
+    send_post(&state, "image/webp", 10_000_000, &[]).await?;
+    assert_eq!(
+        post_rejection(&pool).await?.as_deref(),
+        Some("lexicon_constraint_violated")
+    );
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn self_labels_must_reference_rules(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, LABELER]).await?;
+
+    // WebP media passes the lexicon's constraints, so the post is only rejected for its label.
+    let label = format!("at://{LABELER}/net.gifdex.labeler.label/3jzfcijpj2z2b");
+    send_post(&state, "image/webp", 1_000, &[label]).await?;
+    assert_eq!(
+        post_rejection(&pool).await?.as_deref(),
+        Some("invalid_self_label")
+    );
+    Ok(())
+}
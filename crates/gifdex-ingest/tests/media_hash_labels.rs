@@ -0,0 +1,153 @@
+//! Checks that labels opting in to media hash matching apply to copies of the labelled media.
+
+mod common;
+
+use anyhow::Result;
+use common::{app_state, insert_accounts, send_record};
+use gifdex_ingest::{AppState, media_matches};
+use serde_json::json;
+use sqlx::{PgPool, query};
+use std::sync::Arc;
+
+const AUTHOR: &str = "did:plc:ewvi7nxzyoun6zhxrhs64oiz";
+const REUPLOADER: &str = "did:plc:44ybard66vv44zksje25o7dz";
+const LABELER: &str = "did:plc:ar7c4by46qjdydhdevvrndac";
+const ORIGINAL_RKEY: &str =
+    "3jzfcijpj2z2a:bafkreidwslb22nkaxoadyaqlhlxgntmiq4jdenhkbrxhcq6avxlt75br5u";
+const COPY_RKEY: &str = "3jzfcijpj2z2b:bafkreib7ytgp45cyodrmbwm7ohzq74dfnsg63va4yhl5hu3wwdn6nbpc6m";
+const UNRELATED_RKEY: &str =
+    "3jzfcijpj2z2c:bafkreie5737gdxlw5i64vzichcalba3z2v5n6icifvx5xytvske7mr3hpm";
+const LABEL_RKEY: &str = "3jzfcijpj2z2b";
+
+const ORIGINAL_HASH: i64 = 0x0f0f_3c3c_5a5a_6969;
+/// The original's hash with a few bits flipped, as re-encoding the media does.
+const COPY_HASH: i64 = ORIGINAL_HASH ^ 0b1001_0110;
+const UNRELATED_HASH: i64 = !ORIGINAL_HASH;
+
+/// Index a post directly, as creating one through ingest requires fetching its blob.
+async fn insert_post(pool: &PgPool, did: &str, rkey: &str, phash: i64) -> Result<()> {
+    query!(
+        "INSERT INTO posts (did, rkey, title, media_blob_cid, media_blob_mime, \
+         media_blob_width, media_blob_height, media_phash, created_at) \
+         VALUES ($1, $2, 'Post', split_part($2, ':', 2), 'image/gif', 1, 1, $3, 0)",
+        did,
+        rkey,
+        phash
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+async fn label_original(state: &Arc<AppState>, match_media_hash: bool) -> Result<()> {
+    send_record(
+        state,
+        LABELER,
+        "net.gifdex.labeler.rule",
+        "spam",
+        "create",
+        Some(json!({
+            "$type": "net.gifdex.labeler.rule",
+            "name": "Spam",
+            "description": "Unwanted content",
+            "behaviour": {
+                "$type": "net.gifdex.labeler.rule#moderate",
+                "takedown": true,
+            },
+            "createdAt": "2026-01-01T00:00:00.000Z",
+        })),
+    )
+    .await?;
+    send_record(
+        state,
+        LABELER,
+        "net.gifdex.labeler.label",
+        LABEL_RKEY,
+        "create",
+        Some(json!({
+            "$type": "net.gifdex.labeler.label",
+            "subject": format!("at://{AUTHOR}/net.gifdex.feed.post/{ORIGINAL_RKEY}"),
+            "rule": format!("at://{LABELER}/net.gifdex.labeler.rule/spam"),
+            "matchMediaHash": match_media_hash,
+            "createdAt": "2026-01-01T00:00:00.000Z",
+        })),
+    )
+    .await
+}
+
+async fn is_labelled(pool: &PgPool, did: &str, rkey: &str) -> Result<bool> {
+    let result = query!(
+        r#"SELECT EXISTS(SELECT 1 FROM post_labels WHERE post_did = $1 AND post_rkey = $2)
+           as "exists!""#,
+        did,
+        rkey
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(result.exists)
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn labels_propagate_to_reencoded_copies(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, REUPLOADER, LABELER]).await?;
+    insert_post(&pool, AUTHOR, ORIGINAL_RKEY, ORIGINAL_HASH).await?;
+    insert_post(&pool, REUPLOADER, COPY_RKEY, COPY_HASH).await?;
+    insert_post(&pool, REUPLOADER, UNRELATED_RKEY, UNRELATED_HASH).await?;
+
+    label_original(&state, true).await?;
+    assert!(is_labelled(&pool, AUTHOR, ORIGINAL_RKEY).await?);
+    assert!(is_labelled(&pool, REUPLOADER, COPY_RKEY).await?);
+    assert!(!is_labelled(&pool, REUPLOADER, UNRELATED_RKEY).await?);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn labels_without_hash_matching_stay_on_their_post(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, REUPLOADER, LABELER]).await?;
+    insert_post(&pool, AUTHOR, ORIGINAL_RKEY, ORIGINAL_HASH).await?;
+    insert_post(&pool, REUPLOADER, COPY_RKEY, COPY_HASH).await?;
+
+    label_original(&state, false).await?;
+    assert!(is_labelled(&pool, AUTHOR, ORIGINAL_RKEY).await?);
+    assert!(!is_labelled(&pool, REUPLOADER, COPY_RKEY).await?);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn labels_apply_to_copies_indexed_after_them(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, REUPLOADER, LABELER]).await?;
+    insert_post(&pool, AUTHOR, ORIGINAL_RKEY, ORIGINAL_HASH).await?;
+    label_original(&state, true).await?;
+
+    insert_post(&pool, REUPLOADER, COPY_RKEY, COPY_HASH).await?;
+    let mut tx = pool.begin().await?;
+    media_matches::match_post(&mut tx, REUPLOADER, COPY_RKEY).await?;
+    tx.commit().await?;
+    assert!(is_labelled(&pool, REUPLOADER, COPY_RKEY).await?);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn deleting_a_label_removes_it_from_copies(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, REUPLOADER, LABELER]).await?;
+    insert_post(&pool, AUTHOR, ORIGINAL_RKEY, ORIGINAL_HASH).await?;
+    insert_post(&pool, REUPLOADER, COPY_RKEY, COPY_HASH).await?;
+    label_original(&state, true).await?;
+
+    send_record(
+        &state,
+        LABELER,
+        "net.gifdex.labeler.label",
+        LABEL_RKEY,
+        "delete",
+        None,
+    )
+    .await?;
+    assert!(!is_labelled(&pool, AUTHOR, ORIGINAL_RKEY).await?);
+    assert!(!is_labelled(&pool, REUPLOADER, COPY_RKEY).await?);
+    Ok(())
+}
@@ -0,0 +1,255 @@
+//! Checks that rows referencing posts stay consistent as posts are deleted and re-created.
+
+mod common;
+
+use anyhow::Result;
+use common::app_state;
+use doubletap::types::EventData;
+use gifdex_ingest::{AppState, handlers::handle_event};
+use jacquard_common::IntoStatic;
+use serde_json::json;
+use sqlx::{PgPool, query};
+use std::sync::Arc;
+
+const AUTHOR: &str = "did:plc:ewvi7nxzyoun6zhxrhs64oiz";
+const FAN_ONE: &str = "did:plc:44ybard66vv44zksje25o7dz";
+const FAN_TWO: &str = "did:plc:vwzwgnygau7ed7b7wt5ux7y2";
+const LABELER: &str = "did:plc:ar7c4by46qjdydhdevvrndac";
+const POST_RKEY: &str = "3jzfcijpj2z2a:bafkreidwslb22nkaxoadyaqlhlxgntmiq4jdenhkbrxhcq6avxlt75br5u";
+const REV: &str = "3jzfcijpj2z2a";
+const RECORD_CID: &str = "bafkreib7ytgp45cyodrmbwm7ohzq74dfnsg63va4yhl5hu3wwdn6nbpc6m";
+
+async fn insert_accounts(pool: &PgPool) -> Result<()> {
+    for did in [AUTHOR, FAN_ONE, FAN_TWO, LABELER] {
+        query!("INSERT INTO accounts (did, created_at) VALUES ($1, 0)", did)
+            .execute(pool)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Index a post directly, as creating one through ingest requires fetching its blob.
+async fn insert_post(pool: &PgPool) -> Result<()> {
+    query!(
+        "INSERT INTO posts (did, rkey, title, media_blob_cid, media_blob_mime, \
+         media_blob_width, media_blob_height, created_at) \
+         VALUES ($1, $2, 'Post', 'bafkreidwslb22nkaxoadyaqlhlxgntmiq4jdenhkbrxhcq6avxlt75br5u', \
+         'image/gif', 1, 1, 0)",
+        AUTHOR,
+        POST_RKEY
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+async fn favourite_count(pool: &PgPool) -> Result<i64> {
+    let result = query!(
+        r#"SELECT COUNT(*) as "count!" FROM post_favourites
+           WHERE post_did = $1 AND post_rkey = $2"#,
+        AUTHOR,
+        POST_RKEY
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(result.count)
+}
+
+async fn label_count(pool: &PgPool) -> Result<i64> {
+    let result = query!(
+        r#"SELECT COUNT(*) as "count!" FROM labels
+           WHERE subject_did = $1 AND subject_rkey = $2"#,
+        AUTHOR,
+        POST_RKEY
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(result.count)
+}
+
+async fn send_create(
+    state: &Arc<AppState>,
+    did: &str,
+    collection: &str,
+    rkey: &str,
+    record: serde_json::Value,
+) -> Result<()> {
+    let event = json!({
+        "type": "record",
+        "record": {
+            "live": true,
+            "did": did,
+            "rev": REV,
+            "collection": collection,
+            "rkey": rkey,
+            "action": "create",
+            "record": record,
+            "cid": RECORD_CID,
+        }
+    })
+    .to_string();
+    let event = serde_json::from_str::<EventData>(&event)?.into_static();
+    handle_event(state.clone(), event).await
+}
+
+async fn send_delete(state: &Arc<AppState>, did: &str, collection: &str, rkey: &str) -> Result<()> {
+    let event = json!({
+        "type": "record",
+        "record": {
+            "live": true,
+            "did": did,
+            "rev": REV,
+            "collection": collection,
+            "rkey": rkey,
+            "action": "delete",
+        }
+    })
+    .to_string();
+    let event = serde_json::from_str::<EventData>(&event)?.into_static();
+    handle_event(state.clone(), event).await
+}
+
+async fn favourite(state: &Arc<AppState>, did: &str, rkey: &str) -> Result<()> {
+    send_create(
+        state,
+        did,
+        "net.gifdex.feed.favourite",
+        rkey,
+        json!({
+            "$type": "net.gifdex.feed.favourite",
+            "subject": format!("at://{AUTHOR}/net.gifdex.feed.post/{POST_RKEY}"),
+            "createdAt": "2026-01-01T00:00:00.000Z",
+        }),
+    )
+    .await
+}
+
+async fn delete_post(state: &Arc<AppState>) -> Result<()> {
+    send_delete(state, AUTHOR, "net.gifdex.feed.post", POST_RKEY).await
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn favourites_are_not_counted_after_post_is_recreated(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool).await?;
+    insert_post(&pool).await?;
+
+    favourite(&state, FAN_ONE, "3jzfcijpj2z2b").await?;
+    favourite(&state, FAN_TWO, "3jzfcijpj2z2c").await?;
+    assert_eq!(favourite_count(&pool).await?, 2);
+
+    delete_post(&state).await?;
+    assert_eq!(favourite_count(&pool).await?, 0);
+
+    insert_post(&pool).await?;
+    assert_eq!(favourite_count(&pool).await?, 0);
+
+    favourite(&state, FAN_ONE, "3jzfcijpj2z2d").await?;
+    assert_eq!(favourite_count(&pool).await?, 1);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn repeated_cycles_keep_counts_correct(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool).await?;
+
+    for rkey in ["3jzfcijpj2z2b", "3jzfcijpj2z2c", "3jzfcijpj2z2d"] {
+        insert_post(&pool).await?;
+        favourite(&state, FAN_ONE, rkey).await?;
+        // Favouriting the same post twice only counts once.
+        favourite(&state, FAN_ONE, "3jzfcijpj2z2e").await?;
+        assert_eq!(favourite_count(&pool).await?, 1);
+        delete_post(&state).await?;
+        assert_eq!(favourite_count(&pool).await?, 0);
+    }
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn unfavouriting_a_deleted_post_succeeds(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool).await?;
+    insert_post(&pool).await?;
+
+    favourite(&state, FAN_ONE, "3jzfcijpj2z2b").await?;
+    delete_post(&state).await?;
+    send_delete(
+        &state,
+        FAN_ONE,
+        "net.gifdex.feed.favourite",
+        "3jzfcijpj2z2b",
+    )
+    .await?;
+    assert_eq!(favourite_count(&pool).await?, 0);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn favourites_received_before_their_post_are_counted(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool).await?;
+
+    favourite(&state, FAN_ONE, "3jzfcijpj2z2b").await?;
+    insert_post(&pool).await?;
+    assert_eq!(favourite_count(&pool).await?, 1);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn labels_are_removed_with_their_post_and_rule(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool).await?;
+    insert_post(&pool).await?;
+
+    send_create(
+        &state,
+        LABELER,
+        "net.gifdex.labeler.rule",
+        "spam",
+        json!({
+            "$type": "net.gifdex.labeler.rule",
+            "name": "Spam",
+            "description": "Unwanted content",
+            "behaviour": {
+                "$type": "net.gifdex.labeler.rule#moderate",
+                "takedown": true,
+            },
+            "createdAt": "2026-01-01T00:00:00.000Z",
+        }),
+    )
+    .await?;
+    let label = json!({
+        "$type": "net.gifdex.labeler.label",
+        "subject": format!("at://{AUTHOR}/net.gifdex.feed.post/{POST_RKEY}"),
+        "rule": format!("at://{LABELER}/net.gifdex.labeler.rule/spam"),
+        "createdAt": "2026-01-01T00:00:00.000Z",
+    });
+
+    send_create(
+        &state,
+        LABELER,
+        "net.gifdex.labeler.label",
+        "3jzfcijpj2z2b",
+        label.clone(),
+    )
+    .await?;
+    assert_eq!(label_count(&pool).await?, 1);
+    delete_post(&state).await?;
+    assert_eq!(label_count(&pool).await?, 0);
+
+    insert_post(&pool).await?;
+    assert_eq!(label_count(&pool).await?, 0);
+    send_create(
+        &state,
+        LABELER,
+        "net.gifdex.labeler.label",
+        "3jzfcijpj2z2c",
+        label,
+    )
+    .await?;
+    assert_eq!(label_count(&pool).await?, 1);
+    send_delete(&state, LABELER, "net.gifdex.labeler.rule", "spam").await?;
+    assert_eq!(label_count(&pool).await?, 0);
+    Ok(())
+}
@@ -0,0 +1,85 @@
+//! Checks that rejected records are recorded for their author and cleared once replaced.
+
+mod common;
+
+use anyhow::Result;
+use common::{RECORD_CID, app_state, insert_accounts, rejections, send_record};
+use gifdex_ingest::AppState;
+use serde_json::json;
+use sqlx::PgPool;
+use std::sync::Arc;
+
+const AUTHOR: &str = "did:plc:ewvi7nxzyoun6zhxrhs64oiz";
+const FAN: &str = "did:plc:44ybard66vv44zksje25o7dz";
+const POST_RKEY: &str = "3jzfcijpj2z2a:bafkreidwslb22nkaxoadyaqlhlxgntmiq4jdenhkbrxhcq6avxlt75br5u";
+const FAVOURITE_RKEY: &str = "3jzfcijpj2z2b";
+
+async fn send(state: &Arc<AppState>, action: &str, subject: Option<String>) -> Result<()> {
+    let record = subject.map(|subject| {
+        json!({
+            "$type": "net.gifdex.feed.favourite",
+            "subject": subject,
+            "createdAt": "2026-01-01T00:00:00.000Z",
+        })
+    });
+    send_record(
+        state,
+        FAN,
+        "net.gifdex.feed.favourite",
+        FAVOURITE_RKEY,
+        action,
+        record,
+    )
+    .await
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn rejected_records_are_recorded_until_replaced(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, FAN]).await?;
+
+    send(
+        &state,
+        "create",
+        Some(format!(
+            "at://{AUTHOR}/net.gifdex.feed.favourite/{POST_RKEY}"
+        )),
+    )
+    .await?;
+    assert_eq!(
+        rejections(&pool, FAN, FAVOURITE_RKEY).await?,
+        [(
+            "invalid_subject_collection".to_owned(),
+            Some(RECORD_CID.to_owned())
+        )]
+    );
+
+    send(
+        &state,
+        "update",
+        Some(format!("at://{AUTHOR}/net.gifdex.feed.post/{POST_RKEY}")),
+    )
+    .await?;
+    assert!(rejections(&pool, FAN, FAVOURITE_RKEY).await?.is_empty());
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../sqlx-migrations")]
+async fn rejected_records_are_cleared_on_delete(pool: PgPool) -> Result<()> {
+    let state = app_state(pool.clone());
+    insert_accounts(&pool, &[AUTHOR, FAN]).await?;
+
+    send(
+        &state,
+        "create",
+        Some(format!(
+            "at://{AUTHOR}/net.gifdex.feed.favourite/{POST_RKEY}"
+        )),
+    )
+    .await?;
+    assert_eq!(rejections(&pool, FAN, FAVOURITE_RKEY).await?.len(), 1);
+
+    send(&state, "delete", None).await?;
+    assert!(rejections(&pool, FAN, FAVOURITE_RKEY).await?.is_empty());
+    Ok(())
+}
@@ -0,0 +1,334 @@
+//! Generates `LexiconValidate` implementations from the constraints declared by each record
+//! lexicon, so they can't drift from the schemas.
+//!
+//! Fields are walked the same way jacquard's code generator lays out the record types: property
+//! names are converted to snake case, and fields that aren't required (or are nullable) are
+//! wrapped in an `Option`. Refs to objects are followed into their fields, while unions and refs
+//! to lexicons that aren't in the corpus are skipped.
+
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    // Only the lexicons of enabled features are generated into the crate, so only those get
+    // implementations. Lexicons of other applications are kept alongside the crate.
+    let mut dirs = Vec::new();
+    if env::var_os("CARGO_FEATURE_NET_GIFDEX").is_some() {
+        dirs.push(manifest_dir.join("../../lexicons"));
+    }
+    if env::var_os("CARGO_FEATURE_APP_BSKY").is_some() {
+        dirs.push(manifest_dir.join("lexicons"));
+    }
+
+    let mut docs = HashMap::new();
+    for dir in &dirs {
+        println!("cargo::rerun-if-changed={}", dir.display());
+        load_lexicons(dir, &mut docs);
+    }
+
+    let mut nsids: Vec<&String> = docs.keys().collect();
+    nsids.sort();
+    let mut out = String::new();
+    for nsid in nsids {
+        let doc = &docs[nsid];
+        let Some(main) = doc["defs"].get("main") else {
+            continue;
+        };
+        if main["type"] != "record" {
+            continue;
+        }
+        let generator = Generator {
+            docs: &docs,
+            nsid,
+            body: String::new(),
+            depth: 0,
+        };
+        out.push_str(&generator.record(&main["record"]));
+    }
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("records.rs");
+    fs::write(out_path, out).unwrap();
+}
+
+fn load_lexicons(dir: &Path, docs: &mut HashMap<String, Value>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            load_lexicons(&path, docs);
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            println!("cargo::rerun-if-changed={}", path.display());
+            let doc: Value = serde_json::from_slice(&fs::read(&path).unwrap())
+                .unwrap_or_else(|err| panic!("invalid lexicon {}: {err}", path.display()));
+            let nsid = doc["id"].as_str().unwrap().to_owned();
+            docs.insert(nsid, doc);
+        }
+    }
+}
+
+struct Generator<'a> {
+    docs: &'a HashMap<String, Value>,
+    /// NSID of the record being generated, used to resolve local refs.
+    nsid: &'a str,
+    body: String,
+    /// Nesting depth, used to give bindings in nested blocks unique names.
+    depth: usize,
+}
+
+impl<'a> Generator<'a> {
+    fn record(mut self, record: &Value) -> String {
+        self.object(record, "self", &JsonPath::root());
+        let type_path = type_path(self.nsid);
+        format!(
+            "impl LexiconValidate for {type_path}<'_> {{\n\
+             fn validate(&self) -> Result<(), ValidationReport> {{\n\
+             #[allow(unused_mut)]\n\
+             let mut validator = Validator::default();\n\
+             {body}\
+             validator.finish({nsid:?})\n\
+             }}\n\
+             }}\n\n",
+            body = self.body,
+            nsid = self.nsid,
+        )
+    }
+
+    /// Check every property of an object held in `access`.
+    fn object(&mut self, object: &Value, access: &str, path: &JsonPath) {
+        let required = string_list(object.get("required"));
+        let nullable = string_list(object.get("nullable"));
+        let Some(properties) = object["properties"].as_object() else {
+            return;
+        };
+        // Sorted by name, matching the order of the fields in the generated types.
+        let mut names: Vec<&String> = properties.keys().collect();
+        names.sort();
+        for name in names {
+            let field = format!("{access}.{}", snake_case(name));
+            let path = path.field(name);
+            let optional = !required.contains(&name.as_str()) || nullable.contains(&name.as_str());
+            let binding = self.binding();
+            let mut inner = Generator {
+                docs: self.docs,
+                nsid: self.nsid,
+                body: String::new(),
+                depth: self.depth + 1,
+            };
+            inner.property(&properties[name], &binding, &path);
+            if inner.body.is_empty() {
+                continue;
+            }
+            if optional {
+                writeln!(self.body, "if let Some({binding}) = &{field} {{").unwrap();
+            } else {
+                writeln!(self.body, "{{\nlet {binding} = &{field};").unwrap();
+            }
+            self.body.push_str(&inner.body);
+            self.body.push_str("}\n");
+        }
+    }
+
+    /// Check a value held by reference in the binding `value`.
+    fn property(&mut self, schema: &Value, value: &str, path: &JsonPath) {
+        let path_expr = path.expr();
+        match schema["type"].as_str() {
+            Some("string") => {
+                for (key, method) in [("maxLength", "max_length"), ("minLength", "min_length")] {
+                    if let Some(limit) = schema[key].as_u64() {
+                        writeln!(
+                            self.body,
+                            "validator.{method}({path_expr}, AsRef::<str>::as_ref({value}).len(), {limit});"
+                        )
+                        .unwrap();
+                    }
+                }
+                for (key, method) in [
+                    ("maxGraphemes", "max_graphemes"),
+                    ("minGraphemes", "min_graphemes"),
+                ] {
+                    if let Some(limit) = schema[key].as_u64() {
+                        writeln!(
+                            self.body,
+                            "validator.{method}({path_expr}, AsRef::<str>::as_ref({value}), {limit});"
+                        )
+                        .unwrap();
+                    }
+                }
+                if let Some(values) = schema["enum"].as_array() {
+                    writeln!(
+                        self.body,
+                        "validator.one_of({path_expr}, AsRef::<str>::as_ref({value}), &{});",
+                        str_slice(values)
+                    )
+                    .unwrap();
+                }
+            }
+            Some("integer") => {
+                for (key, method) in [("minimum", "minimum"), ("maximum", "maximum")] {
+                    if let Some(limit) = schema[key].as_i64() {
+                        writeln!(
+                            self.body,
+                            "validator.{method}({path_expr}, *{value}, {limit});"
+                        )
+                        .unwrap();
+                    }
+                }
+            }
+            Some("blob") => {
+                if let Some(accept) = schema["accept"].as_array() {
+                    writeln!(
+                        self.body,
+                        "validator.accept({path_expr}, {value}, &{});",
+                        str_slice(accept)
+                    )
+                    .unwrap();
+                }
+                if let Some(limit) = schema["maxSize"].as_u64() {
+                    writeln!(
+                        self.body,
+                        "validator.max_size({path_expr}, {value}, {limit});"
+                    )
+                    .unwrap();
+                }
+            }
+            Some("array") => {
+                for (key, method) in [("maxLength", "max_length"), ("minLength", "min_length")] {
+                    if let Some(limit) = schema[key].as_u64() {
+                        writeln!(
+                            self.body,
+                            "validator.{method}({path_expr}, {value}.len(), {limit});"
+                        )
+                        .unwrap();
+                    }
+                }
+                let index = format!("idx{}", self.depth);
+                let item = self.binding();
+                let mut inner = Generator {
+                    docs: self.docs,
+                    nsid: self.nsid,
+                    body: String::new(),
+                    depth: self.depth + 1,
+                };
+                inner.property(&schema["items"], &item, &path.index(&index));
+                if !inner.body.is_empty() {
+                    writeln!(
+                        self.body,
+                        "for ({index}, {item}) in {value}.iter().enumerate() {{"
+                    )
+                    .unwrap();
+                    self.body.push_str(&inner.body);
+                    self.body.push_str("}\n");
+                }
+            }
+            Some("object") => self.object(schema, value, path),
+            Some("ref") => {
+                if let Some(def) = self.resolve(schema["ref"].as_str().unwrap()) {
+                    self.property(def, value, path);
+                }
+            }
+            // Union members are generated as enums whose variants aren't named after anything
+            // in the schema, so they aren't walked.
+            _ => {}
+        }
+    }
+
+    fn binding(&self) -> String {
+        format!("value{}", self.depth)
+    }
+
+    /// Find the definition a ref points at, if its lexicon is known.
+    fn resolve(&self, reference: &str) -> Option<&'a Value> {
+        let (nsid, def) = reference.split_once('#').unwrap_or((reference, "main"));
+        let nsid = if nsid.is_empty() { self.nsid } else { nsid };
+        self.docs.get(nsid)?["defs"].get(def)
+    }
+}
+
+/// A JSON path into a record, such as `$.tags[2]`, where array indices are only known at runtime.
+struct JsonPath {
+    /// A `format!` string for the path, with index bindings as arguments.
+    template: String,
+    dynamic: bool,
+}
+
+impl JsonPath {
+    fn root() -> Self {
+        Self {
+            template: "$".to_owned(),
+            dynamic: false,
+        }
+    }
+
+    fn field(&self, name: &str) -> Self {
+        Self {
+            template: format!("{}.{name}", self.template),
+            dynamic: self.dynamic,
+        }
+    }
+
+    fn index(&self, binding: &str) -> Self {
+        Self {
+            template: format!("{}[{{{binding}}}]", self.template),
+            dynamic: true,
+        }
+    }
+
+    /// A Rust expression evaluating to the path as a `&str`.
+    fn expr(&self) -> String {
+        if self.dynamic {
+            format!("&format!({:?})", self.template)
+        } else {
+            format!("{:?}", self.template)
+        }
+    }
+}
+
+fn string_list(value: Option<&Value>) -> Vec<&str> {
+    value
+        .and_then(Value::as_array)
+        .map(|values| values.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+fn str_slice(values: &[Value]) -> String {
+    let values: Vec<String> = values
+        .iter()
+        .map(|value| format!("{:?}", value.as_str().unwrap()))
+        .collect();
+    format!("[{}]", values.join(", "))
+}
+
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for ch in name.chars() {
+        if ch.is_ascii_uppercase() {
+            out.push('_');
+            out.push(ch.to_ascii_lowercase());
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+/// Path of the type jacquard generates for a record, e.g. `net.gifdex.feed.collectionItem` is
+/// `net_gifdex::feed::collection_item::CollectionItem`.
+fn type_path(nsid: &str) -> String {
+    let segments: Vec<&str> = nsid.split('.').collect();
+    let (name, modules) = segments.split_last().unwrap();
+    let (authority, modules) = modules.split_at(2);
+    let mut path = format!("crate::{}", authority.join("_"));
+    for module in modules {
+        write!(path, "::{}", snake_case(module)).unwrap();
+    }
+    let mut type_name = name.to_string();
+    type_name[..1].make_ascii_uppercase();
+    write!(path, "::{}::{type_name}", snake_case(name)).unwrap();
+    path
+}
@@ -3,4 +3,5 @@
 // This file was automatically generated from Lexicon schemas.
 // Any manual changes will be overwritten on the next regeneration.
 
+pub mod profile;
 pub mod status;
\ No newline at end of file
@@ -4,7 +4,11 @@
 // Any manual changes will be overwritten on the next regeneration.
 
 extern crate alloc;
+#[cfg(feature = "app_bsky")]
+pub mod app_bsky;
 pub mod builder_types;
 
 #[cfg(feature = "net_gifdex")]
-pub mod net_gifdex;
\ No newline at end of file
+pub mod net_gifdex;
+
+pub mod validate;
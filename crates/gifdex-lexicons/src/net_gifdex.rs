@@ -4,5 +4,8 @@
 // Any manual changes will be overwritten on the next regeneration.
 
 pub mod actor;
+pub mod admin;
 pub mod feed;
-pub mod labeler;
\ No newline at end of file
+pub mod graph;
+pub mod labeler;
+pub mod moderation;
\ No newline at end of file
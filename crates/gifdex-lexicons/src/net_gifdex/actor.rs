@@ -5,6 +5,7 @@
 // This file was automatically generated from Lexicon schemas.
 // Any manual changes will be overwritten on the next regeneration.
 
+pub mod get_ingest_status;
 pub mod get_profile;
 pub mod get_profiles;
 pub mod profile;
@@ -30,6 +31,8 @@ pub struct ProfileView<'a> {
     #[serde(skip_serializing_if = "std::option::Option::is_none")]
     #[serde(borrow)]
     pub display_name: std::option::Option<jacquard_common::CowStr<'a>>,
+    pub follower_count: i64,
+    pub following_count: i64,
     #[serde(skip_serializing_if = "std::option::Option::is_none")]
     #[serde(borrow)]
     pub handle: std::option::Option<jacquard_common::types::string::Handle<'a>>,
@@ -37,6 +40,9 @@ pub struct ProfileView<'a> {
     #[serde(skip_serializing_if = "std::option::Option::is_none")]
     #[serde(borrow)]
     pub pronouns: std::option::Option<jacquard_common::CowStr<'a>>,
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    #[serde(borrow)]
+    pub viewer: std::option::Option<crate::net_gifdex::actor::ProfileViewerState<'a>>,
 }
 
 pub mod profile_view_state {
@@ -51,6 +57,8 @@ pub mod profile_view_state {
     pub trait State: sealed::Sealed {
         type PostCount;
         type Did;
+        type FollowerCount;
+        type FollowingCount;
     }
     /// Empty state - all required fields are unset
     pub struct Empty(());
@@ -58,6 +66,8 @@ pub mod profile_view_state {
     impl State for Empty {
         type PostCount = Unset;
         type Did = Unset;
+        type FollowerCount = Unset;
+        type FollowingCount = Unset;
     }
     ///State transition - sets the `post_count` field to Set
     pub struct SetPostCount<S: State = Empty>(PhantomData<fn() -> S>);
@@ -65,6 +75,8 @@ pub mod profile_view_state {
     impl<S: State> State for SetPostCount<S> {
         type PostCount = Set<members::post_count>;
         type Did = S::Did;
+        type FollowerCount = S::FollowerCount;
+        type FollowingCount = S::FollowingCount;
     }
     ///State transition - sets the `did` field to Set
     pub struct SetDid<S: State = Empty>(PhantomData<fn() -> S>);
@@ -72,6 +84,26 @@ pub mod profile_view_state {
     impl<S: State> State for SetDid<S> {
         type PostCount = S::PostCount;
         type Did = Set<members::did>;
+        type FollowerCount = S::FollowerCount;
+        type FollowingCount = S::FollowingCount;
+    }
+    ///State transition - sets the `follower_count` field to Set
+    pub struct SetFollowerCount<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetFollowerCount<S> {}
+    impl<S: State> State for SetFollowerCount<S> {
+        type PostCount = S::PostCount;
+        type Did = S::Did;
+        type FollowerCount = Set<members::follower_count>;
+        type FollowingCount = S::FollowingCount;
+    }
+    ///State transition - sets the `following_count` field to Set
+    pub struct SetFollowingCount<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetFollowingCount<S> {}
+    impl<S: State> State for SetFollowingCount<S> {
+        type PostCount = S::PostCount;
+        type Did = S::Did;
+        type FollowerCount = S::FollowerCount;
+        type FollowingCount = Set<members::following_count>;
     }
     /// Marker types for field names
     #[allow(non_camel_case_types)]
@@ -80,6 +112,10 @@ pub mod profile_view_state {
         pub struct post_count(());
         ///Marker type for the `did` field
         pub struct did(());
+        ///Marker type for the `follower_count` field
+        pub struct follower_count(());
+        ///Marker type for the `following_count` field
+        pub struct following_count(());
     }
 }
 
@@ -90,9 +126,12 @@ pub struct ProfileViewBuilder<'a, S: profile_view_state::State> {
         ::core::option::Option<jacquard_common::types::string::Uri<'a>>,
         ::core::option::Option<jacquard_common::types::string::Did<'a>>,
         ::core::option::Option<jacquard_common::CowStr<'a>>,
+        ::core::option::Option<i64>,
+        ::core::option::Option<i64>,
         ::core::option::Option<jacquard_common::types::string::Handle<'a>>,
         ::core::option::Option<i64>,
         ::core::option::Option<jacquard_common::CowStr<'a>>,
+        ::core::option::Option<crate::net_gifdex::actor::ProfileViewerState<'a>>,
     ),
     _phantom: ::core::marker::PhantomData<&'a ()>,
 }
@@ -109,7 +148,17 @@ impl<'a> ProfileViewBuilder<'a, profile_view_state::Empty> {
     pub fn new() -> Self {
         ProfileViewBuilder {
             _phantom_state: ::core::marker::PhantomData,
-            __unsafe_private_named: (None, None, None, None, None, None),
+            __unsafe_private_named: (
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ),
             _phantom: ::core::marker::PhantomData,
         }
     }
@@ -172,13 +221,51 @@ impl<'a, S: profile_view_state::State> ProfileViewBuilder<'a, S> {
     }
 }
 
+impl<'a, S> ProfileViewBuilder<'a, S>
+where
+    S: profile_view_state::State,
+    S::FollowerCount: profile_view_state::IsUnset,
+{
+    /// Set the `followerCount` field (required)
+    pub fn follower_count(
+        mut self,
+        value: impl Into<i64>,
+    ) -> ProfileViewBuilder<'a, profile_view_state::SetFollowerCount<S>> {
+        self.__unsafe_private_named.3 = ::core::option::Option::Some(value.into());
+        ProfileViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> ProfileViewBuilder<'a, S>
+where
+    S: profile_view_state::State,
+    S::FollowingCount: profile_view_state::IsUnset,
+{
+    /// Set the `followingCount` field (required)
+    pub fn following_count(
+        mut self,
+        value: impl Into<i64>,
+    ) -> ProfileViewBuilder<'a, profile_view_state::SetFollowingCount<S>> {
+        self.__unsafe_private_named.4 = ::core::option::Option::Some(value.into());
+        ProfileViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
 impl<'a, S: profile_view_state::State> ProfileViewBuilder<'a, S> {
     /// Set the `handle` field (optional)
     pub fn handle(
         mut self,
         value: impl Into<Option<jacquard_common::types::string::Handle<'a>>>,
     ) -> Self {
-        self.__unsafe_private_named.3 = value.into();
+        self.__unsafe_private_named.5 = value.into();
         self
     }
     /// Set the `handle` field to an Option value (optional)
@@ -186,7 +273,7 @@ impl<'a, S: profile_view_state::State> ProfileViewBuilder<'a, S> {
         mut self,
         value: Option<jacquard_common::types::string::Handle<'a>>,
     ) -> Self {
-        self.__unsafe_private_named.3 = value;
+        self.__unsafe_private_named.5 = value;
         self
     }
 }
@@ -201,7 +288,7 @@ where
         mut self,
         value: impl Into<i64>,
     ) -> ProfileViewBuilder<'a, profile_view_state::SetPostCount<S>> {
-        self.__unsafe_private_named.4 = ::core::option::Option::Some(value.into());
+        self.__unsafe_private_named.6 = ::core::option::Option::Some(value.into());
         ProfileViewBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
@@ -216,12 +303,31 @@ impl<'a, S: profile_view_state::State> ProfileViewBuilder<'a, S> {
         mut self,
         value: impl Into<Option<jacquard_common::CowStr<'a>>>,
     ) -> Self {
-        self.__unsafe_private_named.5 = value.into();
+        self.__unsafe_private_named.7 = value.into();
         self
     }
     /// Set the `pronouns` field to an Option value (optional)
     pub fn maybe_pronouns(mut self, value: Option<jacquard_common::CowStr<'a>>) -> Self {
-        self.__unsafe_private_named.5 = value;
+        self.__unsafe_private_named.7 = value;
+        self
+    }
+}
+
+impl<'a, S: profile_view_state::State> ProfileViewBuilder<'a, S> {
+    /// Set the `viewer` field (optional)
+    pub fn viewer(
+        mut self,
+        value: impl Into<Option<crate::net_gifdex::actor::ProfileViewerState<'a>>>,
+    ) -> Self {
+        self.__unsafe_private_named.8 = value.into();
+        self
+    }
+    /// Set the `viewer` field to an Option value (optional)
+    pub fn maybe_viewer(
+        mut self,
+        value: Option<crate::net_gifdex::actor::ProfileViewerState<'a>>,
+    ) -> Self {
+        self.__unsafe_private_named.8 = value;
         self
     }
 }
@@ -231,6 +337,8 @@ where
     S: profile_view_state::State,
     S::PostCount: profile_view_state::IsSet,
     S::Did: profile_view_state::IsSet,
+    S::FollowerCount: profile_view_state::IsSet,
+    S::FollowingCount: profile_view_state::IsSet,
 {
     /// Build the final struct
     pub fn build(self) -> ProfileView<'a> {
@@ -238,9 +346,12 @@ where
             avatar: self.__unsafe_private_named.0,
             did: self.__unsafe_private_named.1.unwrap(),
             display_name: self.__unsafe_private_named.2,
-            handle: self.__unsafe_private_named.3,
-            post_count: self.__unsafe_private_named.4.unwrap(),
-            pronouns: self.__unsafe_private_named.5,
+            follower_count: self.__unsafe_private_named.3.unwrap(),
+            following_count: self.__unsafe_private_named.4.unwrap(),
+            handle: self.__unsafe_private_named.5,
+            post_count: self.__unsafe_private_named.6.unwrap(),
+            pronouns: self.__unsafe_private_named.7,
+            viewer: self.__unsafe_private_named.8,
             extra_data: Default::default(),
         }
     }
@@ -256,9 +367,12 @@ where
             avatar: self.__unsafe_private_named.0,
             did: self.__unsafe_private_named.1.unwrap(),
             display_name: self.__unsafe_private_named.2,
-            handle: self.__unsafe_private_named.3,
-            post_count: self.__unsafe_private_named.4.unwrap(),
-            pronouns: self.__unsafe_private_named.5,
+            follower_count: self.__unsafe_private_named.3.unwrap(),
+            following_count: self.__unsafe_private_named.4.unwrap(),
+            handle: self.__unsafe_private_named.5,
+            post_count: self.__unsafe_private_named.6.unwrap(),
+            pronouns: self.__unsafe_private_named.7,
+            viewer: self.__unsafe_private_named.8,
             extra_data: Some(extra_data),
         }
     }
@@ -279,13 +393,15 @@ fn lexicon_doc_net_gifdex_actor_defs() -> ::jacquard_lexicon::lexicon::LexiconDo
                 ::jacquard_lexicon::lexicon::LexUserType::Object(::jacquard_lexicon::lexicon::LexObject {
                     description: Some(
                         ::jacquard_common::CowStr::new_static(
-                            "A full representation of an actor's profile, ideal for profile pages.",
+                            "A full representation of an actor's profile.",
                         ),
                     ),
                     required: Some(
                         vec![
                             ::jacquard_common::smol_str::SmolStr::new_static("did"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("postCount")
+                            ::jacquard_common::smol_str::SmolStr::new_static("postCount"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("followerCount"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("followingCount")
                         ],
                     ),
                     nullable: None,
@@ -343,6 +459,32 @@ fn lexicon_doc_net_gifdex_actor_defs() -> ::jacquard_lexicon::lexicon::LexiconDo
                                 known_values: None,
                             }),
                         );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "followerCount",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Integer(::jacquard_lexicon::lexicon::LexInteger {
+                                description: None,
+                                default: None,
+                                minimum: None,
+                                maximum: None,
+                                r#enum: None,
+                                r#const: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "followingCount",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Integer(::jacquard_lexicon::lexicon::LexInteger {
+                                description: None,
+                                default: None,
+                                minimum: None,
+                                maximum: None,
+                                r#enum: None,
+                                r#const: None,
+                            }),
+                        );
                         map.insert(
                             ::jacquard_common::smol_str::SmolStr::new_static("handle"),
                             ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
@@ -388,6 +530,15 @@ fn lexicon_doc_net_gifdex_actor_defs() -> ::jacquard_lexicon::lexicon::LexiconDo
                                 known_values: None,
                             }),
                         );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("viewer"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
+                                description: None,
+                                r#ref: ::jacquard_common::CowStr::new_static(
+                                    "#profileViewerState",
+                                ),
+                            }),
+                        );
                         map
                     },
                 }),
@@ -397,7 +548,7 @@ fn lexicon_doc_net_gifdex_actor_defs() -> ::jacquard_lexicon::lexicon::LexiconDo
                 ::jacquard_lexicon::lexicon::LexUserType::Object(::jacquard_lexicon::lexicon::LexObject {
                     description: Some(
                         ::jacquard_common::CowStr::new_static(
-                            "A basic representation of an actor's profile, ideal for displaying a post's creator.",
+                            "A basic representation of an actor's profile with essential information.",
                         ),
                     ),
                     required: Some(
@@ -479,6 +630,224 @@ fn lexicon_doc_net_gifdex_actor_defs() -> ::jacquard_lexicon::lexicon::LexiconDo
                     },
                 }),
             );
+            map.insert(
+                ::jacquard_common::smol_str::SmolStr::new_static("profileViewSearch"),
+                ::jacquard_lexicon::lexicon::LexUserType::Object(::jacquard_lexicon::lexicon::LexObject {
+                    description: Some(
+                        ::jacquard_common::CowStr::new_static(
+                            "A minimal search representation of an actor's profile with just the core information.",
+                        ),
+                    ),
+                    required: Some(
+                        vec![::jacquard_common::smol_str::SmolStr::new_static("did")],
+                    ),
+                    nullable: None,
+                    properties: {
+                        #[allow(unused_mut)]
+                        let mut map = ::alloc::collections::BTreeMap::new();
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("avatar"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Uri,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("did"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Did,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "displayName",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: None,
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: Some(64usize),
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("handle"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Handle,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map
+                    },
+                }),
+            );
+            map.insert(
+                ::jacquard_common::smol_str::SmolStr::new_static("profileViewerState"),
+                ::jacquard_lexicon::lexicon::LexUserType::Object(::jacquard_lexicon::lexicon::LexObject {
+                    description: Some(
+                        ::jacquard_common::CowStr::new_static(
+                            "Metadata about the requesting account's relationship with the actor. Only present for authed requests.",
+                        ),
+                    ),
+                    required: None,
+                    nullable: None,
+                    properties: {
+                        #[allow(unused_mut)]
+                        let mut map = ::alloc::collections::BTreeMap::new();
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "following",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: Some(
+                                    ::jacquard_common::CowStr::new_static(
+                                        "The rkey of the requesting account's follow record for the actor, if it follows them.",
+                                    ),
+                                ),
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Tid,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map
+                    },
+                }),
+            );
+            map.insert(
+                ::jacquard_common::smol_str::SmolStr::new_static("rejectedRecord"),
+                ::jacquard_lexicon::lexicon::LexUserType::Object(::jacquard_lexicon::lexicon::LexObject {
+                    description: None,
+                    required: Some(
+                        vec![
+                            ::jacquard_common::smol_str::SmolStr::new_static("uri"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("reason"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("rejectedAt")
+                        ],
+                    ),
+                    nullable: None,
+                    properties: {
+                        #[allow(unused_mut)]
+                        let mut map = ::alloc::collections::BTreeMap::new();
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("cid"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Cid,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("reason"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: Some(
+                                    ::jacquard_common::CowStr::new_static(
+                                        "Machine-readable code for why the record was refused.",
+                                    ),
+                                ),
+                                format: None,
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "rejectedAt",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Datetime,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("uri"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::AtUri,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map
+                    },
+                }),
+            );
             map
         },
     }
@@ -767,4 +1136,261 @@ impl<'a> ::jacquard_lexicon::schema::LexiconSchema for ProfileViewBasic<'a> {
         }
         Ok(())
     }
-}
\ No newline at end of file
+}
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RejectedRecord<'a> {
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    #[serde(borrow)]
+    pub cid: std::option::Option<jacquard_common::types::string::Cid<'a>>,
+    /// Machine-readable code for why the record was refused.
+    #[serde(borrow)]
+    pub reason: jacquard_common::CowStr<'a>,
+    pub rejected_at: jacquard_common::types::string::Datetime,
+    #[serde(borrow)]
+    pub uri: jacquard_common::types::string::AtUri<'a>,
+}
+
+/// Metadata about the requesting account's relationship with the actor. Only present for authed requests.
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic,
+    Default
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileViewerState<'a> {
+    /// The rkey of the requesting account's follow record for the actor, if it follows them.
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub following: std::option::Option<jacquard_common::types::string::Tid>,
+}
+
+impl<'a> ::jacquard_lexicon::schema::LexiconSchema for ProfileViewerState<'a> {
+    fn nsid() -> &'static str {
+        "net.gifdex.actor.defs"
+    }
+    fn def_name() -> &'static str {
+        "profileViewerState"
+    }
+    fn lexicon_doc() -> ::jacquard_lexicon::lexicon::LexiconDoc<'static> {
+        lexicon_doc_net_gifdex_actor_defs()
+    }
+    fn validate(
+        &self,
+    ) -> ::core::result::Result<(), ::jacquard_lexicon::validation::ConstraintError> {
+        Ok(())
+    }
+}
+
+pub mod rejected_record_state {
+
+    pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
+    #[allow(unused)]
+    use ::core::marker::PhantomData;
+    mod sealed {
+        pub trait Sealed {}
+    }
+    /// State trait tracking which required fields have been set
+    pub trait State: sealed::Sealed {
+        type Reason;
+        type RejectedAt;
+        type Uri;
+    }
+    /// Empty state - all required fields are unset
+    pub struct Empty(());
+    impl sealed::Sealed for Empty {}
+    impl State for Empty {
+        type Reason = Unset;
+        type RejectedAt = Unset;
+        type Uri = Unset;
+    }
+    ///State transition - sets the `reason` field to Set
+    pub struct SetReason<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetReason<S> {}
+    impl<S: State> State for SetReason<S> {
+        type Reason = Set<members::reason>;
+        type RejectedAt = S::RejectedAt;
+        type Uri = S::Uri;
+    }
+    ///State transition - sets the `rejected_at` field to Set
+    pub struct SetRejectedAt<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetRejectedAt<S> {}
+    impl<S: State> State for SetRejectedAt<S> {
+        type Reason = S::Reason;
+        type RejectedAt = Set<members::rejected_at>;
+        type Uri = S::Uri;
+    }
+    ///State transition - sets the `uri` field to Set
+    pub struct SetUri<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetUri<S> {}
+    impl<S: State> State for SetUri<S> {
+        type Reason = S::Reason;
+        type RejectedAt = S::RejectedAt;
+        type Uri = Set<members::uri>;
+    }
+    /// Marker types for field names
+    #[allow(non_camel_case_types)]
+    pub mod members {
+        ///Marker type for the `reason` field
+        pub struct reason(());
+        ///Marker type for the `rejected_at` field
+        pub struct rejected_at(());
+        ///Marker type for the `uri` field
+        pub struct uri(());
+    }
+}
+
+/// Builder for constructing an instance of this type
+pub struct RejectedRecordBuilder<'a, S: rejected_record_state::State> {
+    _phantom_state: ::core::marker::PhantomData<fn() -> S>,
+    __unsafe_private_named: (
+        ::core::option::Option<jacquard_common::types::string::Cid<'a>>,
+        ::core::option::Option<jacquard_common::CowStr<'a>>,
+        ::core::option::Option<jacquard_common::types::string::Datetime>,
+        ::core::option::Option<jacquard_common::types::string::AtUri<'a>>,
+    ),
+    _phantom: ::core::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> RejectedRecord<'a> {
+    /// Create a new builder for this type
+    pub fn new() -> RejectedRecordBuilder<'a, rejected_record_state::Empty> {
+        RejectedRecordBuilder::new()
+    }
+}
+
+impl<'a> RejectedRecordBuilder<'a, rejected_record_state::Empty> {
+    /// Create a new builder with all fields unset
+    pub fn new() -> Self {
+        RejectedRecordBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: (None, None, None, None),
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: rejected_record_state::State> RejectedRecordBuilder<'a, S> {
+    /// Set the `cid` field (optional)
+    pub fn cid(
+        mut self,
+        value: impl Into<Option<jacquard_common::types::string::Cid<'a>>>,
+    ) -> Self {
+        self.__unsafe_private_named.0 = value.into();
+        self
+    }
+    /// Set the `cid` field to an Option value (optional)
+    pub fn maybe_cid(
+        mut self,
+        value: Option<jacquard_common::types::string::Cid<'a>>,
+    ) -> Self {
+        self.__unsafe_private_named.0 = value;
+        self
+    }
+}
+
+impl<'a, S> RejectedRecordBuilder<'a, S>
+where
+    S: rejected_record_state::State,
+    S::Reason: rejected_record_state::IsUnset,
+{
+    /// Set the `reason` field (required)
+    pub fn reason(
+        mut self,
+        value: impl Into<jacquard_common::CowStr<'a>>,
+    ) -> RejectedRecordBuilder<'a, rejected_record_state::SetReason<S>> {
+        self.__unsafe_private_named.1 = ::core::option::Option::Some(value.into());
+        RejectedRecordBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> RejectedRecordBuilder<'a, S>
+where
+    S: rejected_record_state::State,
+    S::RejectedAt: rejected_record_state::IsUnset,
+{
+    /// Set the `rejectedAt` field (required)
+    pub fn rejected_at(
+        mut self,
+        value: impl Into<jacquard_common::types::string::Datetime>,
+    ) -> RejectedRecordBuilder<'a, rejected_record_state::SetRejectedAt<S>> {
+        self.__unsafe_private_named.2 = ::core::option::Option::Some(value.into());
+        RejectedRecordBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> RejectedRecordBuilder<'a, S>
+where
+    S: rejected_record_state::State,
+    S::Uri: rejected_record_state::IsUnset,
+{
+    /// Set the `uri` field (required)
+    pub fn uri(
+        mut self,
+        value: impl Into<jacquard_common::types::string::AtUri<'a>>,
+    ) -> RejectedRecordBuilder<'a, rejected_record_state::SetUri<S>> {
+        self.__unsafe_private_named.3 = ::core::option::Option::Some(value.into());
+        RejectedRecordBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> RejectedRecordBuilder<'a, S>
+where
+    S: rejected_record_state::State,
+    S::Reason: rejected_record_state::IsSet,
+    S::RejectedAt: rejected_record_state::IsSet,
+    S::Uri: rejected_record_state::IsSet,
+{
+    /// Build the final struct
+    pub fn build(self) -> RejectedRecord<'a> {
+        RejectedRecord {
+            cid: self.__unsafe_private_named.0,
+            reason: self.__unsafe_private_named.1.unwrap(),
+            rejected_at: self.__unsafe_private_named.2.unwrap(),
+            uri: self.__unsafe_private_named.3.unwrap(),
+            extra_data: Default::default(),
+        }
+    }
+    /// Build the final struct with custom extra_data
+    pub fn build_with_data(
+        self,
+        extra_data: std::collections::BTreeMap<
+            jacquard_common::smol_str::SmolStr,
+            jacquard_common::types::value::Data<'a>,
+        >,
+    ) -> RejectedRecord<'a> {
+        RejectedRecord {
+            cid: self.__unsafe_private_named.0,
+            reason: self.__unsafe_private_named.1.unwrap(),
+            rejected_at: self.__unsafe_private_named.2.unwrap(),
+            uri: self.__unsafe_private_named.3.unwrap(),
+            extra_data: Some(extra_data),
+        }
+    }
+}
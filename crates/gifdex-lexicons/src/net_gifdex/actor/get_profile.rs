@@ -146,6 +146,12 @@ pub enum GetProfileError<'a> {
     /// The requested profile does not exist or has not been indexed yet.
     #[serde(rename = "ProfileNotFound")]
     ProfileNotFound(std::option::Option<jacquard_common::CowStr<'a>>),
+    /// The account is deactivated or suspended and its content is unavailable.
+    #[serde(rename = "AccountDeactivated")]
+    AccountDeactivated(std::option::Option<jacquard_common::CowStr<'a>>),
+    /// The account has been taken down and its content is unavailable.
+    #[serde(rename = "AccountTakedown")]
+    AccountTakedown(std::option::Option<jacquard_common::CowStr<'a>>),
 }
 
 impl core::fmt::Display for GetProfileError<'_> {
@@ -158,6 +164,20 @@ impl core::fmt::Display for GetProfileError<'_> {
                 }
                 Ok(())
             }
+            Self::AccountDeactivated(msg) => {
+                write!(f, "AccountDeactivated")?;
+                if let Some(msg) = msg {
+                    write!(f, ": {}", msg)?;
+                }
+                Ok(())
+            }
+            Self::AccountTakedown(msg) => {
+                write!(f, "AccountTakedown")?;
+                if let Some(msg) = msg {
+                    write!(f, ": {}", msg)?;
+                }
+                Ok(())
+            }
             Self::Unknown(err) => write!(f, "Unknown error: {:?}", err),
         }
     }
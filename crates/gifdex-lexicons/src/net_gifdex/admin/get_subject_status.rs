@@ -0,0 +1,195 @@
+// @generated by jacquard-lexicon. DO NOT EDIT.
+//
+// Lexicon: net.gifdex.admin.getSubjectStatus
+//
+// This file was automatically generated from Lexicon schemas.
+// Any manual changes will be overwritten on the next regeneration.
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSubjectStatus<'a> {
+    #[serde(borrow)]
+    pub subject: jacquard_common::types::string::AtUri<'a>,
+}
+
+pub mod get_subject_status_state {
+
+    pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
+    #[allow(unused)]
+    use ::core::marker::PhantomData;
+    mod sealed {
+        pub trait Sealed {}
+    }
+    /// State trait tracking which required fields have been set
+    pub trait State: sealed::Sealed {
+        type Subject;
+    }
+    /// Empty state - all required fields are unset
+    pub struct Empty(());
+    impl sealed::Sealed for Empty {}
+    impl State for Empty {
+        type Subject = Unset;
+    }
+    ///State transition - sets the `subject` field to Set
+    pub struct SetSubject<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetSubject<S> {}
+    impl<S: State> State for SetSubject<S> {
+        type Subject = Set<members::subject>;
+    }
+    /// Marker types for field names
+    #[allow(non_camel_case_types)]
+    pub mod members {
+        ///Marker type for the `subject` field
+        pub struct subject(());
+    }
+}
+
+/// Builder for constructing an instance of this type
+pub struct GetSubjectStatusBuilder<'a, S: get_subject_status_state::State> {
+    _phantom_state: ::core::marker::PhantomData<fn() -> S>,
+    __unsafe_private_named: (
+        ::core::option::Option<jacquard_common::types::string::AtUri<'a>>,
+    ),
+    _phantom: ::core::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> GetSubjectStatus<'a> {
+    /// Create a new builder for this type
+    pub fn new() -> GetSubjectStatusBuilder<'a, get_subject_status_state::Empty> {
+        GetSubjectStatusBuilder::new()
+    }
+}
+
+impl<'a> GetSubjectStatusBuilder<'a, get_subject_status_state::Empty> {
+    /// Create a new builder with all fields unset
+    pub fn new() -> Self {
+        GetSubjectStatusBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: (None,),
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> GetSubjectStatusBuilder<'a, S>
+where
+    S: get_subject_status_state::State,
+    S::Subject: get_subject_status_state::IsUnset,
+{
+    /// Set the `subject` field (required)
+    pub fn subject(
+        mut self,
+        value: impl Into<jacquard_common::types::string::AtUri<'a>>,
+    ) -> GetSubjectStatusBuilder<'a, get_subject_status_state::SetSubject<S>> {
+        self.__unsafe_private_named.0 = ::core::option::Option::Some(value.into());
+        GetSubjectStatusBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> GetSubjectStatusBuilder<'a, S>
+where
+    S: get_subject_status_state::State,
+    S::Subject: get_subject_status_state::IsSet,
+{
+    /// Build the final struct
+    pub fn build(self) -> GetSubjectStatus<'a> {
+        GetSubjectStatus {
+            subject: self.__unsafe_private_named.0.unwrap(),
+        }
+    }
+}
+
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSubjectStatusOutput<'a> {
+    /// Takedowns and reversals of the subject, newest first.
+    #[serde(borrow)]
+    pub history: Vec<crate::net_gifdex::admin::TakedownEvent<'a>>,
+    #[serde(borrow)]
+    pub subject: jacquard_common::types::string::AtUri<'a>,
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    #[serde(borrow)]
+    pub takedown: std::option::Option<crate::net_gifdex::admin::TakedownView<'a>>,
+}
+
+#[jacquard_derive::open_union]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    thiserror::Error,
+    miette::Diagnostic,
+    jacquard_derive::IntoStatic
+)]
+#[serde(tag = "error", content = "message")]
+#[serde(bound(deserialize = "'de: 'a"))]
+pub enum GetSubjectStatusError<'a> {
+    /// The subject must be a DID, or a record referenced by DID, collection and rkey.
+    #[serde(rename = "InvalidSubject")]
+    InvalidSubject(std::option::Option<jacquard_common::CowStr<'a>>),
+}
+
+impl core::fmt::Display for GetSubjectStatusError<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidSubject(msg) => {
+                write!(f, "InvalidSubject")?;
+                if let Some(msg) = msg {
+                    write!(f, ": {}", msg)?;
+                }
+                Ok(())
+            }
+            Self::Unknown(err) => write!(f, "Unknown error: {:?}", err),
+        }
+    }
+}
+
+/// Response type for
+///net.gifdex.admin.getSubjectStatus
+pub struct GetSubjectStatusResponse;
+impl jacquard_common::xrpc::XrpcResp for GetSubjectStatusResponse {
+    const NSID: &'static str = "net.gifdex.admin.getSubjectStatus";
+    const ENCODING: &'static str = "application/json";
+    type Output<'de> = GetSubjectStatusOutput<'de>;
+    type Err<'de> = GetSubjectStatusError<'de>;
+}
+
+impl<'a> jacquard_common::xrpc::XrpcRequest for GetSubjectStatus<'a> {
+    const NSID: &'static str = "net.gifdex.admin.getSubjectStatus";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Query;
+    type Response = GetSubjectStatusResponse;
+}
+
+/// Endpoint type for
+///net.gifdex.admin.getSubjectStatus
+pub struct GetSubjectStatusRequest;
+impl jacquard_common::xrpc::XrpcEndpoint for GetSubjectStatusRequest {
+    const PATH: &'static str = "/xrpc/net.gifdex.admin.getSubjectStatus";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Query;
+    type Request<'de> = GetSubjectStatus<'de>;
+    type Response = GetSubjectStatusResponse;
+}
\ No newline at end of file
@@ -0,0 +1,149 @@
+// @generated by jacquard-lexicon. DO NOT EDIT.
+//
+// Lexicon: net.gifdex.admin.listTakedowns
+//
+// This file was automatically generated from Lexicon schemas.
+// Any manual changes will be overwritten on the next regeneration.
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ListTakedowns {
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub cursor: std::option::Option<i64>,
+    ///(default: 50, min: 1, max: 100)
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub limit: std::option::Option<i64>,
+}
+
+pub mod list_takedowns_state {
+
+    pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
+    #[allow(unused)]
+    use ::core::marker::PhantomData;
+    mod sealed {
+        pub trait Sealed {}
+    }
+    /// State trait tracking which required fields have been set
+    pub trait State: sealed::Sealed {}
+    /// Empty state - all required fields are unset
+    pub struct Empty(());
+    impl sealed::Sealed for Empty {}
+    impl State for Empty {}
+    /// Marker types for field names
+    #[allow(non_camel_case_types)]
+    pub mod members {}
+}
+
+/// Builder for constructing an instance of this type
+pub struct ListTakedownsBuilder<S: list_takedowns_state::State> {
+    _phantom_state: ::core::marker::PhantomData<fn() -> S>,
+    __unsafe_private_named: (::core::option::Option<i64>, ::core::option::Option<i64>),
+}
+
+impl ListTakedowns {
+    /// Create a new builder for this type
+    pub fn new() -> ListTakedownsBuilder<list_takedowns_state::Empty> {
+        ListTakedownsBuilder::new()
+    }
+}
+
+impl ListTakedownsBuilder<list_takedowns_state::Empty> {
+    /// Create a new builder with all fields unset
+    pub fn new() -> Self {
+        ListTakedownsBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: (None, None),
+        }
+    }
+}
+
+impl<S: list_takedowns_state::State> ListTakedownsBuilder<S> {
+    /// Set the `cursor` field (optional)
+    pub fn cursor(mut self, value: impl Into<Option<i64>>) -> Self {
+        self.__unsafe_private_named.0 = value.into();
+        self
+    }
+    /// Set the `cursor` field to an Option value (optional)
+    pub fn maybe_cursor(mut self, value: Option<i64>) -> Self {
+        self.__unsafe_private_named.0 = value;
+        self
+    }
+}
+
+impl<S: list_takedowns_state::State> ListTakedownsBuilder<S> {
+    /// Set the `limit` field (optional)
+    pub fn limit(mut self, value: impl Into<Option<i64>>) -> Self {
+        self.__unsafe_private_named.1 = value.into();
+        self
+    }
+    /// Set the `limit` field to an Option value (optional)
+    pub fn maybe_limit(mut self, value: Option<i64>) -> Self {
+        self.__unsafe_private_named.1 = value;
+        self
+    }
+}
+
+impl<S> ListTakedownsBuilder<S>
+where
+    S: list_takedowns_state::State,
+{
+    /// Build the final struct
+    pub fn build(self) -> ListTakedowns {
+        ListTakedowns {
+            cursor: self.__unsafe_private_named.0,
+            limit: self.__unsafe_private_named.1,
+        }
+    }
+}
+
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ListTakedownsOutput<'a> {
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub cursor: std::option::Option<i64>,
+    #[serde(borrow)]
+    pub takedowns: Vec<crate::net_gifdex::admin::TakedownView<'a>>,
+}
+
+/// Response type for
+///net.gifdex.admin.listTakedowns
+pub struct ListTakedownsResponse;
+impl jacquard_common::xrpc::XrpcResp for ListTakedownsResponse {
+    const NSID: &'static str = "net.gifdex.admin.listTakedowns";
+    const ENCODING: &'static str = "application/json";
+    type Output<'de> = ListTakedownsOutput<'de>;
+    type Err<'de> = jacquard_common::xrpc::GenericError<'de>;
+}
+
+impl jacquard_common::xrpc::XrpcRequest for ListTakedowns {
+    const NSID: &'static str = "net.gifdex.admin.listTakedowns";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Query;
+    type Response = ListTakedownsResponse;
+}
+
+/// Endpoint type for
+///net.gifdex.admin.listTakedowns
+pub struct ListTakedownsRequest;
+impl jacquard_common::xrpc::XrpcEndpoint for ListTakedownsRequest {
+    const PATH: &'static str = "/xrpc/net.gifdex.admin.listTakedowns";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Query;
+    type Request<'de> = ListTakedowns;
+    type Response = ListTakedownsResponse;
+}
\ No newline at end of file
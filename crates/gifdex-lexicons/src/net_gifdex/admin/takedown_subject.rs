@@ -0,0 +1,231 @@
+// @generated by jacquard-lexicon. DO NOT EDIT.
+//
+// Lexicon: net.gifdex.admin.takedownSubject
+//
+// This file was automatically generated from Lexicon schemas.
+// Any manual changes will be overwritten on the next regeneration.
+
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TakedownSubject<'a> {
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    #[serde(borrow)]
+    pub reason: std::option::Option<jacquard_common::CowStr<'a>>,
+    /// The account (as at://did) or record to take down.
+    #[serde(borrow)]
+    pub subject: jacquard_common::types::string::AtUri<'a>,
+}
+
+pub mod takedown_subject_state {
+
+    pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
+    #[allow(unused)]
+    use ::core::marker::PhantomData;
+    mod sealed {
+        pub trait Sealed {}
+    }
+    /// State trait tracking which required fields have been set
+    pub trait State: sealed::Sealed {
+        type Subject;
+    }
+    /// Empty state - all required fields are unset
+    pub struct Empty(());
+    impl sealed::Sealed for Empty {}
+    impl State for Empty {
+        type Subject = Unset;
+    }
+    ///State transition - sets the `subject` field to Set
+    pub struct SetSubject<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetSubject<S> {}
+    impl<S: State> State for SetSubject<S> {
+        type Subject = Set<members::subject>;
+    }
+    /// Marker types for field names
+    #[allow(non_camel_case_types)]
+    pub mod members {
+        ///Marker type for the `subject` field
+        pub struct subject(());
+    }
+}
+
+/// Builder for constructing an instance of this type
+pub struct TakedownSubjectBuilder<'a, S: takedown_subject_state::State> {
+    _phantom_state: ::core::marker::PhantomData<fn() -> S>,
+    __unsafe_private_named: (
+        ::core::option::Option<jacquard_common::CowStr<'a>>,
+        ::core::option::Option<jacquard_common::types::string::AtUri<'a>>,
+    ),
+    _phantom: ::core::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> TakedownSubject<'a> {
+    /// Create a new builder for this type
+    pub fn new() -> TakedownSubjectBuilder<'a, takedown_subject_state::Empty> {
+        TakedownSubjectBuilder::new()
+    }
+}
+
+impl<'a> TakedownSubjectBuilder<'a, takedown_subject_state::Empty> {
+    /// Create a new builder with all fields unset
+    pub fn new() -> Self {
+        TakedownSubjectBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: (None, None),
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: takedown_subject_state::State> TakedownSubjectBuilder<'a, S> {
+    /// Set the `reason` field (optional)
+    pub fn reason(
+        mut self,
+        value: impl Into<Option<jacquard_common::CowStr<'a>>>,
+    ) -> Self {
+        self.__unsafe_private_named.0 = value.into();
+        self
+    }
+    /// Set the `reason` field to an Option value (optional)
+    pub fn maybe_reason(mut self, value: Option<jacquard_common::CowStr<'a>>) -> Self {
+        self.__unsafe_private_named.0 = value;
+        self
+    }
+}
+
+impl<'a, S> TakedownSubjectBuilder<'a, S>
+where
+    S: takedown_subject_state::State,
+    S::Subject: takedown_subject_state::IsUnset,
+{
+    /// Set the `subject` field (required)
+    pub fn subject(
+        mut self,
+        value: impl Into<jacquard_common::types::string::AtUri<'a>>,
+    ) -> TakedownSubjectBuilder<'a, takedown_subject_state::SetSubject<S>> {
+        self.__unsafe_private_named.1 = ::core::option::Option::Some(value.into());
+        TakedownSubjectBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> TakedownSubjectBuilder<'a, S>
+where
+    S: takedown_subject_state::State,
+    S::Subject: takedown_subject_state::IsSet,
+{
+    /// Build the final struct
+    pub fn build(self) -> TakedownSubject<'a> {
+        TakedownSubject {
+            reason: self.__unsafe_private_named.0,
+            subject: self.__unsafe_private_named.1.unwrap(),
+            extra_data: Default::default(),
+        }
+    }
+    /// Build the final struct with custom extra_data
+    pub fn build_with_data(
+        self,
+        extra_data: std::collections::BTreeMap<
+            jacquard_common::smol_str::SmolStr,
+            jacquard_common::types::value::Data<'a>,
+        >,
+    ) -> TakedownSubject<'a> {
+        TakedownSubject {
+            reason: self.__unsafe_private_named.0,
+            subject: self.__unsafe_private_named.1.unwrap(),
+            extra_data: Some(extra_data),
+        }
+    }
+}
+
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TakedownSubjectOutput<'a> {
+    #[serde(borrow)]
+    pub takedown: crate::net_gifdex::admin::TakedownView<'a>,
+}
+
+#[jacquard_derive::open_union]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    thiserror::Error,
+    miette::Diagnostic,
+    jacquard_derive::IntoStatic
+)]
+#[serde(tag = "error", content = "message")]
+#[serde(bound(deserialize = "'de: 'a"))]
+pub enum TakedownSubjectError<'a> {
+    /// The subject must be a DID, or a record referenced by DID, collection and rkey.
+    #[serde(rename = "InvalidSubject")]
+    InvalidSubject(std::option::Option<jacquard_common::CowStr<'a>>),
+}
+
+impl core::fmt::Display for TakedownSubjectError<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidSubject(msg) => {
+                write!(f, "InvalidSubject")?;
+                if let Some(msg) = msg {
+                    write!(f, ": {}", msg)?;
+                }
+                Ok(())
+            }
+            Self::Unknown(err) => write!(f, "Unknown error: {:?}", err),
+        }
+    }
+}
+
+/// Response type for
+///net.gifdex.admin.takedownSubject
+pub struct TakedownSubjectResponse;
+impl jacquard_common::xrpc::XrpcResp for TakedownSubjectResponse {
+    const NSID: &'static str = "net.gifdex.admin.takedownSubject";
+    const ENCODING: &'static str = "application/json";
+    type Output<'de> = TakedownSubjectOutput<'de>;
+    type Err<'de> = TakedownSubjectError<'de>;
+}
+
+impl<'a> jacquard_common::xrpc::XrpcRequest for TakedownSubject<'a> {
+    const NSID: &'static str = "net.gifdex.admin.takedownSubject";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Procedure(
+        "application/json",
+    );
+    type Response = TakedownSubjectResponse;
+}
+
+/// Endpoint type for
+///net.gifdex.admin.takedownSubject
+pub struct TakedownSubjectRequest;
+impl jacquard_common::xrpc::XrpcEndpoint for TakedownSubjectRequest {
+    const PATH: &'static str = "/xrpc/net.gifdex.admin.takedownSubject";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Procedure(
+        "application/json",
+    );
+    type Request<'de> = TakedownSubject<'de>;
+    type Response = TakedownSubjectResponse;
+}
\ No newline at end of file
@@ -5,13 +5,20 @@
 // This file was automatically generated from Lexicon schemas.
 // Any manual changes will be overwritten on the next regeneration.
 
+pub mod collection;
+pub mod collection_item;
 pub mod favourite;
+pub mod get_actor_collections;
+pub mod get_collection;
+pub mod get_collections_for_post;
 pub mod get_post;
 pub mod get_posts_by_actor;
 pub mod get_posts_by_query;
+pub mod get_similar_posts;
+pub mod get_timeline;
 pub mod post;
 
-/// Feed-optimized view of a post with all metadata needed for display in timelines.
+/// View of a collection of posts, without its items.
 #[jacquard_derive::lexicon]
 #[derive(
     serde::Serialize,
@@ -23,28 +30,24 @@ pub mod post;
     jacquard_derive::IntoStatic
 )]
 #[serde(rename_all = "camelCase")]
-pub struct PostFeedView<'a> {
+pub struct CollectionView<'a> {
     #[serde(borrow)]
     pub author: crate::net_gifdex::actor::ProfileViewBasic<'a>,
     pub created_at: jacquard_common::types::string::Datetime,
     #[serde(skip_serializing_if = "std::option::Option::is_none")]
-    pub edited_at: std::option::Option<jacquard_common::types::string::Datetime>,
-    pub favourite_count: i64,
-    pub indexed_at: jacquard_common::types::string::Datetime,
     #[serde(borrow)]
-    pub media: crate::net_gifdex::feed::PostViewMedia<'a>,
-    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub description: std::option::Option<jacquard_common::CowStr<'a>>,
+    pub indexed_at: jacquard_common::types::string::Datetime,
+    pub item_count: i64,
     #[serde(borrow)]
-    pub tags: std::option::Option<Vec<jacquard_common::CowStr<'a>>>,
+    pub ordering: jacquard_common::CowStr<'a>,
     #[serde(borrow)]
     pub title: jacquard_common::CowStr<'a>,
     #[serde(borrow)]
     pub uri: jacquard_common::types::string::AtUri<'a>,
-    #[serde(borrow)]
-    pub viewer: crate::net_gifdex::feed::ViewerState<'a>,
 }
 
-pub mod post_feed_view_state {
+pub mod collection_view_state {
 
     pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
     #[allow(unused)]
@@ -54,213 +57,176 @@ pub mod post_feed_view_state {
     }
     /// State trait tracking which required fields have been set
     pub trait State: sealed::Sealed {
-        type IndexedAt;
-        type Viewer;
+        type Ordering;
         type Author;
-        type Uri;
-        type FavouriteCount;
-        type Title;
-        type Media;
         type CreatedAt;
+        type Title;
+        type ItemCount;
+        type IndexedAt;
+        type Uri;
     }
     /// Empty state - all required fields are unset
     pub struct Empty(());
     impl sealed::Sealed for Empty {}
     impl State for Empty {
-        type IndexedAt = Unset;
-        type Viewer = Unset;
+        type Ordering = Unset;
         type Author = Unset;
-        type Uri = Unset;
-        type FavouriteCount = Unset;
-        type Title = Unset;
-        type Media = Unset;
         type CreatedAt = Unset;
+        type Title = Unset;
+        type ItemCount = Unset;
+        type IndexedAt = Unset;
+        type Uri = Unset;
     }
-    ///State transition - sets the `indexed_at` field to Set
-    pub struct SetIndexedAt<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetIndexedAt<S> {}
-    impl<S: State> State for SetIndexedAt<S> {
-        type IndexedAt = Set<members::indexed_at>;
-        type Viewer = S::Viewer;
+    ///State transition - sets the `ordering` field to Set
+    pub struct SetOrdering<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetOrdering<S> {}
+    impl<S: State> State for SetOrdering<S> {
+        type Ordering = Set<members::ordering>;
         type Author = S::Author;
-        type Uri = S::Uri;
-        type FavouriteCount = S::FavouriteCount;
-        type Title = S::Title;
-        type Media = S::Media;
         type CreatedAt = S::CreatedAt;
-    }
-    ///State transition - sets the `viewer` field to Set
-    pub struct SetViewer<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetViewer<S> {}
-    impl<S: State> State for SetViewer<S> {
+        type Title = S::Title;
+        type ItemCount = S::ItemCount;
         type IndexedAt = S::IndexedAt;
-        type Viewer = Set<members::viewer>;
-        type Author = S::Author;
         type Uri = S::Uri;
-        type FavouriteCount = S::FavouriteCount;
-        type Title = S::Title;
-        type Media = S::Media;
-        type CreatedAt = S::CreatedAt;
     }
     ///State transition - sets the `author` field to Set
     pub struct SetAuthor<S: State = Empty>(PhantomData<fn() -> S>);
     impl<S: State> sealed::Sealed for SetAuthor<S> {}
     impl<S: State> State for SetAuthor<S> {
-        type IndexedAt = S::IndexedAt;
-        type Viewer = S::Viewer;
+        type Ordering = S::Ordering;
         type Author = Set<members::author>;
-        type Uri = S::Uri;
-        type FavouriteCount = S::FavouriteCount;
-        type Title = S::Title;
-        type Media = S::Media;
         type CreatedAt = S::CreatedAt;
-    }
-    ///State transition - sets the `uri` field to Set
-    pub struct SetUri<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetUri<S> {}
-    impl<S: State> State for SetUri<S> {
+        type Title = S::Title;
+        type ItemCount = S::ItemCount;
         type IndexedAt = S::IndexedAt;
-        type Viewer = S::Viewer;
+        type Uri = S::Uri;
+    }
+    ///State transition - sets the `created_at` field to Set
+    pub struct SetCreatedAt<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetCreatedAt<S> {}
+    impl<S: State> State for SetCreatedAt<S> {
+        type Ordering = S::Ordering;
         type Author = S::Author;
-        type Uri = Set<members::uri>;
-        type FavouriteCount = S::FavouriteCount;
+        type CreatedAt = Set<members::created_at>;
         type Title = S::Title;
-        type Media = S::Media;
-        type CreatedAt = S::CreatedAt;
-    }
-    ///State transition - sets the `favourite_count` field to Set
-    pub struct SetFavouriteCount<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetFavouriteCount<S> {}
-    impl<S: State> State for SetFavouriteCount<S> {
+        type ItemCount = S::ItemCount;
         type IndexedAt = S::IndexedAt;
-        type Viewer = S::Viewer;
-        type Author = S::Author;
         type Uri = S::Uri;
-        type FavouriteCount = Set<members::favourite_count>;
-        type Title = S::Title;
-        type Media = S::Media;
-        type CreatedAt = S::CreatedAt;
     }
     ///State transition - sets the `title` field to Set
     pub struct SetTitle<S: State = Empty>(PhantomData<fn() -> S>);
     impl<S: State> sealed::Sealed for SetTitle<S> {}
     impl<S: State> State for SetTitle<S> {
-        type IndexedAt = S::IndexedAt;
-        type Viewer = S::Viewer;
+        type Ordering = S::Ordering;
         type Author = S::Author;
-        type Uri = S::Uri;
-        type FavouriteCount = S::FavouriteCount;
-        type Title = Set<members::title>;
-        type Media = S::Media;
         type CreatedAt = S::CreatedAt;
-    }
-    ///State transition - sets the `media` field to Set
-    pub struct SetMedia<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetMedia<S> {}
-    impl<S: State> State for SetMedia<S> {
+        type Title = Set<members::title>;
+        type ItemCount = S::ItemCount;
         type IndexedAt = S::IndexedAt;
-        type Viewer = S::Viewer;
-        type Author = S::Author;
         type Uri = S::Uri;
-        type FavouriteCount = S::FavouriteCount;
-        type Title = S::Title;
-        type Media = Set<members::media>;
-        type CreatedAt = S::CreatedAt;
     }
-    ///State transition - sets the `created_at` field to Set
-    pub struct SetCreatedAt<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetCreatedAt<S> {}
-    impl<S: State> State for SetCreatedAt<S> {
+    ///State transition - sets the `item_count` field to Set
+    pub struct SetItemCount<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetItemCount<S> {}
+    impl<S: State> State for SetItemCount<S> {
+        type Ordering = S::Ordering;
+        type Author = S::Author;
+        type CreatedAt = S::CreatedAt;
+        type Title = S::Title;
+        type ItemCount = Set<members::item_count>;
         type IndexedAt = S::IndexedAt;
-        type Viewer = S::Viewer;
+        type Uri = S::Uri;
+    }
+    ///State transition - sets the `indexed_at` field to Set
+    pub struct SetIndexedAt<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetIndexedAt<S> {}
+    impl<S: State> State for SetIndexedAt<S> {
+        type Ordering = S::Ordering;
         type Author = S::Author;
+        type CreatedAt = S::CreatedAt;
+        type Title = S::Title;
+        type ItemCount = S::ItemCount;
+        type IndexedAt = Set<members::indexed_at>;
         type Uri = S::Uri;
-        type FavouriteCount = S::FavouriteCount;
+    }
+    ///State transition - sets the `uri` field to Set
+    pub struct SetUri<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetUri<S> {}
+    impl<S: State> State for SetUri<S> {
+        type Ordering = S::Ordering;
+        type Author = S::Author;
+        type CreatedAt = S::CreatedAt;
         type Title = S::Title;
-        type Media = S::Media;
-        type CreatedAt = Set<members::created_at>;
+        type ItemCount = S::ItemCount;
+        type IndexedAt = S::IndexedAt;
+        type Uri = Set<members::uri>;
     }
     /// Marker types for field names
     #[allow(non_camel_case_types)]
     pub mod members {
-        ///Marker type for the `indexed_at` field
-        pub struct indexed_at(());
-        ///Marker type for the `viewer` field
-        pub struct viewer(());
+        ///Marker type for the `ordering` field
+        pub struct ordering(());
         ///Marker type for the `author` field
         pub struct author(());
-        ///Marker type for the `uri` field
-        pub struct uri(());
-        ///Marker type for the `favourite_count` field
-        pub struct favourite_count(());
-        ///Marker type for the `title` field
-        pub struct title(());
-        ///Marker type for the `media` field
-        pub struct media(());
         ///Marker type for the `created_at` field
         pub struct created_at(());
+        ///Marker type for the `title` field
+        pub struct title(());
+        ///Marker type for the `item_count` field
+        pub struct item_count(());
+        ///Marker type for the `indexed_at` field
+        pub struct indexed_at(());
+        ///Marker type for the `uri` field
+        pub struct uri(());
     }
 }
 
 /// Builder for constructing an instance of this type
-pub struct PostFeedViewBuilder<'a, S: post_feed_view_state::State> {
+pub struct CollectionViewBuilder<'a, S: collection_view_state::State> {
     _phantom_state: ::core::marker::PhantomData<fn() -> S>,
     __unsafe_private_named: (
         ::core::option::Option<crate::net_gifdex::actor::ProfileViewBasic<'a>>,
         ::core::option::Option<jacquard_common::types::string::Datetime>,
+        ::core::option::Option<jacquard_common::CowStr<'a>>,
         ::core::option::Option<jacquard_common::types::string::Datetime>,
         ::core::option::Option<i64>,
-        ::core::option::Option<jacquard_common::types::string::Datetime>,
-        ::core::option::Option<crate::net_gifdex::feed::PostViewMedia<'a>>,
-        ::core::option::Option<Vec<jacquard_common::CowStr<'a>>>,
+        ::core::option::Option<jacquard_common::CowStr<'a>>,
         ::core::option::Option<jacquard_common::CowStr<'a>>,
         ::core::option::Option<jacquard_common::types::string::AtUri<'a>>,
-        ::core::option::Option<crate::net_gifdex::feed::ViewerState<'a>>,
     ),
     _phantom: ::core::marker::PhantomData<&'a ()>,
 }
 
-impl<'a> PostFeedView<'a> {
+impl<'a> CollectionView<'a> {
     /// Create a new builder for this type
-    pub fn new() -> PostFeedViewBuilder<'a, post_feed_view_state::Empty> {
-        PostFeedViewBuilder::new()
+    pub fn new() -> CollectionViewBuilder<'a, collection_view_state::Empty> {
+        CollectionViewBuilder::new()
     }
 }
 
-impl<'a> PostFeedViewBuilder<'a, post_feed_view_state::Empty> {
+impl<'a> CollectionViewBuilder<'a, collection_view_state::Empty> {
     /// Create a new builder with all fields unset
     pub fn new() -> Self {
-        PostFeedViewBuilder {
+        CollectionViewBuilder {
             _phantom_state: ::core::marker::PhantomData,
-            __unsafe_private_named: (
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-            ),
+            __unsafe_private_named: (None, None, None, None, None, None, None, None),
             _phantom: ::core::marker::PhantomData,
         }
     }
 }
 
-impl<'a, S> PostFeedViewBuilder<'a, S>
+impl<'a, S> CollectionViewBuilder<'a, S>
 where
-    S: post_feed_view_state::State,
-    S::Author: post_feed_view_state::IsUnset,
+    S: collection_view_state::State,
+    S::Author: collection_view_state::IsUnset,
 {
     /// Set the `author` field (required)
     pub fn author(
         mut self,
         value: impl Into<crate::net_gifdex::actor::ProfileViewBasic<'a>>,
-    ) -> PostFeedViewBuilder<'a, post_feed_view_state::SetAuthor<S>> {
+    ) -> CollectionViewBuilder<'a, collection_view_state::SetAuthor<S>> {
         self.__unsafe_private_named.0 = ::core::option::Option::Some(value.into());
-        PostFeedViewBuilder {
+        CollectionViewBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
             _phantom: ::core::marker::PhantomData,
@@ -268,18 +234,18 @@ where
     }
 }
 
-impl<'a, S> PostFeedViewBuilder<'a, S>
+impl<'a, S> CollectionViewBuilder<'a, S>
 where
-    S: post_feed_view_state::State,
-    S::CreatedAt: post_feed_view_state::IsUnset,
+    S: collection_view_state::State,
+    S::CreatedAt: collection_view_state::IsUnset,
 {
     /// Set the `createdAt` field (required)
     pub fn created_at(
         mut self,
         value: impl Into<jacquard_common::types::string::Datetime>,
-    ) -> PostFeedViewBuilder<'a, post_feed_view_state::SetCreatedAt<S>> {
+    ) -> CollectionViewBuilder<'a, collection_view_state::SetCreatedAt<S>> {
         self.__unsafe_private_named.1 = ::core::option::Option::Some(value.into());
-        PostFeedViewBuilder {
+        CollectionViewBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
             _phantom: ::core::marker::PhantomData,
@@ -287,37 +253,37 @@ where
     }
 }
 
-impl<'a, S: post_feed_view_state::State> PostFeedViewBuilder<'a, S> {
-    /// Set the `editedAt` field (optional)
-    pub fn edited_at(
+impl<'a, S: collection_view_state::State> CollectionViewBuilder<'a, S> {
+    /// Set the `description` field (optional)
+    pub fn description(
         mut self,
-        value: impl Into<Option<jacquard_common::types::string::Datetime>>,
+        value: impl Into<Option<jacquard_common::CowStr<'a>>>,
     ) -> Self {
         self.__unsafe_private_named.2 = value.into();
         self
     }
-    /// Set the `editedAt` field to an Option value (optional)
-    pub fn maybe_edited_at(
+    /// Set the `description` field to an Option value (optional)
+    pub fn maybe_description(
         mut self,
-        value: Option<jacquard_common::types::string::Datetime>,
+        value: Option<jacquard_common::CowStr<'a>>,
     ) -> Self {
         self.__unsafe_private_named.2 = value;
         self
     }
 }
 
-impl<'a, S> PostFeedViewBuilder<'a, S>
+impl<'a, S> CollectionViewBuilder<'a, S>
 where
-    S: post_feed_view_state::State,
-    S::FavouriteCount: post_feed_view_state::IsUnset,
+    S: collection_view_state::State,
+    S::IndexedAt: collection_view_state::IsUnset,
 {
-    /// Set the `favouriteCount` field (required)
-    pub fn favourite_count(
+    /// Set the `indexedAt` field (required)
+    pub fn indexed_at(
         mut self,
-        value: impl Into<i64>,
-    ) -> PostFeedViewBuilder<'a, post_feed_view_state::SetFavouriteCount<S>> {
+        value: impl Into<jacquard_common::types::string::Datetime>,
+    ) -> CollectionViewBuilder<'a, collection_view_state::SetIndexedAt<S>> {
         self.__unsafe_private_named.3 = ::core::option::Option::Some(value.into());
-        PostFeedViewBuilder {
+        CollectionViewBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
             _phantom: ::core::marker::PhantomData,
@@ -325,18 +291,18 @@ where
     }
 }
 
-impl<'a, S> PostFeedViewBuilder<'a, S>
+impl<'a, S> CollectionViewBuilder<'a, S>
 where
-    S: post_feed_view_state::State,
-    S::IndexedAt: post_feed_view_state::IsUnset,
+    S: collection_view_state::State,
+    S::ItemCount: collection_view_state::IsUnset,
 {
-    /// Set the `indexedAt` field (required)
-    pub fn indexed_at(
+    /// Set the `itemCount` field (required)
+    pub fn item_count(
         mut self,
-        value: impl Into<jacquard_common::types::string::Datetime>,
-    ) -> PostFeedViewBuilder<'a, post_feed_view_state::SetIndexedAt<S>> {
+        value: impl Into<i64>,
+    ) -> CollectionViewBuilder<'a, collection_view_state::SetItemCount<S>> {
         self.__unsafe_private_named.4 = ::core::option::Option::Some(value.into());
-        PostFeedViewBuilder {
+        CollectionViewBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
             _phantom: ::core::marker::PhantomData,
@@ -344,18 +310,18 @@ where
     }
 }
 
-impl<'a, S> PostFeedViewBuilder<'a, S>
+impl<'a, S> CollectionViewBuilder<'a, S>
 where
-    S: post_feed_view_state::State,
-    S::Media: post_feed_view_state::IsUnset,
+    S: collection_view_state::State,
+    S::Ordering: collection_view_state::IsUnset,
 {
-    /// Set the `media` field (required)
-    pub fn media(
+    /// Set the `ordering` field (required)
+    pub fn ordering(
         mut self,
-        value: impl Into<crate::net_gifdex::feed::PostViewMedia<'a>>,
-    ) -> PostFeedViewBuilder<'a, post_feed_view_state::SetMedia<S>> {
+        value: impl Into<jacquard_common::CowStr<'a>>,
+    ) -> CollectionViewBuilder<'a, collection_view_state::SetOrdering<S>> {
         self.__unsafe_private_named.5 = ::core::option::Option::Some(value.into());
-        PostFeedViewBuilder {
+        CollectionViewBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
             _phantom: ::core::marker::PhantomData,
@@ -363,37 +329,18 @@ where
     }
 }
 
-impl<'a, S: post_feed_view_state::State> PostFeedViewBuilder<'a, S> {
-    /// Set the `tags` field (optional)
-    pub fn tags(
-        mut self,
-        value: impl Into<Option<Vec<jacquard_common::CowStr<'a>>>>,
-    ) -> Self {
-        self.__unsafe_private_named.6 = value.into();
-        self
-    }
-    /// Set the `tags` field to an Option value (optional)
-    pub fn maybe_tags(
-        mut self,
-        value: Option<Vec<jacquard_common::CowStr<'a>>>,
-    ) -> Self {
-        self.__unsafe_private_named.6 = value;
-        self
-    }
-}
-
-impl<'a, S> PostFeedViewBuilder<'a, S>
+impl<'a, S> CollectionViewBuilder<'a, S>
 where
-    S: post_feed_view_state::State,
-    S::Title: post_feed_view_state::IsUnset,
+    S: collection_view_state::State,
+    S::Title: collection_view_state::IsUnset,
 {
     /// Set the `title` field (required)
     pub fn title(
         mut self,
         value: impl Into<jacquard_common::CowStr<'a>>,
-    ) -> PostFeedViewBuilder<'a, post_feed_view_state::SetTitle<S>> {
-        self.__unsafe_private_named.7 = ::core::option::Option::Some(value.into());
-        PostFeedViewBuilder {
+    ) -> CollectionViewBuilder<'a, collection_view_state::SetTitle<S>> {
+        self.__unsafe_private_named.6 = ::core::option::Option::Some(value.into());
+        CollectionViewBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
             _phantom: ::core::marker::PhantomData,
@@ -401,18 +348,18 @@ where
     }
 }
 
-impl<'a, S> PostFeedViewBuilder<'a, S>
+impl<'a, S> CollectionViewBuilder<'a, S>
 where
-    S: post_feed_view_state::State,
-    S::Uri: post_feed_view_state::IsUnset,
+    S: collection_view_state::State,
+    S::Uri: collection_view_state::IsUnset,
 {
     /// Set the `uri` field (required)
     pub fn uri(
         mut self,
         value: impl Into<jacquard_common::types::string::AtUri<'a>>,
-    ) -> PostFeedViewBuilder<'a, post_feed_view_state::SetUri<S>> {
-        self.__unsafe_private_named.8 = ::core::option::Option::Some(value.into());
-        PostFeedViewBuilder {
+    ) -> CollectionViewBuilder<'a, collection_view_state::SetUri<S>> {
+        self.__unsafe_private_named.7 = ::core::option::Option::Some(value.into());
+        CollectionViewBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
             _phantom: ::core::marker::PhantomData,
@@ -420,50 +367,28 @@ where
     }
 }
 
-impl<'a, S> PostFeedViewBuilder<'a, S>
+impl<'a, S> CollectionViewBuilder<'a, S>
 where
-    S: post_feed_view_state::State,
-    S::Viewer: post_feed_view_state::IsUnset,
-{
-    /// Set the `viewer` field (required)
-    pub fn viewer(
-        mut self,
-        value: impl Into<crate::net_gifdex::feed::ViewerState<'a>>,
-    ) -> PostFeedViewBuilder<'a, post_feed_view_state::SetViewer<S>> {
-        self.__unsafe_private_named.9 = ::core::option::Option::Some(value.into());
-        PostFeedViewBuilder {
-            _phantom_state: ::core::marker::PhantomData,
-            __unsafe_private_named: self.__unsafe_private_named,
-            _phantom: ::core::marker::PhantomData,
-        }
-    }
-}
-
-impl<'a, S> PostFeedViewBuilder<'a, S>
-where
-    S: post_feed_view_state::State,
-    S::IndexedAt: post_feed_view_state::IsSet,
-    S::Viewer: post_feed_view_state::IsSet,
-    S::Author: post_feed_view_state::IsSet,
-    S::Uri: post_feed_view_state::IsSet,
-    S::FavouriteCount: post_feed_view_state::IsSet,
-    S::Title: post_feed_view_state::IsSet,
-    S::Media: post_feed_view_state::IsSet,
-    S::CreatedAt: post_feed_view_state::IsSet,
+    S: collection_view_state::State,
+    S::Ordering: collection_view_state::IsSet,
+    S::Author: collection_view_state::IsSet,
+    S::CreatedAt: collection_view_state::IsSet,
+    S::Title: collection_view_state::IsSet,
+    S::ItemCount: collection_view_state::IsSet,
+    S::IndexedAt: collection_view_state::IsSet,
+    S::Uri: collection_view_state::IsSet,
 {
     /// Build the final struct
-    pub fn build(self) -> PostFeedView<'a> {
-        PostFeedView {
+    pub fn build(self) -> CollectionView<'a> {
+        CollectionView {
             author: self.__unsafe_private_named.0.unwrap(),
             created_at: self.__unsafe_private_named.1.unwrap(),
-            edited_at: self.__unsafe_private_named.2,
-            favourite_count: self.__unsafe_private_named.3.unwrap(),
-            indexed_at: self.__unsafe_private_named.4.unwrap(),
-            media: self.__unsafe_private_named.5.unwrap(),
-            tags: self.__unsafe_private_named.6,
-            title: self.__unsafe_private_named.7.unwrap(),
-            uri: self.__unsafe_private_named.8.unwrap(),
-            viewer: self.__unsafe_private_named.9.unwrap(),
+            description: self.__unsafe_private_named.2,
+            indexed_at: self.__unsafe_private_named.3.unwrap(),
+            item_count: self.__unsafe_private_named.4.unwrap(),
+            ordering: self.__unsafe_private_named.5.unwrap(),
+            title: self.__unsafe_private_named.6.unwrap(),
+            uri: self.__unsafe_private_named.7.unwrap(),
             extra_data: Default::default(),
         }
     }
@@ -474,248 +399,1056 @@ where
             jacquard_common::smol_str::SmolStr,
             jacquard_common::types::value::Data<'a>,
         >,
-    ) -> PostFeedView<'a> {
-        PostFeedView {
+    ) -> CollectionView<'a> {
+        CollectionView {
             author: self.__unsafe_private_named.0.unwrap(),
             created_at: self.__unsafe_private_named.1.unwrap(),
-            edited_at: self.__unsafe_private_named.2,
-            favourite_count: self.__unsafe_private_named.3.unwrap(),
-            indexed_at: self.__unsafe_private_named.4.unwrap(),
-            media: self.__unsafe_private_named.5.unwrap(),
-            tags: self.__unsafe_private_named.6,
-            title: self.__unsafe_private_named.7.unwrap(),
-            uri: self.__unsafe_private_named.8.unwrap(),
-            viewer: self.__unsafe_private_named.9.unwrap(),
+            description: self.__unsafe_private_named.2,
+            indexed_at: self.__unsafe_private_named.3.unwrap(),
+            item_count: self.__unsafe_private_named.4.unwrap(),
+            ordering: self.__unsafe_private_named.5.unwrap(),
+            title: self.__unsafe_private_named.6.unwrap(),
+            uri: self.__unsafe_private_named.7.unwrap(),
             extra_data: Some(extra_data),
         }
     }
 }
 
-fn lexicon_doc_net_gifdex_feed_defs() -> ::jacquard_lexicon::lexicon::LexiconDoc<
-    'static,
-> {
-    ::jacquard_lexicon::lexicon::LexiconDoc {
-        lexicon: ::jacquard_lexicon::lexicon::Lexicon::Lexicon1,
-        id: ::jacquard_common::CowStr::new_static("net.gifdex.feed.defs"),
-        revision: None,
-        description: None,
-        defs: {
-            let mut map = ::alloc::collections::BTreeMap::new();
-            map.insert(
-                ::jacquard_common::smol_str::SmolStr::new_static("postFeedView"),
-                ::jacquard_lexicon::lexicon::LexUserType::Object(::jacquard_lexicon::lexicon::LexObject {
-                    description: Some(
-                        ::jacquard_common::CowStr::new_static(
-                            "Feed-optimized view of a post with all metadata needed for display in timelines.",
-                        ),
-                    ),
-                    required: Some(
-                        vec![
-                            ::jacquard_common::smol_str::SmolStr::new_static("uri"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("author"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("title"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("media"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("favouriteCount"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("viewer"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("createdAt"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("indexedAt")
-                        ],
-                    ),
-                    nullable: None,
-                    properties: {
-                        #[allow(unused_mut)]
-                        let mut map = ::alloc::collections::BTreeMap::new();
-                        map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("author"),
-                            ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
-                                description: None,
-                                r#ref: ::jacquard_common::CowStr::new_static(
-                                    "net.gifdex.actor.defs#profileViewBasic",
-                                ),
-                            }),
-                        );
-                        map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static(
-                                "createdAt",
-                            ),
-                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
-                                description: None,
-                                format: Some(
-                                    ::jacquard_lexicon::lexicon::LexStringFormat::Datetime,
-                                ),
-                                default: None,
-                                min_length: None,
-                                max_length: None,
-                                min_graphemes: None,
-                                max_graphemes: None,
-                                r#enum: None,
-                                r#const: None,
-                                known_values: None,
-                            }),
-                        );
-                        map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("editedAt"),
-                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
-                                description: None,
-                                format: Some(
-                                    ::jacquard_lexicon::lexicon::LexStringFormat::Datetime,
-                                ),
-                                default: None,
-                                min_length: None,
-                                max_length: None,
-                                min_graphemes: None,
-                                max_graphemes: None,
-                                r#enum: None,
-                                r#const: None,
-                                known_values: None,
-                            }),
-                        );
-                        map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static(
-                                "favouriteCount",
-                            ),
-                            ::jacquard_lexicon::lexicon::LexObjectProperty::Integer(::jacquard_lexicon::lexicon::LexInteger {
-                                description: None,
-                                default: None,
-                                minimum: None,
-                                maximum: None,
-                                r#enum: None,
-                                r#const: None,
-                            }),
-                        );
-                        map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static(
-                                "indexedAt",
-                            ),
-                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
-                                description: None,
-                                format: Some(
-                                    ::jacquard_lexicon::lexicon::LexStringFormat::Datetime,
-                                ),
-                                default: None,
-                                min_length: None,
-                                max_length: None,
-                                min_graphemes: None,
-                                max_graphemes: None,
-                                r#enum: None,
-                                r#const: None,
-                                known_values: None,
-                            }),
-                        );
-                        map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("media"),
-                            ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
-                                description: None,
-                                r#ref: ::jacquard_common::CowStr::new_static(
-                                    "#postViewMedia",
-                                ),
-                            }),
-                        );
-                        map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("tags"),
-                            ::jacquard_lexicon::lexicon::LexObjectProperty::Array(::jacquard_lexicon::lexicon::LexArray {
-                                description: None,
-                                items: ::jacquard_lexicon::lexicon::LexArrayItem::String(::jacquard_lexicon::lexicon::LexString {
-                                    description: None,
-                                    format: None,
-                                    default: None,
-                                    min_length: None,
-                                    max_length: None,
-                                    min_graphemes: None,
-                                    max_graphemes: None,
-                                    r#enum: None,
-                                    r#const: None,
-                                    known_values: None,
-                                }),
-                                min_length: None,
-                                max_length: None,
-                            }),
-                        );
-                        map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("title"),
-                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
-                                description: None,
-                                format: None,
-                                default: None,
-                                min_length: None,
-                                max_length: None,
-                                min_graphemes: None,
-                                max_graphemes: None,
-                                r#enum: None,
-                                r#const: None,
-                                known_values: None,
-                            }),
-                        );
-                        map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("uri"),
-                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
-                                description: None,
-                                format: Some(
-                                    ::jacquard_lexicon::lexicon::LexStringFormat::AtUri,
-                                ),
-                                default: None,
-                                min_length: None,
-                                max_length: None,
-                                min_graphemes: None,
-                                max_graphemes: None,
-                                r#enum: None,
-                                r#const: None,
-                                known_values: None,
-                            }),
-                        );
-                        map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("viewer"),
-                            ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
-                                description: None,
-                                r#ref: ::jacquard_common::CowStr::new_static("#viewerState"),
-                            }),
-                        );
-                        map
-                    },
-                }),
-            );
-            map.insert(
-                ::jacquard_common::smol_str::SmolStr::new_static("postView"),
-                ::jacquard_lexicon::lexicon::LexUserType::Object(::jacquard_lexicon::lexicon::LexObject {
-                    description: Some(
-                        ::jacquard_common::CowStr::new_static(
-                            "Detailed view of a post, including author information and engagement metrics.",
-                        ),
-                    ),
-                    required: Some(
-                        vec![
-                            ::jacquard_common::smol_str::SmolStr::new_static("uri"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("author"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("title"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("media"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("favouriteCount"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("viewer"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("createdAt"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("indexedAt")
-                        ],
-                    ),
-                    nullable: None,
-                    properties: {
-                        #[allow(unused_mut)]
-                        let mut map = ::alloc::collections::BTreeMap::new();
-                        map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("author"),
-                            ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
-                                description: None,
-                                r#ref: ::jacquard_common::CowStr::new_static(
-                                    "net.gifdex.actor.defs#profileViewBasic",
-                                ),
-                            }),
-                        );
-                        map.insert(
+impl<'a> ::jacquard_lexicon::schema::LexiconSchema for CollectionView<'a> {
+    fn nsid() -> &'static str {
+        "net.gifdex.feed.defs"
+    }
+    fn def_name() -> &'static str {
+        "collectionView"
+    }
+    fn lexicon_doc() -> ::jacquard_lexicon::lexicon::LexiconDoc<'static> {
+        lexicon_doc_net_gifdex_feed_defs()
+    }
+    fn validate(
+        &self,
+    ) -> ::core::result::Result<(), ::jacquard_lexicon::validation::ConstraintError> {
+        Ok(())
+    }
+}
+
+/// Feed-optimized view of a post with all metadata needed for display in timelines.
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PostFeedView<'a> {
+    #[serde(borrow)]
+    pub author: crate::net_gifdex::actor::ProfileViewBasic<'a>,
+    pub created_at: jacquard_common::types::string::Datetime,
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub edited_at: std::option::Option<jacquard_common::types::string::Datetime>,
+    pub favourite_count: i64,
+    pub indexed_at: jacquard_common::types::string::Datetime,
+    #[serde(borrow)]
+    pub media: crate::net_gifdex::feed::PostViewMedia<'a>,
+    /// Labels the author applied to their own post.
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    #[serde(borrow)]
+    pub self_labels: std::option::Option<
+        Vec<crate::net_gifdex::feed::SelfLabelView<'a>>,
+    >,
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    #[serde(borrow)]
+    pub tags: std::option::Option<Vec<jacquard_common::CowStr<'a>>>,
+    #[serde(borrow)]
+    pub title: jacquard_common::CowStr<'a>,
+    #[serde(borrow)]
+    pub uri: jacquard_common::types::string::AtUri<'a>,
+    #[serde(borrow)]
+    pub viewer: crate::net_gifdex::feed::ViewerState<'a>,
+}
+
+pub mod post_feed_view_state {
+
+    pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
+    #[allow(unused)]
+    use ::core::marker::PhantomData;
+    mod sealed {
+        pub trait Sealed {}
+    }
+    /// State trait tracking which required fields have been set
+    pub trait State: sealed::Sealed {
+        type IndexedAt;
+        type Viewer;
+        type Author;
+        type Uri;
+        type FavouriteCount;
+        type Title;
+        type Media;
+        type CreatedAt;
+    }
+    /// Empty state - all required fields are unset
+    pub struct Empty(());
+    impl sealed::Sealed for Empty {}
+    impl State for Empty {
+        type IndexedAt = Unset;
+        type Viewer = Unset;
+        type Author = Unset;
+        type Uri = Unset;
+        type FavouriteCount = Unset;
+        type Title = Unset;
+        type Media = Unset;
+        type CreatedAt = Unset;
+    }
+    ///State transition - sets the `indexed_at` field to Set
+    pub struct SetIndexedAt<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetIndexedAt<S> {}
+    impl<S: State> State for SetIndexedAt<S> {
+        type IndexedAt = Set<members::indexed_at>;
+        type Viewer = S::Viewer;
+        type Author = S::Author;
+        type Uri = S::Uri;
+        type FavouriteCount = S::FavouriteCount;
+        type Title = S::Title;
+        type Media = S::Media;
+        type CreatedAt = S::CreatedAt;
+    }
+    ///State transition - sets the `viewer` field to Set
+    pub struct SetViewer<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetViewer<S> {}
+    impl<S: State> State for SetViewer<S> {
+        type IndexedAt = S::IndexedAt;
+        type Viewer = Set<members::viewer>;
+        type Author = S::Author;
+        type Uri = S::Uri;
+        type FavouriteCount = S::FavouriteCount;
+        type Title = S::Title;
+        type Media = S::Media;
+        type CreatedAt = S::CreatedAt;
+    }
+    ///State transition - sets the `author` field to Set
+    pub struct SetAuthor<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetAuthor<S> {}
+    impl<S: State> State for SetAuthor<S> {
+        type IndexedAt = S::IndexedAt;
+        type Viewer = S::Viewer;
+        type Author = Set<members::author>;
+        type Uri = S::Uri;
+        type FavouriteCount = S::FavouriteCount;
+        type Title = S::Title;
+        type Media = S::Media;
+        type CreatedAt = S::CreatedAt;
+    }
+    ///State transition - sets the `uri` field to Set
+    pub struct SetUri<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetUri<S> {}
+    impl<S: State> State for SetUri<S> {
+        type IndexedAt = S::IndexedAt;
+        type Viewer = S::Viewer;
+        type Author = S::Author;
+        type Uri = Set<members::uri>;
+        type FavouriteCount = S::FavouriteCount;
+        type Title = S::Title;
+        type Media = S::Media;
+        type CreatedAt = S::CreatedAt;
+    }
+    ///State transition - sets the `favourite_count` field to Set
+    pub struct SetFavouriteCount<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetFavouriteCount<S> {}
+    impl<S: State> State for SetFavouriteCount<S> {
+        type IndexedAt = S::IndexedAt;
+        type Viewer = S::Viewer;
+        type Author = S::Author;
+        type Uri = S::Uri;
+        type FavouriteCount = Set<members::favourite_count>;
+        type Title = S::Title;
+        type Media = S::Media;
+        type CreatedAt = S::CreatedAt;
+    }
+    ///State transition - sets the `title` field to Set
+    pub struct SetTitle<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetTitle<S> {}
+    impl<S: State> State for SetTitle<S> {
+        type IndexedAt = S::IndexedAt;
+        type Viewer = S::Viewer;
+        type Author = S::Author;
+        type Uri = S::Uri;
+        type FavouriteCount = S::FavouriteCount;
+        type Title = Set<members::title>;
+        type Media = S::Media;
+        type CreatedAt = S::CreatedAt;
+    }
+    ///State transition - sets the `media` field to Set
+    pub struct SetMedia<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetMedia<S> {}
+    impl<S: State> State for SetMedia<S> {
+        type IndexedAt = S::IndexedAt;
+        type Viewer = S::Viewer;
+        type Author = S::Author;
+        type Uri = S::Uri;
+        type FavouriteCount = S::FavouriteCount;
+        type Title = S::Title;
+        type Media = Set<members::media>;
+        type CreatedAt = S::CreatedAt;
+    }
+    ///State transition - sets the `created_at` field to Set
+    pub struct SetCreatedAt<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetCreatedAt<S> {}
+    impl<S: State> State for SetCreatedAt<S> {
+        type IndexedAt = S::IndexedAt;
+        type Viewer = S::Viewer;
+        type Author = S::Author;
+        type Uri = S::Uri;
+        type FavouriteCount = S::FavouriteCount;
+        type Title = S::Title;
+        type Media = S::Media;
+        type CreatedAt = Set<members::created_at>;
+    }
+    /// Marker types for field names
+    #[allow(non_camel_case_types)]
+    pub mod members {
+        ///Marker type for the `indexed_at` field
+        pub struct indexed_at(());
+        ///Marker type for the `viewer` field
+        pub struct viewer(());
+        ///Marker type for the `author` field
+        pub struct author(());
+        ///Marker type for the `uri` field
+        pub struct uri(());
+        ///Marker type for the `favourite_count` field
+        pub struct favourite_count(());
+        ///Marker type for the `title` field
+        pub struct title(());
+        ///Marker type for the `media` field
+        pub struct media(());
+        ///Marker type for the `created_at` field
+        pub struct created_at(());
+    }
+}
+
+/// Builder for constructing an instance of this type
+pub struct PostFeedViewBuilder<'a, S: post_feed_view_state::State> {
+    _phantom_state: ::core::marker::PhantomData<fn() -> S>,
+    __unsafe_private_named: (
+        ::core::option::Option<crate::net_gifdex::actor::ProfileViewBasic<'a>>,
+        ::core::option::Option<jacquard_common::types::string::Datetime>,
+        ::core::option::Option<jacquard_common::types::string::Datetime>,
+        ::core::option::Option<i64>,
+        ::core::option::Option<jacquard_common::types::string::Datetime>,
+        ::core::option::Option<crate::net_gifdex::feed::PostViewMedia<'a>>,
+        ::core::option::Option<Vec<crate::net_gifdex::feed::SelfLabelView<'a>>>,
+        ::core::option::Option<Vec<jacquard_common::CowStr<'a>>>,
+        ::core::option::Option<jacquard_common::CowStr<'a>>,
+        ::core::option::Option<jacquard_common::types::string::AtUri<'a>>,
+        ::core::option::Option<crate::net_gifdex::feed::ViewerState<'a>>,
+    ),
+    _phantom: ::core::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> PostFeedView<'a> {
+    /// Create a new builder for this type
+    pub fn new() -> PostFeedViewBuilder<'a, post_feed_view_state::Empty> {
+        PostFeedViewBuilder::new()
+    }
+}
+
+impl<'a> PostFeedViewBuilder<'a, post_feed_view_state::Empty> {
+    /// Create a new builder with all fields unset
+    pub fn new() -> Self {
+        PostFeedViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: (
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ),
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> PostFeedViewBuilder<'a, S>
+where
+    S: post_feed_view_state::State,
+    S::Author: post_feed_view_state::IsUnset,
+{
+    /// Set the `author` field (required)
+    pub fn author(
+        mut self,
+        value: impl Into<crate::net_gifdex::actor::ProfileViewBasic<'a>>,
+    ) -> PostFeedViewBuilder<'a, post_feed_view_state::SetAuthor<S>> {
+        self.__unsafe_private_named.0 = ::core::option::Option::Some(value.into());
+        PostFeedViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> PostFeedViewBuilder<'a, S>
+where
+    S: post_feed_view_state::State,
+    S::CreatedAt: post_feed_view_state::IsUnset,
+{
+    /// Set the `createdAt` field (required)
+    pub fn created_at(
+        mut self,
+        value: impl Into<jacquard_common::types::string::Datetime>,
+    ) -> PostFeedViewBuilder<'a, post_feed_view_state::SetCreatedAt<S>> {
+        self.__unsafe_private_named.1 = ::core::option::Option::Some(value.into());
+        PostFeedViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: post_feed_view_state::State> PostFeedViewBuilder<'a, S> {
+    /// Set the `editedAt` field (optional)
+    pub fn edited_at(
+        mut self,
+        value: impl Into<Option<jacquard_common::types::string::Datetime>>,
+    ) -> Self {
+        self.__unsafe_private_named.2 = value.into();
+        self
+    }
+    /// Set the `editedAt` field to an Option value (optional)
+    pub fn maybe_edited_at(
+        mut self,
+        value: Option<jacquard_common::types::string::Datetime>,
+    ) -> Self {
+        self.__unsafe_private_named.2 = value;
+        self
+    }
+}
+
+impl<'a, S> PostFeedViewBuilder<'a, S>
+where
+    S: post_feed_view_state::State,
+    S::FavouriteCount: post_feed_view_state::IsUnset,
+{
+    /// Set the `favouriteCount` field (required)
+    pub fn favourite_count(
+        mut self,
+        value: impl Into<i64>,
+    ) -> PostFeedViewBuilder<'a, post_feed_view_state::SetFavouriteCount<S>> {
+        self.__unsafe_private_named.3 = ::core::option::Option::Some(value.into());
+        PostFeedViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> PostFeedViewBuilder<'a, S>
+where
+    S: post_feed_view_state::State,
+    S::IndexedAt: post_feed_view_state::IsUnset,
+{
+    /// Set the `indexedAt` field (required)
+    pub fn indexed_at(
+        mut self,
+        value: impl Into<jacquard_common::types::string::Datetime>,
+    ) -> PostFeedViewBuilder<'a, post_feed_view_state::SetIndexedAt<S>> {
+        self.__unsafe_private_named.4 = ::core::option::Option::Some(value.into());
+        PostFeedViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> PostFeedViewBuilder<'a, S>
+where
+    S: post_feed_view_state::State,
+    S::Media: post_feed_view_state::IsUnset,
+{
+    /// Set the `media` field (required)
+    pub fn media(
+        mut self,
+        value: impl Into<crate::net_gifdex::feed::PostViewMedia<'a>>,
+    ) -> PostFeedViewBuilder<'a, post_feed_view_state::SetMedia<S>> {
+        self.__unsafe_private_named.5 = ::core::option::Option::Some(value.into());
+        PostFeedViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: post_feed_view_state::State> PostFeedViewBuilder<'a, S> {
+    /// Set the `selfLabels` field (optional)
+    pub fn self_labels(
+        mut self,
+        value: impl Into<Option<Vec<crate::net_gifdex::feed::SelfLabelView<'a>>>>,
+    ) -> Self {
+        self.__unsafe_private_named.6 = value.into();
+        self
+    }
+    /// Set the `selfLabels` field to an Option value (optional)
+    pub fn maybe_self_labels(
+        mut self,
+        value: Option<Vec<crate::net_gifdex::feed::SelfLabelView<'a>>>,
+    ) -> Self {
+        self.__unsafe_private_named.6 = value;
+        self
+    }
+}
+
+impl<'a, S: post_feed_view_state::State> PostFeedViewBuilder<'a, S> {
+    /// Set the `tags` field (optional)
+    pub fn tags(
+        mut self,
+        value: impl Into<Option<Vec<jacquard_common::CowStr<'a>>>>,
+    ) -> Self {
+        self.__unsafe_private_named.7 = value.into();
+        self
+    }
+    /// Set the `tags` field to an Option value (optional)
+    pub fn maybe_tags(
+        mut self,
+        value: Option<Vec<jacquard_common::CowStr<'a>>>,
+    ) -> Self {
+        self.__unsafe_private_named.7 = value;
+        self
+    }
+}
+
+impl<'a, S> PostFeedViewBuilder<'a, S>
+where
+    S: post_feed_view_state::State,
+    S::Title: post_feed_view_state::IsUnset,
+{
+    /// Set the `title` field (required)
+    pub fn title(
+        mut self,
+        value: impl Into<jacquard_common::CowStr<'a>>,
+    ) -> PostFeedViewBuilder<'a, post_feed_view_state::SetTitle<S>> {
+        self.__unsafe_private_named.8 = ::core::option::Option::Some(value.into());
+        PostFeedViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> PostFeedViewBuilder<'a, S>
+where
+    S: post_feed_view_state::State,
+    S::Uri: post_feed_view_state::IsUnset,
+{
+    /// Set the `uri` field (required)
+    pub fn uri(
+        mut self,
+        value: impl Into<jacquard_common::types::string::AtUri<'a>>,
+    ) -> PostFeedViewBuilder<'a, post_feed_view_state::SetUri<S>> {
+        self.__unsafe_private_named.9 = ::core::option::Option::Some(value.into());
+        PostFeedViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> PostFeedViewBuilder<'a, S>
+where
+    S: post_feed_view_state::State,
+    S::Viewer: post_feed_view_state::IsUnset,
+{
+    /// Set the `viewer` field (required)
+    pub fn viewer(
+        mut self,
+        value: impl Into<crate::net_gifdex::feed::ViewerState<'a>>,
+    ) -> PostFeedViewBuilder<'a, post_feed_view_state::SetViewer<S>> {
+        self.__unsafe_private_named.10 = ::core::option::Option::Some(value.into());
+        PostFeedViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> PostFeedViewBuilder<'a, S>
+where
+    S: post_feed_view_state::State,
+    S::IndexedAt: post_feed_view_state::IsSet,
+    S::Viewer: post_feed_view_state::IsSet,
+    S::Author: post_feed_view_state::IsSet,
+    S::Uri: post_feed_view_state::IsSet,
+    S::FavouriteCount: post_feed_view_state::IsSet,
+    S::Title: post_feed_view_state::IsSet,
+    S::Media: post_feed_view_state::IsSet,
+    S::CreatedAt: post_feed_view_state::IsSet,
+{
+    /// Build the final struct
+    pub fn build(self) -> PostFeedView<'a> {
+        PostFeedView {
+            author: self.__unsafe_private_named.0.unwrap(),
+            created_at: self.__unsafe_private_named.1.unwrap(),
+            edited_at: self.__unsafe_private_named.2,
+            favourite_count: self.__unsafe_private_named.3.unwrap(),
+            indexed_at: self.__unsafe_private_named.4.unwrap(),
+            media: self.__unsafe_private_named.5.unwrap(),
+            self_labels: self.__unsafe_private_named.6,
+            tags: self.__unsafe_private_named.7,
+            title: self.__unsafe_private_named.8.unwrap(),
+            uri: self.__unsafe_private_named.9.unwrap(),
+            viewer: self.__unsafe_private_named.10.unwrap(),
+            extra_data: Default::default(),
+        }
+    }
+    /// Build the final struct with custom extra_data
+    pub fn build_with_data(
+        self,
+        extra_data: std::collections::BTreeMap<
+            jacquard_common::smol_str::SmolStr,
+            jacquard_common::types::value::Data<'a>,
+        >,
+    ) -> PostFeedView<'a> {
+        PostFeedView {
+            author: self.__unsafe_private_named.0.unwrap(),
+            created_at: self.__unsafe_private_named.1.unwrap(),
+            edited_at: self.__unsafe_private_named.2,
+            favourite_count: self.__unsafe_private_named.3.unwrap(),
+            indexed_at: self.__unsafe_private_named.4.unwrap(),
+            media: self.__unsafe_private_named.5.unwrap(),
+            self_labels: self.__unsafe_private_named.6,
+            tags: self.__unsafe_private_named.7,
+            title: self.__unsafe_private_named.8.unwrap(),
+            uri: self.__unsafe_private_named.9.unwrap(),
+            viewer: self.__unsafe_private_named.10.unwrap(),
+            extra_data: Some(extra_data),
+        }
+    }
+}
+
+fn lexicon_doc_net_gifdex_feed_defs() -> ::jacquard_lexicon::lexicon::LexiconDoc<
+    'static,
+> {
+    ::jacquard_lexicon::lexicon::LexiconDoc {
+        lexicon: ::jacquard_lexicon::lexicon::Lexicon::Lexicon1,
+        id: ::jacquard_common::CowStr::new_static("net.gifdex.feed.defs"),
+        revision: None,
+        description: None,
+        defs: {
+            let mut map = ::alloc::collections::BTreeMap::new();
+            map.insert(
+                ::jacquard_common::smol_str::SmolStr::new_static("collectionView"),
+                ::jacquard_lexicon::lexicon::LexUserType::Object(::jacquard_lexicon::lexicon::LexObject {
+                    description: Some(
+                        ::jacquard_common::CowStr::new_static(
+                            "View of a collection of posts, without its items.",
+                        ),
+                    ),
+                    required: Some(
+                        vec![
+                            ::jacquard_common::smol_str::SmolStr::new_static("uri"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("author"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("title"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("ordering"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("itemCount"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("createdAt"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("indexedAt")
+                        ],
+                    ),
+                    nullable: None,
+                    properties: {
+                        #[allow(unused_mut)]
+                        let mut map = ::alloc::collections::BTreeMap::new();
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("author"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
+                                description: None,
+                                r#ref: ::jacquard_common::CowStr::new_static(
+                                    "net.gifdex.actor.defs#profileViewBasic",
+                                ),
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "createdAt",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Datetime,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "description",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: None,
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "indexedAt",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Datetime,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "itemCount",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Integer(::jacquard_lexicon::lexicon::LexInteger {
+                                description: None,
+                                default: None,
+                                minimum: None,
+                                maximum: None,
+                                r#enum: None,
+                                r#const: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("ordering"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: None,
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("title"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: None,
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("uri"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::AtUri,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map
+                    },
+                }),
+            );
+            map.insert(
+                ::jacquard_common::smol_str::SmolStr::new_static("postFeedView"),
+                ::jacquard_lexicon::lexicon::LexUserType::Object(::jacquard_lexicon::lexicon::LexObject {
+                    description: Some(
+                        ::jacquard_common::CowStr::new_static(
+                            "Feed-optimized view of a post with all metadata needed for display in timelines.",
+                        ),
+                    ),
+                    required: Some(
+                        vec![
+                            ::jacquard_common::smol_str::SmolStr::new_static("uri"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("author"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("title"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("media"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("favouriteCount"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("viewer"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("createdAt"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("indexedAt")
+                        ],
+                    ),
+                    nullable: None,
+                    properties: {
+                        #[allow(unused_mut)]
+                        let mut map = ::alloc::collections::BTreeMap::new();
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("author"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
+                                description: None,
+                                r#ref: ::jacquard_common::CowStr::new_static(
+                                    "net.gifdex.actor.defs#profileViewBasic",
+                                ),
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "createdAt",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Datetime,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("editedAt"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Datetime,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "favouriteCount",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Integer(::jacquard_lexicon::lexicon::LexInteger {
+                                description: None,
+                                default: None,
+                                minimum: None,
+                                maximum: None,
+                                r#enum: None,
+                                r#const: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "indexedAt",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Datetime,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("media"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
+                                description: None,
+                                r#ref: ::jacquard_common::CowStr::new_static(
+                                    "#postViewMedia",
+                                ),
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "selfLabels",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Array(::jacquard_lexicon::lexicon::LexArray {
+                                description: Some(
+                                    ::jacquard_common::CowStr::new_static(
+                                        "Labels the author applied to their own post.",
+                                    ),
+                                ),
+                                items: ::jacquard_lexicon::lexicon::LexArrayItem::Ref(::jacquard_lexicon::lexicon::LexRef {
+                                    description: None,
+                                    r#ref: ::jacquard_common::CowStr::new_static(
+                                        "#selfLabelView",
+                                    ),
+                                }),
+                                min_length: None,
+                                max_length: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("tags"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Array(::jacquard_lexicon::lexicon::LexArray {
+                                description: None,
+                                items: ::jacquard_lexicon::lexicon::LexArrayItem::String(::jacquard_lexicon::lexicon::LexString {
+                                    description: None,
+                                    format: None,
+                                    default: None,
+                                    min_length: None,
+                                    max_length: None,
+                                    min_graphemes: None,
+                                    max_graphemes: None,
+                                    r#enum: None,
+                                    r#const: None,
+                                    known_values: None,
+                                }),
+                                min_length: None,
+                                max_length: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("title"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: None,
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("uri"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::AtUri,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("viewer"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
+                                description: None,
+                                r#ref: ::jacquard_common::CowStr::new_static("#viewerState"),
+                            }),
+                        );
+                        map
+                    },
+                }),
+            );
+            map.insert(
+                ::jacquard_common::smol_str::SmolStr::new_static("postView"),
+                ::jacquard_lexicon::lexicon::LexUserType::Object(::jacquard_lexicon::lexicon::LexObject {
+                    description: Some(
+                        ::jacquard_common::CowStr::new_static(
+                            "Detailed view of a post, including author information and engagement metrics.",
+                        ),
+                    ),
+                    required: Some(
+                        vec![
+                            ::jacquard_common::smol_str::SmolStr::new_static("uri"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("author"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("title"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("media"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("favouriteCount"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("viewer"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("createdAt"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("indexedAt")
+                        ],
+                    ),
+                    nullable: None,
+                    properties: {
+                        #[allow(unused_mut)]
+                        let mut map = ::alloc::collections::BTreeMap::new();
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("author"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
+                                description: None,
+                                r#ref: ::jacquard_common::CowStr::new_static(
+                                    "net.gifdex.actor.defs#profileViewBasic",
+                                ),
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "createdAt",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Datetime,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("editedAt"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Datetime,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "favouriteCount",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Integer(::jacquard_lexicon::lexicon::LexInteger {
+                                description: None,
+                                default: None,
+                                minimum: None,
+                                maximum: None,
+                                r#enum: None,
+                                r#const: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "indexedAt",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Datetime,
+                                ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("media"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
+                                description: None,
+                                r#ref: ::jacquard_common::CowStr::new_static(
+                                    "#postViewMedia",
+                                ),
+                            }),
+                        );
+                        map.insert(
                             ::jacquard_common::smol_str::SmolStr::new_static(
-                                "createdAt",
+                                "selfLabels",
                             ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Array(::jacquard_lexicon::lexicon::LexArray {
+                                description: Some(
+                                    ::jacquard_common::CowStr::new_static(
+                                        "Labels the author applied to their own post.",
+                                    ),
+                                ),
+                                items: ::jacquard_lexicon::lexicon::LexArrayItem::Ref(::jacquard_lexicon::lexicon::LexRef {
+                                    description: None,
+                                    r#ref: ::jacquard_common::CowStr::new_static(
+                                        "#selfLabelView",
+                                    ),
+                                }),
+                                min_length: None,
+                                max_length: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("tags"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Array(::jacquard_lexicon::lexicon::LexArray {
+                                description: None,
+                                items: ::jacquard_lexicon::lexicon::LexArrayItem::String(::jacquard_lexicon::lexicon::LexString {
+                                    description: None,
+                                    format: None,
+                                    default: None,
+                                    min_length: None,
+                                    max_length: None,
+                                    min_graphemes: None,
+                                    max_graphemes: None,
+                                    r#enum: None,
+                                    r#const: None,
+                                    known_values: None,
+                                }),
+                                min_length: None,
+                                max_length: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("title"),
                             ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
                                 description: None,
-                                format: Some(
-                                    ::jacquard_lexicon::lexicon::LexStringFormat::Datetime,
-                                ),
+                                format: None,
                                 default: None,
                                 min_length: None,
                                 max_length: None,
@@ -727,11 +1460,11 @@ fn lexicon_doc_net_gifdex_feed_defs() -> ::jacquard_lexicon::lexicon::LexiconDoc
                             }),
                         );
                         map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("editedAt"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("uri"),
                             ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
                                 description: None,
                                 format: Some(
-                                    ::jacquard_lexicon::lexicon::LexStringFormat::Datetime,
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::AtUri,
                                 ),
                                 default: None,
                                 min_length: None,
@@ -743,9 +1476,77 @@ fn lexicon_doc_net_gifdex_feed_defs() -> ::jacquard_lexicon::lexicon::LexiconDoc
                                 known_values: None,
                             }),
                         );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("viewer"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
+                                description: None,
+                                r#ref: ::jacquard_common::CowStr::new_static("#viewerState"),
+                            }),
+                        );
+                        map
+                    },
+                }),
+            );
+            map.insert(
+                ::jacquard_common::smol_str::SmolStr::new_static("postViewMedia"),
+                ::jacquard_lexicon::lexicon::LexUserType::Object(::jacquard_lexicon::lexicon::LexObject {
+                    description: Some(
+                        ::jacquard_common::CowStr::new_static(
+                            "A post's media content with alongside its dimensions data",
+                        ),
+                    ),
+                    required: Some(
+                        vec![
+                            ::jacquard_common::smol_str::SmolStr::new_static("fullsizeUrl"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("thumbnailUrl"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("mimeType"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("dimensions")
+                        ],
+                    ),
+                    nullable: None,
+                    properties: {
+                        #[allow(unused_mut)]
+                        let mut map = ::alloc::collections::BTreeMap::new();
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("alt"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: None,
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
                         map.insert(
                             ::jacquard_common::smol_str::SmolStr::new_static(
-                                "favouriteCount",
+                                "dimensions",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
+                                description: None,
+                                r#ref: ::jacquard_common::CowStr::new_static(
+                                    "#postViewMediaDimensions",
+                                ),
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("duration"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Integer(::jacquard_lexicon::lexicon::LexInteger {
+                                description: None,
+                                default: None,
+                                minimum: None,
+                                maximum: None,
+                                r#enum: None,
+                                r#const: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "frameCount",
                             ),
                             ::jacquard_lexicon::lexicon::LexObjectProperty::Integer(::jacquard_lexicon::lexicon::LexInteger {
                                 description: None,
@@ -758,12 +1559,12 @@ fn lexicon_doc_net_gifdex_feed_defs() -> ::jacquard_lexicon::lexicon::LexiconDoc
                         );
                         map.insert(
                             ::jacquard_common::smol_str::SmolStr::new_static(
-                                "indexedAt",
+                                "fullsizeUrl",
                             ),
                             ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
                                 description: None,
                                 format: Some(
-                                    ::jacquard_lexicon::lexicon::LexStringFormat::Datetime,
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Uri,
                                 ),
                                 default: None,
                                 min_length: None,
@@ -776,36 +1577,138 @@ fn lexicon_doc_net_gifdex_feed_defs() -> ::jacquard_lexicon::lexicon::LexiconDoc
                             }),
                         );
                         map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("media"),
-                            ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
+                            ::jacquard_common::smol_str::SmolStr::new_static("mimeType"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
                                 description: None,
-                                r#ref: ::jacquard_common::CowStr::new_static(
-                                    "#postViewMedia",
+                                format: None,
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("size"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Integer(::jacquard_lexicon::lexicon::LexInteger {
+                                description: None,
+                                default: None,
+                                minimum: None,
+                                maximum: None,
+                                r#enum: None,
+                                r#const: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "thumbnailUrl",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
+                                description: None,
+                                format: Some(
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Uri,
                                 ),
+                                default: None,
+                                min_length: None,
+                                max_length: None,
+                                min_graphemes: None,
+                                max_graphemes: None,
+                                r#enum: None,
+                                r#const: None,
+                                known_values: None,
                             }),
                         );
                         map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("tags"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("variants"),
                             ::jacquard_lexicon::lexicon::LexObjectProperty::Array(::jacquard_lexicon::lexicon::LexArray {
-                                description: None,
-                                items: ::jacquard_lexicon::lexicon::LexArrayItem::String(::jacquard_lexicon::lexicon::LexString {
+                                description: Some(
+                                    ::jacquard_common::CowStr::new_static(
+                                        "Alternative encodings of the media that can be requested from the CDN, including the original.",
+                                    ),
+                                ),
+                                items: ::jacquard_lexicon::lexicon::LexArrayItem::Ref(::jacquard_lexicon::lexicon::LexRef {
                                     description: None,
-                                    format: None,
-                                    default: None,
-                                    min_length: None,
-                                    max_length: None,
-                                    min_graphemes: None,
-                                    max_graphemes: None,
-                                    r#enum: None,
-                                    r#const: None,
-                                    known_values: None,
+                                    r#ref: ::jacquard_common::CowStr::new_static(
+                                        "#postViewMediaVariant",
+                                    ),
                                 }),
                                 min_length: None,
                                 max_length: None,
                             }),
                         );
+                        map
+                    },
+                }),
+            );
+            map.insert(
+                ::jacquard_common::smol_str::SmolStr::new_static(
+                    "postViewMediaDimensions",
+                ),
+                ::jacquard_lexicon::lexicon::LexUserType::Object(::jacquard_lexicon::lexicon::LexObject {
+                    description: Some(
+                        ::jacquard_common::CowStr::new_static(
+                            "Width and height of a post's media.",
+                        ),
+                    ),
+                    required: Some(
+                        vec![
+                            ::jacquard_common::smol_str::SmolStr::new_static("width"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("height")
+                        ],
+                    ),
+                    nullable: None,
+                    properties: {
+                        #[allow(unused_mut)]
+                        let mut map = ::alloc::collections::BTreeMap::new();
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("height"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Integer(::jacquard_lexicon::lexicon::LexInteger {
+                                description: None,
+                                default: None,
+                                minimum: None,
+                                maximum: None,
+                                r#enum: None,
+                                r#const: None,
+                            }),
+                        );
                         map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("title"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("width"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Integer(::jacquard_lexicon::lexicon::LexInteger {
+                                description: None,
+                                default: None,
+                                minimum: None,
+                                maximum: None,
+                                r#enum: None,
+                                r#const: None,
+                            }),
+                        );
+                        map
+                    },
+                }),
+            );
+            map.insert(
+                ::jacquard_common::smol_str::SmolStr::new_static("postViewMediaVariant"),
+                ::jacquard_lexicon::lexicon::LexUserType::Object(::jacquard_lexicon::lexicon::LexObject {
+                    description: Some(
+                        ::jacquard_common::CowStr::new_static(
+                            "A single encoding of a post's media.",
+                        ),
+                    ),
+                    required: Some(
+                        vec![
+                            ::jacquard_common::smol_str::SmolStr::new_static("url"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("mimeType")
+                        ],
+                    ),
+                    nullable: None,
+                    properties: {
+                        #[allow(unused_mut)]
+                        let mut map = ::alloc::collections::BTreeMap::new();
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("mimeType"),
                             ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
                                 description: None,
                                 format: None,
@@ -820,11 +1723,22 @@ fn lexicon_doc_net_gifdex_feed_defs() -> ::jacquard_lexicon::lexicon::LexiconDoc
                             }),
                         );
                         map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("uri"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("size"),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Integer(::jacquard_lexicon::lexicon::LexInteger {
+                                description: None,
+                                default: None,
+                                minimum: None,
+                                maximum: None,
+                                r#enum: None,
+                                r#const: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static("url"),
                             ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
                                 description: None,
                                 format: Some(
-                                    ::jacquard_lexicon::lexicon::LexStringFormat::AtUri,
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::Uri,
                                 ),
                                 default: None,
                                 min_length: None,
@@ -836,31 +1750,25 @@ fn lexicon_doc_net_gifdex_feed_defs() -> ::jacquard_lexicon::lexicon::LexiconDoc
                                 known_values: None,
                             }),
                         );
-                        map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("viewer"),
-                            ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
-                                description: None,
-                                r#ref: ::jacquard_common::CowStr::new_static("#viewerState"),
-                            }),
-                        );
                         map
                     },
                 }),
             );
             map.insert(
-                ::jacquard_common::smol_str::SmolStr::new_static("postViewMedia"),
+                ::jacquard_common::smol_str::SmolStr::new_static("selfLabelView"),
                 ::jacquard_lexicon::lexicon::LexUserType::Object(::jacquard_lexicon::lexicon::LexObject {
                     description: Some(
                         ::jacquard_common::CowStr::new_static(
-                            "A post's media content with alongside its dimensions data",
+                            "A labeler rule that a post's author applied to their own post.",
                         ),
                     ),
                     required: Some(
                         vec![
-                            ::jacquard_common::smol_str::SmolStr::new_static("fullsizeUrl"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("thumbnailUrl"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("mimeType"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("dimensions")
+                            ::jacquard_common::smol_str::SmolStr::new_static("rule"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("name"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("description"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("adultContent"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("defaultSetting")
                         ],
                     ),
                     nullable: None,
@@ -868,7 +1776,19 @@ fn lexicon_doc_net_gifdex_feed_defs() -> ::jacquard_lexicon::lexicon::LexiconDoc
                         #[allow(unused_mut)]
                         let mut map = ::alloc::collections::BTreeMap::new();
                         map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("alt"),
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "adultContent",
+                            ),
+                            ::jacquard_lexicon::lexicon::LexObjectProperty::Boolean(::jacquard_lexicon::lexicon::LexBoolean {
+                                description: None,
+                                default: None,
+                                r#const: None,
+                            }),
+                        );
+                        map.insert(
+                            ::jacquard_common::smol_str::SmolStr::new_static(
+                                "defaultSetting",
+                            ),
                             ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
                                 description: None,
                                 format: None,
@@ -884,24 +1804,11 @@ fn lexicon_doc_net_gifdex_feed_defs() -> ::jacquard_lexicon::lexicon::LexiconDoc
                         );
                         map.insert(
                             ::jacquard_common::smol_str::SmolStr::new_static(
-                                "dimensions",
-                            ),
-                            ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
-                                description: None,
-                                r#ref: ::jacquard_common::CowStr::new_static(
-                                    "#postViewMediaDimensions",
-                                ),
-                            }),
-                        );
-                        map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static(
-                                "fullsizeUrl",
+                                "description",
                             ),
                             ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
                                 description: None,
-                                format: Some(
-                                    ::jacquard_lexicon::lexicon::LexStringFormat::Uri,
-                                ),
+                                format: None,
                                 default: None,
                                 min_length: None,
                                 max_length: None,
@@ -913,7 +1820,7 @@ fn lexicon_doc_net_gifdex_feed_defs() -> ::jacquard_lexicon::lexicon::LexiconDoc
                             }),
                         );
                         map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("mimeType"),
+                            ::jacquard_common::smol_str::SmolStr::new_static("name"),
                             ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
                                 description: None,
                                 format: None,
@@ -928,13 +1835,11 @@ fn lexicon_doc_net_gifdex_feed_defs() -> ::jacquard_lexicon::lexicon::LexiconDoc
                             }),
                         );
                         map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static(
-                                "thumbnailUrl",
-                            ),
+                            ::jacquard_common::smol_str::SmolStr::new_static("rule"),
                             ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
                                 description: None,
                                 format: Some(
-                                    ::jacquard_lexicon::lexicon::LexStringFormat::Uri,
+                                    ::jacquard_lexicon::lexicon::LexStringFormat::AtUri,
                                 ),
                                 default: None,
                                 min_length: None,
@@ -950,52 +1855,6 @@ fn lexicon_doc_net_gifdex_feed_defs() -> ::jacquard_lexicon::lexicon::LexiconDoc
                     },
                 }),
             );
-            map.insert(
-                ::jacquard_common::smol_str::SmolStr::new_static(
-                    "postViewMediaDimensions",
-                ),
-                ::jacquard_lexicon::lexicon::LexUserType::Object(::jacquard_lexicon::lexicon::LexObject {
-                    description: Some(
-                        ::jacquard_common::CowStr::new_static(
-                            "Width and height of a post's media.",
-                        ),
-                    ),
-                    required: Some(
-                        vec![
-                            ::jacquard_common::smol_str::SmolStr::new_static("width"),
-                            ::jacquard_common::smol_str::SmolStr::new_static("height")
-                        ],
-                    ),
-                    nullable: None,
-                    properties: {
-                        #[allow(unused_mut)]
-                        let mut map = ::alloc::collections::BTreeMap::new();
-                        map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("height"),
-                            ::jacquard_lexicon::lexicon::LexObjectProperty::Integer(::jacquard_lexicon::lexicon::LexInteger {
-                                description: None,
-                                default: None,
-                                minimum: None,
-                                maximum: None,
-                                r#enum: None,
-                                r#const: None,
-                            }),
-                        );
-                        map.insert(
-                            ::jacquard_common::smol_str::SmolStr::new_static("width"),
-                            ::jacquard_lexicon::lexicon::LexObjectProperty::Integer(::jacquard_lexicon::lexicon::LexInteger {
-                                description: None,
-                                default: None,
-                                minimum: None,
-                                maximum: None,
-                                r#enum: None,
-                                r#const: None,
-                            }),
-                        );
-                        map
-                    },
-                }),
-            );
             map.insert(
                 ::jacquard_common::smol_str::SmolStr::new_static("viewerState"),
                 ::jacquard_lexicon::lexicon::LexUserType::Object(::jacquard_lexicon::lexicon::LexObject {
@@ -1037,12 +1896,538 @@ fn lexicon_doc_net_gifdex_feed_defs() -> ::jacquard_lexicon::lexicon::LexiconDoc
     }
 }
 
-impl<'a> ::jacquard_lexicon::schema::LexiconSchema for PostFeedView<'a> {
+impl<'a> ::jacquard_lexicon::schema::LexiconSchema for PostFeedView<'a> {
+    fn nsid() -> &'static str {
+        "net.gifdex.feed.defs"
+    }
+    fn def_name() -> &'static str {
+        "postFeedView"
+    }
+    fn lexicon_doc() -> ::jacquard_lexicon::lexicon::LexiconDoc<'static> {
+        lexicon_doc_net_gifdex_feed_defs()
+    }
+    fn validate(
+        &self,
+    ) -> ::core::result::Result<(), ::jacquard_lexicon::validation::ConstraintError> {
+        Ok(())
+    }
+}
+
+/// Detailed view of a post, including author information and engagement metrics.
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PostView<'a> {
+    #[serde(borrow)]
+    pub author: crate::net_gifdex::actor::ProfileViewBasic<'a>,
+    pub created_at: jacquard_common::types::string::Datetime,
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub edited_at: std::option::Option<jacquard_common::types::string::Datetime>,
+    pub favourite_count: i64,
+    pub indexed_at: jacquard_common::types::string::Datetime,
+    #[serde(borrow)]
+    pub media: crate::net_gifdex::feed::PostViewMedia<'a>,
+    /// Labels the author applied to their own post.
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    #[serde(borrow)]
+    pub self_labels: std::option::Option<
+        Vec<crate::net_gifdex::feed::SelfLabelView<'a>>,
+    >,
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    #[serde(borrow)]
+    pub tags: std::option::Option<Vec<jacquard_common::CowStr<'a>>>,
+    #[serde(borrow)]
+    pub title: jacquard_common::CowStr<'a>,
+    #[serde(borrow)]
+    pub uri: jacquard_common::types::string::AtUri<'a>,
+    #[serde(borrow)]
+    pub viewer: crate::net_gifdex::feed::ViewerState<'a>,
+}
+
+pub mod post_view_state {
+
+    pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
+    #[allow(unused)]
+    use ::core::marker::PhantomData;
+    mod sealed {
+        pub trait Sealed {}
+    }
+    /// State trait tracking which required fields have been set
+    pub trait State: sealed::Sealed {
+        type Media;
+        type Title;
+        type Author;
+        type CreatedAt;
+        type FavouriteCount;
+        type Uri;
+        type Viewer;
+        type IndexedAt;
+    }
+    /// Empty state - all required fields are unset
+    pub struct Empty(());
+    impl sealed::Sealed for Empty {}
+    impl State for Empty {
+        type Media = Unset;
+        type Title = Unset;
+        type Author = Unset;
+        type CreatedAt = Unset;
+        type FavouriteCount = Unset;
+        type Uri = Unset;
+        type Viewer = Unset;
+        type IndexedAt = Unset;
+    }
+    ///State transition - sets the `media` field to Set
+    pub struct SetMedia<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetMedia<S> {}
+    impl<S: State> State for SetMedia<S> {
+        type Media = Set<members::media>;
+        type Title = S::Title;
+        type Author = S::Author;
+        type CreatedAt = S::CreatedAt;
+        type FavouriteCount = S::FavouriteCount;
+        type Uri = S::Uri;
+        type Viewer = S::Viewer;
+        type IndexedAt = S::IndexedAt;
+    }
+    ///State transition - sets the `title` field to Set
+    pub struct SetTitle<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetTitle<S> {}
+    impl<S: State> State for SetTitle<S> {
+        type Media = S::Media;
+        type Title = Set<members::title>;
+        type Author = S::Author;
+        type CreatedAt = S::CreatedAt;
+        type FavouriteCount = S::FavouriteCount;
+        type Uri = S::Uri;
+        type Viewer = S::Viewer;
+        type IndexedAt = S::IndexedAt;
+    }
+    ///State transition - sets the `author` field to Set
+    pub struct SetAuthor<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetAuthor<S> {}
+    impl<S: State> State for SetAuthor<S> {
+        type Media = S::Media;
+        type Title = S::Title;
+        type Author = Set<members::author>;
+        type CreatedAt = S::CreatedAt;
+        type FavouriteCount = S::FavouriteCount;
+        type Uri = S::Uri;
+        type Viewer = S::Viewer;
+        type IndexedAt = S::IndexedAt;
+    }
+    ///State transition - sets the `created_at` field to Set
+    pub struct SetCreatedAt<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetCreatedAt<S> {}
+    impl<S: State> State for SetCreatedAt<S> {
+        type Media = S::Media;
+        type Title = S::Title;
+        type Author = S::Author;
+        type CreatedAt = Set<members::created_at>;
+        type FavouriteCount = S::FavouriteCount;
+        type Uri = S::Uri;
+        type Viewer = S::Viewer;
+        type IndexedAt = S::IndexedAt;
+    }
+    ///State transition - sets the `favourite_count` field to Set
+    pub struct SetFavouriteCount<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetFavouriteCount<S> {}
+    impl<S: State> State for SetFavouriteCount<S> {
+        type Media = S::Media;
+        type Title = S::Title;
+        type Author = S::Author;
+        type CreatedAt = S::CreatedAt;
+        type FavouriteCount = Set<members::favourite_count>;
+        type Uri = S::Uri;
+        type Viewer = S::Viewer;
+        type IndexedAt = S::IndexedAt;
+    }
+    ///State transition - sets the `uri` field to Set
+    pub struct SetUri<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetUri<S> {}
+    impl<S: State> State for SetUri<S> {
+        type Media = S::Media;
+        type Title = S::Title;
+        type Author = S::Author;
+        type CreatedAt = S::CreatedAt;
+        type FavouriteCount = S::FavouriteCount;
+        type Uri = Set<members::uri>;
+        type Viewer = S::Viewer;
+        type IndexedAt = S::IndexedAt;
+    }
+    ///State transition - sets the `viewer` field to Set
+    pub struct SetViewer<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetViewer<S> {}
+    impl<S: State> State for SetViewer<S> {
+        type Media = S::Media;
+        type Title = S::Title;
+        type Author = S::Author;
+        type CreatedAt = S::CreatedAt;
+        type FavouriteCount = S::FavouriteCount;
+        type Uri = S::Uri;
+        type Viewer = Set<members::viewer>;
+        type IndexedAt = S::IndexedAt;
+    }
+    ///State transition - sets the `indexed_at` field to Set
+    pub struct SetIndexedAt<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetIndexedAt<S> {}
+    impl<S: State> State for SetIndexedAt<S> {
+        type Media = S::Media;
+        type Title = S::Title;
+        type Author = S::Author;
+        type CreatedAt = S::CreatedAt;
+        type FavouriteCount = S::FavouriteCount;
+        type Uri = S::Uri;
+        type Viewer = S::Viewer;
+        type IndexedAt = Set<members::indexed_at>;
+    }
+    /// Marker types for field names
+    #[allow(non_camel_case_types)]
+    pub mod members {
+        ///Marker type for the `media` field
+        pub struct media(());
+        ///Marker type for the `title` field
+        pub struct title(());
+        ///Marker type for the `author` field
+        pub struct author(());
+        ///Marker type for the `created_at` field
+        pub struct created_at(());
+        ///Marker type for the `favourite_count` field
+        pub struct favourite_count(());
+        ///Marker type for the `uri` field
+        pub struct uri(());
+        ///Marker type for the `viewer` field
+        pub struct viewer(());
+        ///Marker type for the `indexed_at` field
+        pub struct indexed_at(());
+    }
+}
+
+/// Builder for constructing an instance of this type
+pub struct PostViewBuilder<'a, S: post_view_state::State> {
+    _phantom_state: ::core::marker::PhantomData<fn() -> S>,
+    __unsafe_private_named: (
+        ::core::option::Option<crate::net_gifdex::actor::ProfileViewBasic<'a>>,
+        ::core::option::Option<jacquard_common::types::string::Datetime>,
+        ::core::option::Option<jacquard_common::types::string::Datetime>,
+        ::core::option::Option<i64>,
+        ::core::option::Option<jacquard_common::types::string::Datetime>,
+        ::core::option::Option<crate::net_gifdex::feed::PostViewMedia<'a>>,
+        ::core::option::Option<Vec<crate::net_gifdex::feed::SelfLabelView<'a>>>,
+        ::core::option::Option<Vec<jacquard_common::CowStr<'a>>>,
+        ::core::option::Option<jacquard_common::CowStr<'a>>,
+        ::core::option::Option<jacquard_common::types::string::AtUri<'a>>,
+        ::core::option::Option<crate::net_gifdex::feed::ViewerState<'a>>,
+    ),
+    _phantom: ::core::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> PostView<'a> {
+    /// Create a new builder for this type
+    pub fn new() -> PostViewBuilder<'a, post_view_state::Empty> {
+        PostViewBuilder::new()
+    }
+}
+
+impl<'a> PostViewBuilder<'a, post_view_state::Empty> {
+    /// Create a new builder with all fields unset
+    pub fn new() -> Self {
+        PostViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: (
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ),
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> PostViewBuilder<'a, S>
+where
+    S: post_view_state::State,
+    S::Author: post_view_state::IsUnset,
+{
+    /// Set the `author` field (required)
+    pub fn author(
+        mut self,
+        value: impl Into<crate::net_gifdex::actor::ProfileViewBasic<'a>>,
+    ) -> PostViewBuilder<'a, post_view_state::SetAuthor<S>> {
+        self.__unsafe_private_named.0 = ::core::option::Option::Some(value.into());
+        PostViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> PostViewBuilder<'a, S>
+where
+    S: post_view_state::State,
+    S::CreatedAt: post_view_state::IsUnset,
+{
+    /// Set the `createdAt` field (required)
+    pub fn created_at(
+        mut self,
+        value: impl Into<jacquard_common::types::string::Datetime>,
+    ) -> PostViewBuilder<'a, post_view_state::SetCreatedAt<S>> {
+        self.__unsafe_private_named.1 = ::core::option::Option::Some(value.into());
+        PostViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: post_view_state::State> PostViewBuilder<'a, S> {
+    /// Set the `editedAt` field (optional)
+    pub fn edited_at(
+        mut self,
+        value: impl Into<Option<jacquard_common::types::string::Datetime>>,
+    ) -> Self {
+        self.__unsafe_private_named.2 = value.into();
+        self
+    }
+    /// Set the `editedAt` field to an Option value (optional)
+    pub fn maybe_edited_at(
+        mut self,
+        value: Option<jacquard_common::types::string::Datetime>,
+    ) -> Self {
+        self.__unsafe_private_named.2 = value;
+        self
+    }
+}
+
+impl<'a, S> PostViewBuilder<'a, S>
+where
+    S: post_view_state::State,
+    S::FavouriteCount: post_view_state::IsUnset,
+{
+    /// Set the `favouriteCount` field (required)
+    pub fn favourite_count(
+        mut self,
+        value: impl Into<i64>,
+    ) -> PostViewBuilder<'a, post_view_state::SetFavouriteCount<S>> {
+        self.__unsafe_private_named.3 = ::core::option::Option::Some(value.into());
+        PostViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> PostViewBuilder<'a, S>
+where
+    S: post_view_state::State,
+    S::IndexedAt: post_view_state::IsUnset,
+{
+    /// Set the `indexedAt` field (required)
+    pub fn indexed_at(
+        mut self,
+        value: impl Into<jacquard_common::types::string::Datetime>,
+    ) -> PostViewBuilder<'a, post_view_state::SetIndexedAt<S>> {
+        self.__unsafe_private_named.4 = ::core::option::Option::Some(value.into());
+        PostViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> PostViewBuilder<'a, S>
+where
+    S: post_view_state::State,
+    S::Media: post_view_state::IsUnset,
+{
+    /// Set the `media` field (required)
+    pub fn media(
+        mut self,
+        value: impl Into<crate::net_gifdex::feed::PostViewMedia<'a>>,
+    ) -> PostViewBuilder<'a, post_view_state::SetMedia<S>> {
+        self.__unsafe_private_named.5 = ::core::option::Option::Some(value.into());
+        PostViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: post_view_state::State> PostViewBuilder<'a, S> {
+    /// Set the `selfLabels` field (optional)
+    pub fn self_labels(
+        mut self,
+        value: impl Into<Option<Vec<crate::net_gifdex::feed::SelfLabelView<'a>>>>,
+    ) -> Self {
+        self.__unsafe_private_named.6 = value.into();
+        self
+    }
+    /// Set the `selfLabels` field to an Option value (optional)
+    pub fn maybe_self_labels(
+        mut self,
+        value: Option<Vec<crate::net_gifdex::feed::SelfLabelView<'a>>>,
+    ) -> Self {
+        self.__unsafe_private_named.6 = value;
+        self
+    }
+}
+
+impl<'a, S: post_view_state::State> PostViewBuilder<'a, S> {
+    /// Set the `tags` field (optional)
+    pub fn tags(
+        mut self,
+        value: impl Into<Option<Vec<jacquard_common::CowStr<'a>>>>,
+    ) -> Self {
+        self.__unsafe_private_named.7 = value.into();
+        self
+    }
+    /// Set the `tags` field to an Option value (optional)
+    pub fn maybe_tags(
+        mut self,
+        value: Option<Vec<jacquard_common::CowStr<'a>>>,
+    ) -> Self {
+        self.__unsafe_private_named.7 = value;
+        self
+    }
+}
+
+impl<'a, S> PostViewBuilder<'a, S>
+where
+    S: post_view_state::State,
+    S::Title: post_view_state::IsUnset,
+{
+    /// Set the `title` field (required)
+    pub fn title(
+        mut self,
+        value: impl Into<jacquard_common::CowStr<'a>>,
+    ) -> PostViewBuilder<'a, post_view_state::SetTitle<S>> {
+        self.__unsafe_private_named.8 = ::core::option::Option::Some(value.into());
+        PostViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> PostViewBuilder<'a, S>
+where
+    S: post_view_state::State,
+    S::Uri: post_view_state::IsUnset,
+{
+    /// Set the `uri` field (required)
+    pub fn uri(
+        mut self,
+        value: impl Into<jacquard_common::types::string::AtUri<'a>>,
+    ) -> PostViewBuilder<'a, post_view_state::SetUri<S>> {
+        self.__unsafe_private_named.9 = ::core::option::Option::Some(value.into());
+        PostViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> PostViewBuilder<'a, S>
+where
+    S: post_view_state::State,
+    S::Viewer: post_view_state::IsUnset,
+{
+    /// Set the `viewer` field (required)
+    pub fn viewer(
+        mut self,
+        value: impl Into<crate::net_gifdex::feed::ViewerState<'a>>,
+    ) -> PostViewBuilder<'a, post_view_state::SetViewer<S>> {
+        self.__unsafe_private_named.10 = ::core::option::Option::Some(value.into());
+        PostViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> PostViewBuilder<'a, S>
+where
+    S: post_view_state::State,
+    S::Media: post_view_state::IsSet,
+    S::Title: post_view_state::IsSet,
+    S::Author: post_view_state::IsSet,
+    S::CreatedAt: post_view_state::IsSet,
+    S::FavouriteCount: post_view_state::IsSet,
+    S::Uri: post_view_state::IsSet,
+    S::Viewer: post_view_state::IsSet,
+    S::IndexedAt: post_view_state::IsSet,
+{
+    /// Build the final struct
+    pub fn build(self) -> PostView<'a> {
+        PostView {
+            author: self.__unsafe_private_named.0.unwrap(),
+            created_at: self.__unsafe_private_named.1.unwrap(),
+            edited_at: self.__unsafe_private_named.2,
+            favourite_count: self.__unsafe_private_named.3.unwrap(),
+            indexed_at: self.__unsafe_private_named.4.unwrap(),
+            media: self.__unsafe_private_named.5.unwrap(),
+            self_labels: self.__unsafe_private_named.6,
+            tags: self.__unsafe_private_named.7,
+            title: self.__unsafe_private_named.8.unwrap(),
+            uri: self.__unsafe_private_named.9.unwrap(),
+            viewer: self.__unsafe_private_named.10.unwrap(),
+            extra_data: Default::default(),
+        }
+    }
+    /// Build the final struct with custom extra_data
+    pub fn build_with_data(
+        self,
+        extra_data: std::collections::BTreeMap<
+            jacquard_common::smol_str::SmolStr,
+            jacquard_common::types::value::Data<'a>,
+        >,
+    ) -> PostView<'a> {
+        PostView {
+            author: self.__unsafe_private_named.0.unwrap(),
+            created_at: self.__unsafe_private_named.1.unwrap(),
+            edited_at: self.__unsafe_private_named.2,
+            favourite_count: self.__unsafe_private_named.3.unwrap(),
+            indexed_at: self.__unsafe_private_named.4.unwrap(),
+            media: self.__unsafe_private_named.5.unwrap(),
+            self_labels: self.__unsafe_private_named.6,
+            tags: self.__unsafe_private_named.7,
+            title: self.__unsafe_private_named.8.unwrap(),
+            uri: self.__unsafe_private_named.9.unwrap(),
+            viewer: self.__unsafe_private_named.10.unwrap(),
+            extra_data: Some(extra_data),
+        }
+    }
+}
+
+impl<'a> ::jacquard_lexicon::schema::LexiconSchema for PostView<'a> {
     fn nsid() -> &'static str {
         "net.gifdex.feed.defs"
     }
     fn def_name() -> &'static str {
-        "postFeedView"
+        "postView"
     }
     fn lexicon_doc() -> ::jacquard_lexicon::lexicon::LexiconDoc<'static> {
         lexicon_doc_net_gifdex_feed_defs()
@@ -1054,7 +2439,7 @@ impl<'a> ::jacquard_lexicon::schema::LexiconSchema for PostFeedView<'a> {
     }
 }
 
-/// Detailed view of a post, including author information and engagement metrics.
+/// A post's media content with alongside its dimensions data
 #[jacquard_derive::lexicon]
 #[derive(
     serde::Serialize,
@@ -1066,28 +2451,36 @@ impl<'a> ::jacquard_lexicon::schema::LexiconSchema for PostFeedView<'a> {
     jacquard_derive::IntoStatic
 )]
 #[serde(rename_all = "camelCase")]
-pub struct PostView<'a> {
-    #[serde(borrow)]
-    pub author: crate::net_gifdex::actor::ProfileViewBasic<'a>,
-    pub created_at: jacquard_common::types::string::Datetime,
+pub struct PostViewMedia<'a> {
     #[serde(skip_serializing_if = "std::option::Option::is_none")]
-    pub edited_at: std::option::Option<jacquard_common::types::string::Datetime>,
-    pub favourite_count: i64,
-    pub indexed_at: jacquard_common::types::string::Datetime,
     #[serde(borrow)]
-    pub media: crate::net_gifdex::feed::PostViewMedia<'a>,
+    pub alt: std::option::Option<jacquard_common::CowStr<'a>>,
+    #[serde(borrow)]
+    pub dimensions: crate::net_gifdex::feed::PostViewMediaDimensions<'a>,
+    /// Length of a single loop of the animation in milliseconds.
     #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub duration: std::option::Option<i64>,
+    /// Number of frames in the media, 1 for still images.
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub frame_count: std::option::Option<i64>,
     #[serde(borrow)]
-    pub tags: std::option::Option<Vec<jacquard_common::CowStr<'a>>>,
+    pub fullsize_url: jacquard_common::types::string::Uri<'a>,
     #[serde(borrow)]
-    pub title: jacquard_common::CowStr<'a>,
+    pub mime_type: jacquard_common::CowStr<'a>,
+    /// Size of the original media in bytes.
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub size: std::option::Option<i64>,
     #[serde(borrow)]
-    pub uri: jacquard_common::types::string::AtUri<'a>,
+    pub thumbnail_url: jacquard_common::types::string::Uri<'a>,
+    /// Alternative encodings of the media that can be requested from the CDN, including the original.
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
     #[serde(borrow)]
-    pub viewer: crate::net_gifdex::feed::ViewerState<'a>,
+    pub variants: std::option::Option<
+        Vec<crate::net_gifdex::feed::PostViewMediaVariant<'a>>,
+    >,
 }
 
-pub mod post_view_state {
+pub mod post_view_media_state {
 
     pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
     #[allow(unused)]
@@ -1097,183 +2490,98 @@ pub mod post_view_state {
     }
     /// State trait tracking which required fields have been set
     pub trait State: sealed::Sealed {
-        type Media;
-        type Title;
-        type Author;
-        type CreatedAt;
-        type FavouriteCount;
-        type Uri;
-        type Viewer;
-        type IndexedAt;
+        type ThumbnailUrl;
+        type MimeType;
+        type Dimensions;
+        type FullsizeUrl;
     }
     /// Empty state - all required fields are unset
     pub struct Empty(());
     impl sealed::Sealed for Empty {}
     impl State for Empty {
-        type Media = Unset;
-        type Title = Unset;
-        type Author = Unset;
-        type CreatedAt = Unset;
-        type FavouriteCount = Unset;
-        type Uri = Unset;
-        type Viewer = Unset;
-        type IndexedAt = Unset;
-    }
-    ///State transition - sets the `media` field to Set
-    pub struct SetMedia<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetMedia<S> {}
-    impl<S: State> State for SetMedia<S> {
-        type Media = Set<members::media>;
-        type Title = S::Title;
-        type Author = S::Author;
-        type CreatedAt = S::CreatedAt;
-        type FavouriteCount = S::FavouriteCount;
-        type Uri = S::Uri;
-        type Viewer = S::Viewer;
-        type IndexedAt = S::IndexedAt;
-    }
-    ///State transition - sets the `title` field to Set
-    pub struct SetTitle<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetTitle<S> {}
-    impl<S: State> State for SetTitle<S> {
-        type Media = S::Media;
-        type Title = Set<members::title>;
-        type Author = S::Author;
-        type CreatedAt = S::CreatedAt;
-        type FavouriteCount = S::FavouriteCount;
-        type Uri = S::Uri;
-        type Viewer = S::Viewer;
-        type IndexedAt = S::IndexedAt;
-    }
-    ///State transition - sets the `author` field to Set
-    pub struct SetAuthor<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetAuthor<S> {}
-    impl<S: State> State for SetAuthor<S> {
-        type Media = S::Media;
-        type Title = S::Title;
-        type Author = Set<members::author>;
-        type CreatedAt = S::CreatedAt;
-        type FavouriteCount = S::FavouriteCount;
-        type Uri = S::Uri;
-        type Viewer = S::Viewer;
-        type IndexedAt = S::IndexedAt;
-    }
-    ///State transition - sets the `created_at` field to Set
-    pub struct SetCreatedAt<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetCreatedAt<S> {}
-    impl<S: State> State for SetCreatedAt<S> {
-        type Media = S::Media;
-        type Title = S::Title;
-        type Author = S::Author;
-        type CreatedAt = Set<members::created_at>;
-        type FavouriteCount = S::FavouriteCount;
-        type Uri = S::Uri;
-        type Viewer = S::Viewer;
-        type IndexedAt = S::IndexedAt;
+        type ThumbnailUrl = Unset;
+        type MimeType = Unset;
+        type Dimensions = Unset;
+        type FullsizeUrl = Unset;
     }
-    ///State transition - sets the `favourite_count` field to Set
-    pub struct SetFavouriteCount<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetFavouriteCount<S> {}
-    impl<S: State> State for SetFavouriteCount<S> {
-        type Media = S::Media;
-        type Title = S::Title;
-        type Author = S::Author;
-        type CreatedAt = S::CreatedAt;
-        type FavouriteCount = Set<members::favourite_count>;
-        type Uri = S::Uri;
-        type Viewer = S::Viewer;
-        type IndexedAt = S::IndexedAt;
+    ///State transition - sets the `thumbnail_url` field to Set
+    pub struct SetThumbnailUrl<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetThumbnailUrl<S> {}
+    impl<S: State> State for SetThumbnailUrl<S> {
+        type ThumbnailUrl = Set<members::thumbnail_url>;
+        type MimeType = S::MimeType;
+        type Dimensions = S::Dimensions;
+        type FullsizeUrl = S::FullsizeUrl;
     }
-    ///State transition - sets the `uri` field to Set
-    pub struct SetUri<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetUri<S> {}
-    impl<S: State> State for SetUri<S> {
-        type Media = S::Media;
-        type Title = S::Title;
-        type Author = S::Author;
-        type CreatedAt = S::CreatedAt;
-        type FavouriteCount = S::FavouriteCount;
-        type Uri = Set<members::uri>;
-        type Viewer = S::Viewer;
-        type IndexedAt = S::IndexedAt;
+    ///State transition - sets the `mime_type` field to Set
+    pub struct SetMimeType<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetMimeType<S> {}
+    impl<S: State> State for SetMimeType<S> {
+        type ThumbnailUrl = S::ThumbnailUrl;
+        type MimeType = Set<members::mime_type>;
+        type Dimensions = S::Dimensions;
+        type FullsizeUrl = S::FullsizeUrl;
     }
-    ///State transition - sets the `viewer` field to Set
-    pub struct SetViewer<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetViewer<S> {}
-    impl<S: State> State for SetViewer<S> {
-        type Media = S::Media;
-        type Title = S::Title;
-        type Author = S::Author;
-        type CreatedAt = S::CreatedAt;
-        type FavouriteCount = S::FavouriteCount;
-        type Uri = S::Uri;
-        type Viewer = Set<members::viewer>;
-        type IndexedAt = S::IndexedAt;
+    ///State transition - sets the `dimensions` field to Set
+    pub struct SetDimensions<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetDimensions<S> {}
+    impl<S: State> State for SetDimensions<S> {
+        type ThumbnailUrl = S::ThumbnailUrl;
+        type MimeType = S::MimeType;
+        type Dimensions = Set<members::dimensions>;
+        type FullsizeUrl = S::FullsizeUrl;
     }
-    ///State transition - sets the `indexed_at` field to Set
-    pub struct SetIndexedAt<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetIndexedAt<S> {}
-    impl<S: State> State for SetIndexedAt<S> {
-        type Media = S::Media;
-        type Title = S::Title;
-        type Author = S::Author;
-        type CreatedAt = S::CreatedAt;
-        type FavouriteCount = S::FavouriteCount;
-        type Uri = S::Uri;
-        type Viewer = S::Viewer;
-        type IndexedAt = Set<members::indexed_at>;
+    ///State transition - sets the `fullsize_url` field to Set
+    pub struct SetFullsizeUrl<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetFullsizeUrl<S> {}
+    impl<S: State> State for SetFullsizeUrl<S> {
+        type ThumbnailUrl = S::ThumbnailUrl;
+        type MimeType = S::MimeType;
+        type Dimensions = S::Dimensions;
+        type FullsizeUrl = Set<members::fullsize_url>;
     }
     /// Marker types for field names
     #[allow(non_camel_case_types)]
     pub mod members {
-        ///Marker type for the `media` field
-        pub struct media(());
-        ///Marker type for the `title` field
-        pub struct title(());
-        ///Marker type for the `author` field
-        pub struct author(());
-        ///Marker type for the `created_at` field
-        pub struct created_at(());
-        ///Marker type for the `favourite_count` field
-        pub struct favourite_count(());
-        ///Marker type for the `uri` field
-        pub struct uri(());
-        ///Marker type for the `viewer` field
-        pub struct viewer(());
-        ///Marker type for the `indexed_at` field
-        pub struct indexed_at(());
+        ///Marker type for the `thumbnail_url` field
+        pub struct thumbnail_url(());
+        ///Marker type for the `mime_type` field
+        pub struct mime_type(());
+        ///Marker type for the `dimensions` field
+        pub struct dimensions(());
+        ///Marker type for the `fullsize_url` field
+        pub struct fullsize_url(());
     }
 }
 
 /// Builder for constructing an instance of this type
-pub struct PostViewBuilder<'a, S: post_view_state::State> {
+pub struct PostViewMediaBuilder<'a, S: post_view_media_state::State> {
     _phantom_state: ::core::marker::PhantomData<fn() -> S>,
     __unsafe_private_named: (
-        ::core::option::Option<crate::net_gifdex::actor::ProfileViewBasic<'a>>,
-        ::core::option::Option<jacquard_common::types::string::Datetime>,
-        ::core::option::Option<jacquard_common::types::string::Datetime>,
+        ::core::option::Option<jacquard_common::CowStr<'a>>,
+        ::core::option::Option<crate::net_gifdex::feed::PostViewMediaDimensions<'a>>,
         ::core::option::Option<i64>,
-        ::core::option::Option<jacquard_common::types::string::Datetime>,
-        ::core::option::Option<crate::net_gifdex::feed::PostViewMedia<'a>>,
-        ::core::option::Option<Vec<jacquard_common::CowStr<'a>>>,
+        ::core::option::Option<i64>,
+        ::core::option::Option<jacquard_common::types::string::Uri<'a>>,
         ::core::option::Option<jacquard_common::CowStr<'a>>,
-        ::core::option::Option<jacquard_common::types::string::AtUri<'a>>,
-        ::core::option::Option<crate::net_gifdex::feed::ViewerState<'a>>,
+        ::core::option::Option<i64>,
+        ::core::option::Option<jacquard_common::types::string::Uri<'a>>,
+        ::core::option::Option<Vec<crate::net_gifdex::feed::PostViewMediaVariant<'a>>>,
     ),
     _phantom: ::core::marker::PhantomData<&'a ()>,
 }
 
-impl<'a> PostView<'a> {
+impl<'a> PostViewMedia<'a> {
     /// Create a new builder for this type
-    pub fn new() -> PostViewBuilder<'a, post_view_state::Empty> {
-        PostViewBuilder::new()
+    pub fn new() -> PostViewMediaBuilder<'a, post_view_media_state::Empty> {
+        PostViewMediaBuilder::new()
     }
 }
 
-impl<'a> PostViewBuilder<'a, post_view_state::Empty> {
+impl<'a> PostViewMediaBuilder<'a, post_view_media_state::Empty> {
     /// Create a new builder with all fields unset
     pub fn new() -> Self {
-        PostViewBuilder {
+        PostViewMediaBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: (
                 None,
@@ -1285,25 +2593,37 @@ impl<'a> PostViewBuilder<'a, post_view_state::Empty> {
                 None,
                 None,
                 None,
-                None,
             ),
             _phantom: ::core::marker::PhantomData,
         }
     }
 }
 
-impl<'a, S> PostViewBuilder<'a, S>
+impl<'a, S: post_view_media_state::State> PostViewMediaBuilder<'a, S> {
+    /// Set the `alt` field (optional)
+    pub fn alt(mut self, value: impl Into<Option<jacquard_common::CowStr<'a>>>) -> Self {
+        self.__unsafe_private_named.0 = value.into();
+        self
+    }
+    /// Set the `alt` field to an Option value (optional)
+    pub fn maybe_alt(mut self, value: Option<jacquard_common::CowStr<'a>>) -> Self {
+        self.__unsafe_private_named.0 = value;
+        self
+    }
+}
+
+impl<'a, S> PostViewMediaBuilder<'a, S>
 where
-    S: post_view_state::State,
-    S::Author: post_view_state::IsUnset,
+    S: post_view_media_state::State,
+    S::Dimensions: post_view_media_state::IsUnset,
 {
-    /// Set the `author` field (required)
-    pub fn author(
+    /// Set the `dimensions` field (required)
+    pub fn dimensions(
         mut self,
-        value: impl Into<crate::net_gifdex::actor::ProfileViewBasic<'a>>,
-    ) -> PostViewBuilder<'a, post_view_state::SetAuthor<S>> {
-        self.__unsafe_private_named.0 = ::core::option::Option::Some(value.into());
-        PostViewBuilder {
+        value: impl Into<crate::net_gifdex::feed::PostViewMediaDimensions<'a>>,
+    ) -> PostViewMediaBuilder<'a, post_view_media_state::SetDimensions<S>> {
+        self.__unsafe_private_named.1 = ::core::option::Option::Some(value.into());
+        PostViewMediaBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
             _phantom: ::core::marker::PhantomData,
@@ -1311,151 +2631,291 @@ where
     }
 }
 
-impl<'a, S> PostViewBuilder<'a, S>
+impl<'a, S: post_view_media_state::State> PostViewMediaBuilder<'a, S> {
+    /// Set the `duration` field (optional)
+    pub fn duration(mut self, value: impl Into<Option<i64>>) -> Self {
+        self.__unsafe_private_named.2 = value.into();
+        self
+    }
+    /// Set the `duration` field to an Option value (optional)
+    pub fn maybe_duration(mut self, value: Option<i64>) -> Self {
+        self.__unsafe_private_named.2 = value;
+        self
+    }
+}
+
+impl<'a, S: post_view_media_state::State> PostViewMediaBuilder<'a, S> {
+    /// Set the `frameCount` field (optional)
+    pub fn frame_count(mut self, value: impl Into<Option<i64>>) -> Self {
+        self.__unsafe_private_named.3 = value.into();
+        self
+    }
+    /// Set the `frameCount` field to an Option value (optional)
+    pub fn maybe_frame_count(mut self, value: Option<i64>) -> Self {
+        self.__unsafe_private_named.3 = value;
+        self
+    }
+}
+
+impl<'a, S> PostViewMediaBuilder<'a, S>
 where
-    S: post_view_state::State,
-    S::CreatedAt: post_view_state::IsUnset,
+    S: post_view_media_state::State,
+    S::FullsizeUrl: post_view_media_state::IsUnset,
 {
-    /// Set the `createdAt` field (required)
-    pub fn created_at(
+    /// Set the `fullsizeUrl` field (required)
+    pub fn fullsize_url(
         mut self,
-        value: impl Into<jacquard_common::types::string::Datetime>,
-    ) -> PostViewBuilder<'a, post_view_state::SetCreatedAt<S>> {
-        self.__unsafe_private_named.1 = ::core::option::Option::Some(value.into());
-        PostViewBuilder {
+        value: impl Into<jacquard_common::types::string::Uri<'a>>,
+    ) -> PostViewMediaBuilder<'a, post_view_media_state::SetFullsizeUrl<S>> {
+        self.__unsafe_private_named.4 = ::core::option::Option::Some(value.into());
+        PostViewMediaBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> PostViewMediaBuilder<'a, S>
+where
+    S: post_view_media_state::State,
+    S::MimeType: post_view_media_state::IsUnset,
+{
+    /// Set the `mimeType` field (required)
+    pub fn mime_type(
+        mut self,
+        value: impl Into<jacquard_common::CowStr<'a>>,
+    ) -> PostViewMediaBuilder<'a, post_view_media_state::SetMimeType<S>> {
+        self.__unsafe_private_named.5 = ::core::option::Option::Some(value.into());
+        PostViewMediaBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: post_view_media_state::State> PostViewMediaBuilder<'a, S> {
+    /// Set the `size` field (optional)
+    pub fn size(mut self, value: impl Into<Option<i64>>) -> Self {
+        self.__unsafe_private_named.6 = value.into();
+        self
+    }
+    /// Set the `size` field to an Option value (optional)
+    pub fn maybe_size(mut self, value: Option<i64>) -> Self {
+        self.__unsafe_private_named.6 = value;
+        self
+    }
+}
+
+impl<'a, S> PostViewMediaBuilder<'a, S>
+where
+    S: post_view_media_state::State,
+    S::ThumbnailUrl: post_view_media_state::IsUnset,
+{
+    /// Set the `thumbnailUrl` field (required)
+    pub fn thumbnail_url(
+        mut self,
+        value: impl Into<jacquard_common::types::string::Uri<'a>>,
+    ) -> PostViewMediaBuilder<'a, post_view_media_state::SetThumbnailUrl<S>> {
+        self.__unsafe_private_named.7 = ::core::option::Option::Some(value.into());
+        PostViewMediaBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
             _phantom: ::core::marker::PhantomData,
         }
     }
-}
-
-impl<'a, S: post_view_state::State> PostViewBuilder<'a, S> {
-    /// Set the `editedAt` field (optional)
-    pub fn edited_at(
-        mut self,
-        value: impl Into<Option<jacquard_common::types::string::Datetime>>,
-    ) -> Self {
-        self.__unsafe_private_named.2 = value.into();
-        self
+}
+
+impl<'a, S: post_view_media_state::State> PostViewMediaBuilder<'a, S> {
+    /// Set the `variants` field (optional)
+    pub fn variants(
+        mut self,
+        value: impl Into<Option<Vec<crate::net_gifdex::feed::PostViewMediaVariant<'a>>>>,
+    ) -> Self {
+        self.__unsafe_private_named.8 = value.into();
+        self
+    }
+    /// Set the `variants` field to an Option value (optional)
+    pub fn maybe_variants(
+        mut self,
+        value: Option<Vec<crate::net_gifdex::feed::PostViewMediaVariant<'a>>>,
+    ) -> Self {
+        self.__unsafe_private_named.8 = value;
+        self
+    }
+}
+
+impl<'a, S> PostViewMediaBuilder<'a, S>
+where
+    S: post_view_media_state::State,
+    S::ThumbnailUrl: post_view_media_state::IsSet,
+    S::MimeType: post_view_media_state::IsSet,
+    S::Dimensions: post_view_media_state::IsSet,
+    S::FullsizeUrl: post_view_media_state::IsSet,
+{
+    /// Build the final struct
+    pub fn build(self) -> PostViewMedia<'a> {
+        PostViewMedia {
+            alt: self.__unsafe_private_named.0,
+            dimensions: self.__unsafe_private_named.1.unwrap(),
+            duration: self.__unsafe_private_named.2,
+            frame_count: self.__unsafe_private_named.3,
+            fullsize_url: self.__unsafe_private_named.4.unwrap(),
+            mime_type: self.__unsafe_private_named.5.unwrap(),
+            size: self.__unsafe_private_named.6,
+            thumbnail_url: self.__unsafe_private_named.7.unwrap(),
+            variants: self.__unsafe_private_named.8,
+            extra_data: Default::default(),
+        }
+    }
+    /// Build the final struct with custom extra_data
+    pub fn build_with_data(
+        self,
+        extra_data: std::collections::BTreeMap<
+            jacquard_common::smol_str::SmolStr,
+            jacquard_common::types::value::Data<'a>,
+        >,
+    ) -> PostViewMedia<'a> {
+        PostViewMedia {
+            alt: self.__unsafe_private_named.0,
+            dimensions: self.__unsafe_private_named.1.unwrap(),
+            duration: self.__unsafe_private_named.2,
+            frame_count: self.__unsafe_private_named.3,
+            fullsize_url: self.__unsafe_private_named.4.unwrap(),
+            mime_type: self.__unsafe_private_named.5.unwrap(),
+            size: self.__unsafe_private_named.6,
+            thumbnail_url: self.__unsafe_private_named.7.unwrap(),
+            variants: self.__unsafe_private_named.8,
+            extra_data: Some(extra_data),
+        }
+    }
+}
+
+impl<'a> ::jacquard_lexicon::schema::LexiconSchema for PostViewMedia<'a> {
+    fn nsid() -> &'static str {
+        "net.gifdex.feed.defs"
+    }
+    fn def_name() -> &'static str {
+        "postViewMedia"
+    }
+    fn lexicon_doc() -> ::jacquard_lexicon::lexicon::LexiconDoc<'static> {
+        lexicon_doc_net_gifdex_feed_defs()
+    }
+    fn validate(
+        &self,
+    ) -> ::core::result::Result<(), ::jacquard_lexicon::validation::ConstraintError> {
+        Ok(())
+    }
+}
+
+/// Width and height of a post's media.
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PostViewMediaDimensions<'a> {
+    pub height: i64,
+    pub width: i64,
+}
+
+pub mod post_view_media_dimensions_state {
+
+    pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
+    #[allow(unused)]
+    use ::core::marker::PhantomData;
+    mod sealed {
+        pub trait Sealed {}
+    }
+    /// State trait tracking which required fields have been set
+    pub trait State: sealed::Sealed {
+        type Height;
+        type Width;
+    }
+    /// Empty state - all required fields are unset
+    pub struct Empty(());
+    impl sealed::Sealed for Empty {}
+    impl State for Empty {
+        type Height = Unset;
+        type Width = Unset;
     }
-    /// Set the `editedAt` field to an Option value (optional)
-    pub fn maybe_edited_at(
-        mut self,
-        value: Option<jacquard_common::types::string::Datetime>,
-    ) -> Self {
-        self.__unsafe_private_named.2 = value;
-        self
+    ///State transition - sets the `height` field to Set
+    pub struct SetHeight<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetHeight<S> {}
+    impl<S: State> State for SetHeight<S> {
+        type Height = Set<members::height>;
+        type Width = S::Width;
     }
-}
-
-impl<'a, S> PostViewBuilder<'a, S>
-where
-    S: post_view_state::State,
-    S::FavouriteCount: post_view_state::IsUnset,
-{
-    /// Set the `favouriteCount` field (required)
-    pub fn favourite_count(
-        mut self,
-        value: impl Into<i64>,
-    ) -> PostViewBuilder<'a, post_view_state::SetFavouriteCount<S>> {
-        self.__unsafe_private_named.3 = ::core::option::Option::Some(value.into());
-        PostViewBuilder {
-            _phantom_state: ::core::marker::PhantomData,
-            __unsafe_private_named: self.__unsafe_private_named,
-            _phantom: ::core::marker::PhantomData,
-        }
+    ///State transition - sets the `width` field to Set
+    pub struct SetWidth<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetWidth<S> {}
+    impl<S: State> State for SetWidth<S> {
+        type Height = S::Height;
+        type Width = Set<members::width>;
     }
-}
-
-impl<'a, S> PostViewBuilder<'a, S>
-where
-    S: post_view_state::State,
-    S::IndexedAt: post_view_state::IsUnset,
-{
-    /// Set the `indexedAt` field (required)
-    pub fn indexed_at(
-        mut self,
-        value: impl Into<jacquard_common::types::string::Datetime>,
-    ) -> PostViewBuilder<'a, post_view_state::SetIndexedAt<S>> {
-        self.__unsafe_private_named.4 = ::core::option::Option::Some(value.into());
-        PostViewBuilder {
-            _phantom_state: ::core::marker::PhantomData,
-            __unsafe_private_named: self.__unsafe_private_named,
-            _phantom: ::core::marker::PhantomData,
-        }
+    /// Marker types for field names
+    #[allow(non_camel_case_types)]
+    pub mod members {
+        ///Marker type for the `height` field
+        pub struct height(());
+        ///Marker type for the `width` field
+        pub struct width(());
     }
 }
 
-impl<'a, S> PostViewBuilder<'a, S>
-where
-    S: post_view_state::State,
-    S::Media: post_view_state::IsUnset,
-{
-    /// Set the `media` field (required)
-    pub fn media(
-        mut self,
-        value: impl Into<crate::net_gifdex::feed::PostViewMedia<'a>>,
-    ) -> PostViewBuilder<'a, post_view_state::SetMedia<S>> {
-        self.__unsafe_private_named.5 = ::core::option::Option::Some(value.into());
-        PostViewBuilder {
-            _phantom_state: ::core::marker::PhantomData,
-            __unsafe_private_named: self.__unsafe_private_named,
-            _phantom: ::core::marker::PhantomData,
-        }
-    }
+/// Builder for constructing an instance of this type
+pub struct PostViewMediaDimensionsBuilder<
+    'a,
+    S: post_view_media_dimensions_state::State,
+> {
+    _phantom_state: ::core::marker::PhantomData<fn() -> S>,
+    __unsafe_private_named: (::core::option::Option<i64>, ::core::option::Option<i64>),
+    _phantom: ::core::marker::PhantomData<&'a ()>,
 }
 
-impl<'a, S: post_view_state::State> PostViewBuilder<'a, S> {
-    /// Set the `tags` field (optional)
-    pub fn tags(
-        mut self,
-        value: impl Into<Option<Vec<jacquard_common::CowStr<'a>>>>,
-    ) -> Self {
-        self.__unsafe_private_named.6 = value.into();
-        self
-    }
-    /// Set the `tags` field to an Option value (optional)
-    pub fn maybe_tags(
-        mut self,
-        value: Option<Vec<jacquard_common::CowStr<'a>>>,
-    ) -> Self {
-        self.__unsafe_private_named.6 = value;
-        self
+impl<'a> PostViewMediaDimensions<'a> {
+    /// Create a new builder for this type
+    pub fn new() -> PostViewMediaDimensionsBuilder<
+        'a,
+        post_view_media_dimensions_state::Empty,
+    > {
+        PostViewMediaDimensionsBuilder::new()
     }
 }
 
-impl<'a, S> PostViewBuilder<'a, S>
-where
-    S: post_view_state::State,
-    S::Title: post_view_state::IsUnset,
-{
-    /// Set the `title` field (required)
-    pub fn title(
-        mut self,
-        value: impl Into<jacquard_common::CowStr<'a>>,
-    ) -> PostViewBuilder<'a, post_view_state::SetTitle<S>> {
-        self.__unsafe_private_named.7 = ::core::option::Option::Some(value.into());
-        PostViewBuilder {
+impl<'a> PostViewMediaDimensionsBuilder<'a, post_view_media_dimensions_state::Empty> {
+    /// Create a new builder with all fields unset
+    pub fn new() -> Self {
+        PostViewMediaDimensionsBuilder {
             _phantom_state: ::core::marker::PhantomData,
-            __unsafe_private_named: self.__unsafe_private_named,
+            __unsafe_private_named: (None, None),
             _phantom: ::core::marker::PhantomData,
         }
     }
 }
 
-impl<'a, S> PostViewBuilder<'a, S>
+impl<'a, S> PostViewMediaDimensionsBuilder<'a, S>
 where
-    S: post_view_state::State,
-    S::Uri: post_view_state::IsUnset,
+    S: post_view_media_dimensions_state::State,
+    S::Height: post_view_media_dimensions_state::IsUnset,
 {
-    /// Set the `uri` field (required)
-    pub fn uri(
+    /// Set the `height` field (required)
+    pub fn height(
         mut self,
-        value: impl Into<jacquard_common::types::string::AtUri<'a>>,
-    ) -> PostViewBuilder<'a, post_view_state::SetUri<S>> {
-        self.__unsafe_private_named.8 = ::core::option::Option::Some(value.into());
-        PostViewBuilder {
+        value: impl Into<i64>,
+    ) -> PostViewMediaDimensionsBuilder<
+        'a,
+        post_view_media_dimensions_state::SetHeight<S>,
+    > {
+        self.__unsafe_private_named.0 = ::core::option::Option::Some(value.into());
+        PostViewMediaDimensionsBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
             _phantom: ::core::marker::PhantomData,
@@ -1463,18 +2923,21 @@ where
     }
 }
 
-impl<'a, S> PostViewBuilder<'a, S>
+impl<'a, S> PostViewMediaDimensionsBuilder<'a, S>
 where
-    S: post_view_state::State,
-    S::Viewer: post_view_state::IsUnset,
+    S: post_view_media_dimensions_state::State,
+    S::Width: post_view_media_dimensions_state::IsUnset,
 {
-    /// Set the `viewer` field (required)
-    pub fn viewer(
+    /// Set the `width` field (required)
+    pub fn width(
         mut self,
-        value: impl Into<crate::net_gifdex::feed::ViewerState<'a>>,
-    ) -> PostViewBuilder<'a, post_view_state::SetViewer<S>> {
-        self.__unsafe_private_named.9 = ::core::option::Option::Some(value.into());
-        PostViewBuilder {
+        value: impl Into<i64>,
+    ) -> PostViewMediaDimensionsBuilder<
+        'a,
+        post_view_media_dimensions_state::SetWidth<S>,
+    > {
+        self.__unsafe_private_named.1 = ::core::option::Option::Some(value.into());
+        PostViewMediaDimensionsBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
             _phantom: ::core::marker::PhantomData,
@@ -1482,31 +2945,17 @@ where
     }
 }
 
-impl<'a, S> PostViewBuilder<'a, S>
+impl<'a, S> PostViewMediaDimensionsBuilder<'a, S>
 where
-    S: post_view_state::State,
-    S::Media: post_view_state::IsSet,
-    S::Title: post_view_state::IsSet,
-    S::Author: post_view_state::IsSet,
-    S::CreatedAt: post_view_state::IsSet,
-    S::FavouriteCount: post_view_state::IsSet,
-    S::Uri: post_view_state::IsSet,
-    S::Viewer: post_view_state::IsSet,
-    S::IndexedAt: post_view_state::IsSet,
+    S: post_view_media_dimensions_state::State,
+    S::Height: post_view_media_dimensions_state::IsSet,
+    S::Width: post_view_media_dimensions_state::IsSet,
 {
     /// Build the final struct
-    pub fn build(self) -> PostView<'a> {
-        PostView {
-            author: self.__unsafe_private_named.0.unwrap(),
-            created_at: self.__unsafe_private_named.1.unwrap(),
-            edited_at: self.__unsafe_private_named.2,
-            favourite_count: self.__unsafe_private_named.3.unwrap(),
-            indexed_at: self.__unsafe_private_named.4.unwrap(),
-            media: self.__unsafe_private_named.5.unwrap(),
-            tags: self.__unsafe_private_named.6,
-            title: self.__unsafe_private_named.7.unwrap(),
-            uri: self.__unsafe_private_named.8.unwrap(),
-            viewer: self.__unsafe_private_named.9.unwrap(),
+    pub fn build(self) -> PostViewMediaDimensions<'a> {
+        PostViewMediaDimensions {
+            height: self.__unsafe_private_named.0.unwrap(),
+            width: self.__unsafe_private_named.1.unwrap(),
             extra_data: Default::default(),
         }
     }
@@ -1517,29 +2966,21 @@ where
             jacquard_common::smol_str::SmolStr,
             jacquard_common::types::value::Data<'a>,
         >,
-    ) -> PostView<'a> {
-        PostView {
-            author: self.__unsafe_private_named.0.unwrap(),
-            created_at: self.__unsafe_private_named.1.unwrap(),
-            edited_at: self.__unsafe_private_named.2,
-            favourite_count: self.__unsafe_private_named.3.unwrap(),
-            indexed_at: self.__unsafe_private_named.4.unwrap(),
-            media: self.__unsafe_private_named.5.unwrap(),
-            tags: self.__unsafe_private_named.6,
-            title: self.__unsafe_private_named.7.unwrap(),
-            uri: self.__unsafe_private_named.8.unwrap(),
-            viewer: self.__unsafe_private_named.9.unwrap(),
+    ) -> PostViewMediaDimensions<'a> {
+        PostViewMediaDimensions {
+            height: self.__unsafe_private_named.0.unwrap(),
+            width: self.__unsafe_private_named.1.unwrap(),
             extra_data: Some(extra_data),
         }
     }
 }
 
-impl<'a> ::jacquard_lexicon::schema::LexiconSchema for PostView<'a> {
+impl<'a> ::jacquard_lexicon::schema::LexiconSchema for PostViewMediaDimensions<'a> {
     fn nsid() -> &'static str {
         "net.gifdex.feed.defs"
     }
     fn def_name() -> &'static str {
-        "postView"
+        "postViewMediaDimensions"
     }
     fn lexicon_doc() -> ::jacquard_lexicon::lexicon::LexiconDoc<'static> {
         lexicon_doc_net_gifdex_feed_defs()
@@ -1551,7 +2992,7 @@ impl<'a> ::jacquard_lexicon::schema::LexiconSchema for PostView<'a> {
     }
 }
 
-/// A post's media content with alongside its dimensions data
+/// A single encoding of a post's media.
 #[jacquard_derive::lexicon]
 #[derive(
     serde::Serialize,
@@ -1563,21 +3004,17 @@ impl<'a> ::jacquard_lexicon::schema::LexiconSchema for PostView<'a> {
     jacquard_derive::IntoStatic
 )]
 #[serde(rename_all = "camelCase")]
-pub struct PostViewMedia<'a> {
-    #[serde(skip_serializing_if = "std::option::Option::is_none")]
-    #[serde(borrow)]
-    pub alt: std::option::Option<jacquard_common::CowStr<'a>>,
-    #[serde(borrow)]
-    pub dimensions: crate::net_gifdex::feed::PostViewMediaDimensions<'a>,
-    #[serde(borrow)]
-    pub fullsize_url: jacquard_common::types::string::Uri<'a>,
+pub struct PostViewMediaVariant<'a> {
     #[serde(borrow)]
     pub mime_type: jacquard_common::CowStr<'a>,
+    /// Size of the encoded media in bytes, if known.
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub size: std::option::Option<i64>,
     #[serde(borrow)]
-    pub thumbnail_url: jacquard_common::types::string::Uri<'a>,
+    pub url: jacquard_common::types::string::Uri<'a>,
 }
 
-pub mod post_view_media_state {
+pub mod post_view_media_variant_state {
 
     pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
     #[allow(unused)]
@@ -1587,126 +3024,84 @@ pub mod post_view_media_state {
     }
     /// State trait tracking which required fields have been set
     pub trait State: sealed::Sealed {
-        type ThumbnailUrl;
+        type Url;
         type MimeType;
-        type Dimensions;
-        type FullsizeUrl;
     }
     /// Empty state - all required fields are unset
     pub struct Empty(());
     impl sealed::Sealed for Empty {}
     impl State for Empty {
-        type ThumbnailUrl = Unset;
-        type MimeType = Unset;
-        type Dimensions = Unset;
-        type FullsizeUrl = Unset;
-    }
-    ///State transition - sets the `thumbnail_url` field to Set
-    pub struct SetThumbnailUrl<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetThumbnailUrl<S> {}
-    impl<S: State> State for SetThumbnailUrl<S> {
-        type ThumbnailUrl = Set<members::thumbnail_url>;
+        type Url = Unset;
+        type MimeType = Unset;
+    }
+    ///State transition - sets the `url` field to Set
+    pub struct SetUrl<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetUrl<S> {}
+    impl<S: State> State for SetUrl<S> {
+        type Url = Set<members::url>;
         type MimeType = S::MimeType;
-        type Dimensions = S::Dimensions;
-        type FullsizeUrl = S::FullsizeUrl;
     }
     ///State transition - sets the `mime_type` field to Set
     pub struct SetMimeType<S: State = Empty>(PhantomData<fn() -> S>);
     impl<S: State> sealed::Sealed for SetMimeType<S> {}
     impl<S: State> State for SetMimeType<S> {
-        type ThumbnailUrl = S::ThumbnailUrl;
+        type Url = S::Url;
         type MimeType = Set<members::mime_type>;
-        type Dimensions = S::Dimensions;
-        type FullsizeUrl = S::FullsizeUrl;
-    }
-    ///State transition - sets the `dimensions` field to Set
-    pub struct SetDimensions<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetDimensions<S> {}
-    impl<S: State> State for SetDimensions<S> {
-        type ThumbnailUrl = S::ThumbnailUrl;
-        type MimeType = S::MimeType;
-        type Dimensions = Set<members::dimensions>;
-        type FullsizeUrl = S::FullsizeUrl;
-    }
-    ///State transition - sets the `fullsize_url` field to Set
-    pub struct SetFullsizeUrl<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetFullsizeUrl<S> {}
-    impl<S: State> State for SetFullsizeUrl<S> {
-        type ThumbnailUrl = S::ThumbnailUrl;
-        type MimeType = S::MimeType;
-        type Dimensions = S::Dimensions;
-        type FullsizeUrl = Set<members::fullsize_url>;
     }
     /// Marker types for field names
     #[allow(non_camel_case_types)]
     pub mod members {
-        ///Marker type for the `thumbnail_url` field
-        pub struct thumbnail_url(());
+        ///Marker type for the `url` field
+        pub struct url(());
         ///Marker type for the `mime_type` field
         pub struct mime_type(());
-        ///Marker type for the `dimensions` field
-        pub struct dimensions(());
-        ///Marker type for the `fullsize_url` field
-        pub struct fullsize_url(());
     }
 }
 
 /// Builder for constructing an instance of this type
-pub struct PostViewMediaBuilder<'a, S: post_view_media_state::State> {
+pub struct PostViewMediaVariantBuilder<'a, S: post_view_media_variant_state::State> {
     _phantom_state: ::core::marker::PhantomData<fn() -> S>,
     __unsafe_private_named: (
         ::core::option::Option<jacquard_common::CowStr<'a>>,
-        ::core::option::Option<crate::net_gifdex::feed::PostViewMediaDimensions<'a>>,
-        ::core::option::Option<jacquard_common::types::string::Uri<'a>>,
-        ::core::option::Option<jacquard_common::CowStr<'a>>,
+        ::core::option::Option<i64>,
         ::core::option::Option<jacquard_common::types::string::Uri<'a>>,
     ),
     _phantom: ::core::marker::PhantomData<&'a ()>,
 }
 
-impl<'a> PostViewMedia<'a> {
+impl<'a> PostViewMediaVariant<'a> {
     /// Create a new builder for this type
-    pub fn new() -> PostViewMediaBuilder<'a, post_view_media_state::Empty> {
-        PostViewMediaBuilder::new()
+    pub fn new() -> PostViewMediaVariantBuilder<
+        'a,
+        post_view_media_variant_state::Empty,
+    > {
+        PostViewMediaVariantBuilder::new()
     }
 }
 
-impl<'a> PostViewMediaBuilder<'a, post_view_media_state::Empty> {
+impl<'a> PostViewMediaVariantBuilder<'a, post_view_media_variant_state::Empty> {
     /// Create a new builder with all fields unset
     pub fn new() -> Self {
-        PostViewMediaBuilder {
+        PostViewMediaVariantBuilder {
             _phantom_state: ::core::marker::PhantomData,
-            __unsafe_private_named: (None, None, None, None, None),
+            __unsafe_private_named: (None, None, None),
             _phantom: ::core::marker::PhantomData,
         }
     }
 }
 
-impl<'a, S: post_view_media_state::State> PostViewMediaBuilder<'a, S> {
-    /// Set the `alt` field (optional)
-    pub fn alt(mut self, value: impl Into<Option<jacquard_common::CowStr<'a>>>) -> Self {
-        self.__unsafe_private_named.0 = value.into();
-        self
-    }
-    /// Set the `alt` field to an Option value (optional)
-    pub fn maybe_alt(mut self, value: Option<jacquard_common::CowStr<'a>>) -> Self {
-        self.__unsafe_private_named.0 = value;
-        self
-    }
-}
-
-impl<'a, S> PostViewMediaBuilder<'a, S>
+impl<'a, S> PostViewMediaVariantBuilder<'a, S>
 where
-    S: post_view_media_state::State,
-    S::Dimensions: post_view_media_state::IsUnset,
+    S: post_view_media_variant_state::State,
+    S::MimeType: post_view_media_variant_state::IsUnset,
 {
-    /// Set the `dimensions` field (required)
-    pub fn dimensions(
+    /// Set the `mimeType` field (required)
+    pub fn mime_type(
         mut self,
-        value: impl Into<crate::net_gifdex::feed::PostViewMediaDimensions<'a>>,
-    ) -> PostViewMediaBuilder<'a, post_view_media_state::SetDimensions<S>> {
-        self.__unsafe_private_named.1 = ::core::option::Option::Some(value.into());
-        PostViewMediaBuilder {
+        value: impl Into<jacquard_common::CowStr<'a>>,
+    ) -> PostViewMediaVariantBuilder<'a, post_view_media_variant_state::SetMimeType<S>> {
+        self.__unsafe_private_named.0 = ::core::option::Option::Some(value.into());
+        PostViewMediaVariantBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
             _phantom: ::core::marker::PhantomData,
@@ -1714,56 +3109,31 @@ where
     }
 }
 
-impl<'a, S> PostViewMediaBuilder<'a, S>
-where
-    S: post_view_media_state::State,
-    S::FullsizeUrl: post_view_media_state::IsUnset,
-{
-    /// Set the `fullsizeUrl` field (required)
-    pub fn fullsize_url(
-        mut self,
-        value: impl Into<jacquard_common::types::string::Uri<'a>>,
-    ) -> PostViewMediaBuilder<'a, post_view_media_state::SetFullsizeUrl<S>> {
-        self.__unsafe_private_named.2 = ::core::option::Option::Some(value.into());
-        PostViewMediaBuilder {
-            _phantom_state: ::core::marker::PhantomData,
-            __unsafe_private_named: self.__unsafe_private_named,
-            _phantom: ::core::marker::PhantomData,
-        }
+impl<'a, S: post_view_media_variant_state::State> PostViewMediaVariantBuilder<'a, S> {
+    /// Set the `size` field (optional)
+    pub fn size(mut self, value: impl Into<Option<i64>>) -> Self {
+        self.__unsafe_private_named.1 = value.into();
+        self
     }
-}
-
-impl<'a, S> PostViewMediaBuilder<'a, S>
-where
-    S: post_view_media_state::State,
-    S::MimeType: post_view_media_state::IsUnset,
-{
-    /// Set the `mimeType` field (required)
-    pub fn mime_type(
-        mut self,
-        value: impl Into<jacquard_common::CowStr<'a>>,
-    ) -> PostViewMediaBuilder<'a, post_view_media_state::SetMimeType<S>> {
-        self.__unsafe_private_named.3 = ::core::option::Option::Some(value.into());
-        PostViewMediaBuilder {
-            _phantom_state: ::core::marker::PhantomData,
-            __unsafe_private_named: self.__unsafe_private_named,
-            _phantom: ::core::marker::PhantomData,
-        }
+    /// Set the `size` field to an Option value (optional)
+    pub fn maybe_size(mut self, value: Option<i64>) -> Self {
+        self.__unsafe_private_named.1 = value;
+        self
     }
 }
 
-impl<'a, S> PostViewMediaBuilder<'a, S>
+impl<'a, S> PostViewMediaVariantBuilder<'a, S>
 where
-    S: post_view_media_state::State,
-    S::ThumbnailUrl: post_view_media_state::IsUnset,
+    S: post_view_media_variant_state::State,
+    S::Url: post_view_media_variant_state::IsUnset,
 {
-    /// Set the `thumbnailUrl` field (required)
-    pub fn thumbnail_url(
+    /// Set the `url` field (required)
+    pub fn url(
         mut self,
         value: impl Into<jacquard_common::types::string::Uri<'a>>,
-    ) -> PostViewMediaBuilder<'a, post_view_media_state::SetThumbnailUrl<S>> {
-        self.__unsafe_private_named.4 = ::core::option::Option::Some(value.into());
-        PostViewMediaBuilder {
+    ) -> PostViewMediaVariantBuilder<'a, post_view_media_variant_state::SetUrl<S>> {
+        self.__unsafe_private_named.2 = ::core::option::Option::Some(value.into());
+        PostViewMediaVariantBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
             _phantom: ::core::marker::PhantomData,
@@ -1771,22 +3141,18 @@ where
     }
 }
 
-impl<'a, S> PostViewMediaBuilder<'a, S>
+impl<'a, S> PostViewMediaVariantBuilder<'a, S>
 where
-    S: post_view_media_state::State,
-    S::ThumbnailUrl: post_view_media_state::IsSet,
-    S::MimeType: post_view_media_state::IsSet,
-    S::Dimensions: post_view_media_state::IsSet,
-    S::FullsizeUrl: post_view_media_state::IsSet,
+    S: post_view_media_variant_state::State,
+    S::Url: post_view_media_variant_state::IsSet,
+    S::MimeType: post_view_media_variant_state::IsSet,
 {
     /// Build the final struct
-    pub fn build(self) -> PostViewMedia<'a> {
-        PostViewMedia {
-            alt: self.__unsafe_private_named.0,
-            dimensions: self.__unsafe_private_named.1.unwrap(),
-            fullsize_url: self.__unsafe_private_named.2.unwrap(),
-            mime_type: self.__unsafe_private_named.3.unwrap(),
-            thumbnail_url: self.__unsafe_private_named.4.unwrap(),
+    pub fn build(self) -> PostViewMediaVariant<'a> {
+        PostViewMediaVariant {
+            mime_type: self.__unsafe_private_named.0.unwrap(),
+            size: self.__unsafe_private_named.1,
+            url: self.__unsafe_private_named.2.unwrap(),
             extra_data: Default::default(),
         }
     }
@@ -1797,24 +3163,22 @@ where
             jacquard_common::smol_str::SmolStr,
             jacquard_common::types::value::Data<'a>,
         >,
-    ) -> PostViewMedia<'a> {
-        PostViewMedia {
-            alt: self.__unsafe_private_named.0,
-            dimensions: self.__unsafe_private_named.1.unwrap(),
-            fullsize_url: self.__unsafe_private_named.2.unwrap(),
-            mime_type: self.__unsafe_private_named.3.unwrap(),
-            thumbnail_url: self.__unsafe_private_named.4.unwrap(),
+    ) -> PostViewMediaVariant<'a> {
+        PostViewMediaVariant {
+            mime_type: self.__unsafe_private_named.0.unwrap(),
+            size: self.__unsafe_private_named.1,
+            url: self.__unsafe_private_named.2.unwrap(),
             extra_data: Some(extra_data),
         }
     }
 }
 
-impl<'a> ::jacquard_lexicon::schema::LexiconSchema for PostViewMedia<'a> {
+impl<'a> ::jacquard_lexicon::schema::LexiconSchema for PostViewMediaVariant<'a> {
     fn nsid() -> &'static str {
         "net.gifdex.feed.defs"
     }
     fn def_name() -> &'static str {
-        "postViewMedia"
+        "postViewMediaVariant"
     }
     fn lexicon_doc() -> ::jacquard_lexicon::lexicon::LexiconDoc<'static> {
         lexicon_doc_net_gifdex_feed_defs()
@@ -1826,7 +3190,7 @@ impl<'a> ::jacquard_lexicon::schema::LexiconSchema for PostViewMedia<'a> {
     }
 }
 
-/// Width and height of a post's media.
+/// A labeler rule that a post's author applied to their own post.
 #[jacquard_derive::lexicon]
 #[derive(
     serde::Serialize,
@@ -1838,12 +3202,19 @@ impl<'a> ::jacquard_lexicon::schema::LexiconSchema for PostViewMedia<'a> {
     jacquard_derive::IntoStatic
 )]
 #[serde(rename_all = "camelCase")]
-pub struct PostViewMediaDimensions<'a> {
-    pub height: i64,
-    pub width: i64,
+pub struct SelfLabelView<'a> {
+    pub adult_content: bool,
+    #[serde(borrow)]
+    pub default_setting: jacquard_common::CowStr<'a>,
+    #[serde(borrow)]
+    pub description: jacquard_common::CowStr<'a>,
+    #[serde(borrow)]
+    pub name: jacquard_common::CowStr<'a>,
+    #[serde(borrow)]
+    pub rule: jacquard_common::types::string::AtUri<'a>,
 }
 
-pub mod post_view_media_dimensions_state {
+pub mod self_label_view_state {
 
     pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
     #[allow(unused)]
@@ -1853,86 +3224,131 @@ pub mod post_view_media_dimensions_state {
     }
     /// State trait tracking which required fields have been set
     pub trait State: sealed::Sealed {
-        type Height;
-        type Width;
+        type AdultContent;
+        type DefaultSetting;
+        type Description;
+        type Name;
+        type Rule;
     }
     /// Empty state - all required fields are unset
     pub struct Empty(());
     impl sealed::Sealed for Empty {}
     impl State for Empty {
-        type Height = Unset;
-        type Width = Unset;
-    }
-    ///State transition - sets the `height` field to Set
-    pub struct SetHeight<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetHeight<S> {}
-    impl<S: State> State for SetHeight<S> {
-        type Height = Set<members::height>;
-        type Width = S::Width;
-    }
-    ///State transition - sets the `width` field to Set
-    pub struct SetWidth<S: State = Empty>(PhantomData<fn() -> S>);
-    impl<S: State> sealed::Sealed for SetWidth<S> {}
-    impl<S: State> State for SetWidth<S> {
-        type Height = S::Height;
-        type Width = Set<members::width>;
+        type AdultContent = Unset;
+        type DefaultSetting = Unset;
+        type Description = Unset;
+        type Name = Unset;
+        type Rule = Unset;
+    }
+    ///State transition - sets the `adult_content` field to Set
+    pub struct SetAdultContent<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetAdultContent<S> {}
+    impl<S: State> State for SetAdultContent<S> {
+        type AdultContent = Set<members::adult_content>;
+        type DefaultSetting = S::DefaultSetting;
+        type Description = S::Description;
+        type Name = S::Name;
+        type Rule = S::Rule;
+    }
+    ///State transition - sets the `default_setting` field to Set
+    pub struct SetDefaultSetting<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetDefaultSetting<S> {}
+    impl<S: State> State for SetDefaultSetting<S> {
+        type AdultContent = S::AdultContent;
+        type DefaultSetting = Set<members::default_setting>;
+        type Description = S::Description;
+        type Name = S::Name;
+        type Rule = S::Rule;
+    }
+    ///State transition - sets the `description` field to Set
+    pub struct SetDescription<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetDescription<S> {}
+    impl<S: State> State for SetDescription<S> {
+        type AdultContent = S::AdultContent;
+        type DefaultSetting = S::DefaultSetting;
+        type Description = Set<members::description>;
+        type Name = S::Name;
+        type Rule = S::Rule;
+    }
+    ///State transition - sets the `name` field to Set
+    pub struct SetName<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetName<S> {}
+    impl<S: State> State for SetName<S> {
+        type AdultContent = S::AdultContent;
+        type DefaultSetting = S::DefaultSetting;
+        type Description = S::Description;
+        type Name = Set<members::name>;
+        type Rule = S::Rule;
+    }
+    ///State transition - sets the `rule` field to Set
+    pub struct SetRule<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetRule<S> {}
+    impl<S: State> State for SetRule<S> {
+        type AdultContent = S::AdultContent;
+        type DefaultSetting = S::DefaultSetting;
+        type Description = S::Description;
+        type Name = S::Name;
+        type Rule = Set<members::rule>;
     }
     /// Marker types for field names
     #[allow(non_camel_case_types)]
     pub mod members {
-        ///Marker type for the `height` field
-        pub struct height(());
-        ///Marker type for the `width` field
-        pub struct width(());
+        ///Marker type for the `adult_content` field
+        pub struct adult_content(());
+        ///Marker type for the `default_setting` field
+        pub struct default_setting(());
+        ///Marker type for the `description` field
+        pub struct description(());
+        ///Marker type for the `name` field
+        pub struct name(());
+        ///Marker type for the `rule` field
+        pub struct rule(());
     }
 }
 
 /// Builder for constructing an instance of this type
-pub struct PostViewMediaDimensionsBuilder<
-    'a,
-    S: post_view_media_dimensions_state::State,
-> {
+pub struct SelfLabelViewBuilder<'a, S: self_label_view_state::State> {
     _phantom_state: ::core::marker::PhantomData<fn() -> S>,
-    __unsafe_private_named: (::core::option::Option<i64>, ::core::option::Option<i64>),
+    __unsafe_private_named: (
+        ::core::option::Option<bool>,
+        ::core::option::Option<jacquard_common::CowStr<'a>>,
+        ::core::option::Option<jacquard_common::CowStr<'a>>,
+        ::core::option::Option<jacquard_common::CowStr<'a>>,
+        ::core::option::Option<jacquard_common::types::string::AtUri<'a>>,
+    ),
     _phantom: ::core::marker::PhantomData<&'a ()>,
 }
 
-impl<'a> PostViewMediaDimensions<'a> {
+impl<'a> SelfLabelView<'a> {
     /// Create a new builder for this type
-    pub fn new() -> PostViewMediaDimensionsBuilder<
-        'a,
-        post_view_media_dimensions_state::Empty,
-    > {
-        PostViewMediaDimensionsBuilder::new()
+    pub fn new() -> SelfLabelViewBuilder<'a, self_label_view_state::Empty> {
+        SelfLabelViewBuilder::new()
     }
 }
 
-impl<'a> PostViewMediaDimensionsBuilder<'a, post_view_media_dimensions_state::Empty> {
+impl<'a> SelfLabelViewBuilder<'a, self_label_view_state::Empty> {
     /// Create a new builder with all fields unset
     pub fn new() -> Self {
-        PostViewMediaDimensionsBuilder {
+        SelfLabelViewBuilder {
             _phantom_state: ::core::marker::PhantomData,
-            __unsafe_private_named: (None, None),
+            __unsafe_private_named: (None, None, None, None, None),
             _phantom: ::core::marker::PhantomData,
         }
     }
 }
 
-impl<'a, S> PostViewMediaDimensionsBuilder<'a, S>
+impl<'a, S> SelfLabelViewBuilder<'a, S>
 where
-    S: post_view_media_dimensions_state::State,
-    S::Height: post_view_media_dimensions_state::IsUnset,
+    S: self_label_view_state::State,
+    S::AdultContent: self_label_view_state::IsUnset,
 {
-    /// Set the `height` field (required)
-    pub fn height(
+    /// Set the `adultContent` field (required)
+    pub fn adult_content(
         mut self,
-        value: impl Into<i64>,
-    ) -> PostViewMediaDimensionsBuilder<
-        'a,
-        post_view_media_dimensions_state::SetHeight<S>,
-    > {
+        value: impl Into<bool>,
+    ) -> SelfLabelViewBuilder<'a, self_label_view_state::SetAdultContent<S>> {
         self.__unsafe_private_named.0 = ::core::option::Option::Some(value.into());
-        PostViewMediaDimensionsBuilder {
+        SelfLabelViewBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
             _phantom: ::core::marker::PhantomData,
@@ -1940,21 +3356,18 @@ where
     }
 }
 
-impl<'a, S> PostViewMediaDimensionsBuilder<'a, S>
+impl<'a, S> SelfLabelViewBuilder<'a, S>
 where
-    S: post_view_media_dimensions_state::State,
-    S::Width: post_view_media_dimensions_state::IsUnset,
+    S: self_label_view_state::State,
+    S::DefaultSetting: self_label_view_state::IsUnset,
 {
-    /// Set the `width` field (required)
-    pub fn width(
+    /// Set the `defaultSetting` field (required)
+    pub fn default_setting(
         mut self,
-        value: impl Into<i64>,
-    ) -> PostViewMediaDimensionsBuilder<
-        'a,
-        post_view_media_dimensions_state::SetWidth<S>,
-    > {
+        value: impl Into<jacquard_common::CowStr<'a>>,
+    ) -> SelfLabelViewBuilder<'a, self_label_view_state::SetDefaultSetting<S>> {
         self.__unsafe_private_named.1 = ::core::option::Option::Some(value.into());
-        PostViewMediaDimensionsBuilder {
+        SelfLabelViewBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
             _phantom: ::core::marker::PhantomData,
@@ -1962,17 +3375,80 @@ where
     }
 }
 
-impl<'a, S> PostViewMediaDimensionsBuilder<'a, S>
+impl<'a, S> SelfLabelViewBuilder<'a, S>
 where
-    S: post_view_media_dimensions_state::State,
-    S::Height: post_view_media_dimensions_state::IsSet,
-    S::Width: post_view_media_dimensions_state::IsSet,
+    S: self_label_view_state::State,
+    S::Description: self_label_view_state::IsUnset,
+{
+    /// Set the `description` field (required)
+    pub fn description(
+        mut self,
+        value: impl Into<jacquard_common::CowStr<'a>>,
+    ) -> SelfLabelViewBuilder<'a, self_label_view_state::SetDescription<S>> {
+        self.__unsafe_private_named.2 = ::core::option::Option::Some(value.into());
+        SelfLabelViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> SelfLabelViewBuilder<'a, S>
+where
+    S: self_label_view_state::State,
+    S::Name: self_label_view_state::IsUnset,
+{
+    /// Set the `name` field (required)
+    pub fn name(
+        mut self,
+        value: impl Into<jacquard_common::CowStr<'a>>,
+    ) -> SelfLabelViewBuilder<'a, self_label_view_state::SetName<S>> {
+        self.__unsafe_private_named.3 = ::core::option::Option::Some(value.into());
+        SelfLabelViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> SelfLabelViewBuilder<'a, S>
+where
+    S: self_label_view_state::State,
+    S::Rule: self_label_view_state::IsUnset,
+{
+    /// Set the `rule` field (required)
+    pub fn rule(
+        mut self,
+        value: impl Into<jacquard_common::types::string::AtUri<'a>>,
+    ) -> SelfLabelViewBuilder<'a, self_label_view_state::SetRule<S>> {
+        self.__unsafe_private_named.4 = ::core::option::Option::Some(value.into());
+        SelfLabelViewBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> SelfLabelViewBuilder<'a, S>
+where
+    S: self_label_view_state::State,
+    S::AdultContent: self_label_view_state::IsSet,
+    S::DefaultSetting: self_label_view_state::IsSet,
+    S::Description: self_label_view_state::IsSet,
+    S::Name: self_label_view_state::IsSet,
+    S::Rule: self_label_view_state::IsSet,
 {
     /// Build the final struct
-    pub fn build(self) -> PostViewMediaDimensions<'a> {
-        PostViewMediaDimensions {
-            height: self.__unsafe_private_named.0.unwrap(),
-            width: self.__unsafe_private_named.1.unwrap(),
+    pub fn build(self) -> SelfLabelView<'a> {
+        SelfLabelView {
+            adult_content: self.__unsafe_private_named.0.unwrap(),
+            default_setting: self.__unsafe_private_named.1.unwrap(),
+            description: self.__unsafe_private_named.2.unwrap(),
+            name: self.__unsafe_private_named.3.unwrap(),
+            rule: self.__unsafe_private_named.4.unwrap(),
             extra_data: Default::default(),
         }
     }
@@ -1983,21 +3459,24 @@ where
             jacquard_common::smol_str::SmolStr,
             jacquard_common::types::value::Data<'a>,
         >,
-    ) -> PostViewMediaDimensions<'a> {
-        PostViewMediaDimensions {
-            height: self.__unsafe_private_named.0.unwrap(),
-            width: self.__unsafe_private_named.1.unwrap(),
+    ) -> SelfLabelView<'a> {
+        SelfLabelView {
+            adult_content: self.__unsafe_private_named.0.unwrap(),
+            default_setting: self.__unsafe_private_named.1.unwrap(),
+            description: self.__unsafe_private_named.2.unwrap(),
+            name: self.__unsafe_private_named.3.unwrap(),
+            rule: self.__unsafe_private_named.4.unwrap(),
             extra_data: Some(extra_data),
         }
     }
 }
 
-impl<'a> ::jacquard_lexicon::schema::LexiconSchema for PostViewMediaDimensions<'a> {
+impl<'a> ::jacquard_lexicon::schema::LexiconSchema for SelfLabelView<'a> {
     fn nsid() -> &'static str {
         "net.gifdex.feed.defs"
     }
     fn def_name() -> &'static str {
-        "postViewMediaDimensions"
+        "selfLabelView"
     }
     fn lexicon_doc() -> ::jacquard_lexicon::lexicon::LexiconDoc<'static> {
         lexicon_doc_net_gifdex_feed_defs()
@@ -0,0 +1,226 @@
+// @generated by jacquard-lexicon. DO NOT EDIT.
+//
+// Lexicon: net.gifdex.feed.getActorCollections
+//
+// This file was automatically generated from Lexicon schemas.
+// Any manual changes will be overwritten on the next regeneration.
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GetActorCollections<'a> {
+    #[serde(borrow)]
+    pub actor: jacquard_common::types::string::Did<'a>,
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub cursor: std::option::Option<i64>,
+    ///(default: 50, min: 1, max: 100)
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub limit: std::option::Option<i64>,
+}
+
+pub mod get_actor_collections_state {
+
+    pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
+    #[allow(unused)]
+    use ::core::marker::PhantomData;
+    mod sealed {
+        pub trait Sealed {}
+    }
+    /// State trait tracking which required fields have been set
+    pub trait State: sealed::Sealed {
+        type Actor;
+    }
+    /// Empty state - all required fields are unset
+    pub struct Empty(());
+    impl sealed::Sealed for Empty {}
+    impl State for Empty {
+        type Actor = Unset;
+    }
+    ///State transition - sets the `actor` field to Set
+    pub struct SetActor<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetActor<S> {}
+    impl<S: State> State for SetActor<S> {
+        type Actor = Set<members::actor>;
+    }
+    /// Marker types for field names
+    #[allow(non_camel_case_types)]
+    pub mod members {
+        ///Marker type for the `actor` field
+        pub struct actor(());
+    }
+}
+
+/// Builder for constructing an instance of this type
+pub struct GetActorCollectionsBuilder<'a, S: get_actor_collections_state::State> {
+    _phantom_state: ::core::marker::PhantomData<fn() -> S>,
+    __unsafe_private_named: (
+        ::core::option::Option<jacquard_common::types::string::Did<'a>>,
+        ::core::option::Option<i64>,
+        ::core::option::Option<i64>,
+    ),
+    _phantom: ::core::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> GetActorCollections<'a> {
+    /// Create a new builder for this type
+    pub fn new() -> GetActorCollectionsBuilder<'a, get_actor_collections_state::Empty> {
+        GetActorCollectionsBuilder::new()
+    }
+}
+
+impl<'a> GetActorCollectionsBuilder<'a, get_actor_collections_state::Empty> {
+    /// Create a new builder with all fields unset
+    pub fn new() -> Self {
+        GetActorCollectionsBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: (None, None, None),
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> GetActorCollectionsBuilder<'a, S>
+where
+    S: get_actor_collections_state::State,
+    S::Actor: get_actor_collections_state::IsUnset,
+{
+    /// Set the `actor` field (required)
+    pub fn actor(
+        mut self,
+        value: impl Into<jacquard_common::types::string::Did<'a>>,
+    ) -> GetActorCollectionsBuilder<'a, get_actor_collections_state::SetActor<S>> {
+        self.__unsafe_private_named.0 = ::core::option::Option::Some(value.into());
+        GetActorCollectionsBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: get_actor_collections_state::State> GetActorCollectionsBuilder<'a, S> {
+    /// Set the `cursor` field (optional)
+    pub fn cursor(mut self, value: impl Into<Option<i64>>) -> Self {
+        self.__unsafe_private_named.1 = value.into();
+        self
+    }
+    /// Set the `cursor` field to an Option value (optional)
+    pub fn maybe_cursor(mut self, value: Option<i64>) -> Self {
+        self.__unsafe_private_named.1 = value;
+        self
+    }
+}
+
+impl<'a, S: get_actor_collections_state::State> GetActorCollectionsBuilder<'a, S> {
+    /// Set the `limit` field (optional)
+    pub fn limit(mut self, value: impl Into<Option<i64>>) -> Self {
+        self.__unsafe_private_named.2 = value.into();
+        self
+    }
+    /// Set the `limit` field to an Option value (optional)
+    pub fn maybe_limit(mut self, value: Option<i64>) -> Self {
+        self.__unsafe_private_named.2 = value;
+        self
+    }
+}
+
+impl<'a, S> GetActorCollectionsBuilder<'a, S>
+where
+    S: get_actor_collections_state::State,
+    S::Actor: get_actor_collections_state::IsSet,
+{
+    /// Build the final struct
+    pub fn build(self) -> GetActorCollections<'a> {
+        GetActorCollections {
+            actor: self.__unsafe_private_named.0.unwrap(),
+            cursor: self.__unsafe_private_named.1,
+            limit: self.__unsafe_private_named.2,
+        }
+    }
+}
+
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GetActorCollectionsOutput<'a> {
+    #[serde(borrow)]
+    pub collections: Vec<crate::net_gifdex::feed::CollectionView<'a>>,
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub cursor: std::option::Option<i64>,
+}
+
+#[jacquard_derive::open_union]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    thiserror::Error,
+    miette::Diagnostic,
+    jacquard_derive::IntoStatic
+)]
+#[serde(tag = "error", content = "message")]
+#[serde(bound(deserialize = "'de: 'a"))]
+pub enum GetActorCollectionsError<'a> {
+    /// The requested actor does not exist or has not been indexed yet.
+    #[serde(rename = "ActorNotFound")]
+    ActorNotFound(std::option::Option<jacquard_common::CowStr<'a>>),
+}
+
+impl core::fmt::Display for GetActorCollectionsError<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ActorNotFound(msg) => {
+                write!(f, "ActorNotFound")?;
+                if let Some(msg) = msg {
+                    write!(f, ": {}", msg)?;
+                }
+                Ok(())
+            }
+            Self::Unknown(err) => write!(f, "Unknown error: {:?}", err),
+        }
+    }
+}
+
+/// Response type for
+///net.gifdex.feed.getActorCollections
+pub struct GetActorCollectionsResponse;
+impl jacquard_common::xrpc::XrpcResp for GetActorCollectionsResponse {
+    const NSID: &'static str = "net.gifdex.feed.getActorCollections";
+    const ENCODING: &'static str = "application/json";
+    type Output<'de> = GetActorCollectionsOutput<'de>;
+    type Err<'de> = GetActorCollectionsError<'de>;
+}
+
+impl<'a> jacquard_common::xrpc::XrpcRequest for GetActorCollections<'a> {
+    const NSID: &'static str = "net.gifdex.feed.getActorCollections";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Query;
+    type Response = GetActorCollectionsResponse;
+}
+
+/// Endpoint type for
+///net.gifdex.feed.getActorCollections
+pub struct GetActorCollectionsRequest;
+impl jacquard_common::xrpc::XrpcEndpoint for GetActorCollectionsRequest {
+    const PATH: &'static str = "/xrpc/net.gifdex.feed.getActorCollections";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Query;
+    type Request<'de> = GetActorCollections<'de>;
+    type Response = GetActorCollectionsResponse;
+}
\ No newline at end of file
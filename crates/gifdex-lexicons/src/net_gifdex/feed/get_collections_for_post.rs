@@ -0,0 +1,194 @@
+// @generated by jacquard-lexicon. DO NOT EDIT.
+//
+// Lexicon: net.gifdex.feed.getCollectionsForPost
+//
+// This file was automatically generated from Lexicon schemas.
+// Any manual changes will be overwritten on the next regeneration.
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCollectionsForPost<'a> {
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub cursor: std::option::Option<i64>,
+    ///(default: 50, min: 1, max: 100)
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub limit: std::option::Option<i64>,
+    #[serde(borrow)]
+    pub uri: jacquard_common::types::string::AtUri<'a>,
+}
+
+pub mod get_collections_for_post_state {
+
+    pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
+    #[allow(unused)]
+    use ::core::marker::PhantomData;
+    mod sealed {
+        pub trait Sealed {}
+    }
+    /// State trait tracking which required fields have been set
+    pub trait State: sealed::Sealed {
+        type Uri;
+    }
+    /// Empty state - all required fields are unset
+    pub struct Empty(());
+    impl sealed::Sealed for Empty {}
+    impl State for Empty {
+        type Uri = Unset;
+    }
+    ///State transition - sets the `uri` field to Set
+    pub struct SetUri<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetUri<S> {}
+    impl<S: State> State for SetUri<S> {
+        type Uri = Set<members::uri>;
+    }
+    /// Marker types for field names
+    #[allow(non_camel_case_types)]
+    pub mod members {
+        ///Marker type for the `uri` field
+        pub struct uri(());
+    }
+}
+
+/// Builder for constructing an instance of this type
+pub struct GetCollectionsForPostBuilder<'a, S: get_collections_for_post_state::State> {
+    _phantom_state: ::core::marker::PhantomData<fn() -> S>,
+    __unsafe_private_named: (
+        ::core::option::Option<i64>,
+        ::core::option::Option<i64>,
+        ::core::option::Option<jacquard_common::types::string::AtUri<'a>>,
+    ),
+    _phantom: ::core::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> GetCollectionsForPost<'a> {
+    /// Create a new builder for this type
+    pub fn new() -> GetCollectionsForPostBuilder<
+        'a,
+        get_collections_for_post_state::Empty,
+    > {
+        GetCollectionsForPostBuilder::new()
+    }
+}
+
+impl<'a> GetCollectionsForPostBuilder<'a, get_collections_for_post_state::Empty> {
+    /// Create a new builder with all fields unset
+    pub fn new() -> Self {
+        GetCollectionsForPostBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: (None, None, None),
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: get_collections_for_post_state::State> GetCollectionsForPostBuilder<'a, S> {
+    /// Set the `cursor` field (optional)
+    pub fn cursor(mut self, value: impl Into<Option<i64>>) -> Self {
+        self.__unsafe_private_named.0 = value.into();
+        self
+    }
+    /// Set the `cursor` field to an Option value (optional)
+    pub fn maybe_cursor(mut self, value: Option<i64>) -> Self {
+        self.__unsafe_private_named.0 = value;
+        self
+    }
+}
+
+impl<'a, S: get_collections_for_post_state::State> GetCollectionsForPostBuilder<'a, S> {
+    /// Set the `limit` field (optional)
+    pub fn limit(mut self, value: impl Into<Option<i64>>) -> Self {
+        self.__unsafe_private_named.1 = value.into();
+        self
+    }
+    /// Set the `limit` field to an Option value (optional)
+    pub fn maybe_limit(mut self, value: Option<i64>) -> Self {
+        self.__unsafe_private_named.1 = value;
+        self
+    }
+}
+
+impl<'a, S> GetCollectionsForPostBuilder<'a, S>
+where
+    S: get_collections_for_post_state::State,
+    S::Uri: get_collections_for_post_state::IsUnset,
+{
+    /// Set the `uri` field (required)
+    pub fn uri(
+        mut self,
+        value: impl Into<jacquard_common::types::string::AtUri<'a>>,
+    ) -> GetCollectionsForPostBuilder<'a, get_collections_for_post_state::SetUri<S>> {
+        self.__unsafe_private_named.2 = ::core::option::Option::Some(value.into());
+        GetCollectionsForPostBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> GetCollectionsForPostBuilder<'a, S>
+where
+    S: get_collections_for_post_state::State,
+    S::Uri: get_collections_for_post_state::IsSet,
+{
+    /// Build the final struct
+    pub fn build(self) -> GetCollectionsForPost<'a> {
+        GetCollectionsForPost {
+            cursor: self.__unsafe_private_named.0,
+            limit: self.__unsafe_private_named.1,
+            uri: self.__unsafe_private_named.2.unwrap(),
+        }
+    }
+}
+
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCollectionsForPostOutput<'a> {
+    #[serde(borrow)]
+    pub collections: Vec<crate::net_gifdex::feed::CollectionView<'a>>,
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub cursor: std::option::Option<i64>,
+}
+
+/// Response type for
+///net.gifdex.feed.getCollectionsForPost
+pub struct GetCollectionsForPostResponse;
+impl jacquard_common::xrpc::XrpcResp for GetCollectionsForPostResponse {
+    const NSID: &'static str = "net.gifdex.feed.getCollectionsForPost";
+    const ENCODING: &'static str = "application/json";
+    type Output<'de> = GetCollectionsForPostOutput<'de>;
+    type Err<'de> = jacquard_common::xrpc::GenericError<'de>;
+}
+
+impl<'a> jacquard_common::xrpc::XrpcRequest for GetCollectionsForPost<'a> {
+    const NSID: &'static str = "net.gifdex.feed.getCollectionsForPost";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Query;
+    type Response = GetCollectionsForPostResponse;
+}
+
+/// Endpoint type for
+///net.gifdex.feed.getCollectionsForPost
+pub struct GetCollectionsForPostRequest;
+impl jacquard_common::xrpc::XrpcEndpoint for GetCollectionsForPostRequest {
+    const PATH: &'static str = "/xrpc/net.gifdex.feed.getCollectionsForPost";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Query;
+    type Request<'de> = GetCollectionsForPost<'de>;
+    type Response = GetCollectionsForPostResponse;
+}
\ No newline at end of file
@@ -181,6 +181,12 @@ pub enum GetPostError<'a> {
     /// The requested post does not exist or has not been indexed yet.
     #[serde(rename = "PostNotFound")]
     PostNotFound(std::option::Option<jacquard_common::CowStr<'a>>),
+    /// The account is deactivated or suspended and its content is unavailable.
+    #[serde(rename = "AccountDeactivated")]
+    AccountDeactivated(std::option::Option<jacquard_common::CowStr<'a>>),
+    /// The account has been taken down and its content is unavailable.
+    #[serde(rename = "AccountTakedown")]
+    AccountTakedown(std::option::Option<jacquard_common::CowStr<'a>>),
 }
 
 impl core::fmt::Display for GetPostError<'_> {
@@ -193,6 +199,20 @@ impl core::fmt::Display for GetPostError<'_> {
                 }
                 Ok(())
             }
+            Self::AccountDeactivated(msg) => {
+                write!(f, "AccountDeactivated")?;
+                if let Some(msg) = msg {
+                    write!(f, ": {}", msg)?;
+                }
+                Ok(())
+            }
+            Self::AccountTakedown(msg) => {
+                write!(f, "AccountTakedown")?;
+                if let Some(msg) = msg {
+                    write!(f, ": {}", msg)?;
+                }
+                Ok(())
+            }
             Self::Unknown(err) => write!(f, "Unknown error: {:?}", err),
         }
     }
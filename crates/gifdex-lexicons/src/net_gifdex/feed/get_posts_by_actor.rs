@@ -149,7 +149,7 @@ pub struct GetPostsByActorBuilder<'a, S: get_posts_by_actor_state::State> {
         ::core::option::Option<jacquard_common::types::string::Did<'a>>,
         ::core::option::Option<i64>,
         ::core::option::Option<i64>,
-        ::core::option::Option<jacquard_common::CowStr<'a>>,
+        ::core::option::Option<GetPostsByActorSortBy>,
     ),
     _phantom: ::core::marker::PhantomData<&'a ()>,
 }
@@ -221,13 +221,13 @@ impl<'a, S: get_posts_by_actor_state::State> GetPostsByActorBuilder<'a, S> {
     /// Set the `sortBy` field (optional)
     pub fn sort_by(
         mut self,
-        value: impl Into<Option<jacquard_common::CowStr<'a>>>,
+        value: impl Into<Option<GetPostsByActorSortBy>>,
     ) -> Self {
         self.__unsafe_private_named.3 = value.into();
         self
     }
     /// Set the `sortBy` field to an Option value (optional)
-    pub fn maybe_sort_by(mut self, value: Option<jacquard_common::CowStr<'a>>) -> Self {
+    pub fn maybe_sort_by(mut self, value: Option<GetPostsByActorSortBy>) -> Self {
         self.__unsafe_private_named.3 = value;
         self
     }
@@ -156,7 +156,7 @@ pub struct GetPostsByQueryBuilder<'a, S: get_posts_by_query_state::State> {
         ::core::option::Option<i64>,
         ::core::option::Option<i64>,
         ::core::option::Option<jacquard_common::CowStr<'a>>,
-        ::core::option::Option<jacquard_common::CowStr<'a>>,
+        ::core::option::Option<GetPostsByQuerySortBy>,
     ),
     _phantom: ::core::marker::PhantomData<&'a ()>,
 }
@@ -244,13 +244,13 @@ impl<'a, S: get_posts_by_query_state::State> GetPostsByQueryBuilder<'a, S> {
     /// Set the `sortBy` field (optional)
     pub fn sort_by(
         mut self,
-        value: impl Into<Option<jacquard_common::CowStr<'a>>>,
+        value: impl Into<Option<GetPostsByQuerySortBy>>,
     ) -> Self {
         self.__unsafe_private_named.4 = value.into();
         self
     }
     /// Set the `sortBy` field to an Option value (optional)
-    pub fn maybe_sort_by(mut self, value: Option<jacquard_common::CowStr<'a>>) -> Self {
+    pub fn maybe_sort_by(mut self, value: Option<GetPostsByQuerySortBy>) -> Self {
         self.__unsafe_private_named.4 = value;
         self
     }
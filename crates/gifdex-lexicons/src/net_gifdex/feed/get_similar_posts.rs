@@ -0,0 +1,243 @@
+// @generated by jacquard-lexicon. DO NOT EDIT.
+//
+// Lexicon: net.gifdex.feed.getSimilarPosts
+//
+// This file was automatically generated from Lexicon schemas.
+// Any manual changes will be overwritten on the next regeneration.
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSimilarPosts<'a> {
+    #[serde(borrow)]
+    pub actor: jacquard_common::types::string::Did<'a>,
+    ///(default: 25, min: 1, max: 100)
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub limit: std::option::Option<i64>,
+    #[serde(borrow)]
+    pub rkey: jacquard_common::CowStr<'a>,
+}
+
+pub mod get_similar_posts_state {
+
+    pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
+    #[allow(unused)]
+    use ::core::marker::PhantomData;
+    mod sealed {
+        pub trait Sealed {}
+    }
+    /// State trait tracking which required fields have been set
+    pub trait State: sealed::Sealed {
+        type Rkey;
+        type Actor;
+    }
+    /// Empty state - all required fields are unset
+    pub struct Empty(());
+    impl sealed::Sealed for Empty {}
+    impl State for Empty {
+        type Rkey = Unset;
+        type Actor = Unset;
+    }
+    ///State transition - sets the `rkey` field to Set
+    pub struct SetRkey<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetRkey<S> {}
+    impl<S: State> State for SetRkey<S> {
+        type Rkey = Set<members::rkey>;
+        type Actor = S::Actor;
+    }
+    ///State transition - sets the `actor` field to Set
+    pub struct SetActor<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetActor<S> {}
+    impl<S: State> State for SetActor<S> {
+        type Rkey = S::Rkey;
+        type Actor = Set<members::actor>;
+    }
+    /// Marker types for field names
+    #[allow(non_camel_case_types)]
+    pub mod members {
+        ///Marker type for the `rkey` field
+        pub struct rkey(());
+        ///Marker type for the `actor` field
+        pub struct actor(());
+    }
+}
+
+/// Builder for constructing an instance of this type
+pub struct GetSimilarPostsBuilder<'a, S: get_similar_posts_state::State> {
+    _phantom_state: ::core::marker::PhantomData<fn() -> S>,
+    __unsafe_private_named: (
+        ::core::option::Option<jacquard_common::types::string::Did<'a>>,
+        ::core::option::Option<i64>,
+        ::core::option::Option<jacquard_common::CowStr<'a>>,
+    ),
+    _phantom: ::core::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> GetSimilarPosts<'a> {
+    /// Create a new builder for this type
+    pub fn new() -> GetSimilarPostsBuilder<'a, get_similar_posts_state::Empty> {
+        GetSimilarPostsBuilder::new()
+    }
+}
+
+impl<'a> GetSimilarPostsBuilder<'a, get_similar_posts_state::Empty> {
+    /// Create a new builder with all fields unset
+    pub fn new() -> Self {
+        GetSimilarPostsBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: (None, None, None),
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> GetSimilarPostsBuilder<'a, S>
+where
+    S: get_similar_posts_state::State,
+    S::Actor: get_similar_posts_state::IsUnset,
+{
+    /// Set the `actor` field (required)
+    pub fn actor(
+        mut self,
+        value: impl Into<jacquard_common::types::string::Did<'a>>,
+    ) -> GetSimilarPostsBuilder<'a, get_similar_posts_state::SetActor<S>> {
+        self.__unsafe_private_named.0 = ::core::option::Option::Some(value.into());
+        GetSimilarPostsBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: get_similar_posts_state::State> GetSimilarPostsBuilder<'a, S> {
+    /// Set the `limit` field (optional)
+    pub fn limit(mut self, value: impl Into<Option<i64>>) -> Self {
+        self.__unsafe_private_named.1 = value.into();
+        self
+    }
+    /// Set the `limit` field to an Option value (optional)
+    pub fn maybe_limit(mut self, value: Option<i64>) -> Self {
+        self.__unsafe_private_named.1 = value;
+        self
+    }
+}
+
+impl<'a, S> GetSimilarPostsBuilder<'a, S>
+where
+    S: get_similar_posts_state::State,
+    S::Rkey: get_similar_posts_state::IsUnset,
+{
+    /// Set the `rkey` field (required)
+    pub fn rkey(
+        mut self,
+        value: impl Into<jacquard_common::CowStr<'a>>,
+    ) -> GetSimilarPostsBuilder<'a, get_similar_posts_state::SetRkey<S>> {
+        self.__unsafe_private_named.2 = ::core::option::Option::Some(value.into());
+        GetSimilarPostsBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> GetSimilarPostsBuilder<'a, S>
+where
+    S: get_similar_posts_state::State,
+    S::Rkey: get_similar_posts_state::IsSet,
+    S::Actor: get_similar_posts_state::IsSet,
+{
+    /// Build the final struct
+    pub fn build(self) -> GetSimilarPosts<'a> {
+        GetSimilarPosts {
+            actor: self.__unsafe_private_named.0.unwrap(),
+            limit: self.__unsafe_private_named.1,
+            rkey: self.__unsafe_private_named.2.unwrap(),
+        }
+    }
+}
+
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSimilarPostsOutput<'a> {
+    #[serde(borrow)]
+    pub feed: Vec<crate::net_gifdex::feed::PostFeedView<'a>>,
+}
+
+#[jacquard_derive::open_union]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    thiserror::Error,
+    miette::Diagnostic,
+    jacquard_derive::IntoStatic
+)]
+#[serde(tag = "error", content = "message")]
+#[serde(bound(deserialize = "'de: 'a"))]
+pub enum GetSimilarPostsError<'a> {
+    /// The requested post does not exist or has not been indexed yet.
+    #[serde(rename = "PostNotFound")]
+    PostNotFound(std::option::Option<jacquard_common::CowStr<'a>>),
+}
+
+impl core::fmt::Display for GetSimilarPostsError<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::PostNotFound(msg) => {
+                write!(f, "PostNotFound")?;
+                if let Some(msg) = msg {
+                    write!(f, ": {}", msg)?;
+                }
+                Ok(())
+            }
+            Self::Unknown(err) => write!(f, "Unknown error: {:?}", err),
+        }
+    }
+}
+
+/// Response type for
+///net.gifdex.feed.getSimilarPosts
+pub struct GetSimilarPostsResponse;
+impl jacquard_common::xrpc::XrpcResp for GetSimilarPostsResponse {
+    const NSID: &'static str = "net.gifdex.feed.getSimilarPosts";
+    const ENCODING: &'static str = "application/json";
+    type Output<'de> = GetSimilarPostsOutput<'de>;
+    type Err<'de> = GetSimilarPostsError<'de>;
+}
+
+impl<'a> jacquard_common::xrpc::XrpcRequest for GetSimilarPosts<'a> {
+    const NSID: &'static str = "net.gifdex.feed.getSimilarPosts";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Query;
+    type Response = GetSimilarPostsResponse;
+}
+
+/// Endpoint type for
+///net.gifdex.feed.getSimilarPosts
+pub struct GetSimilarPostsRequest;
+impl jacquard_common::xrpc::XrpcEndpoint for GetSimilarPostsRequest {
+    const PATH: &'static str = "/xrpc/net.gifdex.feed.getSimilarPosts";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Query;
+    type Request<'de> = GetSimilarPosts<'de>;
+    type Response = GetSimilarPostsResponse;
+}
\ No newline at end of file
@@ -19,6 +19,10 @@
 pub struct Post<'a> {
     /// Client-declared timestamp when this post was originally created.
     pub created_at: jacquard_common::types::string::Datetime,
+    /// Labeler rules with selfLabel set that the author applies to their own post, such as to mark it as adult content or a spoiler.
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    #[serde(borrow)]
+    pub labels: std::option::Option<Vec<jacquard_common::types::string::AtUri<'a>>>,
     #[serde(borrow)]
     pub media: crate::net_gifdex::feed::post::PostMedia<'a>,
     /// Tags that apply to the content of the post, used for discoverability.
@@ -93,6 +97,7 @@ pub struct PostBuilder<'a, S: post_state::State> {
     _phantom_state: ::core::marker::PhantomData<fn() -> S>,
     __unsafe_private_named: (
         ::core::option::Option<jacquard_common::types::string::Datetime>,
+        ::core::option::Option<Vec<jacquard_common::types::string::AtUri<'a>>>,
         ::core::option::Option<crate::net_gifdex::feed::post::PostMedia<'a>>,
         ::core::option::Option<Vec<jacquard_common::CowStr<'a>>>,
         ::core::option::Option<jacquard_common::CowStr<'a>>,
@@ -112,7 +117,7 @@ impl<'a> PostBuilder<'a, post_state::Empty> {
     pub fn new() -> Self {
         PostBuilder {
             _phantom_state: ::core::marker::PhantomData,
-            __unsafe_private_named: (None, None, None, None),
+            __unsafe_private_named: (None, None, None, None, None),
             _phantom: ::core::marker::PhantomData,
         }
     }
@@ -137,6 +142,25 @@ where
     }
 }
 
+impl<'a, S: post_state::State> PostBuilder<'a, S> {
+    /// Set the `labels` field (optional)
+    pub fn labels(
+        mut self,
+        value: impl Into<Option<Vec<jacquard_common::types::string::AtUri<'a>>>>,
+    ) -> Self {
+        self.__unsafe_private_named.1 = value.into();
+        self
+    }
+    /// Set the `labels` field to an Option value (optional)
+    pub fn maybe_labels(
+        mut self,
+        value: Option<Vec<jacquard_common::types::string::AtUri<'a>>>,
+    ) -> Self {
+        self.__unsafe_private_named.1 = value;
+        self
+    }
+}
+
 impl<'a, S> PostBuilder<'a, S>
 where
     S: post_state::State,
@@ -147,7 +171,7 @@ where
         mut self,
         value: impl Into<crate::net_gifdex::feed::post::PostMedia<'a>>,
     ) -> PostBuilder<'a, post_state::SetMedia<S>> {
-        self.__unsafe_private_named.1 = ::core::option::Option::Some(value.into());
+        self.__unsafe_private_named.2 = ::core::option::Option::Some(value.into());
         PostBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
@@ -162,7 +186,7 @@ impl<'a, S: post_state::State> PostBuilder<'a, S> {
         mut self,
         value: impl Into<Option<Vec<jacquard_common::CowStr<'a>>>>,
     ) -> Self {
-        self.__unsafe_private_named.2 = value.into();
+        self.__unsafe_private_named.3 = value.into();
         self
     }
     /// Set the `tags` field to an Option value (optional)
@@ -170,7 +194,7 @@ impl<'a, S: post_state::State> PostBuilder<'a, S> {
         mut self,
         value: Option<Vec<jacquard_common::CowStr<'a>>>,
     ) -> Self {
-        self.__unsafe_private_named.2 = value;
+        self.__unsafe_private_named.3 = value;
         self
     }
 }
@@ -185,7 +209,7 @@ where
         mut self,
         value: impl Into<jacquard_common::CowStr<'a>>,
     ) -> PostBuilder<'a, post_state::SetTitle<S>> {
-        self.__unsafe_private_named.3 = ::core::option::Option::Some(value.into());
+        self.__unsafe_private_named.4 = ::core::option::Option::Some(value.into());
         PostBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
@@ -205,9 +229,10 @@ where
     pub fn build(self) -> Post<'a> {
         Post {
             created_at: self.__unsafe_private_named.0.unwrap(),
-            media: self.__unsafe_private_named.1.unwrap(),
-            tags: self.__unsafe_private_named.2,
-            title: self.__unsafe_private_named.3.unwrap(),
+            labels: self.__unsafe_private_named.1,
+            media: self.__unsafe_private_named.2.unwrap(),
+            tags: self.__unsafe_private_named.3,
+            title: self.__unsafe_private_named.4.unwrap(),
             extra_data: Default::default(),
         }
     }
@@ -221,9 +246,10 @@ where
     ) -> Post<'a> {
         Post {
             created_at: self.__unsafe_private_named.0.unwrap(),
-            media: self.__unsafe_private_named.1.unwrap(),
-            tags: self.__unsafe_private_named.2,
-            title: self.__unsafe_private_named.3.unwrap(),
+            labels: self.__unsafe_private_named.1,
+            media: self.__unsafe_private_named.2.unwrap(),
+            tags: self.__unsafe_private_named.3,
+            title: self.__unsafe_private_named.4.unwrap(),
             extra_data: Some(extra_data),
         }
     }
@@ -303,6 +329,18 @@ impl<'a> ::jacquard_lexicon::schema::LexiconSchema for Post<'a> {
     fn validate(
         &self,
     ) -> ::core::result::Result<(), ::jacquard_lexicon::validation::ConstraintError> {
+        if let Some(ref value) = self.labels {
+            #[allow(unused_comparisons)]
+            if value.len() > 5usize {
+                return Err(::jacquard_lexicon::validation::ConstraintError::MaxLength {
+                    path: ::jacquard_lexicon::validation::ValidationPath::from_field(
+                        "labels",
+                    ),
+                    max: 5usize,
+                    actual: value.len(),
+                });
+            }
+        }
         if let Some(ref value) = self.tags {
             #[allow(unused_comparisons)]
             if value.len() > 5usize {
@@ -389,6 +427,32 @@ fn lexicon_doc_net_gifdex_feed_post() -> ::jacquard_lexicon::lexicon::LexiconDoc
                                     known_values: None,
                                 }),
                             );
+                            map.insert(
+                                ::jacquard_common::smol_str::SmolStr::new_static("labels"),
+                                ::jacquard_lexicon::lexicon::LexObjectProperty::Array(::jacquard_lexicon::lexicon::LexArray {
+                                    description: Some(
+                                        ::jacquard_common::CowStr::new_static(
+                                            "Labeler rules with selfLabel set that the author applies to their own post, such as to mark it as adult content or a spoiler.",
+                                        ),
+                                    ),
+                                    items: ::jacquard_lexicon::lexicon::LexArrayItem::String(::jacquard_lexicon::lexicon::LexString {
+                                        description: None,
+                                        format: Some(
+                                            ::jacquard_lexicon::lexicon::LexStringFormat::AtUri,
+                                        ),
+                                        default: None,
+                                        min_length: None,
+                                        max_length: None,
+                                        min_graphemes: None,
+                                        max_graphemes: None,
+                                        r#enum: None,
+                                        r#const: None,
+                                        known_values: None,
+                                    }),
+                                    min_length: None,
+                                    max_length: Some(5usize),
+                                }),
+                            );
                             map.insert(
                                 ::jacquard_common::smol_str::SmolStr::new_static("media"),
                                 ::jacquard_lexicon::lexicon::LexObjectProperty::Ref(::jacquard_lexicon::lexicon::LexRef {
@@ -0,0 +1,10 @@
+// @generated by jacquard-lexicon. DO NOT EDIT.
+//
+// This file was automatically generated from Lexicon schemas.
+// Any manual changes will be overwritten on the next regeneration.
+
+pub mod block;
+pub mod follow;
+pub mod get_mutes;
+pub mod mute_actor;
+pub mod unmute_actor;
\ No newline at end of file
@@ -0,0 +1,157 @@
+// @generated by jacquard-lexicon. DO NOT EDIT.
+//
+// Lexicon: net.gifdex.graph.muteActor
+//
+// This file was automatically generated from Lexicon schemas.
+// Any manual changes will be overwritten on the next regeneration.
+
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct MuteActor<'a> {
+    #[serde(borrow)]
+    pub actor: jacquard_common::types::string::Did<'a>,
+}
+
+pub mod mute_actor_state {
+
+    pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
+    #[allow(unused)]
+    use ::core::marker::PhantomData;
+    mod sealed {
+        pub trait Sealed {}
+    }
+    /// State trait tracking which required fields have been set
+    pub trait State: sealed::Sealed {
+        type Actor;
+    }
+    /// Empty state - all required fields are unset
+    pub struct Empty(());
+    impl sealed::Sealed for Empty {}
+    impl State for Empty {
+        type Actor = Unset;
+    }
+    ///State transition - sets the `actor` field to Set
+    pub struct SetActor<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetActor<S> {}
+    impl<S: State> State for SetActor<S> {
+        type Actor = Set<members::actor>;
+    }
+    /// Marker types for field names
+    #[allow(non_camel_case_types)]
+    pub mod members {
+        ///Marker type for the `actor` field
+        pub struct actor(());
+    }
+}
+
+/// Builder for constructing an instance of this type
+pub struct MuteActorBuilder<'a, S: mute_actor_state::State> {
+    _phantom_state: ::core::marker::PhantomData<fn() -> S>,
+    __unsafe_private_named: (
+        ::core::option::Option<jacquard_common::types::string::Did<'a>>,
+    ),
+    _phantom: ::core::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> MuteActor<'a> {
+    /// Create a new builder for this type
+    pub fn new() -> MuteActorBuilder<'a, mute_actor_state::Empty> {
+        MuteActorBuilder::new()
+    }
+}
+
+impl<'a> MuteActorBuilder<'a, mute_actor_state::Empty> {
+    /// Create a new builder with all fields unset
+    pub fn new() -> Self {
+        MuteActorBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: (None,),
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> MuteActorBuilder<'a, S>
+where
+    S: mute_actor_state::State,
+    S::Actor: mute_actor_state::IsUnset,
+{
+    /// Set the `actor` field (required)
+    pub fn actor(
+        mut self,
+        value: impl Into<jacquard_common::types::string::Did<'a>>,
+    ) -> MuteActorBuilder<'a, mute_actor_state::SetActor<S>> {
+        self.__unsafe_private_named.0 = ::core::option::Option::Some(value.into());
+        MuteActorBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> MuteActorBuilder<'a, S>
+where
+    S: mute_actor_state::State,
+    S::Actor: mute_actor_state::IsSet,
+{
+    /// Build the final struct
+    pub fn build(self) -> MuteActor<'a> {
+        MuteActor {
+            actor: self.__unsafe_private_named.0.unwrap(),
+            extra_data: Default::default(),
+        }
+    }
+    /// Build the final struct with custom extra_data
+    pub fn build_with_data(
+        self,
+        extra_data: std::collections::BTreeMap<
+            jacquard_common::smol_str::SmolStr,
+            jacquard_common::types::value::Data<'a>,
+        >,
+    ) -> MuteActor<'a> {
+        MuteActor {
+            actor: self.__unsafe_private_named.0.unwrap(),
+            extra_data: Some(extra_data),
+        }
+    }
+}
+
+/// Response type for
+///net.gifdex.graph.muteActor
+pub struct MuteActorResponse;
+impl jacquard_common::xrpc::XrpcResp for MuteActorResponse {
+    const NSID: &'static str = "net.gifdex.graph.muteActor";
+    const ENCODING: &'static str = "application/json";
+    type Output<'de> = ();
+    type Err<'de> = jacquard_common::xrpc::GenericError<'de>;
+}
+
+impl<'a> jacquard_common::xrpc::XrpcRequest for MuteActor<'a> {
+    const NSID: &'static str = "net.gifdex.graph.muteActor";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Procedure(
+        "application/json",
+    );
+    type Response = MuteActorResponse;
+}
+
+/// Endpoint type for
+///net.gifdex.graph.muteActor
+pub struct MuteActorRequest;
+impl jacquard_common::xrpc::XrpcEndpoint for MuteActorRequest {
+    const PATH: &'static str = "/xrpc/net.gifdex.graph.muteActor";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Procedure(
+        "application/json",
+    );
+    type Request<'de> = MuteActor<'de>;
+    type Response = MuteActorResponse;
+}
\ No newline at end of file
@@ -0,0 +1,157 @@
+// @generated by jacquard-lexicon. DO NOT EDIT.
+//
+// Lexicon: net.gifdex.graph.unmuteActor
+//
+// This file was automatically generated from Lexicon schemas.
+// Any manual changes will be overwritten on the next regeneration.
+
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct UnmuteActor<'a> {
+    #[serde(borrow)]
+    pub actor: jacquard_common::types::string::Did<'a>,
+}
+
+pub mod unmute_actor_state {
+
+    pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
+    #[allow(unused)]
+    use ::core::marker::PhantomData;
+    mod sealed {
+        pub trait Sealed {}
+    }
+    /// State trait tracking which required fields have been set
+    pub trait State: sealed::Sealed {
+        type Actor;
+    }
+    /// Empty state - all required fields are unset
+    pub struct Empty(());
+    impl sealed::Sealed for Empty {}
+    impl State for Empty {
+        type Actor = Unset;
+    }
+    ///State transition - sets the `actor` field to Set
+    pub struct SetActor<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetActor<S> {}
+    impl<S: State> State for SetActor<S> {
+        type Actor = Set<members::actor>;
+    }
+    /// Marker types for field names
+    #[allow(non_camel_case_types)]
+    pub mod members {
+        ///Marker type for the `actor` field
+        pub struct actor(());
+    }
+}
+
+/// Builder for constructing an instance of this type
+pub struct UnmuteActorBuilder<'a, S: unmute_actor_state::State> {
+    _phantom_state: ::core::marker::PhantomData<fn() -> S>,
+    __unsafe_private_named: (
+        ::core::option::Option<jacquard_common::types::string::Did<'a>>,
+    ),
+    _phantom: ::core::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> UnmuteActor<'a> {
+    /// Create a new builder for this type
+    pub fn new() -> UnmuteActorBuilder<'a, unmute_actor_state::Empty> {
+        UnmuteActorBuilder::new()
+    }
+}
+
+impl<'a> UnmuteActorBuilder<'a, unmute_actor_state::Empty> {
+    /// Create a new builder with all fields unset
+    pub fn new() -> Self {
+        UnmuteActorBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: (None,),
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> UnmuteActorBuilder<'a, S>
+where
+    S: unmute_actor_state::State,
+    S::Actor: unmute_actor_state::IsUnset,
+{
+    /// Set the `actor` field (required)
+    pub fn actor(
+        mut self,
+        value: impl Into<jacquard_common::types::string::Did<'a>>,
+    ) -> UnmuteActorBuilder<'a, unmute_actor_state::SetActor<S>> {
+        self.__unsafe_private_named.0 = ::core::option::Option::Some(value.into());
+        UnmuteActorBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> UnmuteActorBuilder<'a, S>
+where
+    S: unmute_actor_state::State,
+    S::Actor: unmute_actor_state::IsSet,
+{
+    /// Build the final struct
+    pub fn build(self) -> UnmuteActor<'a> {
+        UnmuteActor {
+            actor: self.__unsafe_private_named.0.unwrap(),
+            extra_data: Default::default(),
+        }
+    }
+    /// Build the final struct with custom extra_data
+    pub fn build_with_data(
+        self,
+        extra_data: std::collections::BTreeMap<
+            jacquard_common::smol_str::SmolStr,
+            jacquard_common::types::value::Data<'a>,
+        >,
+    ) -> UnmuteActor<'a> {
+        UnmuteActor {
+            actor: self.__unsafe_private_named.0.unwrap(),
+            extra_data: Some(extra_data),
+        }
+    }
+}
+
+/// Response type for
+///net.gifdex.graph.unmuteActor
+pub struct UnmuteActorResponse;
+impl jacquard_common::xrpc::XrpcResp for UnmuteActorResponse {
+    const NSID: &'static str = "net.gifdex.graph.unmuteActor";
+    const ENCODING: &'static str = "application/json";
+    type Output<'de> = ();
+    type Err<'de> = jacquard_common::xrpc::GenericError<'de>;
+}
+
+impl<'a> jacquard_common::xrpc::XrpcRequest for UnmuteActor<'a> {
+    const NSID: &'static str = "net.gifdex.graph.unmuteActor";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Procedure(
+        "application/json",
+    );
+    type Response = UnmuteActorResponse;
+}
+
+/// Endpoint type for
+///net.gifdex.graph.unmuteActor
+pub struct UnmuteActorRequest;
+impl jacquard_common::xrpc::XrpcEndpoint for UnmuteActorRequest {
+    const PATH: &'static str = "/xrpc/net.gifdex.graph.unmuteActor";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Procedure(
+        "application/json",
+    );
+    type Request<'de> = UnmuteActor<'de>;
+    type Response = UnmuteActorResponse;
+}
\ No newline at end of file
@@ -21,6 +21,9 @@ pub struct Label<'a> {
     /// Optional expiration time for this label
     #[serde(skip_serializing_if = "std::option::Option::is_none")]
     pub expires_at: std::option::Option<jacquard_common::types::string::Datetime>,
+    /// When the subject is a post, also apply this label to every post with visually similar media, such as re-encoded copies
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    pub match_media_hash: std::option::Option<bool>,
     /// Optional context for why this label was applied
     #[serde(skip_serializing_if = "std::option::Option::is_none")]
     #[serde(borrow)]
@@ -97,6 +100,7 @@ pub struct LabelBuilder<'a, S: label_state::State> {
     __unsafe_private_named: (
         ::core::option::Option<jacquard_common::types::string::Datetime>,
         ::core::option::Option<jacquard_common::types::string::Datetime>,
+        ::core::option::Option<bool>,
         ::core::option::Option<jacquard_common::CowStr<'a>>,
         ::core::option::Option<jacquard_common::types::string::AtUri<'a>>,
         ::core::option::Option<jacquard_common::types::string::AtUri<'a>>,
@@ -116,7 +120,7 @@ impl<'a> LabelBuilder<'a, label_state::Empty> {
     pub fn new() -> Self {
         LabelBuilder {
             _phantom_state: ::core::marker::PhantomData,
-            __unsafe_private_named: (None, None, None, None, None),
+            __unsafe_private_named: (None, None, None, None, None, None),
             _phantom: ::core::marker::PhantomData,
         }
     }
@@ -160,18 +164,31 @@ impl<'a, S: label_state::State> LabelBuilder<'a, S> {
     }
 }
 
+impl<'a, S: label_state::State> LabelBuilder<'a, S> {
+    /// Set the `matchMediaHash` field (optional)
+    pub fn match_media_hash(mut self, value: impl Into<Option<bool>>) -> Self {
+        self.__unsafe_private_named.2 = value.into();
+        self
+    }
+    /// Set the `matchMediaHash` field to an Option value (optional)
+    pub fn maybe_match_media_hash(mut self, value: Option<bool>) -> Self {
+        self.__unsafe_private_named.2 = value;
+        self
+    }
+}
+
 impl<'a, S: label_state::State> LabelBuilder<'a, S> {
     /// Set the `reason` field (optional)
     pub fn reason(
         mut self,
         value: impl Into<Option<jacquard_common::CowStr<'a>>>,
     ) -> Self {
-        self.__unsafe_private_named.2 = value.into();
+        self.__unsafe_private_named.3 = value.into();
         self
     }
     /// Set the `reason` field to an Option value (optional)
     pub fn maybe_reason(mut self, value: Option<jacquard_common::CowStr<'a>>) -> Self {
-        self.__unsafe_private_named.2 = value;
+        self.__unsafe_private_named.3 = value;
         self
     }
 }
@@ -186,7 +203,7 @@ where
         mut self,
         value: impl Into<jacquard_common::types::string::AtUri<'a>>,
     ) -> LabelBuilder<'a, label_state::SetRule<S>> {
-        self.__unsafe_private_named.3 = ::core::option::Option::Some(value.into());
+        self.__unsafe_private_named.4 = ::core::option::Option::Some(value.into());
         LabelBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
@@ -205,7 +222,7 @@ where
         mut self,
         value: impl Into<jacquard_common::types::string::AtUri<'a>>,
     ) -> LabelBuilder<'a, label_state::SetSubject<S>> {
-        self.__unsafe_private_named.4 = ::core::option::Option::Some(value.into());
+        self.__unsafe_private_named.5 = ::core::option::Option::Some(value.into());
         LabelBuilder {
             _phantom_state: ::core::marker::PhantomData,
             __unsafe_private_named: self.__unsafe_private_named,
@@ -226,9 +243,10 @@ where
         Label {
             created_at: self.__unsafe_private_named.0.unwrap(),
             expires_at: self.__unsafe_private_named.1,
-            reason: self.__unsafe_private_named.2,
-            rule: self.__unsafe_private_named.3.unwrap(),
-            subject: self.__unsafe_private_named.4.unwrap(),
+            match_media_hash: self.__unsafe_private_named.2,
+            reason: self.__unsafe_private_named.3,
+            rule: self.__unsafe_private_named.4.unwrap(),
+            subject: self.__unsafe_private_named.5.unwrap(),
             extra_data: Default::default(),
         }
     }
@@ -243,9 +261,10 @@ where
         Label {
             created_at: self.__unsafe_private_named.0.unwrap(),
             expires_at: self.__unsafe_private_named.1,
-            reason: self.__unsafe_private_named.2,
-            rule: self.__unsafe_private_named.3.unwrap(),
-            subject: self.__unsafe_private_named.4.unwrap(),
+            match_media_hash: self.__unsafe_private_named.2,
+            reason: self.__unsafe_private_named.3,
+            rule: self.__unsafe_private_named.4.unwrap(),
+            subject: self.__unsafe_private_named.5.unwrap(),
             extra_data: Some(extra_data),
         }
     }
@@ -417,6 +436,16 @@ fn lexicon_doc_net_gifdex_labeler_label() -> ::jacquard_lexicon::lexicon::Lexico
                                     known_values: None,
                                 }),
                             );
+                            map.insert(
+                                ::jacquard_common::smol_str::SmolStr::new_static(
+                                    "matchMediaHash",
+                                ),
+                                ::jacquard_lexicon::lexicon::LexObjectProperty::Boolean(::jacquard_lexicon::lexicon::LexBoolean {
+                                    description: None,
+                                    default: None,
+                                    r#const: None,
+                                }),
+                            );
                             map.insert(
                                 ::jacquard_common::smol_str::SmolStr::new_static("reason"),
                                 ::jacquard_lexicon::lexicon::LexObjectProperty::String(::jacquard_lexicon::lexicon::LexString {
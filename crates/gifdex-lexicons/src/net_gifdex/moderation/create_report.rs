@@ -0,0 +1,369 @@
+// @generated by jacquard-lexicon. DO NOT EDIT.
+//
+// Lexicon: net.gifdex.moderation.createReport
+//
+// This file was automatically generated from Lexicon schemas.
+// Any manual changes will be overwritten on the next regeneration.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CreateReportReasonType {
+    Spam,
+    Violation,
+    Misleading,
+    Sexual,
+    Rude,
+    Other,
+}
+
+impl CreateReportReasonType {
+    /// Returns the string representation of this enum variant.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Spam => "spam",
+            Self::Violation => "violation",
+            Self::Misleading => "misleading",
+            Self::Sexual => "sexual",
+            Self::Rude => "rude",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl core::str::FromStr for CreateReportReasonType {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "spam" => Ok(Self::Spam),
+            "violation" => Ok(Self::Violation),
+            "misleading" => Ok(Self::Misleading),
+            "sexual" => Ok(Self::Sexual),
+            "rude" => Ok(Self::Rude),
+            "other" => Ok(Self::Other),
+            _ => {
+                Err(
+                    format!(
+                        "invalid value '{}', expected one of: {}", s,
+                        "spam, violation, misleading, sexual, rude, other"
+                    ),
+                )
+            }
+        }
+    }
+}
+
+impl TryFrom<&str> for CreateReportReasonType {
+    type Error = String;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl core::fmt::Display for CreateReportReasonType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl AsRef<str> for CreateReportReasonType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl serde::Serialize for CreateReportReasonType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CreateReportReasonType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = <&str>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl jacquard_common::IntoStatic for CreateReportReasonType {
+    type Output = Self;
+    fn into_static(self) -> Self::Output {
+        self
+    }
+}
+
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateReport<'a> {
+    /// Additional context about the report.
+    #[serde(skip_serializing_if = "std::option::Option::is_none")]
+    #[serde(borrow)]
+    pub reason: std::option::Option<jacquard_common::CowStr<'a>>,
+    pub reason_type: CreateReportReasonType,
+    /// The DID of the account, or the at-uri of the record, to report.
+    #[serde(borrow)]
+    pub subject: jacquard_common::types::string::Uri<'a>,
+}
+
+pub mod create_report_state {
+
+    pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
+    #[allow(unused)]
+    use ::core::marker::PhantomData;
+    mod sealed {
+        pub trait Sealed {}
+    }
+    /// State trait tracking which required fields have been set
+    pub trait State: sealed::Sealed {
+        type Subject;
+        type ReasonType;
+    }
+    /// Empty state - all required fields are unset
+    pub struct Empty(());
+    impl sealed::Sealed for Empty {}
+    impl State for Empty {
+        type Subject = Unset;
+        type ReasonType = Unset;
+    }
+    ///State transition - sets the `subject` field to Set
+    pub struct SetSubject<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetSubject<S> {}
+    impl<S: State> State for SetSubject<S> {
+        type Subject = Set<members::subject>;
+        type ReasonType = S::ReasonType;
+    }
+    ///State transition - sets the `reason_type` field to Set
+    pub struct SetReasonType<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetReasonType<S> {}
+    impl<S: State> State for SetReasonType<S> {
+        type Subject = S::Subject;
+        type ReasonType = Set<members::reason_type>;
+    }
+    /// Marker types for field names
+    #[allow(non_camel_case_types)]
+    pub mod members {
+        ///Marker type for the `subject` field
+        pub struct subject(());
+        ///Marker type for the `reason_type` field
+        pub struct reason_type(());
+    }
+}
+
+/// Builder for constructing an instance of this type
+pub struct CreateReportBuilder<'a, S: create_report_state::State> {
+    _phantom_state: ::core::marker::PhantomData<fn() -> S>,
+    __unsafe_private_named: (
+        ::core::option::Option<jacquard_common::CowStr<'a>>,
+        ::core::option::Option<CreateReportReasonType>,
+        ::core::option::Option<jacquard_common::types::string::Uri<'a>>,
+    ),
+    _phantom: ::core::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> CreateReport<'a> {
+    /// Create a new builder for this type
+    pub fn new() -> CreateReportBuilder<'a, create_report_state::Empty> {
+        CreateReportBuilder::new()
+    }
+}
+
+impl<'a> CreateReportBuilder<'a, create_report_state::Empty> {
+    /// Create a new builder with all fields unset
+    pub fn new() -> Self {
+        CreateReportBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: (None, None, None),
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S: create_report_state::State> CreateReportBuilder<'a, S> {
+    /// Set the `reason` field (optional)
+    pub fn reason(
+        mut self,
+        value: impl Into<Option<jacquard_common::CowStr<'a>>>,
+    ) -> Self {
+        self.__unsafe_private_named.0 = value.into();
+        self
+    }
+    /// Set the `reason` field to an Option value (optional)
+    pub fn maybe_reason(mut self, value: Option<jacquard_common::CowStr<'a>>) -> Self {
+        self.__unsafe_private_named.0 = value;
+        self
+    }
+}
+
+impl<'a, S> CreateReportBuilder<'a, S>
+where
+    S: create_report_state::State,
+    S::ReasonType: create_report_state::IsUnset,
+{
+    /// Set the `reasonType` field (required)
+    pub fn reason_type(
+        mut self,
+        value: impl Into<CreateReportReasonType>,
+    ) -> CreateReportBuilder<'a, create_report_state::SetReasonType<S>> {
+        self.__unsafe_private_named.1 = ::core::option::Option::Some(value.into());
+        CreateReportBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> CreateReportBuilder<'a, S>
+where
+    S: create_report_state::State,
+    S::Subject: create_report_state::IsUnset,
+{
+    /// Set the `subject` field (required)
+    pub fn subject(
+        mut self,
+        value: impl Into<jacquard_common::types::string::Uri<'a>>,
+    ) -> CreateReportBuilder<'a, create_report_state::SetSubject<S>> {
+        self.__unsafe_private_named.2 = ::core::option::Option::Some(value.into());
+        CreateReportBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> CreateReportBuilder<'a, S>
+where
+    S: create_report_state::State,
+    S::Subject: create_report_state::IsSet,
+    S::ReasonType: create_report_state::IsSet,
+{
+    /// Build the final struct
+    pub fn build(self) -> CreateReport<'a> {
+        CreateReport {
+            reason: self.__unsafe_private_named.0,
+            reason_type: self.__unsafe_private_named.1.unwrap(),
+            subject: self.__unsafe_private_named.2.unwrap(),
+            extra_data: Default::default(),
+        }
+    }
+    /// Build the final struct with custom extra_data
+    pub fn build_with_data(
+        self,
+        extra_data: std::collections::BTreeMap<
+            jacquard_common::smol_str::SmolStr,
+            jacquard_common::types::value::Data<'a>,
+        >,
+    ) -> CreateReport<'a> {
+        CreateReport {
+            reason: self.__unsafe_private_named.0,
+            reason_type: self.__unsafe_private_named.1.unwrap(),
+            subject: self.__unsafe_private_named.2.unwrap(),
+            extra_data: Some(extra_data),
+        }
+    }
+}
+
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateReportOutput<'a> {
+    #[serde(borrow)]
+    pub report: crate::net_gifdex::moderation::ReportView<'a>,
+}
+
+#[jacquard_derive::open_union]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    thiserror::Error,
+    miette::Diagnostic,
+    jacquard_derive::IntoStatic
+)]
+#[serde(tag = "error", content = "message")]
+#[serde(bound(deserialize = "'de: 'a"))]
+pub enum CreateReportError<'a> {
+    /// The subject must be a DID, or a record referenced by DID, collection and rkey.
+    #[serde(rename = "InvalidSubject")]
+    InvalidSubject(std::option::Option<jacquard_common::CowStr<'a>>),
+    /// The account has made too many reports recently.
+    #[serde(rename = "RateLimitExceeded")]
+    RateLimitExceeded(std::option::Option<jacquard_common::CowStr<'a>>),
+}
+
+impl core::fmt::Display for CreateReportError<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidSubject(msg) => {
+                write!(f, "InvalidSubject")?;
+                if let Some(msg) = msg {
+                    write!(f, ": {}", msg)?;
+                }
+                Ok(())
+            }
+            Self::RateLimitExceeded(msg) => {
+                write!(f, "RateLimitExceeded")?;
+                if let Some(msg) = msg {
+                    write!(f, ": {}", msg)?;
+                }
+                Ok(())
+            }
+            Self::Unknown(err) => write!(f, "Unknown error: {:?}", err),
+        }
+    }
+}
+
+/// Response type for
+///net.gifdex.moderation.createReport
+pub struct CreateReportResponse;
+impl jacquard_common::xrpc::XrpcResp for CreateReportResponse {
+    const NSID: &'static str = "net.gifdex.moderation.createReport";
+    const ENCODING: &'static str = "application/json";
+    type Output<'de> = CreateReportOutput<'de>;
+    type Err<'de> = CreateReportError<'de>;
+}
+
+impl<'a> jacquard_common::xrpc::XrpcRequest for CreateReport<'a> {
+    const NSID: &'static str = "net.gifdex.moderation.createReport";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Procedure(
+        "application/json",
+    );
+    type Response = CreateReportResponse;
+}
+
+/// Endpoint type for
+///net.gifdex.moderation.createReport
+pub struct CreateReportRequest;
+impl jacquard_common::xrpc::XrpcEndpoint for CreateReportRequest {
+    const PATH: &'static str = "/xrpc/net.gifdex.moderation.createReport";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Procedure(
+        "application/json",
+    );
+    type Request<'de> = CreateReport<'de>;
+    type Response = CreateReportResponse;
+}
\ No newline at end of file
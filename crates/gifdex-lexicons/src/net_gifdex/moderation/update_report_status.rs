@@ -0,0 +1,253 @@
+// @generated by jacquard-lexicon. DO NOT EDIT.
+//
+// Lexicon: net.gifdex.moderation.updateReportStatus
+//
+// This file was automatically generated from Lexicon schemas.
+// Any manual changes will be overwritten on the next regeneration.
+
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateReportStatus<'a> {
+    pub id: i64,
+    #[serde(borrow)]
+    pub status: jacquard_common::CowStr<'a>,
+}
+
+pub mod update_report_status_state {
+
+    pub use crate::builder_types::{Set, Unset, IsSet, IsUnset};
+    #[allow(unused)]
+    use ::core::marker::PhantomData;
+    mod sealed {
+        pub trait Sealed {}
+    }
+    /// State trait tracking which required fields have been set
+    pub trait State: sealed::Sealed {
+        type Status;
+        type Id;
+    }
+    /// Empty state - all required fields are unset
+    pub struct Empty(());
+    impl sealed::Sealed for Empty {}
+    impl State for Empty {
+        type Status = Unset;
+        type Id = Unset;
+    }
+    ///State transition - sets the `status` field to Set
+    pub struct SetStatus<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetStatus<S> {}
+    impl<S: State> State for SetStatus<S> {
+        type Status = Set<members::status>;
+        type Id = S::Id;
+    }
+    ///State transition - sets the `id` field to Set
+    pub struct SetId<S: State = Empty>(PhantomData<fn() -> S>);
+    impl<S: State> sealed::Sealed for SetId<S> {}
+    impl<S: State> State for SetId<S> {
+        type Status = S::Status;
+        type Id = Set<members::id>;
+    }
+    /// Marker types for field names
+    #[allow(non_camel_case_types)]
+    pub mod members {
+        ///Marker type for the `status` field
+        pub struct status(());
+        ///Marker type for the `id` field
+        pub struct id(());
+    }
+}
+
+/// Builder for constructing an instance of this type
+pub struct UpdateReportStatusBuilder<'a, S: update_report_status_state::State> {
+    _phantom_state: ::core::marker::PhantomData<fn() -> S>,
+    __unsafe_private_named: (
+        ::core::option::Option<i64>,
+        ::core::option::Option<jacquard_common::CowStr<'a>>,
+    ),
+    _phantom: ::core::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> UpdateReportStatus<'a> {
+    /// Create a new builder for this type
+    pub fn new() -> UpdateReportStatusBuilder<'a, update_report_status_state::Empty> {
+        UpdateReportStatusBuilder::new()
+    }
+}
+
+impl<'a> UpdateReportStatusBuilder<'a, update_report_status_state::Empty> {
+    /// Create a new builder with all fields unset
+    pub fn new() -> Self {
+        UpdateReportStatusBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: (None, None),
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> UpdateReportStatusBuilder<'a, S>
+where
+    S: update_report_status_state::State,
+    S::Id: update_report_status_state::IsUnset,
+{
+    /// Set the `id` field (required)
+    pub fn id(
+        mut self,
+        value: impl Into<i64>,
+    ) -> UpdateReportStatusBuilder<'a, update_report_status_state::SetId<S>> {
+        self.__unsafe_private_named.0 = ::core::option::Option::Some(value.into());
+        UpdateReportStatusBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> UpdateReportStatusBuilder<'a, S>
+where
+    S: update_report_status_state::State,
+    S::Status: update_report_status_state::IsUnset,
+{
+    /// Set the `status` field (required)
+    pub fn status(
+        mut self,
+        value: impl Into<jacquard_common::CowStr<'a>>,
+    ) -> UpdateReportStatusBuilder<'a, update_report_status_state::SetStatus<S>> {
+        self.__unsafe_private_named.1 = ::core::option::Option::Some(value.into());
+        UpdateReportStatusBuilder {
+            _phantom_state: ::core::marker::PhantomData,
+            __unsafe_private_named: self.__unsafe_private_named,
+            _phantom: ::core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, S> UpdateReportStatusBuilder<'a, S>
+where
+    S: update_report_status_state::State,
+    S::Status: update_report_status_state::IsSet,
+    S::Id: update_report_status_state::IsSet,
+{
+    /// Build the final struct
+    pub fn build(self) -> UpdateReportStatus<'a> {
+        UpdateReportStatus {
+            id: self.__unsafe_private_named.0.unwrap(),
+            status: self.__unsafe_private_named.1.unwrap(),
+            extra_data: Default::default(),
+        }
+    }
+    /// Build the final struct with custom extra_data
+    pub fn build_with_data(
+        self,
+        extra_data: std::collections::BTreeMap<
+            jacquard_common::smol_str::SmolStr,
+            jacquard_common::types::value::Data<'a>,
+        >,
+    ) -> UpdateReportStatus<'a> {
+        UpdateReportStatus {
+            id: self.__unsafe_private_named.0.unwrap(),
+            status: self.__unsafe_private_named.1.unwrap(),
+            extra_data: Some(extra_data),
+        }
+    }
+}
+
+#[jacquard_derive::lexicon]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    jacquard_derive::IntoStatic
+)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateReportStatusOutput<'a> {
+    #[serde(borrow)]
+    pub report: crate::net_gifdex::moderation::ReportView<'a>,
+}
+
+#[jacquard_derive::open_union]
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    thiserror::Error,
+    miette::Diagnostic,
+    jacquard_derive::IntoStatic
+)]
+#[serde(tag = "error", content = "message")]
+#[serde(bound(deserialize = "'de: 'a"))]
+pub enum UpdateReportStatusError<'a> {
+    #[serde(rename = "ReportNotFound")]
+    ReportNotFound(std::option::Option<jacquard_common::CowStr<'a>>),
+    /// The report has already reached the requested status or a later one.
+    #[serde(rename = "InvalidTransition")]
+    InvalidTransition(std::option::Option<jacquard_common::CowStr<'a>>),
+}
+
+impl core::fmt::Display for UpdateReportStatusError<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ReportNotFound(msg) => {
+                write!(f, "ReportNotFound")?;
+                if let Some(msg) = msg {
+                    write!(f, ": {}", msg)?;
+                }
+                Ok(())
+            }
+            Self::InvalidTransition(msg) => {
+                write!(f, "InvalidTransition")?;
+                if let Some(msg) = msg {
+                    write!(f, ": {}", msg)?;
+                }
+                Ok(())
+            }
+            Self::Unknown(err) => write!(f, "Unknown error: {:?}", err),
+        }
+    }
+}
+
+/// Response type for
+///net.gifdex.moderation.updateReportStatus
+pub struct UpdateReportStatusResponse;
+impl jacquard_common::xrpc::XrpcResp for UpdateReportStatusResponse {
+    const NSID: &'static str = "net.gifdex.moderation.updateReportStatus";
+    const ENCODING: &'static str = "application/json";
+    type Output<'de> = UpdateReportStatusOutput<'de>;
+    type Err<'de> = UpdateReportStatusError<'de>;
+}
+
+impl<'a> jacquard_common::xrpc::XrpcRequest for UpdateReportStatus<'a> {
+    const NSID: &'static str = "net.gifdex.moderation.updateReportStatus";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Procedure(
+        "application/json",
+    );
+    type Response = UpdateReportStatusResponse;
+}
+
+/// Endpoint type for
+///net.gifdex.moderation.updateReportStatus
+pub struct UpdateReportStatusRequest;
+impl jacquard_common::xrpc::XrpcEndpoint for UpdateReportStatusRequest {
+    const PATH: &'static str = "/xrpc/net.gifdex.moderation.updateReportStatus";
+    const METHOD: jacquard_common::xrpc::XrpcMethod = jacquard_common::xrpc::XrpcMethod::Procedure(
+        "application/json",
+    );
+    type Request<'de> = UpdateReportStatus<'de>;
+    type Response = UpdateReportStatusResponse;
+}
\ No newline at end of file
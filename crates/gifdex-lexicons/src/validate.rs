@@ -0,0 +1,369 @@
+//! Validation of records against the constraints declared by their lexicons.
+//!
+//! Unlike `LexiconSchema::validate`, which stops at the first top-level field that fails, this
+//! also checks nested objects, array items and blobs, and reports every violation it finds.
+//! The implementations are generated from the lexicon JSON by the build script.
+
+use jacquard_common::types::blob::BlobRef;
+use miette::Diagnostic;
+use thiserror::Error;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A record type that can be checked against the constraints declared by its lexicon.
+pub trait LexiconValidate {
+    /// Check every constraint declared by the record's lexicon, returning all that are violated.
+    fn validate(&self) -> Result<(), ValidationReport>;
+}
+
+/// A kind of constraint a lexicon can declare on a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Constraint {
+    MaxGraphemes,
+    MinGraphemes,
+    MaxLength,
+    MinLength,
+    MaxSize,
+    Minimum,
+    Maximum,
+    Accept,
+    Enum,
+}
+
+impl Constraint {
+    /// The name of the constraint as it's written in lexicon JSON.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MaxGraphemes => "maxGraphemes",
+            Self::MinGraphemes => "minGraphemes",
+            Self::MaxLength => "maxLength",
+            Self::MinLength => "minLength",
+            Self::MaxSize => "maxSize",
+            Self::Minimum => "minimum",
+            Self::Maximum => "maximum",
+            Self::Accept => "accept",
+            Self::Enum => "enum",
+        }
+    }
+}
+
+impl core::fmt::Display for Constraint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A field that breaks a constraint declared by its lexicon.
+#[derive(Debug, Clone, PartialEq, Eq, Error, Diagnostic)]
+#[error("{path} violates {constraint}: got {actual}, limit is {limit}")]
+#[diagnostic(code(gifdex_lexicons::validate::constraint_violation))]
+pub struct ConstraintViolation {
+    /// JSON path to the field within the record, such as `$.tags[2]`.
+    pub path: String,
+    /// The constraint that was broken.
+    pub constraint: Constraint,
+    /// The measured value of the field, such as its grapheme count or mimetype.
+    pub actual: String,
+    /// The limit declared by the lexicon.
+    pub limit: String,
+}
+
+/// Every constraint violated by a record.
+#[derive(Debug, Clone, PartialEq, Eq, Diagnostic)]
+#[diagnostic(code(gifdex_lexicons::validate::invalid_record))]
+pub struct ValidationReport {
+    /// NSID of the record's lexicon.
+    pub nsid: &'static str,
+    /// The violations, in the order the fields are declared on the record type.
+    #[related]
+    pub violations: Vec<ConstraintViolation>,
+}
+
+impl core::fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} record violates its lexicon", self.nsid)?;
+        for (idx, violation) in self.violations.iter().enumerate() {
+            f.write_str(if idx == 0 { ": " } else { "; " })?;
+            write!(f, "{violation}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationReport {}
+
+/// Collects violations while walking a record.
+#[derive(Default)]
+struct Validator {
+    violations: Vec<ConstraintViolation>,
+}
+
+// Not every kind of constraint is declared by the current lexicons.
+#[allow(dead_code)]
+impl Validator {
+    fn violation(&mut self, path: &str, constraint: Constraint, actual: String, limit: String) {
+        self.violations.push(ConstraintViolation {
+            path: path.to_owned(),
+            constraint,
+            actual,
+            limit,
+        });
+    }
+
+    fn max_graphemes(&mut self, path: &str, value: &str, max: usize) {
+        let count = value.graphemes(true).count();
+        if count > max {
+            self.violation(
+                path,
+                Constraint::MaxGraphemes,
+                count.to_string(),
+                max.to_string(),
+            );
+        }
+    }
+
+    fn min_graphemes(&mut self, path: &str, value: &str, min: usize) {
+        let count = value.graphemes(true).count();
+        if count < min {
+            self.violation(
+                path,
+                Constraint::MinGraphemes,
+                count.to_string(),
+                min.to_string(),
+            );
+        }
+    }
+
+    /// Check the length of an array, or of a string in UTF-8 bytes.
+    fn max_length(&mut self, path: &str, length: usize, max: usize) {
+        if length > max {
+            self.violation(
+                path,
+                Constraint::MaxLength,
+                length.to_string(),
+                max.to_string(),
+            );
+        }
+    }
+
+    fn min_length(&mut self, path: &str, length: usize, min: usize) {
+        if length < min {
+            self.violation(
+                path,
+                Constraint::MinLength,
+                length.to_string(),
+                min.to_string(),
+            );
+        }
+    }
+
+    fn minimum(&mut self, path: &str, value: i64, min: i64) {
+        if value < min {
+            self.violation(
+                path,
+                Constraint::Minimum,
+                value.to_string(),
+                min.to_string(),
+            );
+        }
+    }
+
+    fn maximum(&mut self, path: &str, value: i64, max: i64) {
+        if value > max {
+            self.violation(
+                path,
+                Constraint::Maximum,
+                value.to_string(),
+                max.to_string(),
+            );
+        }
+    }
+
+    fn one_of(&mut self, path: &str, value: &str, values: &[&str]) {
+        if !values.contains(&value) {
+            self.violation(path, Constraint::Enum, value.to_owned(), values.join(", "));
+        }
+    }
+
+    /// Check a blob's mimetype, where accepted types may end in a `*` wildcard.
+    fn accept(&mut self, path: &str, blob: &BlobRef<'_>, accept: &[&str]) {
+        let mime_type = blob.blob().mime_type.as_str();
+        let accepted = accept
+            .iter()
+            .any(|pattern| match pattern.strip_suffix('*') {
+                Some(prefix) => mime_type.starts_with(prefix),
+                None => mime_type == *pattern,
+            });
+        if !accepted {
+            self.violation(
+                &format!("{path}.mimeType"),
+                Constraint::Accept,
+                mime_type.to_owned(),
+                accept.join(", "),
+            );
+        }
+    }
+
+    fn max_size(&mut self, path: &str, blob: &BlobRef<'_>, max_size: usize) {
+        let size = blob.blob().size;
+        if size > max_size {
+            self.violation(
+                &format!("{path}.size"),
+                Constraint::MaxSize,
+                size.to_string(),
+                max_size.to_string(),
+            );
+        }
+    }
+
+    fn finish(self, nsid: &'static str) -> Result<(), ValidationReport> {
+        if self.violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationReport {
+                nsid,
+                violations: self.violations,
+            })
+        }
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/records.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::net_gifdex::feed::{collection_item::CollectionItem, post::Post};
+    use serde_json::{Value, json};
+
+    const CID: &str = "bafkreidwslb22nkaxoadyaqlhlxgntmiq4jdenhkbrxhcq6avxlt75br5u";
+
+    fn post(title: &str, tags: Value, mime_type: &str, size: usize) -> String {
+        json!({
+            "$type": "net.gifdex.feed.post",
+            "title": title,
+            "media": {
+                "alt": "A cat",
+                "blob": {
+                    "$type": "blob",
+                    "ref": { "$link": CID },
+                    "mimeType": mime_type,
+                    "size": size,
+                },
+            },
+            "tags": tags,
+            "createdAt": "2026-01-01T00:00:00.000Z",
+        })
+        .to_string()
+    }
+
+    fn validate_post(json: &str) -> Result<(), ValidationReport> {
+        serde_json::from_str::<Post>(json).unwrap().validate()
+    }
+
+    fn violation(
+        path: &str,
+        constraint: Constraint,
+        actual: &str,
+        limit: &str,
+    ) -> ConstraintViolation {
+        ConstraintViolation {
+            path: path.to_owned(),
+            constraint,
+            actual: actual.to_owned(),
+            limit: limit.to_owned(),
+        }
+    }
+
+    #[test]
+    fn valid_post_passes() {
+        let record = post("Cat", json!(["cat", "funny"]), "image/webp", 1_000);
+        assert_eq!(validate_post(&record), Ok(()));
+    }
+
+    #[test]
+    fn graphemes_are_counted_rather_than_bytes() {
+        // Each of these is a single grapheme made up of several code points.
+        let record = post(&"👍🏽".repeat(80), json!([]), "image/webp", 1_000);
+        assert_eq!(validate_post(&record), Ok(()));
+
+        let record = post(&"e".repeat(81), json!([]), "image/webp", 1_000);
+        let report = validate_post(&record).unwrap_err();
+        assert_eq!(
+            report.violations,
+            [violation("$.title", Constraint::MaxGraphemes, "81", "80")]
+        );
+    }
+
+    #[test]
+    fn array_bounds_and_items_are_checked() {
+        let tags = json!(["a", "b", "c", "d", "e", "f"]);
+        let report = validate_post(&post("Cat", tags, "image/webp", 1_000)).unwrap_err();
+        assert_eq!(
+            report.violations,
+            [violation("$.tags", Constraint::MaxLength, "6", "5")]
+        );
+
+        let tags = json!(["cat", "x".repeat(41)]);
+        let report = validate_post(&post("Cat", tags, "image/webp", 1_000)).unwrap_err();
+        assert_eq!(
+            report.violations,
+            [violation("$.tags[1]", Constraint::MaxGraphemes, "41", "40")]
+        );
+    }
+
+    #[test]
+    fn blob_mimetype_and_size_are_checked() {
+        let report = validate_post(&post("Cat", json!([]), "video/mp4", 6_000_000)).unwrap_err();
+        assert_eq!(
+            report.violations,
+            [
+                violation(
+                    "$.media.blob.mimeType",
+                    Constraint::Accept,
+                    "video/mp4",
+                    "image/webp"
+                ),
+                violation(
+                    "$.media.blob.size",
+                    Constraint::MaxSize,
+                    "6000000",
+                    "5000000"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn integer_bounds_are_checked() {
+        let item = json!({
+            "$type": "net.gifdex.feed.collectionItem",
+            "collection": "at://did:plc:ewvi7nxzyoun6zhxrhs64oiz/net.gifdex.feed.collection/3jzfcijpj2z2a",
+            "subject": format!("at://did:plc:ewvi7nxzyoun6zhxrhs64oiz/net.gifdex.feed.post/3jzfcijpj2z2a:{CID}"),
+            "position": -1,
+            "createdAt": "2026-01-01T00:00:00.000Z",
+        })
+        .to_string();
+        let item: CollectionItem = serde_json::from_str(&item).unwrap();
+        let report = item.validate().unwrap_err();
+        assert_eq!(
+            report.violations,
+            [violation("$.position", Constraint::Minimum, "-1", "0")]
+        );
+    }
+
+    #[test]
+    fn report_lists_every_violation() {
+        let tags = json!(["cat", "x".repeat(41)]);
+        let record = post(&"e".repeat(81), tags, "video/mp4", 1_000);
+        let report = validate_post(&record).unwrap_err();
+        assert_eq!(report.nsid, "net.gifdex.feed.post");
+        assert_eq!(report.violations.len(), 3);
+        assert_eq!(
+            report.to_string(),
+            "net.gifdex.feed.post record violates its lexicon: \
+             $.media.blob.mimeType violates accept: got video/mp4, limit is image/webp; \
+             $.tags[1] violates maxGraphemes: got 41, limit is 40; \
+             $.title violates maxGraphemes: got 81, limit is 80"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{InspectError, MediaFormat};
+use image::{
+    AnimationDecoder, DynamicImage, Frames,
+    codecs::{gif::GifDecoder, webp::WebPDecoder},
+    imageops::{self, FilterType},
+};
+use std::io::Cursor;
+
+const HASH_WIDTH: u32 = 9;
+const HASH_HEIGHT: u32 = 8;
+
+/// Maximum number of differing bits for two hashes to be treated as the same media.
+///
+/// Re-encoding, palette changes and re-timing flip a handful of bits, while unrelated media
+/// differs in about half of them.
+pub const SIMILAR_HASH_DISTANCE: u32 = 10;
+
+/// Number of bits that differ between two perceptual hashes.
+pub fn hash_distance(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+/// Compute a 64-bit perceptual difference hash of a GIF or WebP.
+///
+/// Every frame is shrunk to a tiny greyscale thumbnail and the thumbnails are averaged
+/// before hashing, so the hash describes the animation as a whole and survives
+/// re-encoding, palette changes and tweaks to frame timing.
+///
+/// Media should be passed through [`crate::inspect`] first so decoding stays within budget.
+pub fn perceptual_hash(bytes: &[u8]) -> Result<u64, InspectError> {
+    let frames: Frames = match MediaFormat::sniff(bytes) {
+        Some(MediaFormat::Gif) => GifDecoder::new(Cursor::new(bytes))?.into_frames(),
+        Some(MediaFormat::Webp) => {
+            let decoder = WebPDecoder::new(Cursor::new(bytes))?;
+            if decoder.has_animation() {
+                decoder.into_frames()
+            } else {
+                let image = DynamicImage::from_decoder(decoder)?;
+                return Ok(difference_hash(&thumbnail(&image)));
+            }
+        }
+        None => return Err(InspectError::UnsupportedFormat),
+    };
+
+    let mut sums = [0u32; (HASH_WIDTH * HASH_HEIGHT) as usize];
+    let mut frame_count = 0;
+    for frame in frames {
+        let image = DynamicImage::ImageRgba8(frame?.into_buffer());
+        for (sum, value) in sums.iter_mut().zip(thumbnail(&image)) {
+            *sum += u32::from(value);
+        }
+        frame_count += 1;
+    }
+    if frame_count == 0 {
+        return Err(InspectError::NoFrames);
+    }
+
+    Ok(difference_hash(&sums.map(|sum| (sum / frame_count) as u8)))
+}
+
+fn thumbnail(image: &DynamicImage) -> [u8; (HASH_WIDTH * HASH_HEIGHT) as usize] {
+    let small = imageops::resize(
+        &image.to_luma8(),
+        HASH_WIDTH,
+        HASH_HEIGHT,
+        FilterType::Triangle,
+    );
+    let mut out = [0; (HASH_WIDTH * HASH_HEIGHT) as usize];
+    out.copy_from_slice(small.as_raw());
+    out
+}
+
+/// Each bit records whether a pixel is brighter than its right-hand neighbour.
+fn difference_hash(pixels: &[u8; (HASH_WIDTH * HASH_HEIGHT) as usize]) -> u64 {
+    let mut hash = 0;
+    for row in pixels.chunks_exact(HASH_WIDTH as usize) {
+        for pair in row.windows(2) {
+            hash = (hash << 1) | u64::from(pair[0] > pair[1]);
+        }
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{
+        Delay, ExtendedColorType, Frame, ImageEncoder, Rgba, RgbaImage,
+        codecs::{
+            gif::{GifEncoder, Repeat},
+            webp::WebPEncoder,
+        },
+    };
+
+    const WIDTH: u32 = 64;
+    const HEIGHT: u32 = 48;
+
+    /// A gradient with a ripple that drifts between frames, optionally mirrored and with every
+    /// pixel nudged by up to `noise` levels.
+    fn frame(index: u32, mirrored: bool, noise: u8) -> RgbaImage {
+        RgbaImage::from_fn(WIDTH, HEIGHT, |x, y| {
+            let x = if mirrored { WIDTH - 1 - x } else { x };
+            let ripple = ((x + y + index * 2) as f32 / 5.0).sin();
+            let nudge = ((x * 31 + y * 17 + index * 7) % (u32::from(noise) * 2 + 1)) as i16
+                - i16::from(noise);
+            let value = (x * 3) as i16 + (30.0 * ripple) as i16 + nudge;
+            let value = value.clamp(0, 255) as u8;
+            Rgba([value, value / 2, 255 - value, 255])
+        })
+    }
+
+    fn make_gif(frames: impl IntoIterator<Item = RgbaImage>, delay_ms: u32) -> Vec<u8> {
+        let mut out = Vec::new();
+        {
+            let mut encoder = GifEncoder::new(&mut out);
+            encoder.set_repeat(Repeat::Infinite).unwrap();
+            for buffer in frames {
+                let delay = Delay::from_numer_denom_ms(delay_ms, 1);
+                encoder
+                    .encode_frame(Frame::from_parts(buffer, 0, 0, delay))
+                    .unwrap();
+            }
+        }
+        out
+    }
+
+    fn make_webp(buffer: &RgbaImage) -> Vec<u8> {
+        let mut out = Vec::new();
+        WebPEncoder::new_lossless(&mut out)
+            .write_image(buffer.as_raw(), WIDTH, HEIGHT, ExtendedColorType::Rgba8)
+            .unwrap();
+        out
+    }
+
+    #[test]
+    fn reencoded_animations_hash_alike() {
+        let original = make_gif((0..4).map(|i| frame(i, false, 0)), 50);
+        let reencoded = make_gif((0..4).map(|i| frame(i, false, 6)), 80);
+        assert_ne!(original, reencoded);
+
+        let distance = hash_distance(
+            perceptual_hash(&original).unwrap(),
+            perceptual_hash(&reencoded).unwrap(),
+        );
+        assert!(distance <= SIMILAR_HASH_DISTANCE, "distance was {distance}");
+    }
+
+    #[test]
+    fn still_media_hashes_alike_across_formats() {
+        let gif = make_gif([frame(0, false, 0)], 0);
+        let webp = make_webp(&frame(0, false, 3));
+
+        let distance = hash_distance(
+            perceptual_hash(&gif).unwrap(),
+            perceptual_hash(&webp).unwrap(),
+        );
+        assert!(distance <= SIMILAR_HASH_DISTANCE, "distance was {distance}");
+    }
+
+    #[test]
+    fn different_animations_hash_apart() {
+        let original = make_gif((0..4).map(|i| frame(i, false, 0)), 50);
+        let mirrored = make_gif((0..4).map(|i| frame(i, true, 0)), 50);
+
+        let distance = hash_distance(
+            perceptual_hash(&original).unwrap(),
+            perceptual_hash(&mirrored).unwrap(),
+        );
+        assert!(distance > SIMILAR_HASH_DISTANCE, "distance was {distance}");
+    }
+
+    #[test]
+    fn unhashable_media_is_rejected() {
+        assert!(perceptual_hash(b"not media").is_err());
+        let gif = make_gif([frame(0, false, 0)], 0);
+        assert!(perceptual_hash(&gif[..gif.len() / 2]).is_err());
+    }
+}
@@ -0,0 +1,416 @@
+mod hash;
+
+pub use hash::{SIMILAR_HASH_DISTANCE, hash_distance, perceptual_hash};
+use std::io::Cursor;
+
+/// Media formats accepted for posts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MediaFormat {
+    Gif,
+    Webp,
+}
+
+impl MediaFormat {
+    /// Detect the format of a blob from its magic bytes.
+    pub fn sniff(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+            Some(Self::Gif)
+        } else if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+            Some(Self::Webp)
+        } else {
+            None
+        }
+    }
+
+    pub fn from_mime(mime_type: &str) -> Option<Self> {
+        match mime_type {
+            "image/gif" => Some(Self::Gif),
+            "image/webp" => Some(Self::Webp),
+            _ => None,
+        }
+    }
+
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "gif" => Some(Self::Gif),
+            "webp" => Some(Self::Webp),
+            _ => None,
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Gif => "image/gif",
+            Self::Webp => "image/webp",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Gif => "gif",
+            Self::Webp => "webp",
+        }
+    }
+}
+
+/// Limits applied while inspecting media, protecting against decompression bombs.
+#[derive(Debug, Clone, Copy)]
+pub struct InspectLimits {
+    /// Maximum width or height of the canvas in pixels, matching the limit media was held to
+    /// before it was fully decoded.
+    pub max_dimension: u32,
+    /// Maximum number of frames in an animation.
+    pub max_frames: u32,
+    /// Maximum canvas pixels multiplied by frame count.
+    pub max_total_pixels: u64,
+}
+
+impl Default for InspectLimits {
+    fn default() -> Self {
+        Self {
+            max_dimension: 10_000,
+            max_frames: 5_000,
+            max_total_pixels: 500_000_000,
+        }
+    }
+}
+
+/// Structural information about a fully decoded piece of media.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MediaInfo {
+    pub format: MediaFormat,
+    pub width: u32,
+    pub height: u32,
+    pub frame_count: u32,
+    /// Length of a single loop of the animation in milliseconds, zero for still images.
+    pub duration_ms: u64,
+    /// Number of times the animation plays, [`None`] if it loops forever.
+    pub loop_count: Option<u32>,
+    /// Size of the encoded media in bytes.
+    pub size: u64,
+}
+
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum InspectError {
+    #[error("media is not a GIF or WebP")]
+    UnsupportedFormat,
+    #[error("invalid dimensions: {0}x{1}")]
+    InvalidDimensions(u32, u32),
+    #[error("media contains no frames")]
+    NoFrames,
+    #[error("media exceeds the decode budget: {0}")]
+    BudgetExceeded(&'static str),
+    #[error("failed to decode GIF: {0}")]
+    Gif(#[from] gif::DecodingError),
+    #[error("failed to decode WebP: {0}")]
+    Webp(#[from] image_webp::DecodingError),
+    #[error("failed to decode frames: {0}")]
+    Image(#[from] image::ImageError),
+}
+
+/// Fully decode a GIF or WebP, validating every frame and collecting information about
+/// the animation. Decoding stops as soon as any limit is exceeded.
+///
+/// This is CPU-bound, async callers should run it on a blocking thread.
+pub fn inspect(bytes: &[u8], limits: &InspectLimits) -> Result<MediaInfo, InspectError> {
+    match MediaFormat::sniff(bytes) {
+        Some(MediaFormat::Gif) => inspect_gif(bytes, limits),
+        Some(MediaFormat::Webp) => inspect_webp(bytes, limits),
+        None => Err(InspectError::UnsupportedFormat),
+    }
+}
+
+fn check_dimensions(width: u32, height: u32, limits: &InspectLimits) -> Result<(), InspectError> {
+    if width == 0 || height == 0 {
+        return Err(InspectError::InvalidDimensions(width, height));
+    }
+    if width > limits.max_dimension || height > limits.max_dimension {
+        return Err(InspectError::BudgetExceeded("dimensions too large"));
+    }
+    Ok(())
+}
+
+/// Account for another decoded frame, failing once the frame or pixel budget is spent.
+fn check_budget(
+    frame_count: u32,
+    width: u32,
+    height: u32,
+    limits: &InspectLimits,
+) -> Result<(), InspectError> {
+    if frame_count > limits.max_frames {
+        return Err(InspectError::BudgetExceeded("too many frames"));
+    }
+    if u64::from(width) * u64::from(height) * u64::from(frame_count) > limits.max_total_pixels {
+        return Err(InspectError::BudgetExceeded(
+            "too many pixels across all frames",
+        ));
+    }
+    Ok(())
+}
+
+fn inspect_gif(bytes: &[u8], limits: &InspectLimits) -> Result<MediaInfo, InspectError> {
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::Indexed);
+    options.check_frame_consistency(true);
+    options.check_lzw_end_code(true);
+    let mut decoder = options.read_info(Cursor::new(bytes))?;
+    let (width, height) = (u32::from(decoder.width()), u32::from(decoder.height()));
+    check_dimensions(width, height, limits)?;
+
+    let mut frame_count = 0;
+    let mut duration_ms = 0;
+    while let Some(frame) = decoder.read_next_frame()? {
+        frame_count += 1;
+        duration_ms += u64::from(frame.delay) * 10;
+        check_budget(frame_count, width, height, limits)?;
+    }
+    if frame_count == 0 {
+        return Err(InspectError::NoFrames);
+    }
+
+    Ok(MediaInfo {
+        format: MediaFormat::Gif,
+        width,
+        height,
+        frame_count,
+        duration_ms: if frame_count > 1 { duration_ms } else { 0 },
+        loop_count: match decoder.repeat() {
+            gif::Repeat::Infinite => None,
+            // A GIF without a looping extension plays once, a count of N means N extra plays.
+            gif::Repeat::Finite(0) => Some(1),
+            gif::Repeat::Finite(n) => Some(u32::from(n) + 1),
+        },
+        size: bytes.len() as u64,
+    })
+}
+
+fn inspect_webp(bytes: &[u8], limits: &InspectLimits) -> Result<MediaInfo, InspectError> {
+    let mut decoder = image_webp::WebPDecoder::new(Cursor::new(bytes))?;
+    let (width, height) = decoder.dimensions();
+    check_dimensions(width, height, limits)?;
+    let buffer_size = decoder
+        .output_buffer_size()
+        .ok_or(InspectError::BudgetExceeded("frame buffer too large"))?;
+    let mut buffer = vec![0; buffer_size];
+
+    if !decoder.is_animated() {
+        check_budget(1, width, height, limits)?;
+        decoder.read_image(&mut buffer)?;
+        return Ok(MediaInfo {
+            format: MediaFormat::Webp,
+            width,
+            height,
+            frame_count: 1,
+            duration_ms: 0,
+            loop_count: Some(1),
+            size: bytes.len() as u64,
+        });
+    }
+
+    let frame_count = decoder.num_frames();
+    if frame_count == 0 {
+        return Err(InspectError::NoFrames);
+    }
+    check_budget(frame_count, width, height, limits)?;
+    let mut duration_ms = 0;
+    for _ in 0..frame_count {
+        duration_ms += u64::from(decoder.read_frame(&mut buffer)?);
+    }
+
+    Ok(MediaInfo {
+        format: MediaFormat::Webp,
+        width,
+        height,
+        frame_count,
+        duration_ms,
+        loop_count: match decoder.loop_count() {
+            image_webp::LoopCount::Forever => None,
+            image_webp::LoopCount::Times(n) => Some(u32::from(n.get())),
+        },
+        size: bytes.len() as u64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{
+        Delay, ExtendedColorType, Frame, ImageEncoder, Rgba, RgbaImage,
+        codecs::{
+            gif::{GifEncoder, Repeat},
+            webp::WebPEncoder,
+        },
+    };
+
+    const WIDTH: u32 = 17;
+    const HEIGHT: u32 = 11;
+
+    fn make_gif(frame_count: u32, width: u32, height: u32) -> Vec<u8> {
+        let mut out = Vec::new();
+        {
+            let mut encoder = GifEncoder::new(&mut out);
+            encoder.set_repeat(Repeat::Infinite).unwrap();
+            for i in 0..frame_count {
+                let shade = (i * 40) as u8;
+                let buffer = RgbaImage::from_pixel(width, height, Rgba([shade, 0, 255, 255]));
+                encoder
+                    .encode_frame(Frame::from_parts(
+                        buffer,
+                        0,
+                        0,
+                        Delay::from_numer_denom_ms(50, 1),
+                    ))
+                    .unwrap();
+            }
+        }
+        out
+    }
+
+    fn make_webp(width: u32, height: u32) -> Vec<u8> {
+        let mut out = Vec::new();
+        let buffer = RgbaImage::from_pixel(width, height, Rgba([255, 0, 0, 255]));
+        WebPEncoder::new_lossless(&mut out)
+            .write_image(buffer.as_raw(), width, height, ExtendedColorType::Rgba8)
+            .unwrap();
+        out
+    }
+
+    /// A GIF claiming the given canvas size, with a single 1x1 frame.
+    fn gif_header(width: u16, height: u16) -> Vec<u8> {
+        let mut out = b"GIF89a".to_vec();
+        out.extend_from_slice(&width.to_le_bytes());
+        out.extend_from_slice(&height.to_le_bytes());
+        out.extend_from_slice(&[0, 0, 0]);
+        out.extend_from_slice(&[0x2c, 0, 0, 0, 0, 1, 0, 1, 0, 0, 2, 2, 0x4c, 0x01, 0, 0x3b]);
+        out
+    }
+
+    #[test]
+    fn animated_gif_is_inspected() {
+        let gif = make_gif(3, WIDTH, HEIGHT);
+        let info = inspect(&gif, &InspectLimits::default()).unwrap();
+        assert_eq!(info.format, MediaFormat::Gif);
+        assert_eq!((info.width, info.height), (WIDTH, HEIGHT));
+        assert_eq!(info.frame_count, 3);
+        assert_eq!(info.duration_ms, 150);
+        assert_eq!(info.loop_count, None);
+        assert_eq!(info.size, gif.len() as u64);
+    }
+
+    #[test]
+    fn still_webp_is_inspected() {
+        let webp = make_webp(WIDTH, HEIGHT);
+        let info = inspect(&webp, &InspectLimits::default()).unwrap();
+        assert_eq!(info.format, MediaFormat::Webp);
+        assert_eq!((info.width, info.height), (WIDTH, HEIGHT));
+        assert_eq!(info.frame_count, 1);
+        assert_eq!(info.duration_ms, 0);
+    }
+
+    #[test]
+    fn unknown_formats_are_rejected() {
+        assert!(matches!(
+            inspect(b"\x89PNG\r\n\x1a\n", &InspectLimits::default()),
+            Err(InspectError::UnsupportedFormat)
+        ));
+    }
+
+    #[test]
+    fn truncated_media_is_rejected() {
+        let gif = make_gif(3, WIDTH, HEIGHT);
+        assert!(inspect(&gif[..gif.len() / 2], &InspectLimits::default()).is_err());
+        let webp = make_webp(WIDTH, HEIGHT);
+        assert!(inspect(&webp[..webp.len() / 2], &InspectLimits::default()).is_err());
+    }
+
+    #[test]
+    fn corrupt_media_is_rejected() {
+        let mut gif = make_gif(3, WIDTH, HEIGHT);
+        // Overwrite everything after the header and logical screen descriptor.
+        let end = gif.len() - 1;
+        gif[13..end].fill(0xff);
+        assert!(inspect(&gif, &InspectLimits::default()).is_err());
+
+        let mut webp = make_webp(WIDTH, HEIGHT);
+        let end = webp.len();
+        webp[20..end].fill(0xff);
+        assert!(inspect(&webp, &InspectLimits::default()).is_err());
+    }
+
+    #[test]
+    fn empty_canvas_is_rejected() {
+        assert!(matches!(
+            inspect(&gif_header(0, 10), &InspectLimits::default()),
+            Err(InspectError::InvalidDimensions(0, 10))
+        ));
+    }
+
+    #[test]
+    fn oversized_canvas_is_rejected_before_decoding() {
+        // A tiny file claiming a huge canvas must fail on the header alone.
+        assert!(matches!(
+            inspect(&gif_header(u16::MAX, u16::MAX), &InspectLimits::default()),
+            Err(InspectError::BudgetExceeded(_))
+        ));
+
+        let limits = InspectLimits {
+            max_dimension: WIDTH - 1,
+            ..Default::default()
+        };
+        assert!(matches!(
+            inspect(&make_gif(1, WIDTH, HEIGHT), &limits),
+            Err(InspectError::BudgetExceeded(_))
+        ));
+        assert!(matches!(
+            inspect(&make_webp(WIDTH, HEIGHT), &limits),
+            Err(InspectError::BudgetExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn default_limits_accept_media_up_to_ten_thousand_pixels_wide() {
+        let info = inspect(&make_gif(1, 10_000, 1), &InspectLimits::default()).unwrap();
+        assert_eq!((info.width, info.height), (10_000, 1));
+        assert!(matches!(
+            inspect(&make_gif(1, 10_001, 1), &InspectLimits::default()),
+            Err(InspectError::BudgetExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn frame_limit_is_enforced() {
+        let limits = InspectLimits {
+            max_frames: 2,
+            ..Default::default()
+        };
+        assert!(inspect(&make_gif(2, WIDTH, HEIGHT), &limits).is_ok());
+        assert!(matches!(
+            inspect(&make_gif(3, WIDTH, HEIGHT), &limits),
+            Err(InspectError::BudgetExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn total_pixel_limit_is_enforced() {
+        let limits = InspectLimits {
+            max_total_pixels: u64::from(WIDTH * HEIGHT) * 2,
+            ..Default::default()
+        };
+        assert!(inspect(&make_gif(2, WIDTH, HEIGHT), &limits).is_ok());
+        assert!(matches!(
+            inspect(&make_gif(3, WIDTH, HEIGHT), &limits),
+            Err(InspectError::BudgetExceeded(_))
+        ));
+        assert!(matches!(
+            inspect(
+                &make_webp(WIDTH, HEIGHT),
+                &InspectLimits {
+                    max_total_pixels: u64::from(WIDTH * HEIGHT) - 1,
+                    ..Default::default()
+                }
+            ),
+            Err(InspectError::BudgetExceeded(_))
+        ));
+    }
+}
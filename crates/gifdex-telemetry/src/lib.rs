@@ -0,0 +1,71 @@
+//! Tracing setup shared by the Gifdex services, with optional export of spans to an OTLP
+//! collector.
+
+use anyhow::{Context, Result};
+use http::HeaderMap;
+use opentelemetry::{global, trace::TracerProvider};
+use opentelemetry_http::HeaderInjector;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{Resource, propagation::TraceContextPropagator, trace::SdkTracerProvider};
+use tracing::{Span, warn};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
+use url::Url;
+
+/// Install the global tracing subscriber, exporting spans to an OTLP/HTTP collector under the
+/// given service name when an endpoint is given.
+///
+/// The returned provider should be passed to [`shutdown_tracing`] before exiting so buffered
+/// spans are flushed.
+pub fn init_tracing(
+    service_name: &'static str,
+    otlp_endpoint: Option<&Url>,
+) -> Result<Option<SdkTracerProvider>> {
+    let provider = match otlp_endpoint {
+        Some(endpoint) => {
+            global::set_text_map_propagator(TraceContextPropagator::new());
+            let exporter = SpanExporter::builder()
+                .with_http()
+                .with_endpoint(endpoint.as_str())
+                .build()
+                .context("failed to build otlp span exporter")?;
+            Some(
+                SdkTracerProvider::builder()
+                    .with_batch_exporter(exporter)
+                    .with_resource(Resource::builder().with_service_name(service_name).build())
+                    .build(),
+            )
+        }
+        None => None,
+    };
+    tracing_subscriber::registry()
+        .with(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("info")))
+        .with(tracing_subscriber::fmt::layer())
+        .with(provider.as_ref().map(|provider| {
+            tracing_opentelemetry::layer().with_tracer(provider.tracer(service_name))
+        }))
+        .init();
+    Ok(provider)
+}
+
+/// Flush any buffered spans and stop exporting them.
+pub fn shutdown_tracing(provider: Option<SdkTracerProvider>) {
+    if let Some(provider) = provider
+        && let Err(err) = provider.shutdown()
+    {
+        warn!("Failed to flush spans on shutdown: {err}");
+    }
+}
+
+/// Headers carrying the current span's trace context, for continuing the trace in the service
+/// a request is sent to.
+pub fn trace_headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(
+            &Span::current().context(),
+            &mut HeaderInjector(&mut headers),
+        )
+    });
+    headers
+}
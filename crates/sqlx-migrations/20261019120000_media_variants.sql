@@ -0,0 +1,8 @@
+-- Media variants
+CREATE TABLE media_variants(
+  cid TEXT NOT NULL, -- CID of the source blob
+  mime_type TEXT NOT NULL,
+  size BIGINT NOT NULL,
+  indexed_at BIGINT NOT NULL DEFAULT (extract(epoch from now()) * 1000)::BIGINT,
+  PRIMARY KEY(cid, mime_type)
+);
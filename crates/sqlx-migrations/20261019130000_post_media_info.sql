@@ -0,0 +1,5 @@
+-- Post media information
+ALTER TABLE posts
+  ADD COLUMN media_blob_size BIGINT,
+  ADD COLUMN media_frame_count INTEGER,
+  ADD COLUMN media_duration_ms BIGINT;
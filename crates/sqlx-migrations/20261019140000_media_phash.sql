@@ -0,0 +1,40 @@
+-- Perceptual hashes
+ALTER TABLE posts ADD COLUMN media_phash BIGINT;
+
+ALTER TABLE labels ADD COLUMN match_media_hash BOOLEAN NOT NULL DEFAULT false;
+CREATE INDEX labels_match_media_hash_idx ON labels(subject_did, subject_rkey)
+  WHERE match_media_hash;
+
+-- Number of bits that differ between two perceptual hashes, as computed by `hash_distance()` in
+-- gifdex-media. Callers compare it against `SIMILAR_HASH_DISTANCE`.
+CREATE FUNCTION media_hash_distance(a BIGINT, b BIGINT)
+RETURNS INTEGER LANGUAGE sql IMMUTABLE AS $$
+  SELECT bit_count((a # b)::BIT(64))::INTEGER
+$$;
+
+-- Posts with media similar to the post of a label that opted in to hash matching. No index can
+-- serve a hash distance, so ingest finds the matches once as the label or post is indexed.
+CREATE TABLE label_media_matches(
+  label_did TEXT NOT NULL,
+  label_rkey TEXT NOT NULL,
+  post_did TEXT NOT NULL,
+  post_rkey TEXT NOT NULL,
+  PRIMARY KEY (label_did, label_rkey, post_did, post_rkey),
+  FOREIGN KEY (label_did, label_rkey) REFERENCES labels(did, rkey) ON DELETE CASCADE,
+  FOREIGN KEY (post_did, post_rkey) REFERENCES posts(did, rkey) ON DELETE CASCADE
+);
+CREATE INDEX label_media_matches_post_idx ON label_media_matches(post_did, post_rkey);
+
+-- Every label that applies to a post, either directly or through a label on
+-- another post with similar media that opted in to hash matching.
+CREATE VIEW post_labels AS
+  SELECT p.did AS post_did, p.rkey AS post_rkey,
+    l.did, l.rkey, l.rule_did, l.rule_rkey, l.expires_at
+  FROM labels l
+  INNER JOIN posts p ON p.did = l.subject_did AND p.rkey = l.subject_rkey
+  WHERE l.subject_collection = 'net.gifdex.feed.post'
+  UNION
+  SELECT m.post_did, m.post_rkey,
+    l.did, l.rkey, l.rule_did, l.rule_rkey, l.expires_at
+  FROM label_media_matches m
+  INNER JOIN labels l ON l.did = m.label_did AND l.rkey = m.label_rkey;
@@ -0,0 +1,20 @@
+-- Labels issued by a labeler that has since been deleted, kept for auditing.
+CREATE TABLE label_tombstones(
+  rkey TEXT NOT NULL,
+  did TEXT NOT NULL,
+  rule_did TEXT NOT NULL,
+  rule_rkey TEXT NOT NULL,
+  rule_name TEXT,
+  rule_behaviour labeler_behaviour,
+  subject_did TEXT NOT NULL,
+  subject_collection TEXT,
+  subject_rkey TEXT,
+  reason TEXT,
+  match_media_hash BOOLEAN NOT NULL,
+  created_at BIGINT NOT NULL,
+  expires_at BIGINT,
+  edited_at BIGINT,
+  indexed_at BIGINT NOT NULL,
+  deleted_at BIGINT NOT NULL DEFAULT (extract(epoch from now()) * 1000)::BIGINT,
+  PRIMARY KEY (did, rkey)
+);
@@ -0,0 +1,3 @@
+-- Lookups of rows that reference a post or account, used for counting and cleanup.
+CREATE INDEX post_favourites_post_idx ON post_favourites(post_did, post_rkey);
+CREATE INDEX labels_subject_idx ON labels(subject_did, subject_collection, subject_rkey);
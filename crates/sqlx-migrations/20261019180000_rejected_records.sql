@@ -0,0 +1,11 @@
+-- Records that ingest refused to index, kept so their authors can find out why.
+CREATE TABLE rejected_records(
+  did TEXT NOT NULL REFERENCES accounts(did) ON DELETE CASCADE,
+  collection TEXT NOT NULL,
+  rkey TEXT NOT NULL,
+  cid TEXT,
+  reason TEXT NOT NULL,
+  rejected_at BIGINT NOT NULL DEFAULT (extract(epoch from now()) * 1000)::BIGINT,
+  PRIMARY KEY (did, collection, rkey)
+);
+CREATE INDEX rejected_records_did_rejected_at_idx ON rejected_records(did, rejected_at DESC);
@@ -0,0 +1,14 @@
+-- Rules that post authors may apply to their own posts.
+ALTER TABLE labeler_rules ADD COLUMN self_label BOOLEAN NOT NULL DEFAULT false;
+
+-- Rules applied by a post's author through the post record's labels, kept apart from
+-- labels issued by labelers. Rules are referenced loosely as they may be indexed later.
+CREATE TABLE post_self_labels(
+  post_did TEXT NOT NULL,
+  post_rkey TEXT NOT NULL,
+  rule_did TEXT NOT NULL,
+  rule_rkey TEXT NOT NULL,
+  PRIMARY KEY (post_did, post_rkey, rule_did, rule_rkey),
+  FOREIGN KEY (post_did, post_rkey) REFERENCES posts(did, rkey) ON DELETE CASCADE
+);
+CREATE INDEX post_self_labels_rule_idx ON post_self_labels(rule_did, rule_rkey);
@@ -0,0 +1,43 @@
+-- Takedowns issued by appview operators, independent of any labeler.
+CREATE TABLE admin_takedowns(
+  subject_did TEXT NOT NULL,
+  subject_collection TEXT,
+  subject_rkey TEXT,
+  admin_did TEXT NOT NULL,
+  reason TEXT,
+  created_at BIGINT NOT NULL DEFAULT (extract(epoch from now()) * 1000)::BIGINT,
+  UNIQUE NULLS NOT DISTINCT (subject_did, subject_collection, subject_rkey),
+  CHECK (
+    (subject_collection IS NULL AND subject_rkey IS NULL) OR
+    (subject_collection IS NOT NULL AND subject_rkey IS NOT NULL)
+  )
+);
+CREATE INDEX admin_takedowns_created_at_idx ON admin_takedowns(created_at DESC);
+
+-- Every takedown and reversal, kept for auditing.
+CREATE TYPE admin_takedown_action AS ENUM ('takedown', 'reverseTakedown');
+CREATE TABLE admin_takedown_events(
+  id BIGSERIAL PRIMARY KEY,
+  action admin_takedown_action NOT NULL,
+  subject_did TEXT NOT NULL,
+  subject_collection TEXT,
+  subject_rkey TEXT,
+  admin_did TEXT NOT NULL,
+  reason TEXT,
+  created_at BIGINT NOT NULL DEFAULT (extract(epoch from now()) * 1000)::BIGINT
+);
+CREATE INDEX admin_takedown_events_subject_idx
+  ON admin_takedown_events(subject_did, subject_collection, subject_rkey);
+
+-- Whether an operator has taken down the account, or the record when a collection
+-- and rkey are given.
+CREATE FUNCTION is_admin_taken_down(did TEXT, collection TEXT, rkey TEXT)
+RETURNS BOOLEAN LANGUAGE sql STABLE AS $$
+  SELECT EXISTS(
+    SELECT 1 FROM admin_takedowns t
+    WHERE t.subject_did = did AND (
+      t.subject_collection IS NULL OR
+      (t.subject_collection = collection AND t.subject_rkey = rkey)
+    )
+  )
+$$;
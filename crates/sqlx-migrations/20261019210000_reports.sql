@@ -0,0 +1,23 @@
+-- Reports made by users about accounts or records, reviewed by admins and labelers.
+CREATE TYPE report_reason AS ENUM ('spam', 'violation', 'misleading', 'sexual', 'rude', 'other');
+CREATE TYPE report_status AS ENUM ('open', 'acknowledged', 'resolved');
+CREATE TABLE reports(
+  id BIGSERIAL PRIMARY KEY,
+  reporter_did TEXT NOT NULL,
+  subject_did TEXT NOT NULL,
+  subject_collection TEXT,
+  subject_rkey TEXT,
+  reason_type report_reason NOT NULL,
+  reason TEXT,
+  status report_status NOT NULL DEFAULT 'open',
+  created_at BIGINT NOT NULL DEFAULT (extract(epoch from now()) * 1000)::BIGINT,
+  updated_at BIGINT,
+  updated_by TEXT,
+  CHECK (
+    (subject_collection IS NULL AND subject_rkey IS NULL) OR
+    (subject_collection IS NOT NULL AND subject_rkey IS NOT NULL)
+  )
+);
+CREATE INDEX reports_reporter_created_at_idx ON reports(reporter_did, created_at DESC);
+CREATE INDEX reports_status_idx ON reports(status, id DESC);
+CREATE INDEX reports_subject_idx ON reports(subject_did, subject_collection, subject_rkey);
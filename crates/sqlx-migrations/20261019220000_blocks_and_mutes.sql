@@ -0,0 +1,31 @@
+-- Public blocks, ingested from net.gifdex.graph.block records.
+CREATE TABLE blocks(
+  did TEXT NOT NULL REFERENCES accounts(did) ON DELETE CASCADE,
+  rkey TEXT NOT NULL,
+  subject_did TEXT NOT NULL,
+  created_at BIGINT NOT NULL,
+  indexed_at BIGINT NOT NULL DEFAULT (extract(epoch from now()) * 1000)::BIGINT,
+  PRIMARY KEY (did, rkey)
+);
+CREATE INDEX blocks_pair_idx ON blocks(did, subject_did);
+CREATE INDEX blocks_subject_idx ON blocks(subject_did, did);
+
+-- Private mutes, set through the AppView and never published.
+CREATE TABLE mutes(
+  did TEXT NOT NULL,
+  subject_did TEXT NOT NULL,
+  created_at BIGINT NOT NULL DEFAULT (extract(epoch from now()) * 1000)::BIGINT,
+  PRIMARY KEY (did, subject_did)
+);
+CREATE INDEX mutes_created_at_idx ON mutes(did, created_at DESC);
+
+-- Whether the author's content is hidden from the viewer because either has blocked the
+-- other or the viewer has muted the author. Anonymous viewers see everything.
+CREATE FUNCTION is_hidden_from_viewer(viewer TEXT, author TEXT)
+RETURNS BOOLEAN LANGUAGE sql STABLE AS $$
+  SELECT viewer IS NOT NULL AND (
+    EXISTS(SELECT 1 FROM blocks b WHERE b.did = viewer AND b.subject_did = author) OR
+    EXISTS(SELECT 1 FROM blocks b WHERE b.did = author AND b.subject_did = viewer) OR
+    EXISTS(SELECT 1 FROM mutes m WHERE m.did = viewer AND m.subject_did = author)
+  )
+$$;
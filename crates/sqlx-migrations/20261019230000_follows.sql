@@ -0,0 +1,31 @@
+-- Follows, ingested from net.gifdex.graph.follow records.
+CREATE TABLE follows(
+  did TEXT NOT NULL REFERENCES accounts(did) ON DELETE CASCADE,
+  rkey TEXT NOT NULL,
+  subject_did TEXT NOT NULL,
+  created_at BIGINT NOT NULL,
+  indexed_at BIGINT NOT NULL DEFAULT (extract(epoch from now()) * 1000)::BIGINT,
+  PRIMARY KEY (did, rkey)
+);
+-- An account can only follow another once, so duplicate follow records don't inflate counts.
+CREATE UNIQUE INDEX follows_pair_idx ON follows(did, subject_did);
+CREATE INDEX follows_subject_idx ON follows(subject_did);
+
+-- Follows counted towards follower and following counts: those between active accounts that
+-- haven't been taken down, where neither account has blocked the other.
+CREATE VIEW counted_follows AS
+SELECT f.did, f.subject_did
+FROM follows f
+INNER JOIN accounts follower ON follower.did = f.did
+INNER JOIN accounts subject ON subject.did = f.subject_did
+WHERE follower.is_active AND subject.is_active
+  AND NOT is_admin_taken_down(f.did, NULL, NULL)
+  AND NOT is_admin_taken_down(f.subject_did, NULL, NULL)
+  AND NOT EXISTS(
+    SELECT 1 FROM blocks b
+    WHERE (b.did = f.did AND b.subject_did = f.subject_did)
+       OR (b.did = f.subject_did AND b.subject_did = f.did)
+  );
+
+-- Timelines read the newest posts of many authors at once.
+CREATE INDEX posts_did_created_at_idx ON posts(did, created_at DESC);
@@ -0,0 +1,42 @@
+-- Collections of posts, ingested from net.gifdex.feed.collection records.
+CREATE TYPE collection_ordering AS ENUM ('manual', 'newest', 'oldest');
+CREATE TABLE collections(
+  did TEXT NOT NULL REFERENCES accounts(did) ON DELETE CASCADE,
+  rkey TEXT NOT NULL,
+  title TEXT NOT NULL,
+  description TEXT,
+  ordering collection_ordering NOT NULL DEFAULT 'manual',
+  created_at BIGINT NOT NULL,
+  indexed_at BIGINT NOT NULL DEFAULT (extract(epoch from now()) * 1000)::BIGINT,
+  PRIMARY KEY (did, rkey)
+);
+CREATE INDEX collections_did_created_at_idx ON collections(did, created_at DESC);
+
+-- Posts added to collections, ingested from net.gifdex.feed.collectionItem records.
+-- Items may be indexed before their collection, so they aren't tied to it by a foreign key.
+CREATE TABLE collection_items(
+  did TEXT NOT NULL REFERENCES accounts(did) ON DELETE CASCADE,
+  rkey TEXT NOT NULL,
+  collection_rkey TEXT NOT NULL,
+  post_did TEXT NOT NULL,
+  post_rkey TEXT NOT NULL,
+  position BIGINT,
+  created_at BIGINT NOT NULL,
+  indexed_at BIGINT NOT NULL DEFAULT (extract(epoch from now()) * 1000)::BIGINT,
+  PRIMARY KEY (did, rkey)
+);
+CREATE UNIQUE INDEX collection_items_collection_post_idx
+  ON collection_items(did, collection_rkey, post_did, post_rkey);
+CREATE INDEX collection_items_post_idx ON collection_items(post_did, post_rkey);
+
+-- Number of items in a collection whose post is indexed and visible to everyone.
+CREATE FUNCTION collection_item_count(collection_did TEXT, collection_rkey TEXT)
+RETURNS BIGINT LANGUAGE sql STABLE AS $$
+  SELECT COUNT(*) FROM collection_items ci
+  INNER JOIN posts p ON p.did = ci.post_did AND p.rkey = ci.post_rkey
+  INNER JOIN accounts a ON a.did = p.did
+  WHERE ci.did = collection_did AND ci.collection_rkey = $2
+    AND a.is_active
+    AND NOT is_admin_taken_down(a.did, NULL, NULL)
+    AND NOT is_admin_taken_down(p.did, 'net.gifdex.feed.post', p.rkey)
+$$;
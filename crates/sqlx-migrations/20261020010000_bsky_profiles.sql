@@ -0,0 +1,24 @@
+-- Whether the account has a net.gifdex.actor.profile record, whose fields then take priority
+-- over its Bluesky profile even when they're empty.
+ALTER TABLE accounts ADD COLUMN has_profile BOOLEAN NOT NULL DEFAULT false;
+UPDATE accounts SET has_profile = true
+WHERE display_name IS NOT NULL OR pronouns IS NOT NULL OR avatar_blob_cid IS NOT NULL;
+
+-- Bluesky profiles, ingested from app.bsky.actor.profile records when enabled.
+CREATE TABLE bsky_profiles(
+  did TEXT PRIMARY KEY REFERENCES accounts(did) ON DELETE CASCADE,
+  display_name TEXT,
+  avatar_blob_cid TEXT,
+  indexed_at BIGINT NOT NULL DEFAULT (extract(epoch from now()) * 1000)::BIGINT
+);
+
+-- The profile shown for each account, so read paths can join it alongside `accounts` instead of
+-- looking each column up again per row. Accounts without a Gifdex profile fall back to their
+-- Bluesky profile.
+CREATE VIEW account_profiles AS
+SELECT a.did,
+  a.handle,
+  CASE WHEN a.has_profile THEN a.display_name ELSE b.display_name END AS display_name,
+  CASE WHEN a.has_profile THEN a.avatar_blob_cid ELSE b.avatar_blob_cid END AS avatar_blob_cid
+FROM accounts a
+LEFT JOIN bsky_profiles b ON b.did = a.did;
@@ -0,0 +1,14 @@
+-- When the account's handle was last verified to resolve back to its DID. Handles that have
+-- never been verified, including those stored before verification was introduced, are shown as
+-- `handle.invalid` until ingest verifies them.
+ALTER TABLE accounts ADD COLUMN handle_verified_at BIGINT;
+
+-- Handles that don't resolve back to the account are shown as `handle.invalid`.
+CREATE OR REPLACE VIEW account_profiles AS
+SELECT a.did,
+  CASE WHEN a.handle_verified_at IS NULL AND a.handle IS NOT NULL THEN 'handle.invalid'
+    ELSE a.handle END AS handle,
+  CASE WHEN a.has_profile THEN a.display_name ELSE b.display_name END AS display_name,
+  CASE WHEN a.has_profile THEN a.avatar_blob_cid ELSE b.avatar_blob_cid END AS avatar_blob_cid
+FROM accounts a
+LEFT JOIN bsky_profiles b ON b.did = a.did;
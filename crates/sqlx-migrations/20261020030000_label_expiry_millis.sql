@@ -0,0 +1,9 @@
+-- Label expiry times were stored in microseconds, unlike every other timestamp, so the CDN's
+-- takedown checks (which compare against the current time in milliseconds) treated expiring
+-- labels as active long after they expired. Ingest now stores milliseconds and existing values
+-- are converted here, including those already copied into tombstones.
+--
+-- Only values too large to be milliseconds (past the year 5000) are converted, so rows that were
+-- already stored in milliseconds are left alone.
+UPDATE labels SET expires_at = expires_at / 1000 WHERE expires_at > 100000000000000;
+UPDATE label_tombstones SET expires_at = expires_at / 1000 WHERE expires_at > 100000000000000;
@@ -0,0 +1,6 @@
+-- Repositories that relay discovery asked tap to track. Discovery only ever untracks these, so
+-- repositories added by an operator are left alone.
+CREATE TABLE discovered_repos(
+  did TEXT PRIMARY KEY,
+  discovered_at BIGINT NOT NULL DEFAULT (extract(epoch from now()) * 1000)::BIGINT
+);